@@ -1,12 +1,14 @@
 use apache_avro::{schema::*, Schema};
+use std::collections::HashMap;
+
+use crate::generated_schema::{needs_generated_enum, LogicalType};
 
 
 pub fn all_schemas_to_generate(root_schemas: Vec<&Schema>) -> Vec<&Schema> 
 {
     let sub_schemas: Vec<&Schema> = root_schemas
         .iter()
-        .map(|s| schemas_to_generate(s))
-        .flat_map(|s|s)
+        .flat_map(|s| schemas_to_generate(s))
         .collect();
 
     root_schemas
@@ -27,6 +29,7 @@ pub fn schemas_to_generate(schema: &Schema) -> Vec<&Schema>
             list
         },
         Schema::Enum(_) => Vec::from([schema]),// TODO:  VERIFY
+        Schema::Fixed(_) => Vec::from([schema]),
         _ => Vec::new(),
     }
 }
@@ -36,8 +39,7 @@ pub fn schemas_to_generate_union(schema: &UnionSchema) -> Vec<&Schema> {
     schema
     .variants()
     .iter()
-    .map(|f| schemas_to_generate(&f))
-    .flat_map(|c| c)
+    .flat_map(|f| schemas_to_generate(f))
     .collect()
 }
 
@@ -46,7 +48,165 @@ pub fn schemas_to_generate_record(schema: &RecordSchema) -> Vec<&Schema> {
 
     schema.fields
     .iter()
-    .map(|f| schemas_to_generate(&f.schema))
-    .flat_map(|c| c)
+    .flat_map(|f| schemas_to_generate(&f.schema))
+    .collect()
+}
+
+/// Return the unions used by the fields of a record which need a generated enum.
+/// Sub records are not browsed, their unions are generated with them.
+pub fn unions_to_generate_record(schema: &RecordSchema) -> Vec<&UnionSchema> {
+
+    schema.fields
+    .iter()
+    .flat_map(|f| unions_to_generate(&f.schema))
     .collect()
 }
+
+pub fn unions_to_generate(schema: &Schema) -> Vec<&UnionSchema> {
+    match schema {
+        Schema::Array(subtype) => unions_to_generate(subtype),
+        Schema::Map(subtype) => unions_to_generate(subtype),
+        Schema::Union(union_schema) => {
+            let mut list: Vec<&UnionSchema> = union_schema
+                .variants()
+                .iter()
+                .flat_map(unions_to_generate)
+                .collect();
+            if needs_generated_enum(union_schema) {
+                list.push(union_schema);
+            }
+            list
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Return the unions which need a generated enum with the namespace where it's generated:
+/// the namespace of the record which uses it, or the generation namespace of a top level union, array or map
+pub fn unions_with_namespace<'a>(schemas: &[&'a Schema]) -> Vec<(Namespace, &'a UnionSchema)> {
+    schemas
+        .iter()
+        .flat_map(|schema| match schema {
+            Schema::Record(record_schema) => unions_to_generate_record(record_schema)
+                .into_iter()
+                .map(|u| (record_schema.name.namespace.to_owned(), u))
+                .collect(),
+            Schema::Union(_) | Schema::Array(_) | Schema::Map(_) => unions_to_generate(schema)
+                .into_iter()
+                .map(|u| (generation_namespace(schema), u))
+                .collect(),
+            _ => Vec::new(),
+        })
+        .collect()
+}
+
+/// Namespace where the types of a schema are generated: the namespace of a named schema,
+/// or the namespace of the first named type used by an union, an array or a map
+pub fn generation_namespace(schema: &Schema) -> Namespace {
+    match schema {
+        Schema::Array(subtype) | Schema::Map(subtype) => generation_namespace(subtype),
+        Schema::Union(union_schema) => union_schema.variants().iter().find_map(generation_namespace),
+        _ => schema.namespace(),
+    }
+}
+
+/// Named schemas (records, enums and fixed) found in the sources, by full name
+pub type NamedSchemas<'a> = HashMap<Name, &'a Schema>;
+
+/// Return all the named schemas defined in the schemas and their sub schemas
+pub fn named_schemas<'a>(schemas: &[&'a Schema]) -> NamedSchemas<'a> {
+
+    let mut named = NamedSchemas::new();
+    for schema in schemas {
+        add_named_schemas(schema, &mut named);
+    }
+    named
+}
+
+fn add_named_schemas<'a>(schema: &'a Schema, named: &mut NamedSchemas<'a>) {
+    match schema {
+        Schema::Array(subtype) | Schema::Map(subtype) => add_named_schemas(subtype, named),
+        Schema::Union(union_schema) => {
+            for variant in union_schema.variants() {
+                add_named_schemas(variant, named);
+            }
+        }
+        Schema::Record(record_schema) => {
+            named.insert(record_schema.name.to_owned(), schema);
+            for field in record_schema.fields.iter() {
+                add_named_schemas(&field.schema, named);
+            }
+        }
+        Schema::Enum(EnumSchema { name, .. }) | Schema::Fixed(FixedSchema { name, .. }) => {
+            named.insert(name.to_owned(), schema);
+        }
+        Schema::Decimal(decimal_schema) => add_named_schemas(&decimal_schema.inner, named),
+        _ => {}
+    }
+}
+
+/// A schema uses bytes when it's bytes, or when its items, values, variants or fields use them.
+/// The bytes of a decimal are read by the decimal glue and don't count
+pub fn uses_bytes(schema: &Schema) -> bool {
+    match schema {
+        Schema::Bytes => true,
+        Schema::Array(subtype) | Schema::Map(subtype) => uses_bytes(subtype),
+        Schema::Union(union_schema) => union_schema.variants().iter().any(uses_bytes),
+        Schema::Record(record_schema) => record_schema.fields.iter().any(|f| uses_bytes(&f.schema)),
+        _ => false,
+    }
+}
+
+/// A schema uses a decimal when it's a decimal, or when its items, values, variants or fields use one
+pub fn uses_decimal(schema: &Schema) -> bool {
+    match schema {
+        Schema::Decimal(_) => true,
+        Schema::Array(subtype) | Schema::Map(subtype) => uses_decimal(subtype),
+        Schema::Union(union_schema) => union_schema.variants().iter().any(uses_decimal),
+        Schema::Record(record_schema) => record_schema.fields.iter().any(|f| uses_decimal(&f.schema)),
+        _ => false,
+    }
+}
+
+/// A schema uses a fixed when it's a fixed or a duration (a fixed of 12 bytes), or when its items, values, variants or fields use one.
+/// The fixed inside a decimal is read by the decimal glue and doesn't count
+pub fn uses_fixed(schema: &Schema) -> bool {
+    match schema {
+        Schema::Fixed(_) | Schema::Duration => true,
+        Schema::Array(subtype) | Schema::Map(subtype) => uses_fixed(subtype),
+        Schema::Union(union_schema) => union_schema.variants().iter().any(uses_fixed),
+        Schema::Record(record_schema) => record_schema.fields.iter().any(|f| uses_fixed(&f.schema)),
+        _ => false,
+    }
+}
+
+/// A schema uses a generated union enum when it's an union of several not null types, or when its items, values, variants or fields use one
+pub fn uses_union(schema: &Schema) -> bool {
+    match schema {
+        Schema::Union(union_schema) => needs_generated_enum(union_schema) || union_schema.variants().iter().any(uses_union),
+        Schema::Array(subtype) | Schema::Map(subtype) => uses_union(subtype),
+        Schema::Record(record_schema) => record_schema.fields.iter().any(|f| uses_union(&f.schema)),
+        _ => false,
+    }
+}
+
+/// A schema uses a duration when it's a duration, or when its items, values, variants or fields use one
+pub fn uses_duration(schema: &Schema) -> bool {
+    match schema {
+        Schema::Duration => true,
+        Schema::Array(subtype) | Schema::Map(subtype) => uses_duration(subtype),
+        Schema::Union(union_schema) => union_schema.variants().iter().any(uses_duration),
+        Schema::Record(record_schema) => record_schema.fields.iter().any(|f| uses_duration(&f.schema)),
+        _ => false,
+    }
+}
+
+/// The logical types used by a schema, its items, values, variants or fields
+pub fn logical_types(schema: &Schema) -> Vec<LogicalType> {
+    match schema {
+        Schema::Array(subtype) | Schema::Map(subtype) => logical_types(subtype),
+        Schema::Union(union_schema) => union_schema.variants().iter().flat_map(logical_types).collect(),
+        Schema::Record(record_schema) => record_schema.fields.iter().flat_map(|f| logical_types(&f.schema)).collect(),
+        _ => LogicalType::from_schema(schema).into_iter().collect(),
+    }
+}
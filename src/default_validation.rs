@@ -0,0 +1,287 @@
+use apache_avro::schema::{Name, RecordSchema};
+use apache_avro::Schema;
+use serde_json::Value;
+use std::collections::HashSet;
+
+use crate::browse_sub_schemas::{named_schemas, NamedSchemas};
+use crate::error::{AvrogenError, Location};
+use crate::generated_schema::is_uuid;
+use crate::Result;
+
+/// Problems found in a default value, each with the path of the value from the field and the message
+type Problems = Vec<(String, String)>;
+
+/// Check the default values of all the record fields against their schema.
+///
+/// Each schema comes with the path of its file. All the problems are reported together as `AvrogenError::Multiple`, each with the file, the record and the field path.
+pub fn validate_defaults(schemas: &[(&Schema, &str)]) -> Result<()> {
+    let all_schemas: Vec<&Schema> = schemas.iter().map(|(schema, _)| *schema).collect();
+    let validator = DefaultValidator {
+        named_schemas: named_schemas(&all_schemas),
+    };
+
+    let mut checked_records = HashSet::new();
+    let mut errors = Vec::new();
+
+    for (schema, file_path) in schemas {
+        let mut records = Vec::new();
+        records_of(schema, &mut records);
+
+        for record_schema in records {
+            if checked_records.insert(&record_schema.name) {
+                validator.check_record(record_schema, file_path, &mut errors);
+            }
+        }
+    }
+
+    match errors.len() {
+        0 => Ok(()),
+        1 => Err(errors.remove(0)),
+        _ => Err(AvrogenError::Multiple(errors)),
+    }
+}
+
+/// Return the records defined in a schema, with the records defined inside them
+fn records_of<'a>(schema: &'a Schema, records: &mut Vec<&'a RecordSchema>) {
+    match schema {
+        Schema::Array(subtype) | Schema::Map(subtype) => records_of(subtype, records),
+        Schema::Union(union_schema) => {
+            for variant in union_schema.variants() {
+                records_of(variant, records);
+            }
+        }
+        Schema::Record(record_schema) => {
+            records.push(record_schema);
+            for field in record_schema.fields.iter() {
+                records_of(&field.schema, records);
+            }
+        }
+        _ => {}
+    }
+}
+
+struct DefaultValidator<'a> {
+    named_schemas: NamedSchemas<'a>,
+}
+
+impl DefaultValidator<'_> {
+    fn check_record(
+        &self,
+        record_schema: &RecordSchema,
+        file_path: &str,
+        errors: &mut Vec<AvrogenError>,
+    ) {
+        for field in record_schema.fields.iter() {
+            if let Some(default_value) = &field.default {
+                let mut problems = Problems::new();
+                self.check_value(default_value, &field.schema, &field.name, &mut problems);
+
+                errors.extend(problems.into_iter().map(|(field_path, message)| {
+                    AvrogenError::InvalidDefault {
+                        location: Location {
+                            file: Some(file_path.to_owned()),
+                            type_name: Some(record_schema.name.fullname(None)),
+                            field_path: Some(field_path),
+                            ..Default::default()
+                        },
+                        message,
+                    }
+                }));
+            }
+        }
+    }
+
+    /// Add a problem for each part of the value which doesn't match the schema, the path is the location of the value
+    fn check_value(&self, value: &Value, schema: &Schema, path: &str, problems: &mut Problems) {
+        let is_valid = match (schema, value) {
+            (Schema::Null, Value::Null) => true,
+            (Schema::Boolean, Value::Bool(_)) => true,
+            (Schema::Int | Schema::Date, Value::Number(number)) => {
+                number.as_i64().is_some_and(|n| i32::try_from(n).is_ok())
+            }
+            (Schema::TimeMillis, Value::Number(number)) => {
+                check_time_of_day(number.as_i64(), 86_400_000, path, problems);
+                true
+            }
+            (Schema::TimeMicros, Value::Number(number)) => {
+                check_time_of_day(number.as_i64(), 86_400_000_000, path, problems);
+                true
+            }
+            (
+                Schema::Long
+                | Schema::TimestampMillis
+                | Schema::TimestampMicros
+                | Schema::LocalTimestampMillis
+                | Schema::LocalTimestampMicros,
+                Value::Number(number),
+            ) => number.is_i64(),
+            (Schema::Float | Schema::Double, Value::Number(_)) => true,
+            (Schema::String, Value::String(_)) => true,
+            (Schema::Uuid, Value::String(uuid)) => {
+                if !is_uuid(uuid) {
+                    problems.push((path.to_owned(), format!(
+                        "the default value {uuid:?} is not a uuid with hexadecimal groups of 8-4-4-4-12 digits"
+                    )));
+                }
+                true
+            }
+            (Schema::Bytes, Value::String(bytes)) => {
+                check_bytes(bytes, None, path, problems);
+                true
+            }
+            (Schema::Fixed(fixed_schema), Value::String(bytes)) => {
+                check_bytes(bytes, Some(fixed_schema.size), path, problems);
+                true
+            }
+            (Schema::Duration, Value::String(bytes)) => {
+                check_bytes(bytes, Some(12), path, problems);
+                true
+            }
+            (Schema::Decimal(decimal_schema), Value::String(_)) => {
+                self.check_value(value, &decimal_schema.inner, path, problems);
+                true
+            }
+            (Schema::Enum(enum_schema), Value::String(symbol)) => {
+                if !enum_schema.symbols.contains(symbol) {
+                    problems.push((
+                        path.to_owned(),
+                        format!(
+                            "the default value {value} is not a symbol of the enum {}",
+                            enum_schema.name.fullname(None)
+                        ),
+                    ));
+                }
+                true
+            }
+            (Schema::Array(items_schema), Value::Array(items)) => {
+                for (index, item) in items.iter().enumerate() {
+                    self.check_value(item, items_schema, &format!("{path}[{index}]"), problems);
+                }
+                true
+            }
+            (Schema::Map(values_schema), Value::Object(values)) => {
+                for (key, item) in values.iter() {
+                    self.check_value(item, values_schema, &format!("{path}.{key}"), problems);
+                }
+                true
+            }
+            (Schema::Record(record_schema), Value::Object(values)) => {
+                for field in record_schema.fields.iter() {
+                    let field_path = format!("{path}.{}", field.name);
+                    match values.get(&field.name) {
+                        Some(field_value) => {
+                            self.check_value(field_value, &field.schema, &field_path, problems)
+                        }
+                        None if field.default.is_none() => problems.push((
+                            field_path.to_owned(),
+                            "the value is missing and the field has no default value".to_string(),
+                        )),
+                        None => {}
+                    }
+                }
+                true
+            }
+            // The default value of an union is a value of its first type
+            (Schema::Union(union_schema), _) => {
+                match union_schema.variants().first() {
+                    Some(Schema::Null) if !value.is_null() => problems.push((path.to_owned(), format!(
+                        "the default value {value} doesn't match the first type of the union, which is null"
+                    ))),
+                    Some(first_variant) => self.check_value(value, first_variant, path, problems),
+                    None => problems.push((path.to_owned(), "an empty union can't have a default value".to_string())),
+                }
+                true
+            }
+            (Schema::Ref { name }, _) => {
+                match self.named_schemas.get(name) {
+                    Some(definition) => self.check_value(value, definition, path, problems),
+                    None => problems.push((
+                        path.to_owned(),
+                        format!("the type {} is not defined", name.fullname(None)),
+                    )),
+                }
+                true
+            }
+            _ => false,
+        };
+
+        if !is_valid {
+            problems.push((
+                path.to_owned(),
+                format!(
+                    "the default value {value} doesn't match the type {}",
+                    type_description(schema)
+                ),
+            ));
+        }
+    }
+}
+
+/// Bytes and fixed values are strings of characters in the range 0-255, a fixed value has the size of the fixed
+fn check_bytes(bytes: &str, size: Option<usize>, path: &str, problems: &mut Problems) {
+    if bytes.chars().any(|c| u32::from(c) > 255) {
+        problems.push((
+            path.to_owned(),
+            format!("the default value {bytes:?} has characters out of the range 0-255"),
+        ));
+    }
+
+    let length = bytes.chars().count();
+    match size {
+        Some(size) if size != length => problems.push((
+            path.to_owned(),
+            format!("the default value {bytes:?} has {length} bytes instead of {size}"),
+        )),
+        _ => {}
+    }
+}
+
+/// Times are a number of units after midnight, they are less than the number of units in a day
+fn check_time_of_day(units: Option<i64>, units_per_day: i64, path: &str, problems: &mut Problems) {
+    match units {
+        Some(units) if (0..units_per_day).contains(&units) => {}
+        Some(units) => problems.push((
+            path.to_owned(),
+            format!(
+                "the default value {units} is out of the range 0-{}",
+                units_per_day - 1
+            ),
+        )),
+        None => problems.push((
+            path.to_owned(),
+            "the default value is not an integer".to_string(),
+        )),
+    }
+}
+
+fn type_description(schema: &Schema) -> String {
+    let named_type = |kind: &str, name: &Name| format!("{kind} {}", name.fullname(None));
+
+    match schema {
+        Schema::Null => "null".to_string(),
+        Schema::Boolean => "boolean".to_string(),
+        Schema::Int => "int".to_string(),
+        Schema::Long => "long".to_string(),
+        Schema::Float => "float".to_string(),
+        Schema::Double => "double".to_string(),
+        Schema::Bytes => "bytes".to_string(),
+        Schema::String => "string".to_string(),
+        Schema::Array(_) => "array".to_string(),
+        Schema::Map(_) => "map".to_string(),
+        Schema::Union(_) => "union".to_string(),
+        Schema::Record(record_schema) => named_type("record", &record_schema.name),
+        Schema::Enum(enum_schema) => named_type("enum", &enum_schema.name),
+        Schema::Fixed(fixed_schema) => named_type("fixed", &fixed_schema.name),
+        Schema::Decimal(_) => "decimal".to_string(),
+        Schema::Uuid => "uuid".to_string(),
+        Schema::Date => "date".to_string(),
+        Schema::TimeMillis => "time-millis".to_string(),
+        Schema::TimeMicros => "time-micros".to_string(),
+        Schema::TimestampMillis => "timestamp-millis".to_string(),
+        Schema::TimestampMicros => "timestamp-micros".to_string(),
+        Schema::LocalTimestampMillis => "local-timestamp-millis".to_string(),
+        Schema::LocalTimestampMicros => "local-timestamp-micros".to_string(),
+        Schema::Duration => "duration".to_string(),
+        Schema::Ref { name } => name.fullname(None),
+    }
+}
@@ -2,12 +2,26 @@ use derive_more::From;
 
 pub type Result<T> = core::result::Result<T, AvrogenError>;
 
+/// Location of a problem in the sources, each part is known or not depending on where the problem is found
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Location
+{
+    /// Path of the source file
+    pub file: Option<String>,
+
+    /// Line and column in the source file, starting at 1
+    pub position: Option<(usize, usize)>,
+
+    /// Full name of the Avro type
+    pub type_name: Option<String>,
+
+    /// Path of the field in the type, like `position.x` or `states[1]`
+    pub field_path: Option<String>,
+}
+
 #[derive(Debug, From)]
 pub enum AvrogenError
 {
-    #[from]
-    Custom(String),
-
     #[from]
     Io(std::io::Error),
 
@@ -23,25 +37,174 @@ pub enum AvrogenError
     #[from]
     Glob(glob::GlobError),
 
+    /// A top level schema which can't be generated as a Rust type
+    UnsupportedSchema{ schema: String, reason: String },
+
+    /// A source which isn't valid JSON or IDL, or a schema rejected by apache_avro
+    Parse{ location: Location, message: String },
+
+    /// A default value which doesn't match the schema of its field
+    InvalidDefault{ location: Location, message: String },
+
+    /// A type which can't be generated
+    Generation{ location: Location, message: String },
+
+    /// Several errors reported together, like all the invalid default values
+    Multiple(Vec<AvrogenError>),
+
+    /// An error without location, like an apache_avro error, found at a location of the sources
+    Located{ location: Location, source: Box<AvrogenError> },
+
 }
 
-impl From<&str> for AvrogenError{
-    fn from(value: &str) -> Self {
-        AvrogenError::Custom(value.to_owned())
+impl AvrogenError{
+    /// Error of a source file which can't be parsed, at a position when it's known
+    pub fn parse(file: &str, position: Option<(usize, usize)>, message: impl std::fmt::Display) -> Self {
+        AvrogenError::Parse{
+            location: Location{ file: Some(file.to_owned()), position, ..Default::default() },
+            message: message.to_string(),
+        }
+    }
+
+    /// Error of a source file which isn't valid JSON, at the position given by serde_json
+    pub fn json(file: &str, error: &serde_json::Error) -> Self {
+        // serde_json reports the column 0 when the error is a line ending
+        let position = (error.line() > 0).then(|| (error.line(), error.column().max(1)));
+        Self::parse(file, position, error)
+    }
+
+    /// Default value which can't be written, the callers add its location
+    pub fn invalid_default(message: impl std::fmt::Display) -> Self {
+        AvrogenError::InvalidDefault{ location: Location::default(), message: message.to_string() }
+    }
+
+    /// Type which can't be generated, the callers add its location
+    pub fn generation(message: impl std::fmt::Display) -> Self {
+        AvrogenError::Generation{ location: Location::default(), message: message.to_string() }
+    }
+
+    /// Location of the error, when it has one
+    pub fn location(&self) -> Option<&Location> {
+        match self {
+            AvrogenError::Parse{ location, .. }
+            | AvrogenError::InvalidDefault{ location, .. }
+            | AvrogenError::Generation{ location, .. }
+            | AvrogenError::Located{ location, .. } => Some(location),
+            _ => None,
+        }
+    }
+
+    /// Set the source file of the error when it's unknown
+    pub fn in_file(self, file: &str) -> Self {
+        self.with_location(&|location| {
+            location.file.get_or_insert_with(|| file.to_owned());
+        })
+    }
+
+    /// Set the full name of the type of the error when it's unknown
+    pub fn in_type(self, type_name: &str) -> Self {
+        self.with_location(&|location| {
+            location.type_name.get_or_insert_with(|| type_name.to_owned());
+        })
+    }
+
+    /// Add the field at the start of the path of the error
+    pub fn in_field(self, field_name: &str) -> Self {
+        self.with_location(&|location| {
+            location.field_path = Some(match location.field_path.take() {
+                Some(field_path) => format!("{field_name}.{field_path}"),
+                None => field_name.to_owned(),
+            });
+        })
+    }
+
+    /// Update the location of the error, the errors without location are wrapped with it
+    fn with_location(self, update: &dyn Fn(&mut Location)) -> Self {
+        match self {
+            AvrogenError::Parse{ mut location, message } => { update(&mut location); AvrogenError::Parse{ location, message } }
+            AvrogenError::InvalidDefault{ mut location, message } => { update(&mut location); AvrogenError::InvalidDefault{ location, message } }
+            AvrogenError::Generation{ mut location, message } => { update(&mut location); AvrogenError::Generation{ location, message } }
+            AvrogenError::Located{ mut location, source } => { update(&mut location); AvrogenError::Located{ location, source } }
+            AvrogenError::Multiple(errors) => AvrogenError::Multiple(errors.into_iter().map(|e| e.with_location(update)).collect()),
+            // The unsupported schemas are named by the schema itself
+            AvrogenError::UnsupportedSchema{ .. } => self,
+            error => {
+                let mut location = Location::default();
+                update(&mut location);
+                AvrogenError::Located{ location, source: Box::new(error) }
+            }
+        }
+    }
+
+    /// Message of the error, without its location
+    fn message(&self) -> String {
+        match self {
+            AvrogenError::Io(e) => e.to_string(),
+            AvrogenError::Fmt(e) => e.to_string(),
+            AvrogenError::Avro(e) => e.to_string(),
+            AvrogenError::GlobPattern(e) => e.to_string(),
+            AvrogenError::Glob(e) => e.to_string(),
+            AvrogenError::UnsupportedSchema{ schema, reason } => format!("the schema {schema} is not supported: {reason}"),
+            AvrogenError::Parse{ message, .. }
+            | AvrogenError::InvalidDefault{ message, .. }
+            | AvrogenError::Generation{ message, .. } => message.to_owned(),
+            AvrogenError::Multiple(errors) => format!("{} errors", errors.len()),
+            AvrogenError::Located{ source, .. } => source.message(),
+        }
     }
 }
 
+/// The errors are displayed like the diagnostics of a compiler:
+/// ```text
+/// error: the default value "ten" doesn't match the type int
+///   --> schemas/sensor.avsc
+///    = field: com.example.Sensor.count
+/// ```
 impl std::fmt::Display for AvrogenError{
   fn fmt(&self,fmt: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error>{
-    match self {
-        AvrogenError::Custom(e) => write!(fmt,"{e}"),
-        AvrogenError::Io(e) => write!(fmt,"{e}"),
-        AvrogenError::Fmt(e) => write!(fmt,"{e}"),
-        AvrogenError::Avro(e) => write!(fmt,"{e}"),
-        AvrogenError::GlobPattern(e) => write!(fmt,"{e}"),
-        AvrogenError::Glob(e) => write!(fmt,"{e}"),
+    if let AvrogenError::Multiple(errors) = self {
+        for (index, error) in errors.iter().enumerate() {
+            if index > 0 {
+                writeln!(fmt)?;
+            }
+            write!(fmt,"{error}")?;
+        }
+        return Ok(());
+    }
+
+    write!(fmt,"error: {}",self.message())?;
+
+    let Some(location) = self.location() else {
+        return Ok(());
+    };
+
+    match (&location.file, location.position) {
+        (Some(file), Some((line, column))) => write!(fmt,"\n  --> {file}:{line}:{column}")?,
+        (Some(file), None) => write!(fmt,"\n  --> {file}")?,
+        _ => {}
     }
+
+    match (&location.type_name, &location.field_path) {
+        (Some(type_name), Some(field_path)) => write!(fmt,"\n   = field: {type_name}.{field_path}")?,
+        (Some(type_name), None) => write!(fmt,"\n   = type: {type_name}")?,
+        (None, Some(field_path)) => write!(fmt,"\n   = field: {field_path}")?,
+        (None, None) => {}
+    }
+
+    Ok(())
  }
 }
 
-impl std::error::Error for AvrogenError{}
\ No newline at end of file
+impl std::error::Error for AvrogenError{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AvrogenError::Io(e) => Some(e),
+            AvrogenError::Fmt(e) => Some(e),
+            AvrogenError::Avro(e) => Some(e),
+            AvrogenError::GlobPattern(e) => Some(e),
+            AvrogenError::Glob(e) => Some(e),
+            AvrogenError::Located{ source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
@@ -1,20 +1,285 @@
-use crate::{error::AvrogenError, source::AvroFile, Result};
+use crate::idl_parser::IdlFile;
+use crate::protocol_parser::{build_protocols, messages_records, ProtocolDefinition};
+use crate::default_validation::validate_defaults;
+use crate::{error::{AvrogenError, Location}, idl_parser, source::AvroFile, Result};
+use crate::browse_sub_schemas;
+use apache_avro::schema::{FixedSchema, Name, UnionSchema};
 use apache_avro::Schema;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
 
+/// Default values of the record fields, by record full name and field name
+type FieldDefaults = HashMap<(String, String), Value>;
 
-pub fn parse_schemas(files: Vec<AvroFile>) -> Result<Vec<Schema>> 
+/// Source file of each named type, to locate the generation errors
+pub type TypeFiles = HashMap<Name, String>;
+
+/// Original JSON definition of each named type, with its field defaults, docs and aliases
+pub type TypeDefinitions = HashMap<Name, Value>;
+
+
+/// Parse all the files as one set of schemas.
+///
+/// Named schemas (record, enum, fixed) are parsed together, so a schema can reference a type defined in another file.
+/// Other schemas (union, array, ...) can't be declared in a list, they are parsed alone.
+/// IDL and protocol files are converted to their named schemas, the protocols are returned with their messages.
+/// The source file and the original JSON of each named type are returned too.
+pub fn parse_schemas(files: Vec<AvroFile>) -> Result<(Vec<Schema>, Vec<ProtocolDefinition>, TypeFiles, TypeDefinitions)>
 {
     let mut schema_list = Vec::<Schema>::new();
+    let mut schema_file_paths = Vec::<String>::new();
+    let mut named_schemas = Vec::<(Value, String)>::new();
+    let mut protocols = Vec::<IdlFile>::new();
+    let mut field_defaults = FieldDefaults::new();
+    let mut type_definitions = TypeDefinitions::new();
 
     for file in files {
 
-        let schema = Schema::parse_str(&file.content)
-        .map_err(|e| AvrogenError::Custom(format!("{:?}: {e}",file.file_path)))?;
-        
-        log::debug!("schema {} read",file.file_path);
+        let protocol = if file.is_idl() {
+            idl_parser::parse_idl(&file.content, &file.file_path)?
+        }
+        else {
+            let json_content: Value = serde_json::from_str(&file.content)
+            .map_err(|e| AvrogenError::json(&file.file_path, &e))?;
+
+            match json_content.get("protocol") {
+                Some(_) => idl_parser::parse_protocol_json(&file.content, &file.file_path)?,
+                None => IdlFile{ types: vec![json_content], ..Default::default() }
+            }
+        };
+
+        let mut json_schemas = protocol.types.to_owned();
+
+        if protocol.protocol.is_some() && !protocol.messages.is_empty() {
+            json_schemas.extend(messages_records(&protocol));
+            protocols.push(protocol);
+        }
+
+        for mut json_schema in json_schemas {
+            add_type_definitions(&json_schema, &None, &mut type_definitions);
+            take_field_defaults(&mut json_schema, &None, &mut field_defaults);
+
+            match json_schema {
+                Value::Object(ref object) if object.contains_key("name") => add_named_schema(&mut named_schemas, json_schema, &file.file_path),
+                _ => {
+                    let schema = Schema::parse(&json_schema)
+                    .map_err(|e| AvrogenError::parse(&file.file_path, None, e))?;
+
+                    log::debug!("schema {} read",file.file_path);
+
+                    schema_list.push(schema);
+                    schema_file_paths.push(file.file_path.to_owned());
+                }
+            }
+        }
+    }
+
+    let named_contents: Vec<String> = named_schemas.iter().map(|(json_schema, _)| json_schema.to_string()).collect();
+    let named_contents: Vec<&str> = named_contents.iter().map(String::as_str).collect();
+
+    // The error is located in the file which defines the type it names, the last one for a type defined twice,
+    // an error which names no type of the files, like an unknown type, has no file
+    let parsed_schemas = Schema::parse_list(&named_contents)
+    .map_err(|e| {
+        let (type_name, file_path) = match &e {
+            apache_avro::Error::NameCollision(full_name) | apache_avro::Error::ParsePrimitive(full_name) => named_schemas
+                .iter()
+                .rev()
+                .find(|(json_schema, _)| defines_type(json_schema, full_name))
+                .map(|(_, file_path)| (full_name.to_owned(), file_path.to_owned())),
+            _ => None,
+        }
+        .unzip();
+        AvrogenError::Parse{
+            location: Location{ file: file_path, type_name, ..Default::default() },
+            message: e.to_string(),
+        }
+    })?;
+
+    for (_, file_path) in named_schemas.iter() {
+        log::debug!("schema {} read",file_path);
+    }
+
+    for schema in parsed_schemas.iter() {
+        let full_name = schema.name().map(|n| n.fullname(None));
+        let file_path = named_schemas
+        .iter()
+        .find(|(json_schema, _)| idl_parser::type_full_name(json_schema) == full_name)
+        .map(|(_, file_path)| file_path.to_owned())
+        .unwrap_or_default();
+
+        schema_file_paths.push(file_path);
+    }
+
+    schema_list.extend(parsed_schemas);
+
+    let mut schema_list: Vec<Schema> = schema_list.into_iter().map(|s| restore_schema(s, &field_defaults, &type_definitions)).collect::<Result<_>>()?;
+
+    let schemas_with_files: Vec<(&Schema, &str)> = schema_list.iter().zip(schema_file_paths.iter().map(String::as_str)).collect();
+    validate_defaults(&schemas_with_files)?;
+
+    let mut type_files = TypeFiles::new();
+    for (schema, file_path) in schemas_with_files {
+        for name in browse_sub_schemas::named_schemas(&[schema]).into_keys() {
+            type_files.entry(name).or_insert_with(|| file_path.to_owned());
+        }
+    }
+
+    let protocols = build_protocols(&protocols, &mut schema_list)?;
+
+    Ok((schema_list, protocols, type_files, type_definitions))
+}
+
+/// The same schema can be found multiple times, for example when IDL files import the same file.
+/// Only the first one is kept, different schemas with the same name are kept to report the collision.
+fn add_named_schema(named_schemas: &mut Vec<(Value, String)>, mut json_schema: Value, file_path: &str)
+{
+    // Protocol errors are records for apache_avro
+    if json_schema.get("type") == Some(&Value::String("error".to_string())) {
+        json_schema["type"] = Value::String("record".to_string());
+    }
+
+    let full_name = idl_parser::type_full_name(&json_schema);
 
-        schema_list.push(schema)
+    let already_added = named_schemas
+    .iter()
+    .any(|(s, _)| idl_parser::type_full_name(s) == full_name && *s == json_schema);
+
+    if !already_added {
+        named_schemas.push((json_schema, file_path.to_string()));
+    }
+}
+
+/// Remove the default values of the record fields, they are checked after the parsing by `validate_defaults` to report all the problems with their path.
+fn take_field_defaults(json_schema: &mut Value, namespace: &Option<String>, field_defaults: &mut FieldDefaults)
+{
+    match json_schema {
+        Value::Array(variants) => {
+            for variant in variants.iter_mut() {
+                take_field_defaults(variant, namespace, field_defaults);
+            }
+        }
+        Value::Object(object) => {
+            match object.get("type").and_then(Value::as_str) {
+                Some("record") | Some("error") => {
+                    let Some(name) = json_type_name(object, namespace) else { return };
+
+                    let full_name = name.fullname(None);
+                    let record_namespace = name.namespace;
+
+                    let Some(Value::Array(fields)) = object.get_mut("fields") else { return };
+
+                    for field in fields.iter_mut() {
+                        let Value::Object(field) = field else { continue };
+
+                        if let (Some(field_name), Some(default_value)) = (field.get("name").and_then(Value::as_str).map(str::to_string), field.remove("default")) {
+                            field_defaults.insert((full_name.to_owned(), field_name), default_value);
+                        }
+
+                        if let Some(field_type) = field.get_mut("type") {
+                            take_field_defaults(field_type, &record_namespace, field_defaults);
+                        }
+                    }
+                }
+                Some("array") => if let Some(items) = object.get_mut("items") { take_field_defaults(items, namespace, field_defaults) },
+                Some("map") => if let Some(values) = object.get_mut("values") { take_field_defaults(values, namespace, field_defaults) },
+                _ => if let Some(inner_type @ (Value::Object(_) | Value::Array(_))) = object.get_mut("type") { take_field_defaults(inner_type, namespace, field_defaults) },
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Name of a named type defined in JSON, its namespace is the one of the enclosing type when it has none.
+pub fn json_type_name(object: &Map<String, Value>, namespace: &Option<String>) -> Option<Name>
+{
+    let name = object.get("name")?.as_str()?;
+
+    match name.rsplit_once('.') {
+        Some((name_namespace, name)) => Some(Name{ name: name.to_string(), namespace: Some(name_namespace.to_string()) }),
+        None => {
+            let type_namespace = match object.get("namespace").and_then(Value::as_str) {
+                Some("") => None,
+                Some(ns) => Some(ns.to_string()),
+                None => namespace.to_owned(),
+            };
+            Some(Name{ name: name.to_string(), namespace: type_namespace })
+        }
     }
+}
+
+/// The JSON schema defines the named type, at its top level or inside it
+fn defines_type(json_schema: &Value, full_name: &str) -> bool
+{
+    let mut type_definitions = TypeDefinitions::new();
+    add_type_definitions(json_schema, &None, &mut type_definitions);
+
+    type_definitions.keys().any(|name| name.fullname(None) == full_name)
+}
+
+/// Keep the JSON of the named types defined in a schema, the first definition of a name is kept
+fn add_type_definitions(json_schema: &Value, namespace: &Option<String>, type_definitions: &mut TypeDefinitions)
+{
+    match json_schema {
+        Value::Array(variants) => {
+            for variant in variants {
+                add_type_definitions(variant, namespace, type_definitions);
+            }
+        }
+        Value::Object(object) => {
+            match object.get("type").and_then(Value::as_str) {
+                Some("record" | "error" | "enum" | "fixed") => {
+                    let Some(name) = json_type_name(object, namespace) else { return };
+
+                    if let Some(Value::Array(fields)) = object.get("fields") {
+                        for field_type in fields.iter().filter_map(|f| f.get("type")) {
+                            add_type_definitions(field_type, &name.namespace, type_definitions);
+                        }
+                    }
 
-    return Ok(schema_list);
+                    type_definitions.entry(name).or_insert_with(|| json_schema.to_owned());
+                }
+                Some("array") => if let Some(items) = object.get("items") { add_type_definitions(items, namespace, type_definitions) },
+                Some("map") => if let Some(values) = object.get("values") { add_type_definitions(values, namespace, type_definitions) },
+                _ => if let Some(inner_type) = object.get("type") { add_type_definitions(inner_type, namespace, type_definitions) },
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Put back what apache_avro loses in the parsed schemas: the default values removed by `take_field_defaults`,
+/// and the durations referenced by their name, which are parsed as plain fixed
+fn restore_schema(schema: Schema, field_defaults: &FieldDefaults, type_definitions: &TypeDefinitions) -> Result<Schema>
+{
+    Ok(match schema {
+        Schema::Record(mut record_schema) => {
+            let full_name = record_schema.name.fullname(None);
+            for field in record_schema.fields.iter_mut() {
+                if let Some(default_value) = field_defaults.get(&(full_name.to_owned(), field.name.to_owned())) {
+                    field.default = Some(default_value.to_owned());
+                }
+                field.schema = restore_schema(field.schema.to_owned(), field_defaults, type_definitions)?;
+            }
+            Schema::Record(record_schema)
+        }
+        Schema::Array(items) => Schema::Array(Box::new(restore_schema(*items, field_defaults, type_definitions)?)),
+        Schema::Map(values) => Schema::Map(Box::new(restore_schema(*values, field_defaults, type_definitions)?)),
+        Schema::Union(union_schema) => {
+            let variants = union_schema
+            .variants()
+            .iter()
+            .map(|v| restore_schema(v.to_owned(), field_defaults, type_definitions))
+            .collect::<Result<Vec<Schema>>>()?;
+            Schema::Union(UnionSchema::new(variants)?)
+        }
+        Schema::Ref { ref name } | Schema::Fixed(FixedSchema { ref name, .. }) if is_duration(type_definitions.get(name)) => Schema::Duration,
+        _ => schema,
+    })
+}
+
+/// The JSON definition is a duration, a fixed with the logical type duration
+pub fn is_duration(definition: Option<&Value>) -> bool
+{
+    definition.and_then(|d| d.get("logicalType")) == Some(&Value::String("duration".to_string()))
 }
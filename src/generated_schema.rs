@@ -1,7 +1,24 @@
-
 mod global;
 pub mod namespace;
 mod schema;
 mod field_type;
 mod field_default_value;
 mod field;
+mod protocol;
+mod confluent;
+mod bytes;
+mod decimal;
+mod duration;
+mod values;
+mod logical_types;
+mod module_paths;
+mod time_backend;
+mod schema_json;
+mod canonical_form;
+
+pub use decimal::DecimalBackend;
+pub use field_type::{needs_generated_enum, TypeMapping};
+pub use logical_types::{is_uuid, LogicalType};
+pub use module_paths::{ModuleLayout, ModulePaths, NamespaceRule, RootPath};
+pub use schema_json::{Fingerprint, SchemaDefinitions};
+pub use time_backend::TimeBackend;
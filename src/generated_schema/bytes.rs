@@ -0,0 +1,244 @@
+use apache_avro::Schema;
+use std::fmt::Write;
+
+use super::field_type::get_values_schema;
+use super::module_paths::ModulePaths;
+use crate::Result;
+
+/// Namespace of the serde glue shared by the bytes fields
+pub const BYTES_NAMESPACE: &str = "bytes";
+
+const GLUE_NAME: &str = "Bytes";
+
+/// Serde glue reading and writing the `Vec<u8>` as Avro bytes, generated once
+#[derive(Debug)]
+pub struct GeneratedBytes {}
+
+impl GeneratedBytes {
+    pub fn produce_content(&self) -> Result<String> {
+        let mut content_string = String::new();
+        writeln!(content_string, "/// Serde glue of the bytes fields, serde serializes a `Vec<u8>` as a sequence and the serde deserializer of apache_avro doesn't read a sequence from `Value::Bytes`.")?;
+        writeln!(content_string, "///")?;
+        writeln!(
+            content_string,
+            "/// The fields use it with `#[serde(with = \"Bytes\")]`"
+        )?;
+        write!(content_string, "pub struct Bytes;\r\n\r\n")?;
+        writeln!(content_string, "impl Bytes {{")?;
+        writeln!(content_string, "    pub fn serialize<T: AvroBytes, S: serde::Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {{")?;
+        writeln!(content_string, "        value.serialize_avro_bytes(serializer)")?;
+        write!(content_string, "    }}\r\n\r\n")?;
+        writeln!(content_string, "    pub fn deserialize<'de, T: AvroBytes, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {{")?;
+        writeln!(content_string, "        T::deserialize_avro_bytes(deserializer)")?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+        writeln!(
+            content_string,
+            "/// Bytes, or a container of bytes, serialized as Avro bytes"
+        )?;
+        writeln!(content_string, "pub trait AvroBytes: Sized {{")?;
+        write!(content_string, "    fn serialize_avro_bytes<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;\r\n\r\n")?;
+        writeln!(content_string, "    fn deserialize_avro_bytes<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        writeln!(content_string, "impl AvroBytes for Vec<u8> {{")?;
+        writeln!(content_string, "    fn serialize_avro_bytes<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{")?;
+        writeln!(content_string, "        serializer.serialize_bytes(self)")?;
+        write!(content_string, "    }}\r\n\r\n")?;
+        writeln!(content_string, "    fn deserialize_avro_bytes<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{")?;
+        writeln!(
+            content_string,
+            "        deserializer.deserialize_byte_buf(BytesVisitor::<Self>(std::marker::PhantomData))"
+        )?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+        writeln!(
+            content_string,
+            "impl<T: AvroBytes> AvroBytes for Option<T> {{"
+        )?;
+        writeln!(content_string, "    fn serialize_avro_bytes<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{")?;
+        writeln!(content_string, "        match self {{")?;
+        writeln!(
+            content_string,
+            "            Some(value) => serializer.serialize_some(&BytesValue(value)),"
+        )?;
+        writeln!(
+            content_string,
+            "            None => serializer.serialize_none(),"
+        )?;
+        writeln!(content_string, "        }}")?;
+        write!(content_string, "    }}\r\n\r\n")?;
+        writeln!(content_string, "    fn deserialize_avro_bytes<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{")?;
+        writeln!(
+            content_string,
+            "        deserializer.deserialize_option(BytesVisitor::<Self>(std::marker::PhantomData))"
+        )?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+        writeln!(content_string, "impl<T: AvroBytes> AvroBytes for Vec<T> {{")?;
+        writeln!(content_string, "    fn serialize_avro_bytes<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{")?;
+        writeln!(
+            content_string,
+            "        serializer.collect_seq(self.iter().map(BytesValue))"
+        )?;
+        write!(content_string, "    }}\r\n\r\n")?;
+        writeln!(content_string, "    fn deserialize_avro_bytes<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{")?;
+        writeln!(
+            content_string,
+            "        deserializer.deserialize_seq(BytesVisitor::<Self>(std::marker::PhantomData))"
+        )?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+        writeln!(
+            content_string,
+            "impl<T: AvroBytes> AvroBytes for std::collections::HashMap<String, T> {{"
+        )?;
+        writeln!(content_string, "    fn serialize_avro_bytes<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{")?;
+        writeln!(content_string, "        serializer.collect_map(self.iter().map(|(key, value)| (key, BytesValue(value))))")?;
+        write!(content_string, "    }}\r\n\r\n")?;
+        writeln!(content_string, "    fn deserialize_avro_bytes<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{")?;
+        writeln!(
+            content_string,
+            "        deserializer.deserialize_map(BytesVisitor::<Self>(std::marker::PhantomData))"
+        )?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        writeln!(content_string, "/// Serialize bytes inside a container")?;
+        write!(content_string, "struct BytesValue<'a, T>(&'a T);\r\n\r\n")?;
+        writeln!(
+            content_string,
+            "impl<T: AvroBytes> serde::Serialize for BytesValue<'_, T> {{"
+        )?;
+        writeln!(content_string, "    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{")?;
+        writeln!(content_string, "        self.0.serialize_avro_bytes(serializer)")?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+        writeln!(
+            content_string,
+            "/// Deserialize bytes, or the bytes of a container"
+        )?;
+        write!(
+            content_string,
+            "struct BytesVisitor<T>(std::marker::PhantomData<T>);\r\n\r\n"
+        )?;
+        writeln!(
+            content_string,
+            "impl<'de, T: AvroBytes> serde::de::DeserializeSeed<'de> for BytesVisitor<T> {{"
+        )?;
+        write!(content_string, "    type Value = T;\r\n\r\n")?;
+        writeln!(content_string, "    fn deserialize<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {{")?;
+        writeln!(content_string, "        T::deserialize_avro_bytes(deserializer)")?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+        writeln!(
+            content_string,
+            "impl<'de> serde::de::Visitor<'de> for BytesVisitor<Vec<u8>> {{"
+        )?;
+        write!(content_string, "    type Value = Vec<u8>;\r\n\r\n")?;
+        writeln!(
+            content_string,
+            "    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {{"
+        )?;
+        writeln!(content_string, "        formatter.write_str(\"bytes\")")?;
+        write!(content_string, "    }}\r\n\r\n")?;
+        writeln!(content_string, "    fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {{")?;
+        writeln!(content_string, "        Ok(bytes.to_vec())")?;
+        write!(content_string, "    }}\r\n\r\n")?;
+        writeln!(content_string, "    fn visit_byte_buf<E: serde::de::Error>(self, bytes: Vec<u8>) -> Result<Self::Value, E> {{")?;
+        writeln!(content_string, "        Ok(bytes)")?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+        writeln!(
+            content_string,
+            "impl<'de, T: AvroBytes> serde::de::Visitor<'de> for BytesVisitor<Option<T>> {{"
+        )?;
+        write!(content_string, "    type Value = Option<T>;\r\n\r\n")?;
+        writeln!(
+            content_string,
+            "    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {{"
+        )?;
+        writeln!(content_string, "        formatter.write_str(\"optional bytes\")")?;
+        write!(content_string, "    }}\r\n\r\n")?;
+        writeln!(
+            content_string,
+            "    fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> {{"
+        )?;
+        writeln!(content_string, "        Ok(None)")?;
+        write!(content_string, "    }}\r\n\r\n")?;
+        writeln!(
+            content_string,
+            "    fn visit_unit<E: serde::de::Error>(self) -> Result<Self::Value, E> {{"
+        )?;
+        writeln!(content_string, "        Ok(None)")?;
+        write!(content_string, "    }}\r\n\r\n")?;
+        writeln!(content_string, "    fn visit_some<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {{")?;
+        writeln!(
+            content_string,
+            "        T::deserialize_avro_bytes(deserializer).map(Some)"
+        )?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+        writeln!(
+            content_string,
+            "impl<'de, T: AvroBytes> serde::de::Visitor<'de> for BytesVisitor<Vec<T>> {{"
+        )?;
+        write!(content_string, "    type Value = Vec<T>;\r\n\r\n")?;
+        writeln!(
+            content_string,
+            "    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {{"
+        )?;
+        writeln!(content_string, "        formatter.write_str(\"an array of bytes\")")?;
+        write!(content_string, "    }}\r\n\r\n")?;
+        writeln!(content_string, "    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {{")?;
+        writeln!(content_string, "        let mut values = Vec::new();")?;
+        writeln!(content_string, "        while let Some(value) = seq.next_element_seed(BytesVisitor::<T>(std::marker::PhantomData))? {{")?;
+        writeln!(content_string, "            values.push(value);")?;
+        writeln!(content_string, "        }}")?;
+        writeln!(content_string, "        Ok(values)")?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+        writeln!(content_string, "impl<'de, T: AvroBytes> serde::de::Visitor<'de> for BytesVisitor<std::collections::HashMap<String, T>> {{")?;
+        write!(
+            content_string,
+            "    type Value = std::collections::HashMap<String, T>;\r\n\r\n"
+        )?;
+        writeln!(
+            content_string,
+            "    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {{"
+        )?;
+        writeln!(content_string, "        formatter.write_str(\"a map of bytes\")")?;
+        write!(content_string, "    }}\r\n\r\n")?;
+        writeln!(content_string, "    fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {{")?;
+        writeln!(
+            content_string,
+            "        let mut values = std::collections::HashMap::new();"
+        )?;
+        writeln!(
+            content_string,
+            "        while let Some(key) = map.next_key::<String>()? {{"
+        )?;
+        writeln!(content_string, "            values.insert(key, map.next_value_seed(BytesVisitor::<T>(std::marker::PhantomData))?);")?;
+        writeln!(content_string, "        }}")?;
+        writeln!(content_string, "        Ok(values)")?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        Ok(content_string)
+    }
+
+    pub fn schema_name(&self) -> String {
+        GLUE_NAME.to_owned()
+    }
+}
+
+/// Serde attribute of a field with bytes: bytes, or an option, an array or a map of bytes
+pub fn bytes_serde_with(schema: &Schema, module_paths: &ModulePaths) -> Result<Option<String>> {
+    match get_values_schema(schema) {
+        Schema::Bytes => Ok(Some(format!(
+            "#[serde(with = \"{}\")]",
+            module_paths.helper_path(BYTES_NAMESPACE, GLUE_NAME)
+        ))),
+        _ => Ok(None),
+    }
+}
@@ -0,0 +1,133 @@
+use apache_avro::schema::{Name, Namespace};
+use serde_json::Value;
+use std::collections::HashSet;
+
+use crate::error::{AvrogenError, Location};
+use crate::file_parser::json_type_name;
+use crate::Result;
+
+/// Types which are never a reference to a named type
+pub const PRIMITIVE_TYPES: &[&str] = &[
+    "null", "boolean", "int", "long", "float", "double", "bytes", "string",
+];
+
+/// Parsing Canonical Form of a JSON schema, as defined by the Avro specification:
+/// - [PRIMITIVES] the primitive schemas are written as their name: `"int"`
+/// - [FULLNAMES] the named types are written with their full name, without namespace
+/// - [STRIP] only `type`, `name`, `fields`, `symbols`, `items`, `values` and `size` are kept,
+///   the docs, aliases, defaults and logical types are removed
+/// - [ORDER] the attributes are written in this order
+///
+/// A named type is defined the first time it's used, then written as its full name.
+pub fn parsing_canonical_form(json: &Value) -> Result<String> {
+    canonical_form(json, &None, &mut HashSet::new())
+}
+
+fn canonical_form(
+    json: &Value,
+    namespace: &Namespace,
+    defined: &mut HashSet<Name>,
+) -> Result<String> {
+    match json {
+        Value::String(type_name) if PRIMITIVE_TYPES.contains(&type_name.as_str()) => {
+            Ok(json_string(type_name))
+        }
+        Value::String(type_name) => {
+            let name = Name::new(type_name)?.fully_qualified_name(namespace);
+            Ok(json_string(&name.fullname(None)))
+        }
+        Value::Array(variants) => {
+            let variants = variants
+                .iter()
+                .map(|v| canonical_form(v, namespace, defined))
+                .collect::<Result<Vec<String>>>()?;
+            Ok(format!("[{}]", variants.join(",")))
+        }
+        Value::Object(object) => match object.get("type") {
+            Some(Value::String(object_type)) => match object_type.as_str() {
+                "record" | "error" | "enum" | "fixed" => {
+                    let Some(name) = json_type_name(object, namespace) else {
+                        return Err(invalid_schema(json, "a named type needs a name"));
+                    };
+                    let full_name = json_string(&name.fullname(None));
+                    if !defined.insert(name.to_owned()) {
+                        return Ok(full_name);
+                    }
+
+                    let attribute = match object_type.as_str() {
+                        "enum" => format!(
+                            "\"symbols\":{}",
+                            object.get("symbols").unwrap_or(&Value::Null)
+                        ),
+                        "fixed" => {
+                            format!("\"size\":{}", object.get("size").unwrap_or(&Value::Null))
+                        }
+                        _ => {
+                            let fields = match object.get("fields") {
+                                Some(Value::Array(fields)) => fields
+                                    .iter()
+                                    .map(|f| canonical_field(f, &name.namespace, defined))
+                                    .collect::<Result<Vec<String>>>()?,
+                                _ => return Err(invalid_schema(json, "a record needs fields")),
+                            };
+                            format!("\"fields\":[{}]", fields.join(","))
+                        }
+                    };
+
+                    Ok(format!(
+                        "{{\"name\":{full_name},\"type\":{},{attribute}}}",
+                        json_string(object_type)
+                    ))
+                }
+                "array" => Ok(format!(
+                    "{{\"type\":\"array\",\"items\":{}}}",
+                    canonical_form(inner_type(json, "items")?, namespace, defined)?
+                )),
+                "map" => Ok(format!(
+                    "{{\"type\":\"map\",\"values\":{}}}",
+                    canonical_form(inner_type(json, "values")?, namespace, defined)?
+                )),
+                // A primitive or a reference with attributes, like a logical type
+                _ => canonical_form(inner_type(json, "type")?, namespace, defined),
+            },
+            Some(_) => canonical_form(inner_type(json, "type")?, namespace, defined),
+            None => Err(invalid_schema(json, "a schema needs a type")),
+        },
+        _ => Err(invalid_schema(
+            json,
+            "a schema is a string, an array or an object",
+        )),
+    }
+}
+
+fn canonical_field(
+    field: &Value,
+    namespace: &Namespace,
+    defined: &mut HashSet<Name>,
+) -> Result<String> {
+    let Some(field_name) = field.get("name").and_then(Value::as_str) else {
+        return Err(invalid_schema(field, "a field needs a name"));
+    };
+    Ok(format!(
+        "{{\"name\":{},\"type\":{}}}",
+        json_string(field_name),
+        canonical_form(inner_type(field, "type")?, namespace, defined)?
+    ))
+}
+
+fn inner_type<'a>(json: &'a Value, key: &str) -> Result<&'a Value> {
+    json.get(key)
+        .ok_or_else(|| invalid_schema(json, &format!("the attribute {key} is missing")))
+}
+
+/// JSON string literal, the non ASCII characters are written as is
+fn json_string(value: &str) -> String {
+    Value::String(value.to_owned()).to_string()
+}
+
+fn invalid_schema(json: &Value, reason: &str) -> AvrogenError {
+    AvrogenError::Generation {
+        location: Location::default(),
+        message: format!("the schema {json} has no Parsing Canonical Form: {reason}"),
+    }
+}
@@ -0,0 +1,215 @@
+use std::fmt::Write;
+
+use super::module_paths::ModulePaths;
+use crate::Result;
+
+/// Namespace of the types shared by the records for the Confluent wire format
+pub const CONFLUENT_NAMESPACE: &str = "confluent";
+
+const RESOLVER_NAME: &str = "SchemaResolver";
+
+const ERROR_NAME: &str = "ConfluentError";
+
+/// Trait to find the writer schemas from their ids and the decoding error, generated once
+#[derive(Debug)]
+pub struct GeneratedConfluent {}
+
+/// Methods of a record to encode and decode the Confluent wire format
+#[derive(Debug)]
+pub struct ConfluentMethods {
+    resolver_path: String,
+
+    error_path: String,
+
+    /// Expression of the value resolved with the schema
+    encoded_value: String,
+
+    /// Expression of the value given to `apache_avro::from_value`
+    decoded_value: String,
+}
+
+impl GeneratedConfluent {
+    pub fn produce_content(&self) -> Result<String> {
+        let mut content_string = String::new();
+        writeln!(
+            content_string,
+            "/// Find the writer schemas registered in the Confluent Schema Registry from their ids"
+        )?;
+        writeln!(content_string, "pub trait {RESOLVER_NAME} {{")?;
+        writeln!(
+            content_string,
+            "    /// Return the schema registered with the id, None when the id is unknown"
+        )?;
+        writeln!(
+            content_string,
+            "    fn resolve_schema(&self, schema_id: u32) -> Option<apache_avro::Schema>;"
+        )?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        writeln!(
+            content_string,
+            "impl {RESOLVER_NAME} for std::collections::HashMap<u32, apache_avro::Schema> {{"
+        )?;
+        writeln!(
+            content_string,
+            "    fn resolve_schema(&self, schema_id: u32) -> Option<apache_avro::Schema> {{"
+        )?;
+        writeln!(content_string, "        self.get(&schema_id).cloned()")?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        writeln!(
+            content_string,
+            "/// Errors returned when a record is decoded from the Confluent wire format"
+        )?;
+        writeln!(content_string, "#[derive(Debug)]")?;
+        writeln!(content_string, "pub enum {ERROR_NAME} {{")?;
+        writeln!(
+            content_string,
+            "    /// The bytes don't start with the magic byte and the schema id"
+        )?;
+        writeln!(content_string, "    InvalidHeader,")?;
+        writeln!(
+            content_string,
+            "    /// The resolver doesn't know the schema id"
+        )?;
+        writeln!(content_string, "    UnknownSchemaId(u32),")?;
+        writeln!(content_string, "    Avro(apache_avro::Error),")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        writeln!(
+            content_string,
+            "impl From<apache_avro::Error> for {ERROR_NAME} {{"
+        )?;
+        writeln!(
+            content_string,
+            "    fn from(error: apache_avro::Error) -> Self {{"
+        )?;
+        writeln!(content_string, "        Self::Avro(error)")?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        writeln!(content_string, "impl std::fmt::Display for {ERROR_NAME} {{")?;
+        writeln!(
+            content_string,
+            "    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{"
+        )?;
+        writeln!(content_string, "        match self {{")?;
+        writeln!(
+            content_string,
+            "            Self::InvalidHeader => write!(f, \"Invalid Confluent wire format header\"),"
+        )?;
+        writeln!(
+            content_string,
+            "            Self::UnknownSchemaId(schema_id) => write!(f, \"Unknown schema id {{schema_id}}\"),"
+        )?;
+        writeln!(
+            content_string,
+            "            Self::Avro(error) => write!(f, \"{{error}}\"),"
+        )?;
+        writeln!(content_string, "        }}")?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        write!(
+            content_string,
+            "impl std::error::Error for {ERROR_NAME} {{}}\r\n\r\n"
+        )?;
+
+        Ok(content_string)
+    }
+
+    pub fn schema_name(&self) -> String {
+        RESOLVER_NAME.to_owned()
+    }
+}
+
+impl ConfluentMethods {
+    pub fn from(
+        module_paths: &ModulePaths,
+        encoded_value: &str,
+        decoded_value: &str,
+    ) -> Result<Self> {
+        Ok(ConfluentMethods {
+            resolver_path: module_paths.helper_path(CONFLUENT_NAMESPACE, RESOLVER_NAME),
+            error_path: module_paths.helper_path(CONFLUENT_NAMESPACE, ERROR_NAME),
+            encoded_value: encoded_value.to_owned(),
+            decoded_value: decoded_value.to_owned(),
+        })
+    }
+
+    pub fn produce_content(&self) -> Result<String> {
+        let mut content_string = String::new();
+        write!(
+            content_string,
+            "\r\n    /// Encode the record with the Confluent wire format: the magic byte 0, the schema id in big endian then the binary encoded record\r\n"
+        )?;
+        writeln!(
+            content_string,
+            "    pub fn to_confluent_bytes(&self, schema_id: u32) -> Result<Vec<u8>, apache_avro::Error> {{"
+        )?;
+        writeln!(
+            content_string,
+            "        let value = {};",
+            self.encoded_value
+        )?;
+        writeln!(content_string, "        let mut bytes = vec![0];")?;
+        writeln!(
+            content_string,
+            "        bytes.extend_from_slice(&schema_id.to_be_bytes());"
+        )?;
+        writeln!(
+            content_string,
+            "        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);"
+        )?;
+        writeln!(content_string, "        Ok(bytes)")?;
+        writeln!(content_string, "    }}")?;
+
+        write!(
+            content_string,
+            "\r\n    /// Decode a record encoded with the Confluent wire format, the writer schema is found by the resolver from the schema id\r\n"
+        )?;
+        writeln!(
+            content_string,
+            "    pub fn from_confluent_bytes(bytes: &[u8], resolver: &impl {}) -> Result<Self, {}> {{",
+            self.resolver_path, self.error_path
+        )?;
+        writeln!(
+            content_string,
+            "        let (schema_id, mut body) = match bytes {{"
+        )?;
+        writeln!(
+            content_string,
+            "            [0, b1, b2, b3, b4, body @ ..] => (u32::from_be_bytes([*b1, *b2, *b3, *b4]), body),"
+        )?;
+        writeln!(
+            content_string,
+            "            _ => return Err({}::InvalidHeader),",
+            self.error_path
+        )?;
+        writeln!(content_string, "        }};")?;
+        writeln!(
+            content_string,
+            "        let writer_schema = resolver.resolve_schema(schema_id).ok_or({}::UnknownSchemaId(schema_id))?;",
+            self.error_path
+        )?;
+        // The schema resolution of apache_avro finds the union variants from the values,
+        // the bytes written with the same schema are read without resolution to keep the variants
+        writeln!(
+            content_string,
+            "        let reader_schema = (writer_schema != *Self::schema()).then(Self::schema);"
+        )?;
+        writeln!(
+            content_string,
+            "        let value = apache_avro::from_avro_datum(&writer_schema, &mut body, reader_schema)?;"
+        )?;
+        writeln!(
+            content_string,
+            "        Ok(apache_avro::from_value({})?)",
+            self.decoded_value
+        )?;
+        writeln!(content_string, "    }}")?;
+
+        Ok(content_string)
+    }
+}
@@ -0,0 +1,489 @@
+use apache_avro::Schema;
+use std::fmt::Write;
+
+use super::field_type::get_values_schema;
+use super::module_paths::ModulePaths;
+use crate::error::AvrogenError;
+use crate::Result;
+
+/// Namespace of the serde glue shared by the decimal fields
+pub const DECIMAL_NAMESPACE: &str = "decimal";
+
+const GLUE_NAME: &str = "PrecisionScale";
+
+/// Rust type of the Avro decimals
+#[derive(Debug, Clone, Copy, Default, PartialEq, clap::ValueEnum)]
+pub enum DecimalBackend {
+    /// `apache_avro::Decimal`, the bytes of the unscaled value
+    #[default]
+    ApacheAvro,
+    /// `rust_decimal::Decimal`, the rust_decimal crate is needed by the generated code
+    RustDecimal,
+    /// `bigdecimal::BigDecimal`, the bigdecimal crate is needed by the generated code
+    BigDecimal,
+}
+
+impl DecimalBackend {
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            DecimalBackend::ApacheAvro => "apache_avro::Decimal",
+            DecimalBackend::RustDecimal => "rust_decimal::Decimal",
+            DecimalBackend::BigDecimal => "bigdecimal::BigDecimal",
+        }
+    }
+
+    /// Rust expression of the decimal used when a field has no default value, apache_avro::Decimal doesn't implement Default
+    pub fn zero_value(&self) -> &'static str {
+        match self {
+            DecimalBackend::ApacheAvro => "apache_avro::Decimal::from([0x00])",
+            DecimalBackend::RustDecimal | DecimalBackend::BigDecimal => "Default::default()",
+        }
+    }
+
+    /// Rust expression of a decimal from the bytes of its unscaled value
+    pub fn value(&self, bytes: &[u8], scale: usize) -> Result<String> {
+        let bytes_content = bytes
+            .iter()
+            .map(|b| format!("0x{b:02x}"))
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        match self {
+            DecimalBackend::ApacheAvro => {
+                Ok(format!("apache_avro::Decimal::from(vec![{bytes_content}])"))
+            }
+            DecimalBackend::RustDecimal => {
+                if bytes.len() > 16 || scale > 28 {
+                    return Err(AvrogenError::invalid_default(format!("the decimal of {} bytes with the scale {scale} doesn't fit in a rust_decimal::Decimal", bytes.len())));
+                }
+                let sign_byte = match bytes.first() {
+                    Some(b) if *b >= 0x80 => 0xff,
+                    _ => 0x00,
+                };
+                let mut unscaled = [sign_byte; 16];
+                unscaled[16 - bytes.len()..].copy_from_slice(bytes);
+                let unscaled = i128::from_be_bytes(unscaled);
+
+                // The unscaled value of a rust_decimal::Decimal has 96 bits
+                if unscaled.unsigned_abs() >= 1 << 96 {
+                    return Err(AvrogenError::invalid_default(format!("the decimal {unscaled} doesn't fit in a rust_decimal::Decimal")));
+                }
+                Ok(format!(
+                    "rust_decimal::Decimal::from_i128_with_scale({unscaled}, {scale})"
+                ))
+            }
+            DecimalBackend::BigDecimal => Ok(format!(
+                "bigdecimal::BigDecimal::new(bigdecimal::num_bigint::BigInt::from_signed_bytes_be(&[{bytes_content}]), {scale})"
+            )),
+        }
+    }
+}
+
+/// Serde glue converting the decimals between their Avro encoding and the Rust type, generated once
+#[derive(Debug)]
+pub struct GeneratedDecimal {
+    backend: DecimalBackend,
+}
+
+impl GeneratedDecimal {
+    pub fn from(backend: DecimalBackend) -> Self {
+        GeneratedDecimal { backend }
+    }
+
+    pub fn produce_content(&self) -> Result<String> {
+        let mut content_string = String::new();
+        writeln!(content_string, "/// Serde glue of the decimal fields, the Avro decimals are the big-endian two's-complement bytes of their unscaled value.")?;
+        writeln!(content_string, "///")?;
+        writeln!(content_string, "/// The fields use the precision and the scale of their schema: `#[serde(with = \"PrecisionScale::<10, 2>\")]`")?;
+        write!(
+            content_string,
+            "pub struct PrecisionScale<const PRECISION: u32, const SCALE: u32>;\r\n\r\n"
+        )?;
+        writeln!(
+            content_string,
+            "impl<const PRECISION: u32, const SCALE: u32> PrecisionScale<PRECISION, SCALE> {{"
+        )?;
+        writeln!(content_string, "    pub fn serialize<T: AvroDecimal, S: serde::Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {{")?;
+        writeln!(
+            content_string,
+            "        value.serialize_decimal(PRECISION, SCALE, serializer)"
+        )?;
+        write!(content_string, "    }}\r\n\r\n")?;
+        writeln!(content_string, "    pub fn deserialize<'de, T: AvroDecimal, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {{")?;
+        writeln!(
+            content_string,
+            "        T::deserialize_decimal(SCALE, deserializer)"
+        )?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+        writeln!(content_string, "/// A decimal, or a container of decimals, serialized with the precision and the scale of its schema")?;
+        writeln!(content_string, "pub trait AvroDecimal: Sized {{")?;
+        write!(content_string, "    fn serialize_decimal<S: serde::Serializer>(&self, precision: u32, scale: u32, serializer: S) -> Result<S::Ok, S::Error>;\r\n\r\n")?;
+        writeln!(content_string, "    fn deserialize_decimal<'de, D: serde::Deserializer<'de>>(scale: u32, deserializer: D) -> Result<Self, D::Error>;")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        match self.backend {
+            DecimalBackend::ApacheAvro => {
+                writeln!(
+                    content_string,
+                    "impl AvroDecimal for apache_avro::Decimal {{"
+                )?;
+                writeln!(content_string, "    fn serialize_decimal<S: serde::Serializer>(&self, precision: u32, _scale: u32, serializer: S) -> Result<S::Ok, S::Error> {{")?;
+                writeln!(content_string, "        let bytes = Vec::<u8>::try_from(self).map_err(serde::ser::Error::custom)?;")?;
+                writeln!(
+                    content_string,
+                    "        serializer.serialize_bytes(&precision_bytes(&bytes, precision))"
+                )?;
+                write!(content_string, "    }}\r\n\r\n")?;
+                writeln!(content_string, "    fn deserialize_decimal<'de, D: serde::Deserializer<'de>>(_scale: u32, deserializer: D) -> Result<Self, D::Error> {{")?;
+                writeln!(content_string, "        Ok(apache_avro::Decimal::from(deserializer.deserialize_byte_buf(BytesVisitor)?))")?;
+                writeln!(content_string, "    }}")?;
+                write!(content_string, "}}\r\n\r\n")?;
+            }
+            DecimalBackend::RustDecimal => {
+                writeln!(
+                    content_string,
+                    "impl AvroDecimal for rust_decimal::Decimal {{"
+                )?;
+                writeln!(content_string, "    fn serialize_decimal<S: serde::Serializer>(&self, precision: u32, scale: u32, serializer: S) -> Result<S::Ok, S::Error> {{")?;
+                writeln!(content_string, "        let mut value = *self;")?;
+                writeln!(content_string, "        value.rescale(scale);")?;
+                writeln!(content_string, "        if value != *self {{")?;
+                writeln!(content_string, "            return Err(serde::ser::Error::custom(format!(\"The decimal {{self}} has more than {{scale}} digits after the decimal point\")));")?;
+                writeln!(content_string, "        }}")?;
+                writeln!(content_string, "        let unscaled = value.mantissa();")?;
+                writeln!(
+                    content_string,
+                    "        if unscaled.unsigned_abs().to_string().len() > precision as usize {{"
+                )?;
+                writeln!(content_string, "            return Err(serde::ser::Error::custom(format!(\"The decimal {{self}} has more than {{precision}} digits\")));")?;
+                writeln!(content_string, "        }}")?;
+                writeln!(
+                    content_string,
+                    "        let bytes = unscaled.to_be_bytes();"
+                )?;
+                writeln!(
+                    content_string,
+                    "        serializer.serialize_bytes(&precision_bytes(&bytes, precision))"
+                )?;
+                write!(content_string, "    }}\r\n\r\n")?;
+                writeln!(content_string, "    fn deserialize_decimal<'de, D: serde::Deserializer<'de>>(scale: u32, deserializer: D) -> Result<Self, D::Error> {{")?;
+                writeln!(
+                    content_string,
+                    "        let bytes = deserializer.deserialize_byte_buf(BytesVisitor)?;"
+                )?;
+                writeln!(content_string, "        if bytes.len() > 16 {{")?;
+                writeln!(content_string, "            return Err(serde::de::Error::custom(format!(\"The decimal of {{}} bytes doesn't fit in a rust_decimal::Decimal\", bytes.len())));")?;
+                writeln!(content_string, "        }}")?;
+                writeln!(content_string, "        let sign_byte = if bytes.first().is_some_and(|b| *b >= 0x80) {{ 0xff }} else {{ 0x00 }};")?;
+                writeln!(
+                    content_string,
+                    "        let mut unscaled = [sign_byte; 16];"
+                )?;
+                writeln!(
+                    content_string,
+                    "        unscaled[16 - bytes.len()..].copy_from_slice(&bytes);"
+                )?;
+                writeln!(content_string, "        rust_decimal::Decimal::try_from_i128_with_scale(i128::from_be_bytes(unscaled), scale).map_err(serde::de::Error::custom)")?;
+                writeln!(content_string, "    }}")?;
+                write!(content_string, "}}\r\n\r\n")?;
+            }
+            DecimalBackend::BigDecimal => {
+                writeln!(
+                    content_string,
+                    "impl AvroDecimal for bigdecimal::BigDecimal {{"
+                )?;
+                writeln!(content_string, "    fn serialize_decimal<S: serde::Serializer>(&self, precision: u32, scale: u32, serializer: S) -> Result<S::Ok, S::Error> {{")?;
+                writeln!(
+                    content_string,
+                    "        let value = self.with_scale(scale.into());"
+                )?;
+                writeln!(content_string, "        if value != *self {{")?;
+                writeln!(content_string, "            return Err(serde::ser::Error::custom(format!(\"The decimal {{self}} has more than {{scale}} digits after the decimal point\")));")?;
+                writeln!(content_string, "        }}")?;
+                writeln!(
+                    content_string,
+                    "        if value.digits() > precision.into() {{"
+                )?;
+                writeln!(content_string, "            return Err(serde::ser::Error::custom(format!(\"The decimal {{self}} has more than {{precision}} digits\")));")?;
+                writeln!(content_string, "        }}")?;
+                writeln!(
+                    content_string,
+                    "        let (unscaled, _) = value.into_bigint_and_exponent();"
+                )?;
+                writeln!(
+                    content_string,
+                    "        serializer.serialize_bytes(&precision_bytes(&unscaled.to_signed_bytes_be(), precision))"
+                )?;
+                write!(content_string, "    }}\r\n\r\n")?;
+                writeln!(content_string, "    fn deserialize_decimal<'de, D: serde::Deserializer<'de>>(scale: u32, deserializer: D) -> Result<Self, D::Error> {{")?;
+                writeln!(
+                    content_string,
+                    "        let bytes = deserializer.deserialize_byte_buf(BytesVisitor)?;"
+                )?;
+                writeln!(content_string, "        let unscaled = bigdecimal::num_bigint::BigInt::from_signed_bytes_be(&bytes);")?;
+                writeln!(
+                    content_string,
+                    "        Ok(bigdecimal::BigDecimal::new(unscaled, scale.into()))"
+                )?;
+                writeln!(content_string, "    }}")?;
+                write!(content_string, "}}\r\n\r\n")?;
+            }
+        }
+
+        writeln!(
+            content_string,
+            "impl<T: AvroDecimal> AvroDecimal for Option<T> {{"
+        )?;
+        writeln!(content_string, "    fn serialize_decimal<S: serde::Serializer>(&self, precision: u32, scale: u32, serializer: S) -> Result<S::Ok, S::Error> {{")?;
+        writeln!(content_string, "        match self {{")?;
+        writeln!(content_string, "            Some(value) => serializer.serialize_some(&ScaledDecimal(value, precision, scale)),")?;
+        writeln!(
+            content_string,
+            "            None => serializer.serialize_none(),"
+        )?;
+        writeln!(content_string, "        }}")?;
+        write!(content_string, "    }}\r\n\r\n")?;
+        writeln!(content_string, "    fn deserialize_decimal<'de, D: serde::Deserializer<'de>>(scale: u32, deserializer: D) -> Result<Self, D::Error> {{")?;
+        writeln!(content_string, "        deserializer.deserialize_option(DecimalVisitor::<Self>(scale, std::marker::PhantomData))")?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+        writeln!(
+            content_string,
+            "impl<T: AvroDecimal> AvroDecimal for Vec<T> {{"
+        )?;
+        writeln!(content_string, "    fn serialize_decimal<S: serde::Serializer>(&self, precision: u32, scale: u32, serializer: S) -> Result<S::Ok, S::Error> {{")?;
+        writeln!(content_string, "        serializer.collect_seq(self.iter().map(|value| ScaledDecimal(value, precision, scale)))")?;
+        write!(content_string, "    }}\r\n\r\n")?;
+        writeln!(content_string, "    fn deserialize_decimal<'de, D: serde::Deserializer<'de>>(scale: u32, deserializer: D) -> Result<Self, D::Error> {{")?;
+        writeln!(
+            content_string,
+            "        deserializer.deserialize_seq(DecimalVisitor::<Self>(scale, std::marker::PhantomData))"
+        )?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+        writeln!(
+            content_string,
+            "impl<T: AvroDecimal> AvroDecimal for std::collections::HashMap<String, T> {{"
+        )?;
+        writeln!(content_string, "    fn serialize_decimal<S: serde::Serializer>(&self, precision: u32, scale: u32, serializer: S) -> Result<S::Ok, S::Error> {{")?;
+        writeln!(content_string, "        serializer.collect_map(self.iter().map(|(key, value)| (key, ScaledDecimal(value, precision, scale))))")?;
+        write!(content_string, "    }}\r\n\r\n")?;
+        writeln!(content_string, "    fn deserialize_decimal<'de, D: serde::Deserializer<'de>>(scale: u32, deserializer: D) -> Result<Self, D::Error> {{")?;
+        writeln!(
+            content_string,
+            "        deserializer.deserialize_map(DecimalVisitor::<Self>(scale, std::marker::PhantomData))"
+        )?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        writeln!(content_string, "/// Sign-extend or trim the bytes of an unscaled value to the size needed by the precision, apache_avro checks this size")?;
+        writeln!(
+            content_string,
+            "fn precision_bytes(bytes: &[u8], precision: u32) -> Vec<u8> {{"
+        )?;
+        writeln!(content_string, "    let mut size = 1;")?;
+        writeln!(content_string, "    while (2.0_f64.powi(8 * size - 1) - 1.0).log10().floor() < f64::from(precision) {{")?;
+        writeln!(content_string, "        size += 1;")?;
+        writeln!(content_string, "    }}")?;
+        writeln!(content_string, "    let size = size as usize;")?;
+        writeln!(content_string, "    let sign_byte = if bytes.first().is_some_and(|b| *b >= 0x80) {{ 0xff }} else {{ 0x00 }};")?;
+        writeln!(content_string, "    let mut start = 0;")?;
+        writeln!(content_string, "    while bytes.len() - start > size && bytes[start] == sign_byte && (bytes[start + 1] >= 0x80) == (sign_byte == 0xff) {{")?;
+        writeln!(content_string, "        start += 1;")?;
+        writeln!(content_string, "    }}")?;
+        writeln!(content_string, "    let mut precision_bytes = vec![sign_byte; size.saturating_sub(bytes.len() - start)];")?;
+        writeln!(
+            content_string,
+            "    precision_bytes.extend_from_slice(&bytes[start..]);"
+        )?;
+        writeln!(content_string, "    precision_bytes")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        writeln!(content_string, "/// Serialize a decimal inside a container")?;
+        write!(
+            content_string,
+            "struct ScaledDecimal<'a, T>(&'a T, u32, u32);\r\n\r\n"
+        )?;
+        writeln!(
+            content_string,
+            "impl<T: AvroDecimal> serde::Serialize for ScaledDecimal<'_, T> {{"
+        )?;
+        writeln!(content_string, "    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{")?;
+        writeln!(
+            content_string,
+            "        self.0.serialize_decimal(self.1, self.2, serializer)"
+        )?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+        writeln!(
+            content_string,
+            "impl<'de, T: AvroDecimal> serde::de::DeserializeSeed<'de> for DecimalVisitor<T> {{"
+        )?;
+        write!(content_string, "    type Value = T;\r\n\r\n")?;
+        writeln!(content_string, "    fn deserialize<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {{")?;
+        writeln!(
+            content_string,
+            "        T::deserialize_decimal(self.0, deserializer)"
+        )?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+        writeln!(
+            content_string,
+            "/// Deserialize the decimals of a container with the scale"
+        )?;
+        write!(
+            content_string,
+            "struct DecimalVisitor<T>(u32, std::marker::PhantomData<T>);\r\n\r\n"
+        )?;
+        writeln!(
+            content_string,
+            "impl<'de, T: AvroDecimal> serde::de::Visitor<'de> for DecimalVisitor<Option<T>> {{"
+        )?;
+        write!(content_string, "    type Value = Option<T>;\r\n\r\n")?;
+        writeln!(
+            content_string,
+            "    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {{"
+        )?;
+        writeln!(
+            content_string,
+            "        formatter.write_str(\"an optional decimal\")"
+        )?;
+        write!(content_string, "    }}\r\n\r\n")?;
+        writeln!(
+            content_string,
+            "    fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> {{"
+        )?;
+        writeln!(content_string, "        Ok(None)")?;
+        write!(content_string, "    }}\r\n\r\n")?;
+        writeln!(
+            content_string,
+            "    fn visit_unit<E: serde::de::Error>(self) -> Result<Self::Value, E> {{"
+        )?;
+        writeln!(content_string, "        Ok(None)")?;
+        write!(content_string, "    }}\r\n\r\n")?;
+        writeln!(content_string, "    fn visit_some<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {{")?;
+        writeln!(
+            content_string,
+            "        T::deserialize_decimal(self.0, deserializer).map(Some)"
+        )?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+        writeln!(
+            content_string,
+            "impl<'de, T: AvroDecimal> serde::de::Visitor<'de> for DecimalVisitor<Vec<T>> {{"
+        )?;
+        write!(content_string, "    type Value = Vec<T>;\r\n\r\n")?;
+        writeln!(
+            content_string,
+            "    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {{"
+        )?;
+        writeln!(
+            content_string,
+            "        formatter.write_str(\"an array of decimals\")"
+        )?;
+        write!(content_string, "    }}\r\n\r\n")?;
+        writeln!(content_string, "    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {{")?;
+        writeln!(content_string, "        let mut values = Vec::new();")?;
+        writeln!(content_string, "        while let Some(value) = seq.next_element_seed(DecimalVisitor::<T>(self.0, std::marker::PhantomData))? {{")?;
+        writeln!(content_string, "            values.push(value);")?;
+        writeln!(content_string, "        }}")?;
+        writeln!(content_string, "        Ok(values)")?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+        writeln!(content_string, "impl<'de, T: AvroDecimal> serde::de::Visitor<'de> for DecimalVisitor<std::collections::HashMap<String, T>> {{")?;
+        write!(
+            content_string,
+            "    type Value = std::collections::HashMap<String, T>;\r\n\r\n"
+        )?;
+        writeln!(
+            content_string,
+            "    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {{"
+        )?;
+        writeln!(
+            content_string,
+            "        formatter.write_str(\"a map of decimals\")"
+        )?;
+        write!(content_string, "    }}\r\n\r\n")?;
+        writeln!(content_string, "    fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {{")?;
+        writeln!(
+            content_string,
+            "        let mut values = std::collections::HashMap::new();"
+        )?;
+        writeln!(
+            content_string,
+            "        while let Some(key) = map.next_key::<String>()? {{"
+        )?;
+        writeln!(content_string, "            values.insert(key, map.next_value_seed(DecimalVisitor::<T>(self.0, std::marker::PhantomData))?);")?;
+        writeln!(content_string, "        }}")?;
+        writeln!(content_string, "        Ok(values)")?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+        writeln!(content_string, "/// Read the bytes of a decimal")?;
+        write!(content_string, "struct BytesVisitor;\r\n\r\n")?;
+        writeln!(
+            content_string,
+            "impl<'de> serde::de::Visitor<'de> for BytesVisitor {{"
+        )?;
+        write!(content_string, "    type Value = Vec<u8>;\r\n\r\n")?;
+        writeln!(
+            content_string,
+            "    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {{"
+        )?;
+        writeln!(
+            content_string,
+            "        formatter.write_str(\"the bytes of a decimal\")"
+        )?;
+        write!(content_string, "    }}\r\n\r\n")?;
+        writeln!(content_string, "    fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {{")?;
+        writeln!(content_string, "        Ok(bytes.to_vec())")?;
+        write!(content_string, "    }}\r\n\r\n")?;
+        writeln!(content_string, "    fn visit_byte_buf<E: serde::de::Error>(self, bytes: Vec<u8>) -> Result<Self::Value, E> {{")?;
+        writeln!(content_string, "        Ok(bytes)")?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+        writeln!(content_string, "/// Replace the decimals of a value read by apache_avro with their bytes, the serde deserializer of apache_avro doesn't read `Value::Decimal`")?;
+        writeln!(content_string, "pub fn decimals_as_bytes(value: apache_avro::types::Value) -> apache_avro::types::Value {{")?;
+        write!(content_string, "    use apache_avro::types::Value;\r\n\r\n")?;
+        writeln!(content_string, "    match value {{")?;
+        writeln!(content_string, "        Value::Decimal(decimal) => Value::Bytes(Vec::<u8>::try_from(decimal).unwrap_or_default()),")?;
+        writeln!(content_string, "        Value::Union(index, value) => Value::Union(index, Box::new(decimals_as_bytes(*value))),")?;
+        writeln!(content_string, "        Value::Array(values) => Value::Array(values.into_iter().map(decimals_as_bytes).collect()),")?;
+        writeln!(content_string, "        Value::Map(values) => Value::Map(values.into_iter().map(|(key, value)| (key, decimals_as_bytes(value))).collect()),")?;
+        writeln!(content_string, "        Value::Record(fields) => Value::Record(fields.into_iter().map(|(name, value)| (name, decimals_as_bytes(value))).collect()),")?;
+        writeln!(content_string, "        value => value,")?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        Ok(content_string)
+    }
+
+    pub fn schema_name(&self) -> String {
+        GLUE_NAME.to_owned()
+    }
+}
+
+/// Serde attribute of a field with decimals: a decimal, or an option, an array or a map of decimals
+pub fn decimal_serde_with(schema: &Schema, module_paths: &ModulePaths) -> Result<Option<String>> {
+    let Schema::Decimal(decimal_schema) = get_values_schema(schema) else {
+        return Ok(None);
+    };
+    let glue_path = glue_path(module_paths)?;
+
+    Ok(Some(format!(
+        "#[serde(with = \"{glue_path}::<{}, {}>\")]",
+        decimal_schema.precision, decimal_schema.scale
+    )))
+}
+
+/// Path of the function replacing the decimals of a value with their bytes
+pub fn decimals_as_bytes_path(module_paths: &ModulePaths) -> Result<String> {
+    let glue_path = glue_path(module_paths)?;
+    let module_path = glue_path.trim_end_matches(GLUE_NAME);
+
+    Ok(format!("{module_path}decimals_as_bytes"))
+}
+
+fn glue_path(module_paths: &ModulePaths) -> Result<String> {
+    Ok(module_paths.helper_path(DECIMAL_NAMESPACE, GLUE_NAME))
+}
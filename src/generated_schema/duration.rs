@@ -0,0 +1,300 @@
+use std::fmt::Write;
+
+use super::module_paths::ModulePaths;
+use crate::Result;
+
+/// Namespace of the type shared by the duration fields
+pub const DURATION_NAMESPACE: &str = "duration";
+
+const TYPE_NAME: &str = "Duration";
+
+const ERROR_NAME: &str = "DurationError";
+
+/// Type of the Avro durations and its conversions, generated once
+#[derive(Debug)]
+pub struct GeneratedDuration {}
+
+impl GeneratedDuration {
+    pub fn produce_content(&self) -> Result<String> {
+        let mut content_string = String::new();
+        writeln!(
+            content_string,
+            "/// Avro duration: an amount of months, days and milliseconds, independent of each other"
+        )?;
+        writeln!(
+            content_string,
+            "#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]"
+        )?;
+        writeln!(content_string, "pub struct {TYPE_NAME} {{")?;
+        writeln!(content_string, "    pub months: u32,")?;
+        writeln!(content_string, "    pub days: u32,")?;
+        writeln!(content_string, "    pub millis: u32,")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        // The Avro encoding is a fixed of 12 bytes: the three numbers in little endian
+        writeln!(content_string, "impl From<[u8; 12]> for {TYPE_NAME} {{")?;
+        writeln!(content_string, "    fn from(bytes: [u8; 12]) -> Self {{")?;
+        writeln!(content_string, "        let number = |index: usize| u32::from_le_bytes([bytes[index], bytes[index + 1], bytes[index + 2], bytes[index + 3]]);")?;
+        writeln!(content_string, "        Self {{")?;
+        writeln!(content_string, "            months: number(0),")?;
+        writeln!(content_string, "            days: number(4),")?;
+        writeln!(content_string, "            millis: number(8),")?;
+        writeln!(content_string, "        }}")?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        writeln!(content_string, "impl From<{TYPE_NAME}> for [u8; 12] {{")?;
+        writeln!(
+            content_string,
+            "    fn from(duration: {TYPE_NAME}) -> Self {{"
+        )?;
+        writeln!(content_string, "        let mut bytes = [0; 12];")?;
+        writeln!(
+            content_string,
+            "        bytes[0..4].copy_from_slice(&duration.months.to_le_bytes());"
+        )?;
+        writeln!(
+            content_string,
+            "        bytes[4..8].copy_from_slice(&duration.days.to_le_bytes());"
+        )?;
+        writeln!(
+            content_string,
+            "        bytes[8..12].copy_from_slice(&duration.millis.to_le_bytes());"
+        )?;
+        writeln!(content_string, "        bytes")?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        writeln!(
+            content_string,
+            "impl From<apache_avro::Duration> for {TYPE_NAME} {{"
+        )?;
+        writeln!(
+            content_string,
+            "    fn from(duration: apache_avro::Duration) -> Self {{"
+        )?;
+        writeln!(
+            content_string,
+            "        Self::from(<[u8; 12]>::from(duration))"
+        )?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        writeln!(
+            content_string,
+            "impl From<{TYPE_NAME}> for apache_avro::Duration {{"
+        )?;
+        writeln!(
+            content_string,
+            "    fn from(duration: {TYPE_NAME}) -> Self {{"
+        )?;
+        writeln!(
+            content_string,
+            "        Self::from(<[u8; 12]>::from(duration))"
+        )?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        writeln!(
+            content_string,
+            "/// A day is 24 hours, a duration with months has no fixed length and returns an error"
+        )?;
+        writeln!(
+            content_string,
+            "impl TryFrom<{TYPE_NAME}> for std::time::Duration {{"
+        )?;
+        writeln!(content_string, "    type Error = {ERROR_NAME};")?;
+        writeln!(
+            content_string,
+            "    fn try_from(duration: {TYPE_NAME}) -> Result<Self, Self::Error> {{"
+        )?;
+        writeln!(content_string, "        if duration.months != 0 {{")?;
+        writeln!(
+            content_string,
+            "            return Err({ERROR_NAME}::Months(duration.months));"
+        )?;
+        writeln!(content_string, "        }}")?;
+        writeln!(content_string, "        Ok(std::time::Duration::from_secs(u64::from(duration.days) * 86_400) + std::time::Duration::from_millis(u64::from(duration.millis)))")?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        writeln!(
+            content_string,
+            "/// The whole days are the days of the duration, the rest is truncated to milliseconds"
+        )?;
+        writeln!(
+            content_string,
+            "impl TryFrom<std::time::Duration> for {TYPE_NAME} {{"
+        )?;
+        writeln!(content_string, "    type Error = {ERROR_NAME};")?;
+        writeln!(
+            content_string,
+            "    fn try_from(duration: std::time::Duration) -> Result<Self, Self::Error> {{"
+        )?;
+        writeln!(content_string, "        let days = u32::try_from(duration.as_secs() / 86_400).map_err(|_| {ERROR_NAME}::OutOfRange(duration))?;")?;
+        writeln!(
+            content_string,
+            "        let millis = (duration.as_secs() % 86_400) * 1_000 + u64::from(duration.subsec_millis());"
+        )?;
+        writeln!(content_string, "        Ok(Self {{")?;
+        writeln!(content_string, "            months: 0,")?;
+        writeln!(content_string, "            days,")?;
+        writeln!(
+            content_string,
+            "            millis: u32::try_from(millis).map_err(|_| {ERROR_NAME}::OutOfRange(duration))?,"
+        )?;
+        writeln!(content_string, "        }})")?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        // The duration is serialized as bytes, like a fixed
+        writeln!(content_string, "impl serde::Serialize for {TYPE_NAME} {{")?;
+        writeln!(
+            content_string,
+            "    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{"
+        )?;
+        writeln!(
+            content_string,
+            "        serializer.serialize_bytes(&<[u8; 12]>::from(*self))"
+        )?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        writeln!(
+            content_string,
+            "impl<'de> serde::Deserialize<'de> for {TYPE_NAME} {{"
+        )?;
+        writeln!(
+            content_string,
+            "    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{"
+        )?;
+        writeln!(content_string, "        struct DurationVisitor;")?;
+        writeln!(content_string)?;
+        writeln!(
+            content_string,
+            "        impl<'de> serde::de::Visitor<'de> for DurationVisitor {{"
+        )?;
+        writeln!(content_string, "            type Value = {TYPE_NAME};")?;
+        writeln!(content_string)?;
+        writeln!(
+            content_string,
+            "            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {{"
+        )?;
+        writeln!(
+            content_string,
+            "                formatter.write_str(\"the 12 bytes of a duration\")"
+        )?;
+        writeln!(content_string, "            }}")?;
+        writeln!(content_string)?;
+        writeln!(
+            content_string,
+            "            fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<{TYPE_NAME}, E> {{"
+        )?;
+        writeln!(content_string, "                <[u8; 12]>::try_from(bytes).map({TYPE_NAME}::from).map_err(|_| E::invalid_length(bytes.len(), &self))")?;
+        writeln!(content_string, "            }}")?;
+        writeln!(content_string)?;
+        writeln!(
+            content_string,
+            "            fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<{TYPE_NAME}, A::Error> {{"
+        )?;
+        writeln!(content_string, "                let mut bytes = [0; 12];")?;
+        writeln!(
+            content_string,
+            "                for (index, byte) in bytes.iter_mut().enumerate() {{"
+        )?;
+        writeln!(
+            content_string,
+            "                    *byte = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(index, &self))?;"
+        )?;
+        writeln!(content_string, "                }}")?;
+        writeln!(
+            content_string,
+            "                if seq.next_element::<u8>()?.is_some() {{"
+        )?;
+        writeln!(
+            content_string,
+            "                    return Err(serde::de::Error::invalid_length(13, &self));"
+        )?;
+        writeln!(content_string, "                }}")?;
+        writeln!(
+            content_string,
+            "                Ok({TYPE_NAME}::from(bytes))"
+        )?;
+        writeln!(content_string, "            }}")?;
+        writeln!(content_string, "        }}")?;
+        writeln!(content_string)?;
+        writeln!(
+            content_string,
+            "        deserializer.deserialize_bytes(DurationVisitor)"
+        )?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        writeln!(
+            content_string,
+            "/// Error of the conversions between the Avro durations and `std::time::Duration`"
+        )?;
+        writeln!(content_string, "#[derive(Debug, PartialEq)]")?;
+        writeln!(content_string, "pub enum {ERROR_NAME} {{")?;
+        writeln!(
+            content_string,
+            "    /// The Avro duration has months, their number of days is unknown"
+        )?;
+        writeln!(content_string, "    Months(u32),")?;
+        writeln!(
+            content_string,
+            "    /// The days or the milliseconds don't fit in an Avro duration"
+        )?;
+        writeln!(content_string, "    OutOfRange(std::time::Duration),")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        writeln!(content_string, "impl std::fmt::Display for {ERROR_NAME} {{")?;
+        writeln!(
+            content_string,
+            "    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{"
+        )?;
+        writeln!(content_string, "        match self {{")?;
+        writeln!(content_string, "            Self::Months(months) => write!(f, \"The duration has {{months}} months, which have no fixed length\"),")?;
+        writeln!(content_string, "            Self::OutOfRange(duration) => write!(f, \"The duration {{duration:?}} is out of the range of an Avro duration\"),")?;
+        writeln!(content_string, "        }}")?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        write!(
+            content_string,
+            "impl std::error::Error for {ERROR_NAME} {{}}\r\n\r\n"
+        )?;
+
+        writeln!(content_string, "/// Replace the durations of a value read by apache_avro with their fixed, the serde deserializer of apache_avro doesn't read `Value::Duration`")?;
+        writeln!(content_string, "pub fn durations_as_fixed(value: apache_avro::types::Value) -> apache_avro::types::Value {{")?;
+        write!(content_string, "    use apache_avro::types::Value;\r\n\r\n")?;
+        writeln!(content_string, "    match value {{")?;
+        writeln!(content_string, "        Value::Duration(duration) => Value::Fixed(12, <[u8; 12]>::from(duration).to_vec()),")?;
+        writeln!(content_string, "        Value::Union(index, value) => Value::Union(index, Box::new(durations_as_fixed(*value))),")?;
+        writeln!(content_string, "        Value::Array(values) => Value::Array(values.into_iter().map(durations_as_fixed).collect()),")?;
+        writeln!(content_string, "        Value::Map(values) => Value::Map(values.into_iter().map(|(key, value)| (key, durations_as_fixed(value))).collect()),")?;
+        writeln!(content_string, "        Value::Record(fields) => Value::Record(fields.into_iter().map(|(name, value)| (name, durations_as_fixed(value))).collect()),")?;
+        writeln!(content_string, "        value => value,")?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        Ok(content_string)
+    }
+
+    pub fn schema_name(&self) -> String {
+        TYPE_NAME.to_owned()
+    }
+}
+
+/// Path of the generated type of the Avro durations
+pub fn duration_path(module_paths: &ModulePaths) -> Result<String> {
+    Ok(module_paths.helper_path(DURATION_NAMESPACE, TYPE_NAME))
+}
+
+/// Path of the function replacing the durations of a value with their fixed
+pub fn durations_as_fixed_path(module_paths: &ModulePaths) -> Result<String> {
+    let type_path = duration_path(module_paths)?;
+    let module_path = type_path.trim_end_matches(TYPE_NAME);
+
+    Ok(format!("{module_path}durations_as_fixed"))
+}
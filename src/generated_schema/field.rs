@@ -2,7 +2,11 @@ use crate::Result;
 use apache_avro::schema::*;
 use std::fmt::Write;
 
-use super::{field_default_value::FieldDefault, field_type::*, global::*};
+use super::{
+    bytes::bytes_serde_with, decimal::decimal_serde_with, field_default_value::FieldDefault, field_type::*, global::*,
+    logical_types::logical_type_serde_with, module_paths::ModulePaths,
+    schema_json::SchemaDefinitions,
+};
 
 #[derive(Debug)]
 pub struct GeneratedStructFields {
@@ -14,39 +18,60 @@ pub struct GeneratedStructFields {
 
     type_name: String,
 
-    serde_with_line: Option<&'static str>,
+    /// Value of the field in the `Default` implementation when it has no default value
+    type_default_value: String,
+
+    serde_with_line: Option<String>,
 
     default: Option<FieldDefault>,
 }
 
 impl GeneratedStructFields {
     pub fn has_default(&self) -> bool {
-        match self.default {
-            None => false,
-            Some(_) => true,
-        }
+        self.default.is_some()
     }
 
     pub fn from(
         field: &RecordField,
         structure_name: &SanitizedName,
-        default_namespace: &Option<String>,
+        structure_namespace: &Namespace,
+        module_paths: &ModulePaths,
+        schema_definitions: &SchemaDefinitions,
+        type_mapping: &TypeMapping,
     ) -> Result<Self> {
         let field_name = SanitizedName::from_field(&field.name);
-        let field_type = get_field_type(&field.schema, default_namespace)?;
+        let field_type = get_field_type(
+            &field.schema,
+            module_paths,
+            structure_namespace,
+            type_mapping,
+        )
+        .map_err(|e| e.in_field(&field.name))?;
 
         let doc = format_doc(&field.doc, "    ")?;
-        let serde_with_line = get_serde_with(field)?;
+        let serde_with_line =
+            get_serde_with(&field.schema, module_paths).map_err(|e| e.in_field(&field.name))?;
 
         let default = match &field.default {
             None => None,
-            Some(val) => Some(FieldDefault::from(&val, &field.schema)?),
+            Some(val) => Some(
+                FieldDefault::from(
+                    val,
+                    &field.schema,
+                    structure_namespace,
+                    schema_definitions,
+                    module_paths,
+                    type_mapping,
+                )
+                .map_err(|e| e.in_field(&field.name))?,
+            ),
         };
 
         Ok(GeneratedStructFields {
             name: field_name,
             parent_struct_fullname: structure_name.sanitized_name.to_owned(),
             type_name: field_type,
+            type_default_value: get_type_default_value(&field.schema, type_mapping),
             serde_with_line,
             doc,
             default,
@@ -66,7 +91,7 @@ impl GeneratedStructFields {
                 self.name.original_name
             )?
         }
-        if let Some(_) = self.default {
+        if self.default.is_some() {
             writeln!(
                 content,
                 "    #[serde(default = \"{}::default_{}\")]",
@@ -79,7 +104,20 @@ impl GeneratedStructFields {
             self.name.sanitized_name, self.type_name
         )?;
 
-        return Ok(content);
+        Ok(content)
+    }
+
+    /// Field initialization of the `Default` implementation: the Avro default value when there is one
+    pub fn write_struct_default_initialization_content(&self) -> Result<String> {
+        let value = match self.default {
+            Some(_) => format!("Self::default_{}()", self.name.sanitized_name),
+            None => self.type_default_value.to_owned(),
+        };
+
+        Ok(format!(
+            "            {}: {value},\r\n",
+            self.name.sanitized_name
+        ))
     }
 
     pub fn write_struct_default_method_content(&self) -> Result<Option<String>> {
@@ -87,7 +125,7 @@ impl GeneratedStructFields {
             Some(default) => {
                 let default_value_str = default
                     .write_content()
-                    .map_err(|e| format!("{} => {e}", self.name.sanitized_name))?;
+                    .map_err(|e| e.in_field(&self.name.original_name))?;
 
                 Ok(format!(
                     "\r\n    #[inline(always)]\r\n    pub fn default_{}() -> {} {{\r\n        {}\r\n    }}\r\n",
@@ -100,23 +138,13 @@ impl GeneratedStructFields {
     }
 }
 
-fn get_serde_with(field: &RecordField) -> Result<Option<&'static str>> {
-    Ok(match field.schema {
-        Schema::Date => None,
-        Schema::TimeMillis => Some("#[serde(with = \"chrono::naive::serde::ts_milliseconds\")]"),
-        Schema::TimeMicros => Some("#[serde(with = \"chrono::naive::serde::ts_microseconds\")]"),
-        Schema::TimestampMillis => {
-            Some("#[serde(with = \"chrono::naive::serde::ts_milliseconds\")]")
-        }
-        Schema::TimestampMicros => {
-            Some("#[serde(with = \"chrono::naive::serde::ts_microseconds\")]")
-        }
-        Schema::LocalTimestampMillis => {
-            Some("#[serde(with = \"chrono::naive::serde::ts_milliseconds\")]")
-        }
-        Schema::LocalTimestampMicros => {
-            Some("#[serde(with = \"chrono::naive::serde::ts_microseconds\")]")
-        }
-        _ => None,
-    })
+/// Serde attribute of the types which don't use the serde implementation of their Rust type: the bytes, the decimals and the logical types
+pub fn get_serde_with(schema: &Schema, module_paths: &ModulePaths) -> Result<Option<String>> {
+    if let Some(line) = bytes_serde_with(schema, module_paths)? {
+        return Ok(Some(line));
+    }
+    match decimal_serde_with(schema, module_paths)? {
+        Some(line) => Ok(Some(line)),
+        None => logical_type_serde_with(schema, module_paths),
+    }
 }
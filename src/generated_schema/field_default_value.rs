@@ -1,7 +1,14 @@
+use apache_avro::schema::{EnumSchema, FixedSchema, Name, Namespace, RecordSchema, UnionSchema};
 use apache_avro::Schema;
 use serde_json::{Map, Value};
+use crate::error::AvrogenError;
 use crate::Result;
-use super::field_type::{get_field_type, is_nullable};
+use super::field_type::{get_field_type, get_not_null_variants, get_union_name, get_union_variant_name, get_union_variant_names, sanitize_container_name, TypeMapping};
+use super::duration::duration_path;
+use super::global::SanitizedName;
+use super::module_paths::ModulePaths;
+use super::logical_types::LogicalType;
+use super::schema_json::SchemaDefinitions;
 
 #[derive(Debug)]
 pub struct FieldDefault{
@@ -9,88 +16,215 @@ pub struct FieldDefault{
 }
 
 impl FieldDefault{
-    pub fn from(default_value: &serde_json::Value,field_schema: &Schema)-> Result<FieldDefault>
+    /// The parent namespace is the namespace of the record, where the enums of its unions are generated
+    pub fn from(default_value: &Value,field_schema: &Schema, parent_namespace: &Namespace, schema_definitions: &SchemaDefinitions, module_paths: &ModulePaths, type_mapping: &TypeMapping)-> Result<FieldDefault>
     {
-        let content= get_field_default_value(default_value,field_schema)?;
+        let writer = DefaultValueWriter{ schema_definitions, module_paths, type_mapping };
+        let content= writer.value(default_value,field_schema,parent_namespace)?;
         Ok(FieldDefault{ content})
     }
 
     pub fn write_content(&self) -> Result<String> {
-        return Ok(self.content.to_owned())
+        Ok(self.content.to_owned())
     }
 }
 
-fn get_field_default_value(default_value: &serde_json::Value,field_schema: &Schema) -> Result<String> {
-    
-    let mut value_as_string = match default_value {
-        serde_json::Value::Null => Ok("None".to_string()),
-        serde_json::Value::Bool(bool_val) => Ok(format!("{bool_val}")),
-        serde_json::Value::Number(num_val) => Ok(format!("{num_val}")),
-        serde_json::Value::String(string_val) => Ok(format!("\"{string_val}\".to_string()")),
-        serde_json::Value::Array(array) =>  get_field_default_array_value(array,&field_schema),
-        serde_json::Value::Object(object) => get_field_default_object_value(object,&field_schema),
-    }?;
-
-    // When the type is nullable and the default value is not null => return Some(default)
-    if  is_nullable(&field_schema) && value_as_string != "None".to_string()
-    {
-        value_as_string= format!("Some({value_as_string})");
-    }
-    Ok(value_as_string)
+/// Write the Rust expression of a default value, the JSON value is read with the schema of the field
+struct DefaultValueWriter<'a> {
+    schema_definitions: &'a SchemaDefinitions<'a>,
+    module_paths: &'a ModulePaths,
+    type_mapping: &'a TypeMapping,
 }
 
-fn get_field_default_array_value(values_map: &Vec<Value>,field_schema: &Schema) -> Result<String>{
-    match field_schema {
-        Schema::Array(inner_type) => {
-            if values_map.is_empty(){
-                Ok("Vec::new()".to_string())
+impl DefaultValueWriter<'_> {
+
+    fn value(&self, default_value: &Value, schema: &Schema, parent_namespace: &Namespace) -> Result<String> {
+
+        match (schema, default_value) {
+            (Schema::Null, Value::Null) => Ok("()".to_string()),
+            (Schema::Boolean, Value::Bool(bool_val)) => Ok(format!("{bool_val}")),
+            (Schema::Int | Schema::Long, Value::Number(num_val)) if num_val.is_i64() => Ok(format!("{num_val}")),
+            (Schema::Float | Schema::Double, Value::Number(num_val)) => {
+                let float_val = num_val.as_f64().ok_or_else(|| self.mismatch(default_value, schema))?;
+                // Debug format always writes a float literal: 2.0 and not 2
+                Ok(format!("{float_val:?}"))
             }
-            else {
-        
-                let values_joined=values_map
+            (Schema::String, Value::String(string_val)) => Ok(format!("{string_val:?}.to_string()")),
+            (Schema::Bytes, Value::String(string_val)) => {
+                let bytes = iso_8859_1_bytes(string_val)?;
+                if bytes.is_empty() {
+                    return Ok("Vec::new()".to_string());
+                }
+                Ok(format!("vec![{}]", bytes_content(&bytes)))
+            }
+            (Schema::Fixed(fixed_schema), Value::String(string_val)) => {
+                let fixed_type = sanitize_container_name(&fixed_schema.name, self.module_paths)?;
+                Ok(format!("{fixed_type}({})", self.fixed_value(string_val, fixed_schema)?))
+            }
+            (Schema::Decimal(decimal_schema), Value::String(string_val)) => {
+                let bytes = match decimal_schema.inner.as_ref() {
+                    Schema::Fixed(fixed_schema) => {
+                        self.fixed_value(string_val, fixed_schema)?;
+                        iso_8859_1_bytes(string_val)?
+                    },
+                    _ => iso_8859_1_bytes(string_val)?,
+                };
+                self.type_mapping.decimal.value(&bytes, decimal_schema.scale)
+            }
+            (Schema::Enum(enum_schema), Value::String(symbol)) => self.enum_value(symbol, enum_schema),
+            (Schema::Array(items_schema), Value::Array(values)) => {
+                if values.is_empty(){
+                    return Ok("Vec::new()".to_string());
+                }
+
+                let values_joined=values
                 .iter()
-                .map(|v|get_field_default_value(v,&inner_type).unwrap())
-                .collect::<Vec<String>>()
+                .map(|v| self.value(v,items_schema,parent_namespace))
+                .collect::<Result<Vec<String>>>()?
                 .join(", ");
-        
+
                 Ok(format!("vec![{values_joined}]"))
             }
+            (Schema::Map(values_schema), Value::Object(values_map)) => {
+                if values_map.is_empty(){
+                    return Ok("std::collections::HashMap::new()".to_string());
+                }
+
+                let values_joined=values_map
+                .iter()
+                .map(|(key,value)| Ok(format!("({key:?}.to_string(), {})",self.value(value,values_schema,parent_namespace)?)))
+                .collect::<Result<Vec<String>>>()?
+                .join(", ");
+
+                Ok(format!("std::collections::HashMap::from([{values_joined}])"))
+            }
+            (Schema::Record(record_schema), Value::Object(values_map)) => self.record_value(values_map, record_schema),
+            (Schema::Union(union_schema), _) => self.union_value(default_value, union_schema, parent_namespace),
+
+            // Named types defined elsewhere are references, their definition is needed to write the value
+            (Schema::Ref { name }, _) => {
+                let definition = self.schema_definitions
+                .named_schema(name)
+                .ok_or_else(|| AvrogenError::generation(format!("the type {} is not defined", name.fullname(None))))?;
+
+                self.value(default_value, definition, parent_namespace)
+            }
+
+            (Schema::Uuid, Value::String(string_val)) => LogicalType::uuid_value(string_val),
+            (Schema::Date | Schema::TimeMillis | Schema::TimeMicros | Schema::TimestampMillis | Schema::TimestampMicros
+            | Schema::LocalTimestampMillis | Schema::LocalTimestampMicros, Value::Number(num_val)) => {
+                let logical_type = LogicalType::from_schema(schema).ok_or_else(|| self.mismatch(default_value, schema))?;
+                let units = num_val.as_i64().ok_or_else(|| self.mismatch(default_value, schema))?;
+                self.type_mapping.time.value(logical_type, units)
+            }
+
+            (Schema::Duration, Value::String(string_val)) => {
+                let bytes = iso_8859_1_bytes(string_val)?;
+                if bytes.len() != 12 {
+                    return Err(AvrogenError::invalid_default(format!("the default value of the duration has {} bytes instead of 12", bytes.len())));
+                }
+                Ok(format!("{}::from([{}])", duration_path(self.module_paths)?, bytes_content(&bytes)))
+            }
+
+            _ => Err(self.mismatch(default_value, schema)),
         }
-        _ =>
-        {
-            // No need to send Namespace, it's just for logs...
-            let field_type = get_field_type(&field_schema,&None)?;
-            Err(format!("Impossible to manage default value Array for type which is a {}",field_type).into())
+    }
+
+    fn mismatch(&self, default_value: &Value, schema: &Schema) -> crate::error::AvrogenError {
+        // No need to send Namespace, it's just for logs...
+        let field_type = get_field_type(schema,self.module_paths,&None,self.type_mapping).unwrap_or_else(|_| get_union_variant_name(schema, self.module_paths));
+        AvrogenError::invalid_default(format!("the default value {default_value} doesn't match the type {field_type}"))
+    }
+
+    fn fixed_value(&self, string_val: &str, fixed_schema: &FixedSchema) -> Result<String> {
+        let bytes = iso_8859_1_bytes(string_val)?;
+        if bytes.len() != fixed_schema.size {
+            return Err(AvrogenError::invalid_default(format!("the default value of the fixed {} has {} bytes instead of {}", fixed_schema.name.fullname(None), bytes.len(), fixed_schema.size)));
         }
+        Ok(format!("[{}]", bytes_content(&bytes)))
     }
 
-}
+    fn enum_value(&self, symbol: &str, enum_schema: &EnumSchema) -> Result<String> {
+        if !enum_schema.symbols.iter().any(|s| s == symbol) {
+            return Err(AvrogenError::invalid_default(format!("the default value {symbol} is not a symbol of the enum {}", enum_schema.name.fullname(None))));
+        }
 
-fn get_field_default_object_value(values_map: &Map<String, Value>,field_schema: &Schema) -> Result<String>{
-    match field_schema {
-        Schema::Map(inner_type) => {
-            if values_map.is_empty(){
-                Ok("std::collections::HashMap::new()".to_string())
-            }
-            else {
-        
-                let values_joined=values_map
-                .iter()
-                .map(|(key,value)|format!("(\"{key}\",{})",get_field_default_value(value,inner_type).unwrap()))
-                .collect::<Vec<String>>()
-                .join(",\r\n");
-        
-                Ok(format!("HashMap::from([{values_joined}])"))
+        let enum_type = sanitize_container_name(&enum_schema.name, self.module_paths)?;
+        Ok(format!("{enum_type}::{}", SanitizedName::from_type(symbol).sanitized_name))
+    }
+
+    /// Write the struct literal of a record, the fields missing in the value use their own default value
+    fn record_value(&self, values_map: &Map<String, Value>,record_schema: &RecordSchema) -> Result<String>{
+
+        let record_type = sanitize_container_name(&record_schema.name, self.module_paths)?;
+
+        let fields_values = record_schema
+        .fields
+        .iter()
+        .map(|field| {
+            let field_value = values_map
+            .get(&field.name)
+            .or(field.default.as_ref())
+            .ok_or_else(|| AvrogenError::invalid_default(format!("the default value of the record {} has no value for the field {}", record_schema.name.fullname(None), field.name)))?;
+
+            let field_name = SanitizedName::from_field(&field.name);
+            let value_as_string = self.value(field_value,&field.schema,&record_schema.name.namespace)?;
+
+            Ok(format!("{}: {value_as_string}", field_name.sanitized_name))
+        })
+        .collect::<Result<Vec<String>>>()?;
+
+        if fields_values.is_empty() {
+            return Ok(format!("{record_type} {{}}"));
+        }
+
+        Ok(format!("{record_type} {{ {} }}", fields_values.join(", ")))
+    }
+
+    /// The default value of an union is a value of its first type
+    fn union_value(&self, default_value: &Value, union_schema: &UnionSchema, parent_namespace: &Namespace) -> Result<String> {
+
+        let first_variant = union_schema.variants().first().ok_or_else(|| AvrogenError::invalid_default("an empty union can't have a default value"))?;
+
+        if let Schema::Null = first_variant {
+            if !default_value.is_null() {
+                return Err(AvrogenError::invalid_default(format!("the default value {default_value} doesn't match the first type of the union, which is null")));
             }
+            return Ok("None".to_string());
         }
-        Schema::Record(_record_schema)=> Ok("???".to_string()),
-        
-        _ =>
-        {
-            // No need to send Namespace, it's just for logs...
-            let field_type = get_field_type(&field_schema,&None)?;
-            Err(format!("Impossible to manage default value Object for type which is a {}",field_type).into())
+
+        let mut value_as_string = self.value(default_value, first_variant, parent_namespace)?;
+
+        // Unions with several types use a generated enum, in the namespace of the record
+        if get_not_null_variants(union_schema).len() > 1 {
+            let union_name = Name {
+                name: get_union_name(union_schema, parent_namespace, self.module_paths).original_name,
+                namespace: parent_namespace.to_owned(),
+            };
+            let union_type = sanitize_container_name(&union_name, self.module_paths)?;
+            let variant_names = get_union_variant_names(union_schema, self.module_paths);
+            value_as_string = format!("{union_type}::{}({value_as_string})", variant_names[0]);
+        }
+
+        if union_schema.is_nullable() {
+            value_as_string = format!("Some({value_as_string})");
         }
+        Ok(value_as_string)
     }
+}
+
+/// Bytes and fixed default values are strings, each character is a byte (ISO-8859-1)
+fn iso_8859_1_bytes(string_val: &str) -> Result<Vec<u8>> {
+    string_val
+    .chars()
+    .map(|c| u8::try_from(c).map_err(|_| AvrogenError::invalid_default(format!("the character {c:?} of the bytes default value {string_val:?} is not in the range 0-255"))))
+    .collect()
+}
 
+fn bytes_content(bytes: &[u8]) -> String {
+    bytes
+    .iter()
+    .map(|b| format!("0x{b:02x}"))
+    .collect::<Vec<String>>()
+    .join(", ")
 }
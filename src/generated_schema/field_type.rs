@@ -1,16 +1,27 @@
 use crate::Result;
 use apache_avro::schema::*;
+use itertools::Itertools;
 
+use super::decimal::DecimalBackend;
+use super::duration::duration_path;
 use super::global::SanitizedName;
+use super::logical_types::LogicalType;
+use super::module_paths::ModulePaths;
+use super::time_backend::TimeBackend;
 
-pub fn is_nullable(schema: &Schema) -> bool {
-    match schema {
-        Schema::Null => true,
-        Schema::Union(union_schema) => union_schema.variants().iter().any(|x| is_nullable(x)),
-        _ => false,
-    }
+/// Rust types generated for the Avro logical types
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TypeMapping {
+    pub decimal: DecimalBackend,
+    pub time: TimeBackend,
 }
-pub fn get_field_type(schema: &Schema, default_namespace: &Option<String>) -> Result<String> {
+
+pub fn get_field_type(
+    schema: &Schema,
+    module_paths: &ModulePaths,
+    parent_namespace: &Namespace,
+    type_mapping: &TypeMapping,
+) -> Result<String> {
     match schema {
         Schema::Null => Ok("null".to_string()),
         Schema::Boolean => Ok("bool".to_string()),
@@ -20,100 +31,243 @@ pub fn get_field_type(schema: &Schema, default_namespace: &Option<String>) -> Re
         Schema::Double => Ok("f64".to_string()),
         Schema::Bytes => Ok("Vec<u8>".to_string()),
         Schema::String => Ok("String".to_string()),
-        Schema::Array(array_schema) => get_field_type_array(array_schema, default_namespace),
-        Schema::Map(map_schema) => get_field_type_map(map_schema, default_namespace),
-        Schema::Union(union_schema) => get_field_type_union(union_schema, default_namespace),
-        Schema::Record(record_schema) => {
-            sanitize_container_name(&record_schema.name, default_namespace)
+        Schema::Array(array_schema) => {
+            get_field_type_array(array_schema, module_paths, parent_namespace, type_mapping)
+        }
+        Schema::Map(map_schema) => {
+            get_field_type_map(map_schema, module_paths, parent_namespace, type_mapping)
+        }
+        Schema::Union(union_schema) => {
+            get_field_type_union(union_schema, module_paths, parent_namespace, type_mapping)
         }
-        Schema::Enum(enum_schema) => sanitize_container_name(&enum_schema.name, default_namespace),
-        Schema::Fixed(fixed_schema) => Ok(format!("[u8; {}]", fixed_schema.size)),
-        Schema::Decimal(_) => Ok("apache_avro::Decimal".to_string()),
-        Schema::Uuid => Ok("Uuid::uuid".to_string()),
-        Schema::Date => Ok("chrono::NaiveDateTime".to_string()),
-        Schema::TimeMillis => Ok("chrono::NaiveDateTime".to_string()),
-        Schema::TimeMicros => Ok("chrono::NaiveDateTime".to_string()),
-        Schema::TimestampMillis => Ok("chrono::NaiveDateTime".to_string()),
-        Schema::TimestampMicros => Ok("chrono::NaiveDateTime".to_string()),
-        Schema::LocalTimestampMillis => Ok("chrono::NaiveDateTime".to_string()),
-        Schema::LocalTimestampMicros => Ok("chrono::NaiveDateTime".to_string()),
-        Schema::Duration => Ok("apache_avro::Duration".to_string()),
-        Schema::Ref { name: ref_name } => sanitize_container_name(&ref_name, default_namespace),
+        Schema::Record(record_schema) => sanitize_container_name(&record_schema.name, module_paths),
+        Schema::Enum(enum_schema) => sanitize_container_name(&enum_schema.name, module_paths),
+        Schema::Fixed(fixed_schema) => sanitize_container_name(&fixed_schema.name, module_paths),
+        Schema::Decimal(_) => Ok(type_mapping.decimal.type_name().to_string()),
+        Schema::Uuid => Ok(type_mapping.time.type_name(LogicalType::Uuid).to_string()),
+        Schema::Date => Ok(type_mapping.time.type_name(LogicalType::Date).to_string()),
+        Schema::TimeMillis => Ok(type_mapping
+            .time
+            .type_name(LogicalType::TimeMillis)
+            .to_string()),
+        Schema::TimeMicros => Ok(type_mapping
+            .time
+            .type_name(LogicalType::TimeMicros)
+            .to_string()),
+        Schema::TimestampMillis => Ok(type_mapping
+            .time
+            .type_name(LogicalType::TimestampMillis)
+            .to_string()),
+        Schema::TimestampMicros => Ok(type_mapping
+            .time
+            .type_name(LogicalType::TimestampMicros)
+            .to_string()),
+        Schema::LocalTimestampMillis => Ok(type_mapping
+            .time
+            .type_name(LogicalType::LocalTimestampMillis)
+            .to_string()),
+        Schema::LocalTimestampMicros => Ok(type_mapping
+            .time
+            .type_name(LogicalType::LocalTimestampMicros)
+            .to_string()),
+        Schema::Duration => duration_path(module_paths),
+        Schema::Ref { name: ref_name } => sanitize_container_name(ref_name, module_paths),
+    }
+}
+
+/// Schema of the values of a type whose Rust type is a value, or an option, a Vec or a HashMap of values
+pub fn get_values_schema(schema: &Schema) -> &Schema {
+    match schema {
+        Schema::Array(values_schema) | Schema::Map(values_schema) => {
+            get_values_schema(values_schema)
+        }
+        Schema::Union(union_schema) => match get_not_null_variants(union_schema).as_slice() {
+            [single_variant] => get_values_schema(single_variant),
+            _ => schema,
+        },
+        _ => schema,
+    }
+}
+
+/// Rust expression of the value used when there is no Avro default value
+pub fn get_type_default_value(schema: &Schema, type_mapping: &TypeMapping) -> String {
+    match schema {
+        Schema::Decimal(_) => type_mapping.decimal.zero_value().to_string(),
+        _ => match LogicalType::from_schema(schema) {
+            Some(logical_type) => type_mapping.time.zero_value(logical_type).to_string(),
+            None => "Default::default()".to_string(),
+        },
     }
 }
 
 fn get_field_type_array(
     items_schema: &Schema,
-    default_namespace: &Option<String>,
+    module_paths: &ModulePaths,
+    parent_namespace: &Namespace,
+    type_mapping: &TypeMapping,
+) -> Result<String> {
+    let items_type = get_field_type(items_schema, module_paths, parent_namespace, type_mapping)?;
+    Ok(format!("Vec<{}>", items_type))
+}
+
+fn get_field_type_map(
+    items_schema: &Schema,
+    module_paths: &ModulePaths,
+    parent_namespace: &Namespace,
+    type_mapping: &TypeMapping,
 ) -> Result<String> {
-    let items_type = get_field_type(items_schema, default_namespace)?;
-    return Ok(format!("Vec<{}>", items_type));
+    let items_type = get_field_type(items_schema, module_paths, parent_namespace, type_mapping)?;
+    Ok(format!("std::collections::HashMap<String, {}>", items_type))
 }
 
-fn get_field_type_map(items_schema: &Schema, default_namespace: &Option<String>) -> Result<String> {
-    let items_type = get_field_type(items_schema, default_namespace)?;
-    return Ok(format!("std::collections::HashMap<String, {}>", items_type));
+/// Rust path of a generated type, it depends on the layout of the modules
+pub fn sanitize_container_name(full_name: &Name, module_paths: &ModulePaths) -> Result<String> {
+    Ok(module_paths.type_path(full_name))
 }
 
-pub fn sanitize_container_name(
-    full_name: &Name,
-    default_namespace: &Option<String>,
+/// Return the Rust type of an union.
+/// - `null` variants are removed and make the type an `Option`
+/// - an union with only one remaining variant use this variant type
+/// - other unions use a generated enum, located in the namespace of the record which use it
+pub fn get_field_type_union(
+    schema: &UnionSchema,
+    module_paths: &ModulePaths,
+    parent_namespace: &Namespace,
+    type_mapping: &TypeMapping,
 ) -> Result<String> {
-    let default_namespace = match default_namespace {
-        None => "".to_string(),
-        Some(ns) => ns.replace(".", "::") + "::",
-    };
+    let not_null_variants = get_not_null_variants(schema);
 
-    let namespace = match &full_name.namespace {
-        None => "".to_string(),
-        Some(ns) => {
-            ns.split('.')
-                .map(|f| SanitizedName::from_module(f).sanitized_name)
-                .collect::<Vec<String>>()
-                .join("::")
-                + "::"
+    let type_name = match not_null_variants.as_slice() {
+        [] => return get_field_type(&Schema::Null, module_paths, parent_namespace, type_mapping),
+        [single_variant] => {
+            get_field_type(single_variant, module_paths, parent_namespace, type_mapping)?
+        }
+        _ => {
+            let union_name = Name {
+                name: get_union_name(schema, parent_namespace, module_paths).original_name,
+                namespace: parent_namespace.to_owned(),
+            };
+            sanitize_container_name(&union_name, module_paths)?
         }
     };
 
-    let type_sanitized_name = SanitizedName::from_type(full_name.name.as_ref());
+    if schema.is_nullable() {
+        return Ok(format!("Option<{type_name}>"));
+    }
+    Ok(type_name)
+}
 
-    Ok(format!(
-        "crate::{default_namespace}{namespace}{}",
-        type_sanitized_name.sanitized_name
-    ))
+pub fn get_not_null_variants(schema: &UnionSchema) -> Vec<&Schema> {
+    schema
+        .variants()
+        .iter()
+        .filter(|v| !matches!(v, Schema::Null))
+        .collect()
 }
 
-pub fn get_field_type_union(
-    schema: &UnionSchema,
-    default_namespace: &Option<String>,
-) -> Result<String> {
-    let allvariants = schema.variants();
+/// An union needs a generated enum when it contains more than one not null variant
+pub fn needs_generated_enum(schema: &UnionSchema) -> bool {
+    get_not_null_variants(schema).len() > 1
+}
 
-    if allvariants.len() == 1 {
-        return get_field_type(&allvariants[0], default_namespace);
+/// Name of the enum generated for an union, in the module of the parent namespace: its base name,
+/// followed by a number when another union of the module has the same base name, like `UnionStringX2`
+pub fn get_union_name(schema: &UnionSchema, parent_namespace: &Namespace, module_paths: &ModulePaths) -> SanitizedName {
+    match module_paths.union_name(parent_namespace, &union_key(schema)) {
+        Some(union_name) => SanitizedName::from_type(union_name),
+        None => get_union_base_name(schema, module_paths),
     }
+}
 
-    if allvariants.len() == 2 {
-        match allvariants[0] {
-            Schema::Null => {
-                return Ok(format!(
-                    "Option<{}>",
-                    get_field_type(&allvariants[1], default_namespace)?
-                )
-                .to_string())
-            }
-            _ => match allvariants[1] {
-                Schema::Null => {
-                    return Ok(format!(
-                        "Option<{}>",
-                        get_field_type(&allvariants[0], default_namespace)?
-                    )
-                    .to_string())
+/// Base name of the enum generated for an union: `Union` followed by the name of each not null variant
+pub fn get_union_base_name(schema: &UnionSchema, module_paths: &ModulePaths) -> SanitizedName {
+    let variants_names: String = get_union_variant_names(schema, module_paths).concat();
+
+    SanitizedName::from_type(&format!("Union{variants_names}"))
+}
+
+/// Types of the not null variants of an union, the named types by full name. The unions with the same key use the same enum
+pub fn union_key(schema: &UnionSchema) -> String {
+    get_not_null_variants(schema).into_iter().map(variant_key).join(", ")
+}
+
+fn variant_key(schema: &Schema) -> String {
+    match schema {
+        Schema::Array(items_schema) => format!("array<{}>", variant_key(items_schema)),
+        Schema::Map(values_schema) => format!("map<{}>", variant_key(values_schema)),
+        Schema::Union(union_schema) => format!("union<{}>", union_key(union_schema)),
+        Schema::Decimal(decimal_schema) => format!("decimal({}, {})", decimal_schema.precision, decimal_schema.scale),
+        _ => match schema.name() {
+            Some(name) => name.fullname(None),
+            None => format!("{:?}", SchemaKind::from(schema)),
+        },
+    }
+}
+
+/// Names of the enum variants of the not null variants of an union
+pub fn get_union_variant_names(schema: &UnionSchema, module_paths: &ModulePaths) -> Vec<String> {
+    get_variant_names(&get_not_null_variants(schema), module_paths)
+}
+
+/// Names of the enum variants of some types, like the variants of an union.
+/// Named types with the same Rust name are prefixed with the last part of their namespace, like the flat layout:
+/// `MachinesStatus` and `ShopStatus`, or with their whole namespace when it's not enough
+pub fn get_variant_names(variants: &[&Schema], module_paths: &ModulePaths) -> Vec<String> {
+    let names: Vec<String> = variants
+        .iter()
+        .map(|v| get_union_variant_name(v, module_paths))
+        .collect();
+
+    let prefixed_names = |names: &[String], prefix: fn(&str) -> String| -> Vec<String> {
+        variants
+            .iter()
+            .zip(names.iter())
+            .map(|(variant, name)| match variant.name() {
+                Some(type_name) if names.iter().filter(|n| *n == name).count() > 1 => {
+                    let namespace = type_name.namespace.as_deref().unwrap_or_default();
+                    SanitizedName::from_type(&format!("{}_{name}", prefix(namespace)))
+                        .sanitized_name
                 }
-                _ => return Ok("Union(2) ???".to_string()),
-            },
-        };
+                _ => name.to_owned(),
+            })
+            .collect()
+    };
+
+    let last_part = |namespace: &str| namespace.rsplit('.').next().unwrap_or_default().to_string();
+    let names_with_last_part = prefixed_names(&names, last_part);
+    match names_with_last_part.iter().all_unique() {
+        true => names_with_last_part,
+        false => prefixed_names(&names, |namespace| namespace.replace('.', "_")),
     }
+}
 
-    return Ok("Union(X) ???".to_string());
+/// Name of the enum variant used for a variant of an union, the named types use their Rust name
+pub fn get_union_variant_name(schema: &Schema, module_paths: &ModulePaths) -> String {
+    match schema {
+        Schema::Null => "Null".to_string(),
+        Schema::Boolean => "Boolean".to_string(),
+        Schema::Int => "Int".to_string(),
+        Schema::Long => "Long".to_string(),
+        Schema::Float => "Float".to_string(),
+        Schema::Double => "Double".to_string(),
+        Schema::Bytes => "Bytes".to_string(),
+        Schema::String => "String".to_string(),
+        Schema::Array(_) => "Array".to_string(),
+        Schema::Map(_) => "Map".to_string(),
+        Schema::Union(_) => "Union".to_string(),
+        Schema::Record(record_schema) => module_paths.type_name(&record_schema.name).sanitized_name,
+        Schema::Enum(enum_schema) => module_paths.type_name(&enum_schema.name).sanitized_name,
+        Schema::Fixed(fixed_schema) => module_paths.type_name(&fixed_schema.name).sanitized_name,
+        Schema::Decimal(_) => "Decimal".to_string(),
+        Schema::Uuid => "Uuid".to_string(),
+        Schema::Date => "Date".to_string(),
+        Schema::TimeMillis => "TimeMillis".to_string(),
+        Schema::TimeMicros => "TimeMicros".to_string(),
+        Schema::TimestampMillis => "TimestampMillis".to_string(),
+        Schema::TimestampMicros => "TimestampMicros".to_string(),
+        Schema::LocalTimestampMillis => "LocalTimestampMillis".to_string(),
+        Schema::LocalTimestampMicros => "LocalTimestampMicros".to_string(),
+        Schema::Duration => "Duration".to_string(),
+        Schema::Ref { name: ref_name } => module_paths.type_name(ref_name).sanitized_name,
+    }
 }
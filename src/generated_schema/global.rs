@@ -1,7 +1,7 @@
 use crate::Result;
 use std::fmt::Write;
 
-pub const RESERVED_NAMES: &'static [&str] = &[
+pub const RESERVED_NAMES: &[&str] = &[
   "as",
   "use",
   "extern crate",
@@ -94,11 +94,15 @@ impl SanitizedName{
         Self::from(original_name, heck::ToUpperCamelCase::to_upper_camel_case,"Type")
     }
 
+    pub fn from_message(original_name: &str) -> SanitizedName {
+        Self::from(original_name, heck::ToSnekCase::to_snek_case,"message_")
+    }
+
     fn from(original_name: &str,apply_fn:impl Fn(&str) ->String,reserved_name_prefix: &str) -> SanitizedName
     {
         let mut sanitized_name = apply_fn(original_name);
 
-        if RESERVED_NAMES.iter().any(|s| s.to_string()  == sanitized_name) {
+        if RESERVED_NAMES.iter().any(|s| *s == sanitized_name) {
             sanitized_name = format!("{}{}",reserved_name_prefix,sanitized_name);
         }
         
@@ -0,0 +1,328 @@
+use apache_avro::Schema;
+use std::fmt::Write;
+
+use super::field_type::get_values_schema;
+use super::module_paths::ModulePaths;
+use super::time_backend::TimeBackend;
+use crate::error::AvrogenError;
+use crate::Result;
+
+/// Namespace of the serde glue shared by the fields with a logical type
+pub const LOGICAL_TYPES_NAMESPACE: &str = "logical_types";
+
+const TRAIT_NAME: &str = "AvroLogicalType";
+
+/// Logical types serialized by the generated glue, each one has a unit struct named like the variant
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum LogicalType {
+    Date,
+    TimeMillis,
+    TimeMicros,
+    TimestampMillis,
+    TimestampMicros,
+    LocalTimestampMillis,
+    LocalTimestampMicros,
+    Uuid,
+}
+
+impl LogicalType {
+    pub fn from_schema(schema: &Schema) -> Option<Self> {
+        match schema {
+            Schema::Date => Some(LogicalType::Date),
+            Schema::TimeMillis => Some(LogicalType::TimeMillis),
+            Schema::TimeMicros => Some(LogicalType::TimeMicros),
+            Schema::TimestampMillis => Some(LogicalType::TimestampMillis),
+            Schema::TimestampMicros => Some(LogicalType::TimestampMicros),
+            Schema::LocalTimestampMillis => Some(LogicalType::LocalTimestampMillis),
+            Schema::LocalTimestampMicros => Some(LogicalType::LocalTimestampMicros),
+            Schema::Uuid => Some(LogicalType::Uuid),
+            _ => None,
+        }
+    }
+
+    /// Name of the unit struct of the logical type in the generated glue
+    pub fn glue_name(&self) -> &'static str {
+        match self {
+            LogicalType::Date => "Date",
+            LogicalType::TimeMillis => "TimeMillis",
+            LogicalType::TimeMicros => "TimeMicros",
+            LogicalType::TimestampMillis => "TimestampMillis",
+            LogicalType::TimestampMicros => "TimestampMicros",
+            LogicalType::LocalTimestampMillis => "LocalTimestampMillis",
+            LogicalType::LocalTimestampMicros => "LocalTimestampMicros",
+            LogicalType::Uuid => "Uuid",
+        }
+    }
+
+    /// Avro encoding of the logical type, documentation of its unit struct
+    fn encoding_doc(&self) -> &'static str {
+        match self {
+            LogicalType::Date => "`date`: the number of days since the Unix epoch, an int",
+            LogicalType::TimeMillis => "`time-millis`: the number of milliseconds after midnight, an int",
+            LogicalType::TimeMicros => "`time-micros`: the number of microseconds after midnight, a long",
+            LogicalType::TimestampMillis => "`timestamp-millis`: the number of milliseconds since the Unix epoch in UTC, a long",
+            LogicalType::TimestampMicros => "`timestamp-micros`: the number of microseconds since the Unix epoch in UTC, a long",
+            LogicalType::LocalTimestampMillis => "`local-timestamp-millis`: the number of milliseconds since the Unix epoch in local time, a long",
+            LogicalType::LocalTimestampMicros => "`local-timestamp-micros`: the number of microseconds since the Unix epoch in local time, a long",
+            LogicalType::Uuid => "`uuid`: the string representation of the UUID",
+        }
+    }
+
+    /// Unit of the times and timestamps, in the name of the methods, and the number of units in a second
+    pub fn unit(&self) -> (&'static str, i64) {
+        match self {
+            LogicalType::TimeMillis
+            | LogicalType::TimestampMillis
+            | LogicalType::LocalTimestampMillis => ("millis", 1_000),
+            _ => ("micros", 1_000_000),
+        }
+    }
+
+    /// Rust expression of a default uuid, written like the Avro spec with hexadecimal groups of 8-4-4-4-12 digits
+    pub fn uuid_value(uuid: &str) -> Result<String> {
+        if !is_uuid(uuid) {
+            return Err(AvrogenError::invalid_default(format!(
+                "the default value {uuid:?} is not a uuid"
+            )));
+        }
+        Ok(format!("uuid::uuid!({uuid:?})"))
+    }
+}
+
+/// Serde glue converting the logical types between their Avro encoding and the Rust types, generated once with the logical types used by the schemas
+#[derive(Debug)]
+pub struct GeneratedLogicalTypes {
+    backend: TimeBackend,
+    logical_types: Vec<LogicalType>,
+}
+
+impl GeneratedLogicalTypes {
+    pub fn from(backend: TimeBackend, logical_types: &[LogicalType]) -> Self {
+        let mut logical_types = logical_types.to_vec();
+        logical_types.sort();
+        logical_types.dedup();
+
+        GeneratedLogicalTypes {
+            backend,
+            logical_types,
+        }
+    }
+
+    pub fn produce_content(&self) -> Result<String> {
+        let mut content_string = String::new();
+        writeln!(content_string, "/// A value of a logical type, or a container of values, serialized like the Avro logical type")?;
+        writeln!(content_string, "pub trait AvroLogicalType<Unit>: Sized {{")?;
+        write!(content_string, "    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;\r\n\r\n")?;
+        writeln!(content_string, "    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        for logical_type in self.logical_types.iter() {
+            let name = logical_type.glue_name();
+            writeln!(content_string, "/// {}", logical_type.encoding_doc())?;
+            writeln!(
+                content_string,
+                "/// The fields use it with `#[serde(with = \"{name}\")]`"
+            )?;
+            write!(content_string, "pub struct {name};\r\n\r\n")?;
+            writeln!(content_string, "impl {name} {{")?;
+            writeln!(content_string, "    pub fn serialize<T: AvroLogicalType<Self>, S: serde::Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {{")?;
+            writeln!(
+                content_string,
+                "        value.serialize_logical(serializer)"
+            )?;
+            write!(content_string, "    }}\r\n\r\n")?;
+            writeln!(content_string, "    pub fn deserialize<'de, T: AvroLogicalType<Self>, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {{")?;
+            writeln!(
+                content_string,
+                "        T::deserialize_logical(deserializer)"
+            )?;
+            writeln!(content_string, "    }}")?;
+            write!(content_string, "}}\r\n\r\n")?;
+
+            let (serialize, deserialize) = self.backend.glue(*logical_type);
+            writeln!(
+                content_string,
+                "impl AvroLogicalType<{name}> for {} {{",
+                self.backend.type_name(*logical_type)
+            )?;
+            writeln!(content_string, "    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{")?;
+            for line in serialize {
+                writeln!(content_string, "        {line}")?;
+            }
+            write!(content_string, "    }}\r\n\r\n")?;
+            writeln!(content_string, "    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{")?;
+            for line in deserialize {
+                writeln!(content_string, "        {line}")?;
+            }
+            writeln!(content_string, "    }}")?;
+            write!(content_string, "}}\r\n\r\n")?;
+        }
+
+        writeln!(
+            content_string,
+            "impl<U, T: AvroLogicalType<U>> AvroLogicalType<U> for Option<T> {{"
+        )?;
+        writeln!(content_string, "    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{")?;
+        writeln!(content_string, "        match self {{")?;
+        writeln!(content_string, "            Some(value) => serializer.serialize_some(&LogicalValue::<U, T>(value, std::marker::PhantomData)),")?;
+        writeln!(
+            content_string,
+            "            None => serializer.serialize_none(),"
+        )?;
+        writeln!(content_string, "        }}")?;
+        write!(content_string, "    }}\r\n\r\n")?;
+        writeln!(content_string, "    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{")?;
+        writeln!(content_string, "        deserializer.deserialize_option(LogicalVisitor::<U, Self>(std::marker::PhantomData))")?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+        writeln!(
+            content_string,
+            "impl<U, T: AvroLogicalType<U>> AvroLogicalType<U> for Vec<T> {{"
+        )?;
+        writeln!(content_string, "    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{")?;
+        writeln!(content_string, "        serializer.collect_seq(self.iter().map(|value| LogicalValue::<U, T>(value, std::marker::PhantomData)))")?;
+        write!(content_string, "    }}\r\n\r\n")?;
+        writeln!(content_string, "    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{")?;
+        writeln!(content_string, "        deserializer.deserialize_seq(LogicalVisitor::<U, Self>(std::marker::PhantomData))")?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+        writeln!(content_string, "impl<U, T: AvroLogicalType<U>> AvroLogicalType<U> for std::collections::HashMap<String, T> {{")?;
+        writeln!(content_string, "    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{")?;
+        writeln!(content_string, "        serializer.collect_map(self.iter().map(|(key, value)| (key, LogicalValue::<U, T>(value, std::marker::PhantomData))))")?;
+        write!(content_string, "    }}\r\n\r\n")?;
+        writeln!(content_string, "    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{")?;
+        writeln!(content_string, "        deserializer.deserialize_map(LogicalVisitor::<U, Self>(std::marker::PhantomData))")?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+        writeln!(content_string, "/// Serialize a value inside a container")?;
+        write!(
+            content_string,
+            "struct LogicalValue<'a, U, T>(&'a T, std::marker::PhantomData<U>);\r\n\r\n"
+        )?;
+        writeln!(
+            content_string,
+            "impl<U, T: AvroLogicalType<U>> serde::Serialize for LogicalValue<'_, U, T> {{"
+        )?;
+        writeln!(content_string, "    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{")?;
+        writeln!(
+            content_string,
+            "        self.0.serialize_logical(serializer)"
+        )?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+        writeln!(content_string, "/// Deserialize the values of a container")?;
+        write!(
+            content_string,
+            "struct LogicalVisitor<U, T>(std::marker::PhantomData<(U, T)>);\r\n\r\n"
+        )?;
+        writeln!(content_string, "impl<'de, U, T: AvroLogicalType<U>> serde::de::DeserializeSeed<'de> for LogicalVisitor<U, T> {{")?;
+        write!(content_string, "    type Value = T;\r\n\r\n")?;
+        writeln!(content_string, "    fn deserialize<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {{")?;
+        writeln!(
+            content_string,
+            "        T::deserialize_logical(deserializer)"
+        )?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+        writeln!(content_string, "impl<'de, U, T: AvroLogicalType<U>> serde::de::Visitor<'de> for LogicalVisitor<U, Option<T>> {{")?;
+        write!(content_string, "    type Value = Option<T>;\r\n\r\n")?;
+        writeln!(
+            content_string,
+            "    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {{"
+        )?;
+        writeln!(
+            content_string,
+            "        formatter.write_str(\"an optional value\")"
+        )?;
+        write!(content_string, "    }}\r\n\r\n")?;
+        writeln!(
+            content_string,
+            "    fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> {{"
+        )?;
+        writeln!(content_string, "        Ok(None)")?;
+        write!(content_string, "    }}\r\n\r\n")?;
+        writeln!(
+            content_string,
+            "    fn visit_unit<E: serde::de::Error>(self) -> Result<Self::Value, E> {{"
+        )?;
+        writeln!(content_string, "        Ok(None)")?;
+        write!(content_string, "    }}\r\n\r\n")?;
+        writeln!(content_string, "    fn visit_some<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {{")?;
+        writeln!(
+            content_string,
+            "        T::deserialize_logical(deserializer).map(Some)"
+        )?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+        writeln!(content_string, "impl<'de, U, T: AvroLogicalType<U>> serde::de::Visitor<'de> for LogicalVisitor<U, Vec<T>> {{")?;
+        write!(content_string, "    type Value = Vec<T>;\r\n\r\n")?;
+        writeln!(
+            content_string,
+            "    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {{"
+        )?;
+        writeln!(content_string, "        formatter.write_str(\"an array\")")?;
+        write!(content_string, "    }}\r\n\r\n")?;
+        writeln!(content_string, "    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {{")?;
+        writeln!(content_string, "        let mut values = Vec::new();")?;
+        writeln!(content_string, "        while let Some(value) = seq.next_element_seed(LogicalVisitor::<U, T>(std::marker::PhantomData))? {{")?;
+        writeln!(content_string, "            values.push(value);")?;
+        writeln!(content_string, "        }}")?;
+        writeln!(content_string, "        Ok(values)")?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+        writeln!(content_string, "impl<'de, U, T: AvroLogicalType<U>> serde::de::Visitor<'de> for LogicalVisitor<U, std::collections::HashMap<String, T>> {{")?;
+        write!(
+            content_string,
+            "    type Value = std::collections::HashMap<String, T>;\r\n\r\n"
+        )?;
+        writeln!(
+            content_string,
+            "    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {{"
+        )?;
+        writeln!(content_string, "        formatter.write_str(\"a map\")")?;
+        write!(content_string, "    }}\r\n\r\n")?;
+        writeln!(content_string, "    fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {{")?;
+        writeln!(
+            content_string,
+            "        let mut values = std::collections::HashMap::new();"
+        )?;
+        writeln!(
+            content_string,
+            "        while let Some(key) = map.next_key::<String>()? {{"
+        )?;
+        writeln!(content_string, "            values.insert(key, map.next_value_seed(LogicalVisitor::<U, T>(std::marker::PhantomData))?);")?;
+        writeln!(content_string, "        }}")?;
+        writeln!(content_string, "        Ok(values)")?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        Ok(content_string)
+    }
+
+    pub fn schema_name(&self) -> String {
+        TRAIT_NAME.to_owned()
+    }
+}
+
+/// Serde attribute of a field with a logical type: a value, or an option, an array or a map of values
+pub fn logical_type_serde_with(
+    schema: &Schema,
+    module_paths: &ModulePaths,
+) -> Result<Option<String>> {
+    let Some(logical_type) = LogicalType::from_schema(get_values_schema(schema)) else {
+        return Ok(None);
+    };
+
+    let glue_path = module_paths.helper_path(LOGICAL_TYPES_NAMESPACE, logical_type.glue_name());
+
+    Ok(Some(format!("#[serde(with = \"{glue_path}\")]")))
+}
+
+/// A uuid is written with hexadecimal groups of 8-4-4-4-12 digits separated by hyphens
+pub fn is_uuid(uuid: &str) -> bool {
+    uuid.len() == 36
+        && uuid.char_indices().all(|(index, c)| match index {
+            8 | 13 | 18 | 23 => c == '-',
+            _ => c.is_ascii_hexdigit(),
+        })
+}
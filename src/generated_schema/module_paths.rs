@@ -0,0 +1,343 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use apache_avro::schema::{Name, Namespace, UnionSchema};
+use itertools::Itertools;
+
+use crate::error::{AvrogenError, Location};
+use crate::Result;
+
+use super::field_type::{get_union_base_name, union_key};
+use super::global::SanitizedName;
+
+/// Modules of the generated types
+#[derive(Debug, Clone, Copy, Default, PartialEq, clap::ValueEnum)]
+pub enum ModuleLayout {
+    /// A module for each part of the namespaces: `crate::com::my_site::machines::Machine`
+    #[default]
+    Nested,
+    /// All the types in one module, the module of the default namespace: `crate::schemas::Machine`. Two types with the same name are an error
+    Flat,
+    /// Like flat, the types with the same name are prefixed with the last part of their namespace: `MachinesStatus`
+    FlatPrefixed,
+}
+
+/// Rule moving the types of an Avro namespace to another module: `com.mysite.machines=machines`,
+/// or of a namespace and its sub namespaces: `org.apache.*=vendor::apache`
+#[derive(Debug, Clone, PartialEq)]
+pub struct NamespaceRule {
+    /// Avro namespace, `org.apache` for the rule `org.apache.*=vendor::apache`
+    namespace: String,
+    /// The namespace ends with `.*`, the rule applies to its sub namespaces too
+    sub_namespaces: bool,
+    /// Namespace of the module, `vendor.apache` for the rule `org.apache.*=vendor::apache`, empty for the root module
+    module: String,
+}
+
+impl NamespaceRule {
+    /// The namespace can end with `.*`, the module can be written with `::` or `.` and can be empty
+    pub fn new(namespace: &str, module: &str) -> Self {
+        let namespace = namespace.trim();
+        let sub_namespaces = namespace.ends_with(".*");
+        NamespaceRule {
+            namespace: namespace.trim_end_matches(".*").to_string(),
+            sub_namespaces,
+            module: module.trim().replace("::", "."),
+        }
+    }
+
+    /// Namespace of the module of the Avro namespace, when the rule applies to it
+    fn apply(&self, namespace: &str) -> Option<String> {
+        let sub_namespace = match namespace.strip_prefix(&self.namespace) {
+            Some("") => None,
+            Some(sub_namespace) if self.sub_namespaces => {
+                Some(sub_namespace.strip_prefix('.')?.to_string())
+            }
+            _ => return None,
+        };
+
+        Some(join_namespaces(
+            &Some(self.module.to_owned()),
+            &sub_namespace,
+        ))
+    }
+}
+
+/// The rules are written `namespace=module`
+impl FromStr for NamespaceRule {
+    type Err = String;
+
+    fn from_str(rule: &str) -> std::result::Result<Self, Self::Err> {
+        match rule.split_once('=') {
+            Some((namespace, module)) if !namespace.trim().is_empty() => {
+                Ok(NamespaceRule::new(namespace, module))
+            }
+            _ => Err(format!(
+                "the rule {rule} must be written namespace=module, like com.mysite.machines=machines"
+            )),
+        }
+    }
+}
+
+/// Root of the paths of the generated types
+#[derive(Debug, Clone, PartialEq)]
+pub enum RootPath {
+    /// Path of the root module of the generated code, written as is in every module: `crate`, `crate::gen`, `::my_schemas`
+    Path(String),
+    /// Path from the module which uses the type: `super::machines::Machine`
+    Relative,
+}
+
+/// Rust modules and paths of the generated types, from their Avro names
+#[derive(Debug, Clone)]
+pub struct ModulePaths {
+    default_namespace: Option<String>,
+    layout: ModuleLayout,
+    namespace_rules: Vec<NamespaceRule>,
+    root_path: RootPath,
+    /// Namespace of the module where the code is generated, the relative paths start from it
+    current_module: String,
+    /// Rust names of the types renamed by the flat layout, because several namespaces have a type with the same name
+    renamed_types: HashMap<Name, String>,
+    /// Rust names of the union enums by module and key of the union, the different unions with the same name in a module are numbered
+    union_names: HashMap<(String, String), String>,
+}
+
+impl ModulePaths {
+    /// The names of the generated types are needed to find the conflicts of the flat layout,
+    /// and the unions with the namespace of their parent to find the conflicts of the union enums
+    pub fn new(
+        default_namespace: Option<String>,
+        layout: ModuleLayout,
+        namespace_rules: Vec<NamespaceRule>,
+        root_path: RootPath,
+        type_names: &[&Name],
+        unions: &[(Namespace, &UnionSchema)],
+    ) -> Result<Self> {
+        let mut module_paths = ModulePaths {
+            default_namespace,
+            layout,
+            namespace_rules,
+            root_path,
+            current_module: String::new(),
+            renamed_types: HashMap::new(),
+            union_names: HashMap::new(),
+        };
+
+        if layout != ModuleLayout::Nested {
+            module_paths.renamed_types = flat_renamed_types(layout, type_names)?;
+        }
+        module_paths.union_names = union_names(&module_paths, unions);
+
+        Ok(module_paths)
+    }
+
+    /// Paths of the code generated in a sub module
+    pub fn sub_module(&self, module_name: &str) -> Self {
+        ModulePaths {
+            current_module: join_namespaces(
+                &Some(self.current_module.to_owned()),
+                &Some(module_name.to_string()),
+            ),
+            ..self.clone()
+        }
+    }
+
+    /// Namespace of the module of the types of an Avro namespace, the empty namespace is the root module.
+    /// With the nested layout, the rule of the longest namespace moves the types to its module
+    pub fn module_namespace(&self, namespace: &Namespace) -> String {
+        match self.layout {
+            ModuleLayout::Nested => {
+                let rule_module = namespace.as_deref().and_then(|namespace| {
+                    self.namespace_rules
+                        .iter()
+                        .filter_map(|rule| Some((rule.namespace.len(), rule.apply(namespace)?)))
+                        .max_by_key(|(length, _)| *length)
+                        .map(|(_, module)| module)
+                });
+
+                match rule_module {
+                    Some(module) => join_namespaces(&self.default_namespace, &Some(module)),
+                    None => join_namespaces(&self.default_namespace, namespace),
+                }
+            }
+            ModuleLayout::Flat | ModuleLayout::FlatPrefixed => {
+                join_namespaces(&self.default_namespace, &None)
+            }
+        }
+    }
+
+    /// Namespace of a module of helpers, like the decimal glue, in the default namespace whatever the layout
+    pub fn helper_namespace(&self, helper_module: &str) -> String {
+        join_namespaces(&self.default_namespace, &Some(helper_module.to_string()))
+    }
+
+    /// Rust name of the generated type
+    pub fn type_name(&self, name: &Name) -> SanitizedName {
+        match self.renamed_types.get(name) {
+            Some(renamed) => SanitizedName {
+                sanitized_name: renamed.to_owned(),
+                original_name: name.name.to_owned(),
+                is_sanitized: true,
+            },
+            None => SanitizedName::from_type(&name.name),
+        }
+    }
+
+    /// Rust name of the enum of an union used in the parent namespace, when the union needs an enum
+    pub fn union_name(&self, parent_namespace: &Namespace, union_key: &str) -> Option<&str> {
+        self.union_names
+            .get(&(self.module_namespace(parent_namespace), union_key.to_string()))
+            .map(String::as_str)
+    }
+
+    /// Rust path of the generated type, like `crate::com::my_site::Machine`
+    pub fn type_path(&self, name: &Name) -> String {
+        self.item_path(
+            &self.module_namespace(&name.namespace),
+            &self.type_name(name).sanitized_name,
+        )
+    }
+
+    /// Rust path of an item of a module of helpers, like `crate::decimal::PrecisionScale`
+    pub fn helper_path(&self, helper_module: &str, item: &str) -> String {
+        self.item_path(&self.helper_namespace(helper_module), item)
+    }
+
+    fn item_path(&self, module_namespace: &str, item: &str) -> String {
+        let modules = module_names(module_namespace);
+
+        match &self.root_path {
+            RootPath::Path(root_path) => {
+                let root_path = root_path.trim_end_matches("::");
+                let modules: String = modules.iter().map(|m| format!("{m}::")).collect();
+
+                format!("{root_path}::{modules}{item}")
+            }
+            RootPath::Relative => {
+                let current_modules = module_names(&self.current_module);
+                let common_length = current_modules
+                    .iter()
+                    .zip(modules.iter())
+                    .take_while(|(current, module)| current == module)
+                    .count();
+
+                let parents = match current_modules.len() - common_length {
+                    0 => "self::".to_string(),
+                    count => "super::".repeat(count),
+                };
+                let modules: String = modules[common_length..]
+                    .iter()
+                    .map(|m| format!("{m}::"))
+                    .collect();
+
+                format!("{parents}{modules}{item}")
+            }
+        }
+    }
+}
+
+fn join_namespaces(default_namespace: &Option<String>, namespace: &Namespace) -> String {
+    [default_namespace.to_owned(), namespace.to_owned()]
+        .into_iter()
+        .flatten()
+        .filter(|ns| !ns.is_empty())
+        .join(".")
+}
+
+/// Rust names of the modules of a namespace
+fn module_names(module_namespace: &str) -> Vec<String> {
+    module_namespace
+        .split('.')
+        .filter(|ns| !ns.is_empty())
+        .map(|ns| SanitizedName::from_module(ns).sanitized_name)
+        .collect()
+}
+
+/// In one module, the types with the same Rust name are an error, or prefixed with the last part of their namespace.
+/// The types are sorted by full name, so the same type is reported when a name is still used twice
+fn flat_renamed_types(layout: ModuleLayout, type_names: &[&Name]) -> Result<HashMap<Name, String>> {
+    let mut renamed_types = HashMap::new();
+
+    let names_by_type = type_names
+        .iter()
+        .unique()
+        .sorted_by_key(|name| name.fullname(None))
+        .into_group_map_by(|name| SanitizedName::from_type(&name.name).sanitized_name);
+
+    if layout == ModuleLayout::FlatPrefixed {
+        for names in names_by_type.values().filter(|names| names.len() > 1) {
+            for name in names {
+                let prefix = name
+                    .namespace
+                    .as_deref()
+                    .and_then(|ns| ns.rsplit('.').next())
+                    .unwrap_or_default();
+                let renamed =
+                    SanitizedName::from_type(&format!("{prefix}_{}", name.name)).sanitized_name;
+                renamed_types.insert((**name).to_owned(), renamed);
+            }
+        }
+    }
+
+    // A prefixed name can be the name of another type too
+    let mut flat_names: HashMap<String, &Name> = HashMap::new();
+    for name in type_names
+        .iter()
+        .unique()
+        .sorted_by_key(|name| name.fullname(None))
+    {
+        let type_name = match renamed_types.get(*name) {
+            Some(renamed) => renamed.to_owned(),
+            None => SanitizedName::from_type(&name.name).sanitized_name,
+        };
+
+        if let Some(other) = flat_names.insert(type_name.to_owned(), name) {
+            return Err(flat_conflict(name, &type_name, other));
+        }
+    }
+
+    Ok(renamed_types)
+}
+
+/// The unions get their base name in the order they are found, a different union with the same base name
+/// in the same module gets the next free number after it: `UnionStringX`, then `UnionStringX2`
+fn union_names(module_paths: &ModulePaths, unions: &[(Namespace, &UnionSchema)]) -> HashMap<(String, String), String> {
+    let mut union_names = HashMap::new();
+    let mut used_names: Vec<(String, String)> = Vec::new();
+
+    for (parent_namespace, union_schema) in unions {
+        let module = module_paths.module_namespace(parent_namespace);
+        let key = (module.to_owned(), union_key(union_schema));
+        if union_names.contains_key(&key) {
+            continue;
+        }
+
+        let base_name = get_union_base_name(union_schema, module_paths).sanitized_name;
+        let union_name = (1..)
+            .map(|number| match number {
+                1 => base_name.to_owned(),
+                _ => format!("{base_name}{number}"),
+            })
+            .find(|name| !used_names.contains(&(module.to_owned(), name.to_owned())))
+            .unwrap_or(base_name);
+
+        used_names.push((module, union_name.to_owned()));
+        union_names.insert(key, union_name);
+    }
+
+    union_names
+}
+
+fn flat_conflict(name: &Name, type_name: &str, other: &Name) -> AvrogenError {
+    AvrogenError::Generation {
+        location: Location {
+            type_name: Some(name.fullname(None)),
+            ..Default::default()
+        },
+        message: format!(
+            "the type {type_name} of the flat layout is already the type of {}, rename one of them or use the nested layout",
+            other.fullname(None)
+        ),
+    }
+}
@@ -1,9 +1,20 @@
 use std::{collections::HashMap, fmt::Debug};
-use apache_avro::{schema::Name, Schema};
+use apache_avro::Schema;
 use log::debug;
+use crate::browse_sub_schemas::generation_namespace;
+use crate::protocol_parser::ProtocolDefinition;
+use crate::error::{AvrogenError, Location};
 use crate::Result;
 
-use super::{global::SanitizedName, schema::*};
+use super::{global::SanitizedName, schema::*, schema_json::SchemaDefinitions};
+use super::bytes::{GeneratedBytes, BYTES_NAMESPACE};
+use super::confluent::{GeneratedConfluent, CONFLUENT_NAMESPACE};
+use super::decimal::{GeneratedDecimal, DECIMAL_NAMESPACE};
+use super::duration::{GeneratedDuration, DURATION_NAMESPACE};
+use super::values::{GeneratedValues, VALUES_NAMESPACE};
+use super::field_type::TypeMapping;
+use super::module_paths::ModulePaths;
+use super::logical_types::{GeneratedLogicalTypes, LogicalType, LOGICAL_TYPES_NAMESPACE};
 
 #[derive(Debug)]
 pub struct NamespaceInfo
@@ -12,84 +23,198 @@ pub struct NamespaceInfo
     pub name: SanitizedName,
     pub generated_types: HashMap<String,GeneratedType>,
     pub children: HashMap<String,NamespaceInfo>,
-    pub default_namespace: Option<String>,
+    pub module_paths: ModulePaths,
+    pub type_mapping: TypeMapping,
 }
 
 impl NamespaceInfo
 {    
-    pub fn root(default_namespace: Option<String>) ->  Self
+    pub fn root(module_paths: ModulePaths, type_mapping: TypeMapping) ->  Self
     {
         NamespaceInfo{ 
             is_root: true,
-            name: SanitizedName::from_module(&"".to_string()),
+            name: SanitizedName::from_module(""),
             children: HashMap::new(),
             generated_types: HashMap::new(),
-            default_namespace:default_namespace.clone()
+            module_paths,
+            type_mapping
         }
     }
     
     fn new(parent: &NamespaceInfo, ns_begining: &str) -> Self {
         NamespaceInfo{ 
-            name: SanitizedName::from_module(&ns_begining), 
+            name: SanitizedName::from_module(ns_begining), 
             children: HashMap::new(),
             is_root: false,
             generated_types: HashMap::new(),
-            default_namespace:parent.default_namespace.clone()
+            module_paths: parent.module_paths.sub_module(ns_begining),
+            type_mapping: parent.type_mapping
         }
     }
 
-    pub fn process_schema(&mut self,schema: &Schema) -> Result<()>
+    pub fn process_schema(&mut self,schema: &Schema, schema_definitions: &SchemaDefinitions) -> Result<()>
     {   
-        let full_namespace = [self.default_namespace.to_owned(), schema.namespace().to_owned()]
-        .into_iter()
-        .flatten()
-        .collect::<Vec<String>>()
-        .join(".");
+        let full_namespace = self.module_paths.module_namespace(&generation_namespace(schema));
     
-        self.child_process_schema(schema,full_namespace)
+        self.child_namespace(full_namespace).real_process(schema, schema_definitions)
     }
 
+    /// The protocol trait is generated in the protocol namespace, with the unions used by the responses
+    pub fn process_protocol(&mut self,protocol: &ProtocolDefinition, schema_definitions: &SchemaDefinitions) -> Result<()>
+    {
+        let full_namespace = self.module_paths.module_namespace(&protocol.namespace);
+        let namespace = self.child_namespace(full_namespace);
 
-     fn child_process_schema(&mut self,schema: &Schema,sub_schema_path: String )-> Result<()>
-     {
-        if sub_schema_path == ""
-        {
-            return self.real_process(schema);
+        let contents = GeneratedType::generate_protocol(protocol, &namespace.module_paths, schema_definitions, &namespace.type_mapping)
+        .map_err(|e| e.in_type(&protocol.name))?;
+
+        for content in contents {
+            namespace.add_generated_type(content)?;
         }
 
-        let (ns_begining,ns_endsection) = sub_schema_path.split_once(".").unwrap_or((sub_schema_path.as_str(),""));
+        Ok(())
+    }
+
+    /// The types shared by the records for the Confluent wire format are generated in their own namespace
+    pub fn process_confluent(&mut self) -> Result<()>
+    {
+        let full_namespace = self.module_paths.helper_namespace(CONFLUENT_NAMESPACE);
+        let content = GeneratedType::Confluent(GeneratedConfluent{});
+
+        self.child_namespace(full_namespace).generated_types.insert(content.schema_name(), content );
+
+        Ok(())
+    }
+
+    /// The serde glue of the bytes fields is generated in its own namespace
+    pub fn process_bytes(&mut self) -> Result<()>
+    {
+        let full_namespace = self.module_paths.helper_namespace(BYTES_NAMESPACE);
+        let content = GeneratedType::Bytes(GeneratedBytes{});
+
+        self.child_namespace(full_namespace).generated_types.insert(content.schema_name(), content );
+
+        Ok(())
+    }
+
+    /// The serde glue of the decimal fields is generated in its own namespace
+    pub fn process_decimal(&mut self) -> Result<()>
+    {
+        let full_namespace = self.module_paths.helper_namespace(DECIMAL_NAMESPACE);
+        let content = GeneratedType::Decimal(GeneratedDecimal::from(self.type_mapping.decimal));
+
+        self.child_namespace(full_namespace).generated_types.insert(content.schema_name(), content );
+
+        Ok(())
+    }
+
+    /// The type of the durations is generated in its own namespace
+    pub fn process_duration(&mut self) -> Result<()>
+    {
+        let full_namespace = self.module_paths.helper_namespace(DURATION_NAMESPACE);
+        let content = GeneratedType::Duration(GeneratedDuration{});
+
+        self.child_namespace(full_namespace).generated_types.insert(content.schema_name(), content );
+
+        Ok(())
+    }
+
+    /// The conversions of the values used by the record readers and writers are generated in their own namespace
+    pub fn process_values(&mut self) -> Result<()>
+    {
+        let full_namespace = self.module_paths.helper_namespace(VALUES_NAMESPACE);
+        let content = GeneratedType::Values(GeneratedValues{});
 
-        match self.children.get_mut(ns_begining){
-            Some(child) => child.child_process_schema(schema, ns_endsection.to_string()),
-            None => {
-                let mut new_ns = NamespaceInfo::new(&self,ns_begining);
+        self.child_namespace(full_namespace).generated_types.insert(content.schema_name(), content );
 
-                debug!("New namespace: {:?}", new_ns.name.sanitized_name);
+        Ok(())
+    }
 
+    /// The serde glue of the logical types used by the schemas is generated in its own namespace
+    pub fn process_logical_types(&mut self, logical_types: &[LogicalType]) -> Result<()>
+    {
+        let full_namespace = self.module_paths.helper_namespace(LOGICAL_TYPES_NAMESPACE);
+        let content = GeneratedType::LogicalTypes(GeneratedLogicalTypes::from(self.type_mapping.time, logical_types));
 
-                let result=new_ns.child_process_schema(schema, ns_endsection.to_string());
+        self.child_namespace(full_namespace).generated_types.insert(content.schema_name(), content );
 
-                self.children.insert(ns_begining.to_string(), new_ns);
+        Ok(())
+    }
 
-                result
+    /// The same type can be generated several times, like an union used by several records.
+    /// Two different types with the same name in a module are an error, the flat layout puts all the types in one module
+    fn add_generated_type(&mut self, content: GeneratedType) -> Result<()>
+    {
+        let type_name = content.schema_name();
+
+        if let Some(existing) = self.generated_types.get(&type_name) {
+            if existing.produce_content()? != content.produce_content()? {
+                let module = match self.is_root {
+                    true => "the root module".to_string(),
+                    false => format!("the module {}", self.name.sanitized_name),
+                };
+                return Err(AvrogenError::Generation{
+                    location: Location{ type_name: Some(type_name.to_owned()), ..Default::default() },
+                    message: format!("two different types are generated with the name {type_name} in {module}"),
+                });
             }
         }
+
+        self.generated_types.insert(type_name, content);
+
+        Ok(())
+    }
+
+     /// Return the namespace for the path, sub namespaces are created when they don't exist
+     fn child_namespace(&mut self,sub_schema_path: String )-> &mut NamespaceInfo
+     {
+        if sub_schema_path.is_empty()
+        {
+            return self;
+        }
+
+        let (ns_begining,ns_endsection) = sub_schema_path.split_once(".").unwrap_or((sub_schema_path.as_str(),""));
+
+        if !self.children.contains_key(ns_begining){
+            let new_ns = NamespaceInfo::new(self,ns_begining);
+
+            debug!("New namespace: {:?}", new_ns.name.sanitized_name);
+
+            self.children.insert(ns_begining.to_string(), new_ns);
+        }
+
+        self.children
+        .get_mut(ns_begining)
+        .expect("The namespace has been created")
+        .child_namespace(ns_endsection.to_string())
      }
 
-     fn real_process(&mut self,schema: &Schema)-> Result<()>
+     fn real_process(&mut self,schema: &Schema, schema_definitions: &SchemaDefinitions)-> Result<()>
      {
-        let unknown_schema_name=Name::new("Unknown_schema_name").unwrap();
-        // A changer
-        let content = GeneratedType::generate_schema_struct( schema, &self.default_namespace )
-        .map_err(|e|format!("{}: {e}", schema
-        .name()
-        .unwrap_or(&unknown_schema_name)
-        .fullname(None)))?;
+        let content = GeneratedType::generate_schema_struct( schema, &self.module_paths, schema_definitions, &self.type_mapping )
+        .map_err(|e| match schema.name() {
+            Some(name) => e.in_type(&name.fullname(None)),
+            None => e,
+        })?;
 
         if let GeneratedType::None = content{
         }
         else {
-            self.generated_types.insert(content.schema_name(), content );
+            self.add_generated_type(content)?;
+        }
+
+        // Unions of the record fields are generated in the same namespace than the record
+        if let Schema::Record(record_schema) = schema {
+            for union_content in GeneratedType::generate_record_unions(record_schema, &self.module_paths, schema_definitions, &self.type_mapping)? {
+                self.add_generated_type(union_content)?;
+            }
+        }
+
+        // Unions used by a top level union, array or map are generated with it
+        if let Schema::Union(_) | Schema::Array(_) | Schema::Map(_) = schema {
+            for union_content in GeneratedType::generate_unions(schema, &generation_namespace(schema), &self.module_paths, schema_definitions, &self.type_mapping)? {
+                self.add_generated_type(union_content)?;
+            }
         }
 
         Ok(())
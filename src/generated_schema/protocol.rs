@@ -0,0 +1,188 @@
+use crate::protocol_parser::{MessageDefinition, ProtocolDefinition};
+use crate::Result;
+use apache_avro::schema::*;
+use itertools::Itertools;
+use std::fmt::Write;
+
+use super::field_type::*;
+use super::global::*;
+use super::module_paths::ModulePaths;
+use super::schema::GeneratedType;
+use super::schema_json::SchemaDefinitions;
+use crate::browse_sub_schemas::unions_to_generate;
+
+#[derive(Debug)]
+pub struct GeneratedProtocol {
+    name: SanitizedName,
+
+    doc: String,
+
+    error_enum_name: SanitizedName,
+
+    /// Name and type of each error declared by the messages
+    errors: Vec<(String, String)>,
+
+    methods: Vec<GeneratedMethod>,
+}
+
+#[derive(Debug)]
+struct GeneratedMethod {
+    name: SanitizedName,
+
+    doc: String,
+
+    request_type: String,
+
+    response_type: String,
+
+    one_way: bool,
+}
+
+impl GeneratedProtocol {
+    pub fn produce_content(&self) -> Result<String> {
+        let error_enum = self.error_enum_name.sanitized_name.to_owned();
+
+        let mut content_string = String::new();
+        writeln!(
+            content_string,
+            "/// Errors of the protocol {}: the system error or one of the declared errors",
+            self.name.original_name
+        )?;
+        writeln!(
+            content_string,
+            "#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]"
+        )?;
+        writeln!(content_string, "#[serde(untagged)]")?;
+        writeln!(content_string, "pub enum {error_enum} {{")?;
+        writeln!(content_string, "    System(String),")?;
+        for (variant_name, variant_type) in self.errors.iter() {
+            writeln!(content_string, "    {variant_name}({variant_type}),")?;
+        }
+        write!(content_string, "}}\r\n\r\n")?;
+
+        writeln!(content_string, "impl std::fmt::Display for {error_enum} {{")?;
+        writeln!(
+            content_string,
+            "    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{"
+        )?;
+        writeln!(content_string, "        write!(f, \"{{self:?}}\")")?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        write!(
+            content_string,
+            "impl std::error::Error for {error_enum} {{}}\r\n\r\n"
+        )?;
+
+        content_string.push_str(&self.doc);
+        writeln!(content_string, "pub trait {} {{", self.name.sanitized_name)?;
+        for method in self.methods.iter() {
+            content_string.push_str(&method.doc);
+            if method.one_way {
+                writeln!(
+                    content_string,
+                    "    fn {}(&self, request: {});",
+                    method.name.sanitized_name, method.request_type
+                )?;
+            } else {
+                writeln!(
+                    content_string,
+                    "    fn {}(&self, request: {}) -> Result<{}, {error_enum}>;",
+                    method.name.sanitized_name, method.request_type, method.response_type
+                )?;
+            }
+        }
+        write!(content_string, "}}\r\n\r\n")?;
+
+        Ok(content_string)
+    }
+
+    /// Return the generated protocol and the unions used by the message responses
+    pub fn from(
+        protocol: &ProtocolDefinition,
+        module_paths: &ModulePaths,
+        schema_definitions: &SchemaDefinitions,
+        type_mapping: &TypeMapping,
+    ) -> Result<Vec<GeneratedType>> {
+        let name = SanitizedName::from_type(&protocol.name);
+        let error_enum_name = SanitizedName::from_type(&format!("{}ProtocolError", protocol.name));
+
+        // An error declared by several messages is one variant, the errors with the same name
+        // in different namespaces are prefixed with their namespace like the variants of the unions
+        let error_schemas: Vec<Schema> = protocol
+            .messages
+            .iter()
+            .flat_map(|m| m.errors.iter())
+            .unique()
+            .map(|error_name| Schema::Ref {
+                name: error_name.to_owned(),
+            })
+            .collect();
+        let errors: Result<Vec<(String, String)>> = get_variant_names(&error_schemas.iter().collect::<Vec<_>>(), module_paths)
+            .into_iter()
+            .zip(error_schemas.iter().filter_map(|s| s.name()))
+            .map(|(variant_name, error_name)| {
+                Ok((variant_name, sanitize_container_name(error_name, module_paths)?))
+            })
+            .collect();
+
+        let methods: Result<Vec<GeneratedMethod>> = protocol
+            .messages
+            .iter()
+            .map(|m| GeneratedMethod::from(m, &protocol.namespace, module_paths, type_mapping))
+            .collect();
+
+        let mut generated_types = vec![GeneratedType::Protocol(GeneratedProtocol {
+            name,
+            doc: format_doc(&protocol.doc, "")?,
+            error_enum_name,
+            errors: errors?,
+            methods: methods?,
+        })];
+
+        for message in protocol.messages.iter() {
+            for union_schema in unions_to_generate(&message.response) {
+                generated_types.push(GeneratedType::Union(GeneratedType::treat_union_schema(
+                    union_schema,
+                    &protocol.namespace,
+                    module_paths,
+                    schema_definitions,
+                    type_mapping,
+                )?));
+            }
+        }
+
+        Ok(generated_types)
+    }
+
+    pub fn schema_name(&self) -> String {
+        self.name.original_name.to_owned()
+    }
+}
+
+impl GeneratedMethod {
+    fn from(
+        message: &MessageDefinition,
+        protocol_namespace: &Namespace,
+        module_paths: &ModulePaths,
+        type_mapping: &TypeMapping,
+    ) -> Result<Self> {
+        let response_type = match message.response {
+            Schema::Null => "()".to_string(),
+            _ => get_field_type(
+                &message.response,
+                module_paths,
+                protocol_namespace,
+                type_mapping,
+            )?,
+        };
+
+        Ok(GeneratedMethod {
+            name: SanitizedName::from_message(&message.name),
+            doc: format_doc(&message.doc, "    ")?,
+            request_type: sanitize_container_name(&message.request, module_paths)?,
+            response_type,
+            one_way: message.one_way,
+        })
+    }
+}
@@ -1,11 +1,26 @@
+use crate::error::AvrogenError;
 use crate::Result;
 use apache_avro::{schema::*, Schema};
 use std::fmt::Write;
 use std::string::*;
 use std::*;
 
-use super::field::GeneratedStructFields;
+use super::bytes::GeneratedBytes;
+use super::confluent::{ConfluentMethods, GeneratedConfluent};
+use super::decimal::{decimals_as_bytes_path, GeneratedDecimal};
+use super::duration::{durations_as_fixed_path, GeneratedDuration};
+use super::field::{get_serde_with, GeneratedStructFields};
+use super::field_type::*;
+use super::values::{resolve_path, union_variants_path, GeneratedValues};
 use super::global::*;
+use super::logical_types::GeneratedLogicalTypes;
+use super::module_paths::ModulePaths;
+use super::protocol::GeneratedProtocol;
+use super::schema_json::{raw_string, EmbeddedSchema, SchemaDefinitions};
+use crate::browse_sub_schemas::{
+    generation_namespace, unions_to_generate, unions_to_generate_record,
+};
+use crate::protocol_parser::ProtocolDefinition;
 
 #[derive(Debug)]
 pub enum GeneratedType {
@@ -14,6 +29,26 @@ pub enum GeneratedType {
     Enum(GeneratedEnum),
 
     Struct(GeneratedStruct),
+
+    Union(GeneratedUnion),
+
+    Fixed(GeneratedFixed),
+
+    Alias(GeneratedAlias),
+
+    Protocol(GeneratedProtocol),
+
+    Confluent(GeneratedConfluent),
+
+    Bytes(GeneratedBytes),
+
+    Decimal(GeneratedDecimal),
+
+    LogicalTypes(GeneratedLogicalTypes),
+
+    Values(GeneratedValues),
+
+    Duration(GeneratedDuration),
 }
 
 impl GeneratedType {
@@ -21,13 +56,33 @@ impl GeneratedType {
         match self {
             GeneratedType::Enum(x) => x.produce_content(),
             GeneratedType::Struct(x) => x.produce_content(),
+            GeneratedType::Union(x) => x.produce_content(),
+            GeneratedType::Fixed(x) => x.produce_content(),
+            GeneratedType::Alias(x) => x.produce_content(),
+            GeneratedType::Protocol(x) => x.produce_content(),
+            GeneratedType::Confluent(x) => x.produce_content(),
+            GeneratedType::Bytes(x) => x.produce_content(),
+            GeneratedType::Decimal(x) => x.produce_content(),
+            GeneratedType::LogicalTypes(x) => x.produce_content(),
+            GeneratedType::Values(x) => x.produce_content(),
+            GeneratedType::Duration(x) => x.produce_content(),
             GeneratedType::None => Ok("".to_string()),
         }
     }
     pub fn schema_name(&self) -> String {
         match self {
-            GeneratedType::Enum(x) => x.name.original_name.to_owned(),
-            GeneratedType::Struct(x) => x.name.original_name.to_owned(),
+            GeneratedType::Enum(x) => x.name.sanitized_name.to_owned(),
+            GeneratedType::Struct(x) => x.name.sanitized_name.to_owned(),
+            GeneratedType::Union(x) => x.name.sanitized_name.to_owned(),
+            GeneratedType::Fixed(x) => x.name.sanitized_name.to_owned(),
+            GeneratedType::Alias(x) => x.name.sanitized_name.to_owned(),
+            GeneratedType::Protocol(x) => x.schema_name(),
+            GeneratedType::Confluent(x) => x.schema_name(),
+            GeneratedType::Bytes(x) => x.schema_name(),
+            GeneratedType::Decimal(x) => x.schema_name(),
+            GeneratedType::LogicalTypes(x) => x.schema_name(),
+            GeneratedType::Values(x) => x.schema_name(),
+            GeneratedType::Duration(x) => x.schema_name(),
             GeneratedType::None => "".to_owned(),
         }
     }
@@ -39,6 +94,17 @@ pub struct GeneratedStruct {
 
     schema_doc: String,
 
+    /// Schema embedded in the generated code
+    embedded_schema: EmbeddedSchema,
+
+    confluent_methods: Option<ConfluentMethods>,
+
+    /// Expression of the encoded value resolved with the schema
+    encoded_value: String,
+
+    /// Expression of the decoded value given to `apache_avro::from_value`
+    decoded_value: String,
+
     fields: Vec<GeneratedStructFields>,
 }
 
@@ -47,7 +113,7 @@ impl GeneratedStruct {
         let mut content_string = self.schema_doc.to_owned();
         writeln!(
             content_string,
-            "#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize, Default)]"
+            "#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]"
         )?;
         writeln!(content_string, "#[serde(default)]")?;
         if self.name.is_sanitized {
@@ -67,6 +133,16 @@ impl GeneratedStruct {
 
         write!(content_string, "impl {} {{", self.name.sanitized_name)?;
 
+        content_string.push_str(&self.embedded_schema.produce_content()?);
+        content_string.push_str(
+            &self
+                .embedded_schema
+                .produce_single_object_content(&self.encoded_value, &self.decoded_value)?,
+        );
+        if let Some(confluent_methods) = &self.confluent_methods {
+            content_string.push_str(&confluent_methods.produce_content()?);
+        }
+
         if self.fields.iter().any(|f| f.has_default()) {
             for field in self.fields.iter() {
                 let field_default_method = field.write_struct_default_method_content()?;
@@ -77,6 +153,27 @@ impl GeneratedStruct {
         }
         write!(content_string, "}}\r\n\r\n")?;
 
+        // The Avro default values are used when a record is created in code, like when it's read
+        writeln!(
+            content_string,
+            "impl Default for {} {{",
+            self.name.sanitized_name
+        )?;
+        writeln!(content_string, "    fn default() -> Self {{")?;
+        if self.fields.is_empty() {
+            writeln!(content_string, "        Self {{}}")?;
+        } else {
+            writeln!(content_string, "        Self {{")?;
+            for field in self.fields.iter() {
+                content_string.push_str(&field.write_struct_default_initialization_content()?);
+            }
+            writeln!(content_string, "        }}")?;
+        }
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        content_string.push_str(&avro_schema_impl_content(&self.name)?);
+
         Ok(content_string)
     }
 }
@@ -87,6 +184,9 @@ pub struct GeneratedEnum {
 
     schema_doc: String,
 
+    /// Schema embedded in the generated code
+    embedded_schema: EmbeddedSchema,
+
     default_record: Option<String>,
 
     records: Vec<String>,
@@ -109,7 +209,7 @@ impl GeneratedEnum {
         }
         writeln!(content_string, "pub enum {} {{", self.name.sanitized_name)?;
 
-        if let None = self.default_record {
+        if self.default_record.is_none() {
             writeln!(content_string, "    #[default]")?;
         }
 
@@ -133,6 +233,312 @@ impl GeneratedEnum {
         }
         write!(content_string, "}}\r\n\r\n")?;
 
+        write!(content_string, "impl {} {{", self.name.sanitized_name)?;
+        content_string.push_str(&self.embedded_schema.produce_content()?);
+        write!(content_string, "}}\r\n\r\n")?;
+
+        content_string.push_str(&avro_schema_impl_content(&self.name)?);
+
+        Ok(content_string)
+    }
+}
+
+fn avro_schema_impl_content(name: &SanitizedName) -> Result<String> {
+    let mut content_string = String::new();
+    writeln!(
+        content_string,
+        "impl apache_avro::AvroSchema for {} {{",
+        name.sanitized_name
+    )?;
+    writeln!(
+        content_string,
+        "    fn get_schema() -> apache_avro::Schema {{"
+    )?;
+    writeln!(content_string, "        Self::schema().clone()")?;
+    writeln!(content_string, "    }}")?;
+    write!(content_string, "}}\r\n\r\n")?;
+    Ok(content_string)
+}
+
+#[derive(Debug)]
+pub struct GeneratedUnion {
+    name: SanitizedName,
+
+    /// Name and type of each not null variant, the type starts with its serde attribute when it needs one
+    variants: Vec<(String, String)>,
+
+    /// Value of the first variant in the `Default` implementation
+    first_variant_default_value: String,
+
+    /// JSON of the not null variants, the schema of the enum
+    schema_json: String,
+}
+
+impl GeneratedUnion {
+    pub fn produce_content(&self) -> Result<String> {
+        let mut content_string = String::new();
+        writeln!(
+            content_string,
+            "/// Enum generated for an avro union, each variant is a type of the union."
+        )?;
+        writeln!(content_string, "///")?;
+        writeln!(content_string, "/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it")?;
+        writeln!(content_string, "/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.")?;
+        writeln!(
+            content_string,
+            "#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]"
+        )?;
+        writeln!(content_string, "pub enum {} {{", self.name.sanitized_name)?;
+
+        for (index, (variant_name, variant_type)) in self.variants.iter().enumerate() {
+            writeln!(content_string, "    #[serde(alias = \"{index}\")]")?;
+            writeln!(content_string, "    {variant_name}({variant_type}),")?;
+        }
+        write!(content_string, "}}\r\n\r\n")?;
+
+        // Like Avro, the default value use the first type of the union
+        if let Some((first_variant_name, _)) = self.variants.first() {
+            writeln!(
+                content_string,
+                "impl Default for {} {{",
+                self.name.sanitized_name
+            )?;
+            writeln!(content_string, "    fn default() -> Self {{")?;
+            writeln!(
+                content_string,
+                "        Self::{first_variant_name}({})",
+                self.first_variant_default_value
+            )?;
+            writeln!(content_string, "    }}")?;
+            write!(content_string, "}}\r\n\r\n")?;
+        }
+
+        writeln!(content_string, "impl {} {{", self.name.sanitized_name)?;
+        writeln!(
+            content_string,
+            "    /// JSON of the Avro schema, the union without null"
+        )?;
+        writeln!(
+            content_string,
+            "    pub const SCHEMA_JSON: &str = {};",
+            raw_string(&self.schema_json)
+        )?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        writeln!(
+            content_string,
+            "impl apache_avro::AvroSchema for {} {{",
+            self.name.sanitized_name
+        )?;
+        writeln!(
+            content_string,
+            "    fn get_schema() -> apache_avro::Schema {{"
+        )?;
+        writeln!(content_string, "        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect(\"The schema is checked by avrogen\")")?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        Ok(content_string)
+    }
+}
+
+#[derive(Debug)]
+pub struct GeneratedFixed {
+    name: SanitizedName,
+
+    schema_doc: String,
+
+    /// Schema embedded in the generated code
+    embedded_schema: EmbeddedSchema,
+
+    size: usize,
+}
+
+impl GeneratedFixed {
+    pub fn produce_content(&self) -> Result<String> {
+        let name = &self.name.sanitized_name;
+        let size = self.size;
+
+        let mut content_string = self.schema_doc.to_owned();
+        // Arrays longer than 32 bytes don't implement Default, it's implemented by hand
+        let derived_default = match size <= 32 {
+            true => ", Default",
+            false => "",
+        };
+        writeln!(
+            content_string,
+            "#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy{derived_default})]"
+        )?;
+        writeln!(content_string, "pub struct {name}(pub [u8; {size}]);")?;
+        write!(content_string, "\r\n")?;
+
+        write!(content_string, "impl {name} {{")?;
+        content_string.push_str(&self.embedded_schema.produce_content()?);
+        write!(content_string, "}}\r\n\r\n")?;
+
+        content_string.push_str(&avro_schema_impl_content(&self.name)?);
+
+        if derived_default.is_empty() {
+            writeln!(content_string, "impl Default for {name} {{")?;
+            writeln!(content_string, "    fn default() -> Self {{")?;
+            writeln!(content_string, "        Self([0; {size}])")?;
+            writeln!(content_string, "    }}")?;
+            write!(content_string, "}}\r\n\r\n")?;
+        }
+
+        writeln!(content_string, "impl AsRef<[u8]> for {name} {{")?;
+        writeln!(content_string, "    fn as_ref(&self) -> &[u8] {{")?;
+        writeln!(content_string, "        &self.0")?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        writeln!(content_string, "impl From<[u8; {size}]> for {name} {{")?;
+        writeln!(
+            content_string,
+            "    fn from(bytes: [u8; {size}]) -> Self {{"
+        )?;
+        writeln!(content_string, "        Self(bytes)")?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        writeln!(content_string, "impl TryFrom<&[u8]> for {name} {{")?;
+        writeln!(
+            content_string,
+            "    type Error = std::array::TryFromSliceError;"
+        )?;
+        writeln!(
+            content_string,
+            "    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {{"
+        )?;
+        writeln!(content_string, "        bytes.try_into().map(Self)")?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        // The bytes are displayed in lowercase hexadecimal, like a digest
+        writeln!(content_string, "impl std::fmt::Display for {name} {{")?;
+        writeln!(
+            content_string,
+            "    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{"
+        )?;
+        writeln!(
+            content_string,
+            "        self.0.iter().try_for_each(|byte| write!(f, \"{{byte:02x}}\"))"
+        )?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        // The fixed is serialized as bytes, apache_avro reads a fixed value as bytes too
+        writeln!(content_string, "impl serde::Serialize for {name} {{")?;
+        writeln!(
+            content_string,
+            "    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{"
+        )?;
+        writeln!(
+            content_string,
+            "        serializer.serialize_bytes(&self.0)"
+        )?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        writeln!(
+            content_string,
+            "impl<'de> serde::Deserialize<'de> for {name} {{"
+        )?;
+        writeln!(
+            content_string,
+            "    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{"
+        )?;
+        writeln!(content_string, "        struct FixedVisitor;")?;
+        writeln!(content_string)?;
+        writeln!(
+            content_string,
+            "        impl<'de> serde::de::Visitor<'de> for FixedVisitor {{"
+        )?;
+        writeln!(content_string, "            type Value = {name};")?;
+        writeln!(content_string)?;
+        writeln!(
+            content_string,
+            "            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {{"
+        )?;
+        writeln!(
+            content_string,
+            "                formatter.write_str(\"{size} bytes\")"
+        )?;
+        writeln!(content_string, "            }}")?;
+        writeln!(content_string)?;
+        writeln!(
+            content_string,
+            "            fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<{name}, E> {{"
+        )?;
+        writeln!(
+            content_string,
+            "                {name}::try_from(bytes).map_err(|_| E::invalid_length(bytes.len(), &self))"
+        )?;
+        writeln!(content_string, "            }}")?;
+        writeln!(content_string)?;
+        writeln!(
+            content_string,
+            "            fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<{name}, A::Error> {{"
+        )?;
+        writeln!(
+            content_string,
+            "                let mut bytes = [0; {size}];"
+        )?;
+        writeln!(
+            content_string,
+            "                for (index, byte) in bytes.iter_mut().enumerate() {{"
+        )?;
+        writeln!(
+            content_string,
+            "                    *byte = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(index, &self))?;"
+        )?;
+        writeln!(content_string, "                }}")?;
+        writeln!(
+            content_string,
+            "                if seq.next_element::<u8>()?.is_some() {{"
+        )?;
+        writeln!(
+            content_string,
+            "                    return Err(serde::de::Error::invalid_length({}, &self));",
+            size + 1
+        )?;
+        writeln!(content_string, "                }}")?;
+        writeln!(content_string, "                Ok({name}(bytes))")?;
+        writeln!(content_string, "            }}")?;
+        writeln!(content_string, "        }}")?;
+        writeln!(content_string)?;
+        writeln!(
+            content_string,
+            "        deserializer.deserialize_bytes(FixedVisitor)"
+        )?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        Ok(content_string)
+    }
+}
+
+/// Type alias of a top level array, map or union without a generated enum
+#[derive(Debug)]
+pub struct GeneratedAlias {
+    name: SanitizedName,
+
+    kind: &'static str,
+
+    alias_type: String,
+}
+
+impl GeneratedAlias {
+    pub fn produce_content(&self) -> Result<String> {
+        let mut content_string = String::new();
+        writeln!(content_string, "/// Type of a top level Avro {}", self.kind)?;
+        writeln!(
+            content_string,
+            "pub type {} = {};",
+            self.name.sanitized_name, self.alias_type
+        )?;
+        write!(content_string, "\r\n")?;
+
         Ok(content_string)
     }
 }
@@ -140,28 +546,85 @@ impl GeneratedEnum {
 impl GeneratedType {
     pub fn generate_schema_struct(
         schema: &Schema,
-        default_namespace: &Option<String>,
+        module_paths: &ModulePaths,
+        schema_definitions: &SchemaDefinitions,
+        type_mapping: &TypeMapping,
     ) -> Result<GeneratedType> {
         match schema {
-            Schema::Record(i) => {
-                Self::treat_record_schema(i, default_namespace).map(|x| GeneratedType::Struct(x))
+            Schema::Record(i) => Self::treat_record_schema(
+                i,
+                module_paths,
+                schema_definitions.embedded_schema(schema)?,
+                schema_definitions,
+                type_mapping,
+            )
+            .map(GeneratedType::Struct),
+            Schema::Array(_) | Schema::Map(_) => {
+                Self::treat_alias_schema(schema, module_paths, type_mapping)
+                    .map(GeneratedType::Alias)
+            }
+            Schema::Union(union_schema) if needs_generated_enum(union_schema) => {
+                Self::treat_union_schema(
+                    union_schema,
+                    &generation_namespace(schema),
+                    module_paths,
+                    schema_definitions,
+                    type_mapping,
+                )
+                .map(GeneratedType::Union)
             }
-            Schema::Array(_) => todo!(),
-            Schema::Map(_) => todo!(),
-            Schema::Union(_) => todo!(),
+            Schema::Union(_) => Self::treat_alias_schema(schema, module_paths, type_mapping)
+                .map(GeneratedType::Alias),
             Schema::Enum(enum_schema) => {
-                Self::treat_enum_schema(enum_schema).map(|x| GeneratedType::Enum(x))
+                Self::treat_enum_schema(enum_schema, module_paths, schema_definitions.embedded_schema(schema)?)
+                    .map(GeneratedType::Enum)
             }
-            Schema::Fixed(_) => todo!(),
-            Schema::Decimal(_) => todo!(),
-            Schema::Duration => todo!(),
-            Schema::Ref { .. } => todo!(),
+            Schema::Fixed(fixed_schema) => {
+                Self::treat_fixed_schema(fixed_schema, module_paths, schema_definitions.embedded_schema(schema)?)
+                    .map(GeneratedType::Fixed)
+            }
+            Schema::Decimal(decimal_schema) => Err(AvrogenError::UnsupportedSchema {
+                schema: format!(
+                    "decimal({}, {})",
+                    decimal_schema.precision, decimal_schema.scale
+                ),
+                reason: "a decimal is serialized with the precision and the scale of a field, use it in a record".to_string(),
+            }),
+            // The durations use the type generated once in the duration module
+            Schema::Duration => Ok(GeneratedType::None),
+            // The referenced type is generated from its definition, in its namespace
+            Schema::Ref { name } => match schema_definitions.named_schema(name) {
+                Some(definition) => Self::generate_schema_struct(
+                    definition,
+                    module_paths,
+                    schema_definitions,
+                    type_mapping,
+                ),
+                None => Err(AvrogenError::UnsupportedSchema {
+                    schema: name.fullname(None),
+                    reason: "the type is not defined".to_string(),
+                }),
+            },
             _ => Ok(GeneratedType::None),
         }
     }
 
-    pub fn treat_enum_schema(enum_schema: &EnumSchema) -> Result<GeneratedEnum> {
-        let schema_name = SanitizedName::from_type(&enum_schema.name.name);
+    /// Generate the trait of a protocol, with the unions used by its responses
+    pub fn generate_protocol(
+        protocol: &ProtocolDefinition,
+        module_paths: &ModulePaths,
+        schema_definitions: &SchemaDefinitions,
+        type_mapping: &TypeMapping,
+    ) -> Result<Vec<GeneratedType>> {
+        GeneratedProtocol::from(protocol, module_paths, schema_definitions, type_mapping)
+    }
+
+    pub fn treat_enum_schema(
+        enum_schema: &EnumSchema,
+        module_paths: &ModulePaths,
+        embedded_schema: EmbeddedSchema,
+    ) -> Result<GeneratedEnum> {
+        let schema_name = module_paths.type_name(&enum_schema.name);
 
         let schema_doc = format_doc(&enum_schema.doc, "")?;
 
@@ -172,30 +635,239 @@ impl GeneratedType {
         Ok(GeneratedEnum {
             name: schema_name,
             schema_doc,
+            embedded_schema,
             default_record,
             records,
         })
     }
 
+    pub fn treat_fixed_schema(
+        fixed_schema: &FixedSchema,
+        module_paths: &ModulePaths,
+        embedded_schema: EmbeddedSchema,
+    ) -> Result<GeneratedFixed> {
+        let doc = fixed_schema
+            .doc
+            .to_owned()
+            .or_else(|| Some(format!("Avro fixed of {} bytes", fixed_schema.size)));
+        let schema_doc = format_doc(&doc, "")?;
+
+        Ok(GeneratedFixed {
+            name: module_paths.type_name(&fixed_schema.name),
+            schema_doc,
+            embedded_schema,
+            size: fixed_schema.size,
+        })
+    }
+
     pub fn treat_record_schema(
         record_schema: &RecordSchema,
-        default_namespace: &Option<String>,
+        module_paths: &ModulePaths,
+        embedded_schema: EmbeddedSchema,
+        schema_definitions: &SchemaDefinitions,
+        type_mapping: &TypeMapping,
     ) -> Result<GeneratedStruct> {
-        let schema_name = SanitizedName::from_type(&record_schema.name.name);
+        let schema_name = module_paths.type_name(&record_schema.name);
 
         let schema_doc = format_doc(&record_schema.doc, "")?;
 
         let fields: Result<Vec<GeneratedStructFields>> = record_schema
             .fields
             .iter()
-            .map(|f| GeneratedStructFields::from(f, &schema_name, default_namespace))
-            .into_iter()
+            .map(|f| {
+                GeneratedStructFields::from(
+                    f,
+                    &schema_name,
+                    &record_schema.name.namespace,
+                    module_paths,
+                    schema_definitions,
+                    type_mapping,
+                )
+            })
             .collect();
 
+        // apache_avro doesn't deserialize Value::Union to the variants of the enums, nor Value::Decimal and Value::Duration:
+        // the unions are read like the serde representation of the variants, the decimals from their bytes and the durations from their fixed
+        let mut decoded_value = "value".to_string();
+        if embedded_schema.uses_union {
+            decoded_value = format!(
+                "{}({decoded_value}, Self::schema())",
+                union_variants_path(module_paths)?
+            );
+        }
+        if embedded_schema.uses_duration {
+            decoded_value = format!(
+                "{}({decoded_value})",
+                durations_as_fixed_path(module_paths)?
+            );
+        }
+        if embedded_schema.uses_decimal {
+            decoded_value = format!("{}({decoded_value})", decimals_as_bytes_path(module_paths)?);
+        }
+        let decoded_value = format!("&{decoded_value}");
+
+        // apache_avro doesn't resolve Value::Bytes to a fixed and finds the union variants from the values,
+        // the values with a fixed or a generated union enum are resolved by the generated conversion
+        let encoded_value = match embedded_schema.uses_fixed || embedded_schema.uses_union {
+            true => format!(
+                "{}(apache_avro::to_value(self)?, Self::schema())?",
+                resolve_path(module_paths)?
+            ),
+            false => "apache_avro::to_value(self)?.resolve(Self::schema())?".to_string(),
+        };
+
+        let confluent_methods = match embedded_schema.confluent_wire_format {
+            true => Some(ConfluentMethods::from(
+                module_paths,
+                &encoded_value,
+                &decoded_value,
+            )?),
+            false => None,
+        };
+
         Ok(GeneratedStruct {
             name: schema_name,
             schema_doc,
+            embedded_schema,
+            confluent_methods,
+            encoded_value,
+            decoded_value,
             fields: fields?,
         })
     }
+
+    /// Generate the enums needed by the unions used in the fields of a record
+    pub fn generate_record_unions(
+        record_schema: &RecordSchema,
+        module_paths: &ModulePaths,
+        schema_definitions: &SchemaDefinitions,
+        type_mapping: &TypeMapping,
+    ) -> Result<Vec<GeneratedType>> {
+        unions_to_generate_record(record_schema)
+            .into_iter()
+            .map(|u| {
+                Self::treat_union_schema(
+                    u,
+                    &record_schema.name.namespace,
+                    module_paths,
+                    schema_definitions,
+                    type_mapping,
+                )
+                .map(GeneratedType::Union)
+            })
+            .collect()
+    }
+
+    /// Generate the enums needed by the unions used by a top level union, array or map
+    pub fn generate_unions(
+        schema: &Schema,
+        parent_namespace: &Namespace,
+        module_paths: &ModulePaths,
+        schema_definitions: &SchemaDefinitions,
+        type_mapping: &TypeMapping,
+    ) -> Result<Vec<GeneratedType>> {
+        unions_to_generate(schema)
+            .into_iter()
+            .map(|u| {
+                Self::treat_union_schema(
+                    u,
+                    parent_namespace,
+                    module_paths,
+                    schema_definitions,
+                    type_mapping,
+                )
+                .map(GeneratedType::Union)
+            })
+            .collect()
+    }
+
+    /// The alias of a top level array or map is named from its items or values: `ArrayEvent`, `MapUnionStringLong`.
+    /// A type alias can't have the serde glue of the bytes, the decimals and the logical types, they must be used in a record
+    pub fn treat_alias_schema(
+        schema: &Schema,
+        module_paths: &ModulePaths,
+        type_mapping: &TypeMapping,
+    ) -> Result<GeneratedAlias> {
+        // An union in an array or a map is named like its enum
+        let values_name = |values_schema: &Schema| match values_schema {
+            Schema::Union(union_schema) => {
+                get_union_name(union_schema, &generation_namespace(schema), module_paths).sanitized_name
+            }
+            _ => get_union_variant_name(values_schema, module_paths),
+        };
+        let (name, kind) = match schema {
+            Schema::Array(items_schema) => (
+                SanitizedName::from_type(&format!("Array{}", values_name(items_schema))),
+                "array",
+            ),
+            Schema::Map(values_schema) => (
+                SanitizedName::from_type(&format!("Map{}", values_name(values_schema))),
+                "map",
+            ),
+            Schema::Union(union_schema) => (
+                get_union_name(union_schema, &generation_namespace(schema), module_paths),
+                "union",
+            ),
+            _ => {
+                return Err(AvrogenError::UnsupportedSchema {
+                    schema: get_union_variant_name(schema, module_paths),
+                    reason: "only the arrays, the maps and the unions have a type alias"
+                        .to_string(),
+                })
+            }
+        };
+
+        if get_serde_with(schema, module_paths)?.is_some() {
+            return Err(AvrogenError::UnsupportedSchema {
+                schema: name.original_name,
+                reason: "its values need the serde glue of a field, use it in a record".to_string(),
+            });
+        }
+
+        let alias_type = get_field_type(
+            schema,
+            module_paths,
+            &generation_namespace(schema),
+            type_mapping,
+        )?;
+
+        Ok(GeneratedAlias {
+            name,
+            kind,
+            alias_type,
+        })
+    }
+
+    pub fn treat_union_schema(
+        union_schema: &UnionSchema,
+        parent_namespace: &Namespace,
+        module_paths: &ModulePaths,
+        schema_definitions: &SchemaDefinitions,
+        type_mapping: &TypeMapping,
+    ) -> Result<GeneratedUnion> {
+        let variants: Result<Vec<(String, String)>> = get_not_null_variants(union_schema)
+            .into_iter()
+            .zip(get_union_variant_names(union_schema, module_paths))
+            .map(|(v, variant_name)| {
+                let variant_type = get_field_type(v, module_paths, parent_namespace, type_mapping)?;
+                let variant_type = match get_serde_with(v, module_paths)? {
+                    Some(serde_with) => format!("{serde_with} {variant_type}"),
+                    None => variant_type,
+                };
+                Ok((variant_name, variant_type))
+            })
+            .collect();
+
+        let first_variant_default_value = match get_not_null_variants(union_schema).first() {
+            Some(first_variant) => get_type_default_value(first_variant, type_mapping),
+            None => "Default::default()".to_string(),
+        };
+
+        Ok(GeneratedUnion {
+            name: get_union_name(union_schema, parent_namespace, module_paths),
+            variants: variants?,
+            first_variant_default_value,
+            schema_json: schema_definitions.union_schema_json(union_schema)?,
+        })
+    }
 }
@@ -0,0 +1,531 @@
+use apache_avro::rabin::Rabin;
+use apache_avro::schema::*;
+use apache_avro::Schema;
+use serde_json::Value;
+use sha2::Digest;
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use super::canonical_form::{parsing_canonical_form, PRIMITIVE_TYPES};
+use crate::browse_sub_schemas::{
+    named_schemas, uses_decimal, uses_duration, uses_fixed, uses_union, NamedSchemas,
+};
+use crate::error::{AvrogenError, Location};
+use crate::file_parser::{is_duration, json_type_name, TypeDefinitions};
+use crate::Result;
+
+/// Optional fingerprints of the schemas, the Rabin fingerprint is always generated
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
+pub enum Fingerprint {
+    Md5,
+    Sha256,
+}
+
+/// Definitions needed to embed the schemas in the generated code
+pub struct SchemaDefinitions<'a> {
+    named_schemas: NamedSchemas<'a>,
+
+    type_definitions: &'a TypeDefinitions,
+
+    fingerprints: Vec<Fingerprint>,
+
+    single_object_encoding: bool,
+
+    confluent_wire_format: bool,
+}
+
+/// Schema embedded in a generated type: its JSON and its fingerprints
+#[derive(Debug)]
+pub struct EmbeddedSchema {
+    json: String,
+
+    /// Fields whose default is rejected by apache_avro and removed from the JSON, like `com.example.Event.day`
+    removed_defaults: Vec<String>,
+
+    /// Name of the constant and value of each fingerprint
+    fingerprints: Vec<(&'static str, Vec<u8>)>,
+
+    /// Header of the single-object encoding, when its helpers are generated
+    single_object_header: Option<Vec<u8>>,
+
+    /// The Confluent wire format helpers are generated
+    pub confluent_wire_format: bool,
+
+    /// The schema or one of the types it uses contains a decimal
+    pub uses_decimal: bool,
+
+    /// The schema or one of the types it uses contains a fixed
+    pub uses_fixed: bool,
+
+    /// The schema or one of the types it uses contains a duration
+    pub uses_duration: bool,
+
+    /// The schema or one of the types it uses contains an union generated as an enum
+    pub uses_union: bool,
+}
+
+impl<'a> SchemaDefinitions<'a> {
+    /// Use all the named schemas defined in the schemas and their sub schemas, and their original JSON
+    pub fn new(
+        schemas: &[&'a Schema],
+        type_definitions: &'a TypeDefinitions,
+        fingerprints: Vec<Fingerprint>,
+        single_object_encoding: bool,
+        confluent_wire_format: bool,
+    ) -> Self {
+        SchemaDefinitions {
+            named_schemas: named_schemas(schemas),
+            type_definitions,
+            fingerprints,
+            single_object_encoding,
+            confluent_wire_format,
+        }
+    }
+
+    /// Return the definition of a named schema
+    pub fn named_schema(&self, name: &Name) -> Option<&Schema> {
+        self.named_schemas.get(name).copied()
+    }
+
+    /// JSON of the not null variants of an union, which can be parsed alone: the named types are defined inline
+    pub fn union_schema_json(&self, union_schema: &UnionSchema) -> Result<String> {
+        let variants = union_schema
+            .variants()
+            .iter()
+            .filter(|v| !matches!(v, Schema::Null))
+            .map(reference_json)
+            .collect::<Result<_>>()?;
+
+        let json = inline_references(
+            &Value::Array(variants),
+            &None,
+            self.type_definitions,
+            &mut HashSet::new(),
+        )?;
+        Ok(parsable_schema(json)?.0.to_string())
+    }
+
+    pub fn embedded_schema(&self, schema: &Schema) -> Result<EmbeddedSchema> {
+        let Some(name) = schema.name() else {
+            return Err(AvrogenError::UnsupportedSchema {
+                schema: schema.canonical_form(),
+                reason: "only the named types embed their schema".to_string(),
+            });
+        };
+        let (json, parsed_schema, removed_defaults) = standalone_schema_json(name, self.type_definitions)?;
+
+        // apache_avro keeps the logical types in its canonical form, the one of the specification is computed from the JSON
+        let canonical_form = parsing_canonical_form(&json)?;
+
+        let rabin_fingerprint = Rabin::digest(&canonical_form).to_vec();
+
+        // The single-object encoding starts with the C3 01 marker and the Rabin fingerprint
+        let single_object_header = self
+            .single_object_encoding
+            .then(|| [&[0xC3, 0x01], rabin_fingerprint.as_slice()].concat());
+
+        let mut fingerprints = vec![("FINGERPRINT_RABIN", rabin_fingerprint)];
+        for fingerprint in self.fingerprints.iter() {
+            fingerprints.push(match fingerprint {
+                Fingerprint::Md5 => (
+                    "FINGERPRINT_MD5",
+                    md5::Md5::digest(&canonical_form).to_vec(),
+                ),
+                Fingerprint::Sha256 => (
+                    "FINGERPRINT_SHA256",
+                    sha2::Sha256::digest(&canonical_form).to_vec(),
+                ),
+            });
+        }
+
+        Ok(EmbeddedSchema {
+            json: json.to_string(),
+            removed_defaults,
+            fingerprints,
+            single_object_header,
+            confluent_wire_format: self.confluent_wire_format,
+            // Each named type is defined inside the standalone schema
+            uses_decimal: uses_decimal(&parsed_schema),
+            uses_fixed: uses_fixed(&parsed_schema),
+            uses_duration: uses_duration(&parsed_schema),
+            uses_union: uses_union(&parsed_schema),
+        })
+    }
+}
+
+impl EmbeddedSchema {
+    /// Constants with the schema JSON and the fingerprints, and the method which returns the schema, parsed only once
+    pub fn produce_content(&self) -> Result<String> {
+        let mut content_string = String::new();
+        write!(content_string, "\r\n    /// JSON of the Avro schema\r\n")?;
+        if !self.removed_defaults.is_empty() {
+            let fields: Vec<String> = self.removed_defaults.iter().map(|f| format!("`{f}`")).collect();
+            let fields = fields.join(", ");
+            writeln!(content_string, "    ///")?;
+            writeln!(content_string, "    /// The defaults of {fields} are removed, apache_avro rejects them: the `Default` impl still uses them,")?;
+            writeln!(content_string, "    /// and the fingerprints of the Parsing Canonical Form don't depend on the defaults")?;
+        }
+        writeln!(
+            content_string,
+            "    pub const SCHEMA_JSON: &str = {};",
+            raw_string(&self.json)
+        )?;
+
+        write!(
+            content_string,
+            "\r\n    /// Fingerprints of the Parsing Canonical Form of the schema\r\n"
+        )?;
+        for (constant_name, bytes) in self.fingerprints.iter() {
+            writeln!(
+                content_string,
+                "    pub const {constant_name}: [u8; {}] = {};",
+                bytes.len(),
+                bytes_content(bytes)
+            )?;
+        }
+
+        write!(
+            content_string,
+            "\r\n    /// Avro schema, parsed the first time it's used\r\n"
+        )?;
+        writeln!(
+            content_string,
+            "    pub fn schema() -> &'static apache_avro::Schema {{"
+        )?;
+        writeln!(content_string, "        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();")?;
+        writeln!(content_string, "        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect(\"The schema is checked by avrogen\"))")?;
+        writeln!(content_string, "    }}")?;
+        Ok(content_string)
+    }
+
+    /// Methods to encode and decode a record with the single-object encoding, when they are enabled.
+    ///
+    /// The encoded value is the expression of the value resolved with the schema,
+    /// the decoded value is the expression of the value given to `apache_avro::from_value`
+    pub fn produce_single_object_content(
+        &self,
+        encoded_value: &str,
+        decoded_value: &str,
+    ) -> Result<String> {
+        let mut content_string = String::new();
+        let Some(header) = &self.single_object_header else {
+            return Ok(content_string);
+        };
+
+        write!(
+            content_string,
+            "\r\n    /// Header of the single-object encoding: the C3 01 marker and the Rabin fingerprint\r\n"
+        )?;
+        writeln!(
+            content_string,
+            "    pub const SINGLE_OBJECT_HEADER: [u8; {}] = {};",
+            header.len(),
+            bytes_content(header)
+        )?;
+
+        write!(
+            content_string,
+            "\r\n    /// Encode the record with the single-object encoding: the header then the binary encoded record\r\n"
+        )?;
+        writeln!(
+            content_string,
+            "    pub fn to_single_object(&self) -> Result<Vec<u8>, apache_avro::Error> {{"
+        )?;
+        writeln!(
+            content_string,
+            "        let value = {encoded_value};"
+        )?;
+        writeln!(
+            content_string,
+            "        let mut bytes = Self::SINGLE_OBJECT_HEADER.to_vec();"
+        )?;
+        writeln!(
+            content_string,
+            "        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);"
+        )?;
+        writeln!(content_string, "        Ok(bytes)")?;
+        writeln!(content_string, "    }}")?;
+
+        write!(
+            content_string,
+            "\r\n    /// Decode a record encoded with the single-object encoding, a different header returns a `SingleObjectHeaderMismatch` error\r\n"
+        )?;
+        writeln!(
+            content_string,
+            "    pub fn from_single_object(bytes: &[u8]) -> Result<Self, apache_avro::Error> {{"
+        )?;
+        writeln!(
+            content_string,
+            "        let header: [u8; {}] = bytes.get(..{}).and_then(|h| h.try_into().ok()).unwrap_or_default();",
+            header.len(),
+            header.len()
+        )?;
+        writeln!(
+            content_string,
+            "        if header != Self::SINGLE_OBJECT_HEADER {{"
+        )?;
+        writeln!(
+            content_string,
+            "            return Err(apache_avro::Error::SingleObjectHeaderMismatch(Self::SINGLE_OBJECT_HEADER, header));"
+        )?;
+        writeln!(content_string, "        }}")?;
+        writeln!(
+            content_string,
+            "        let value = apache_avro::from_avro_datum(Self::schema(), &mut &bytes[{}..], None)?;",
+            header.len()
+        )?;
+        writeln!(
+            content_string,
+            "        apache_avro::from_value({decoded_value})"
+        )?;
+        writeln!(content_string, "    }}")?;
+
+        Ok(content_string)
+    }
+}
+
+/// Rust raw string literal of a JSON, with more hashes than any quote followed by hashes in the JSON
+pub fn raw_string(json: &str) -> String {
+    let hashes = "#".repeat(
+        json.split('"')
+            .skip(1)
+            .map(|s| s.len() - s.trim_start_matches('#').len() + 1)
+            .max()
+            .unwrap_or(1),
+    );
+    format!("r{hashes}\"{json}\"{hashes}")
+}
+
+/// Rust array literal of bytes
+fn bytes_content(bytes: &[u8]) -> String {
+    let bytes_content = bytes
+        .iter()
+        .map(|b| format!("0x{b:02x}"))
+        .collect::<Vec<String>>()
+        .join(", ");
+    format!("[{bytes_content}]")
+}
+
+/// Return the JSON of a named type which can be parsed alone, with its parsed schema and the fields whose default is removed.
+///
+/// The JSON is the original definition: the types it references are defined inline, the first time they are used,
+/// and their docs, aliases and defaults are kept, except the defaults rejected by apache_avro.
+fn standalone_schema_json(name: &Name, definitions: &TypeDefinitions) -> Result<(Value, Schema, Vec<String>)> {
+    parsable_schema(inline_definition(
+        name,
+        &None,
+        definitions,
+        &mut HashSet::new(),
+    )?)
+}
+
+/// Return a JSON schema accepted by apache_avro, with its parsed schema and the fields whose default is removed.
+///
+/// apache_avro rejects some valid field defaults, like the ones of dates or decimals.
+/// They are removed from the embedded schema and documented on its constant, the generated Default impls still use them
+fn parsable_schema(mut json: Value) -> Result<(Value, Schema, Vec<String>)> {
+    let mut removed_defaults = Vec::new();
+    loop {
+        match Schema::parse(&json) {
+            Ok(schema) => return Ok((json, schema, removed_defaults)),
+            Err(apache_avro::Error::GetDefaultRecordField(field_name, record_name, _))
+                if remove_field_default(&mut json, &None, &record_name, &field_name) =>
+            {
+                removed_defaults.push(format!("{record_name}.{field_name}"));
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
+
+/// JSON of a parsed schema, where the named types are referenced by their full name
+fn reference_json(schema: &Schema) -> Result<Value> {
+    let json = match schema {
+        Schema::Record(RecordSchema { name, .. })
+        | Schema::Enum(EnumSchema { name, .. })
+        | Schema::Fixed(FixedSchema { name, .. })
+        | Schema::Ref { name } => Value::String(name.fullname(None)),
+        Schema::Array(items) => {
+            serde_json::json!({"type": "array", "items": reference_json(items)?})
+        }
+        Schema::Map(values) => {
+            serde_json::json!({"type": "map", "values": reference_json(values)?})
+        }
+        Schema::Union(union_schema) => Value::Array(
+            union_schema
+                .variants()
+                .iter()
+                .map(reference_json)
+                .collect::<Result<_>>()?,
+        ),
+        // The primitive and the logical types, a decimal in a fixed writes the definition of the fixed
+        _ => serde_json::to_value(schema).map_err(|e| AvrogenError::Generation {
+            location: Location::default(),
+            message: format!(
+                "the schema {} can't be written: {e}",
+                schema.canonical_form()
+            ),
+        })?,
+    };
+    Ok(json)
+}
+
+/// Definition of a named type moved to another place of the schema: its namespace is written, so it stays the same
+fn inline_definition(
+    name: &Name,
+    namespace: &Namespace,
+    definitions: &TypeDefinitions,
+    defined: &mut HashSet<Name>,
+) -> Result<Value> {
+    let Some(definition) = definitions.get(name) else {
+        return Err(AvrogenError::Generation {
+            location: Location {
+                type_name: Some(name.fullname(None)),
+                ..Default::default()
+            },
+            message: "the type is not defined".to_string(),
+        });
+    };
+
+    let mut definition = definition.to_owned();
+    if let (Value::Object(object), Some(type_namespace)) = (&mut definition, &name.namespace) {
+        let full_name = object
+            .get("name")
+            .and_then(Value::as_str)
+            .is_some_and(|n| n.contains('.'));
+        // An inherited namespace is written after the name
+        let index = match object.keys().position(|key| key == "namespace") {
+            Some(index) => index,
+            None => object
+                .keys()
+                .position(|key| key == "name")
+                .map_or(0, |i| i + 1),
+        };
+        if !full_name {
+            object.shift_insert(
+                index,
+                "namespace".to_string(),
+                Value::String(type_namespace.to_owned()),
+            );
+        }
+    }
+
+    inline_references(&definition, namespace, definitions, defined)
+}
+
+/// Copy of a JSON schema where the references to types not defined yet are replaced by their definitions
+fn inline_references(
+    json: &Value,
+    namespace: &Namespace,
+    definitions: &TypeDefinitions,
+    defined: &mut HashSet<Name>,
+) -> Result<Value> {
+    let json = match json {
+        Value::String(type_name) if !PRIMITIVE_TYPES.contains(&type_name.as_str()) => {
+            let name = Name::new(type_name)?.fully_qualified_name(namespace);
+            if defined.contains(&name) && !is_duration(definitions.get(&name)) {
+                json.to_owned()
+            } else {
+                inline_definition(&name, namespace, definitions, defined)?
+            }
+        }
+        Value::Array(variants) => Value::Array(
+            variants
+                .iter()
+                .map(|v| inline_references(v, namespace, definitions, defined))
+                .collect::<Result<_>>()?,
+        ),
+        Value::Object(object) => {
+            let mut object = object.to_owned();
+            match object.get("type").and_then(Value::as_str) {
+                Some("record" | "error" | "enum" | "fixed") => {
+                    let Some(name) = json_type_name(&object, namespace) else {
+                        return Ok(Value::Object(object));
+                    };
+                    // A type defined again is a reference to its first definition.
+                    // apache_avro forgets the name of the durations, they are defined at each use
+                    if !defined.insert(name.to_owned()) && !is_duration(Some(json)) {
+                        return Ok(Value::String(name.fullname(None)));
+                    }
+                    // The protocol errors are records for apache_avro
+                    if object.get("type") == Some(&Value::String("error".to_string())) {
+                        object.insert("type".to_string(), Value::String("record".to_string()));
+                    }
+                    if let Some(Value::Array(fields)) = object.get_mut("fields") {
+                        for field in fields.iter_mut().filter_map(Value::as_object_mut) {
+                            if let Some(field_type) = field.get_mut("type") {
+                                *field_type = inline_references(
+                                    field_type,
+                                    &name.namespace,
+                                    definitions,
+                                    defined,
+                                )?;
+                            }
+                        }
+                    }
+                }
+                object_type => {
+                    let key = match object_type {
+                        Some("array") => "items",
+                        Some("map") => "values",
+                        _ => "type",
+                    };
+                    if let Some(inner_type) = object.get_mut(key) {
+                        *inner_type =
+                            inline_references(inner_type, namespace, definitions, defined)?;
+                    }
+                }
+            }
+            Value::Object(object)
+        }
+        _ => json.to_owned(),
+    };
+    Ok(json)
+}
+
+/// Remove the default of a field of a record, return false when the field isn't found
+fn remove_field_default(
+    json: &mut Value,
+    namespace: &Namespace,
+    record_name: &str,
+    field_name: &str,
+) -> bool {
+    match json {
+        Value::Array(variants) => variants
+            .iter_mut()
+            .any(|v| remove_field_default(v, namespace, record_name, field_name)),
+        Value::Object(object) => match object.get("type").and_then(Value::as_str) {
+            Some("record") => {
+                let Some(name) = json_type_name(object, namespace) else {
+                    return false;
+                };
+                let Some(Value::Array(fields)) = object.get_mut("fields") else {
+                    return false;
+                };
+                if name.fullname(None) == record_name {
+                    return fields
+                        .iter_mut()
+                        .filter_map(Value::as_object_mut)
+                        .find(|f| f.get("name").and_then(Value::as_str) == Some(field_name))
+                        .and_then(|f| f.shift_remove("default"))
+                        .is_some();
+                }
+                fields
+                    .iter_mut()
+                    .filter_map(|f| f.get_mut("type"))
+                    .any(|t| remove_field_default(t, &name.namespace, record_name, field_name))
+            }
+            object_type => {
+                let key = match object_type {
+                    Some("array") => "items",
+                    Some("map") => "values",
+                    _ => "type",
+                };
+                object
+                    .get_mut(key)
+                    .is_some_and(|t| remove_field_default(t, namespace, record_name, field_name))
+            }
+        },
+        _ => false,
+    }
+}
@@ -0,0 +1,569 @@
+use super::logical_types::LogicalType;
+use crate::error::AvrogenError;
+use crate::Result;
+
+/// Message of the expect of the default values, which are checked when generating
+const IN_RANGE: &str = "The default value is in range";
+
+/// Rust types of the Avro dates, times and timestamps
+#[derive(Debug, Clone, Copy, Default, PartialEq, clap::ValueEnum)]
+pub enum TimeBackend {
+    /// `chrono::NaiveDate`, `NaiveTime`, `DateTime<Utc>` and `NaiveDateTime`, the chrono crate is needed by the generated code
+    #[default]
+    Chrono,
+    /// `time::Date`, `Time`, `OffsetDateTime` and `PrimitiveDateTime`, the time crate is needed by the generated code
+    Time,
+    /// `jiff::civil::Date`, `civil::Time`, `Timestamp` and `civil::DateTime`, the jiff crate is needed by the generated code
+    Jiff,
+    /// No crate: the dates and the local timestamps are their Avro integers, the times a `std::time::Duration` after midnight and the timestamps a `std::time::SystemTime`
+    Std,
+}
+
+impl TimeBackend {
+    pub fn type_name(&self, logical_type: LogicalType) -> &'static str {
+        match (self, logical_type) {
+            (_, LogicalType::Uuid) => "uuid::Uuid",
+            (TimeBackend::Chrono, LogicalType::Date) => "chrono::NaiveDate",
+            (TimeBackend::Chrono, LogicalType::TimeMillis | LogicalType::TimeMicros) => {
+                "chrono::NaiveTime"
+            }
+            (TimeBackend::Chrono, LogicalType::TimestampMillis | LogicalType::TimestampMicros) => {
+                "chrono::DateTime<chrono::Utc>"
+            }
+            (
+                TimeBackend::Chrono,
+                LogicalType::LocalTimestampMillis | LogicalType::LocalTimestampMicros,
+            ) => "chrono::NaiveDateTime",
+            (TimeBackend::Time, LogicalType::Date) => "time::Date",
+            (TimeBackend::Time, LogicalType::TimeMillis | LogicalType::TimeMicros) => "time::Time",
+            (TimeBackend::Time, LogicalType::TimestampMillis | LogicalType::TimestampMicros) => {
+                "time::OffsetDateTime"
+            }
+            (
+                TimeBackend::Time,
+                LogicalType::LocalTimestampMillis | LogicalType::LocalTimestampMicros,
+            ) => "time::PrimitiveDateTime",
+            (TimeBackend::Jiff, LogicalType::Date) => "jiff::civil::Date",
+            (TimeBackend::Jiff, LogicalType::TimeMillis | LogicalType::TimeMicros) => {
+                "jiff::civil::Time"
+            }
+            (TimeBackend::Jiff, LogicalType::TimestampMillis | LogicalType::TimestampMicros) => {
+                "jiff::Timestamp"
+            }
+            (
+                TimeBackend::Jiff,
+                LogicalType::LocalTimestampMillis | LogicalType::LocalTimestampMicros,
+            ) => "jiff::civil::DateTime",
+            (TimeBackend::Std, LogicalType::Date) => "i32",
+            (TimeBackend::Std, LogicalType::TimeMillis | LogicalType::TimeMicros) => {
+                "std::time::Duration"
+            }
+            (TimeBackend::Std, LogicalType::TimestampMillis | LogicalType::TimestampMicros) => {
+                "std::time::SystemTime"
+            }
+            (
+                TimeBackend::Std,
+                LogicalType::LocalTimestampMillis | LogicalType::LocalTimestampMicros,
+            ) => "i64",
+        }
+    }
+
+    /// Rust expression of the value used when a field has no default value: the Unix epoch, or midnight for the times.
+    ///
+    /// The types of the time crate and `std::time::SystemTime` don't implement Default
+    pub fn zero_value(&self, logical_type: LogicalType) -> &'static str {
+        match (self, logical_type) {
+            (TimeBackend::Time, LogicalType::Date) => "time::OffsetDateTime::UNIX_EPOCH.date()",
+            (TimeBackend::Time, LogicalType::TimeMillis | LogicalType::TimeMicros) => {
+                "time::Time::MIDNIGHT"
+            }
+            (TimeBackend::Time, LogicalType::TimestampMillis | LogicalType::TimestampMicros) => {
+                "time::OffsetDateTime::UNIX_EPOCH"
+            }
+            (
+                TimeBackend::Time,
+                LogicalType::LocalTimestampMillis | LogicalType::LocalTimestampMicros,
+            ) => "time::OffsetDateTime::UNIX_EPOCH.date().midnight()",
+            (TimeBackend::Jiff, LogicalType::Date) => "jiff::civil::date(1970, 1, 1)",
+            (TimeBackend::Jiff, LogicalType::TimestampMillis | LogicalType::TimestampMicros) => {
+                "jiff::Timestamp::UNIX_EPOCH"
+            }
+            (
+                TimeBackend::Jiff,
+                LogicalType::LocalTimestampMillis | LogicalType::LocalTimestampMicros,
+            ) => "jiff::civil::date(1970, 1, 1).at(0, 0, 0, 0)",
+            (TimeBackend::Std, LogicalType::TimestampMillis | LogicalType::TimestampMicros) => {
+                "std::time::UNIX_EPOCH"
+            }
+            _ => "Default::default()",
+        }
+    }
+
+    /// Years of the dates supported by the library
+    fn years(&self) -> std::ops::RangeInclusive<i64> {
+        match self {
+            TimeBackend::Chrono => -262_143..=262_142,
+            TimeBackend::Time | TimeBackend::Jiff => -9_999..=9_999,
+            TimeBackend::Std => i64::MIN..=i64::MAX,
+        }
+    }
+
+    /// Rust expression of a default value, the number of days, of units after midnight or of units since the Unix epoch
+    pub fn value(&self, logical_type: LogicalType, units: i64) -> Result<String> {
+        let out_of_range = || {
+            AvrogenError::invalid_default(format!(
+                "the default value {units} of the type {} is out of range",
+                logical_type.glue_name()
+            ))
+        };
+        let (unit, units_per_second) = logical_type.unit();
+        let units_per_day = 86_400 * units_per_second;
+
+        match logical_type {
+            LogicalType::Uuid => {
+                return Err(AvrogenError::invalid_default(format!(
+                    "the default value {units} doesn't match the type uuid"
+                )))
+            }
+            LogicalType::TimeMillis | LogicalType::TimeMicros
+                if !(0..units_per_day).contains(&units) =>
+            {
+                return Err(out_of_range())
+            }
+            _ => {}
+        }
+
+        let value = match (self, logical_type) {
+            (TimeBackend::Std, LogicalType::Date) => format!("{units}"),
+            (TimeBackend::Std, LogicalType::TimeMillis | LogicalType::TimeMicros) => {
+                format!("std::time::Duration::from_{unit}({units})")
+            }
+            (TimeBackend::Std, LogicalType::TimestampMillis | LogicalType::TimestampMicros) => {
+                let operator = if units < 0 { "-" } else { "+" };
+                format!(
+                    "std::time::UNIX_EPOCH {operator} std::time::Duration::from_{unit}({})",
+                    units.unsigned_abs()
+                )
+            }
+            (TimeBackend::Std, _) => format!("{units}"),
+            (_, LogicalType::Date) => {
+                let (year, month, day) = self.civil_date(units).ok_or_else(out_of_range)?;
+                self.date(year, month, day)
+            }
+            (_, LogicalType::TimeMillis | LogicalType::TimeMicros) => {
+                let (hour, minute, second, fraction) = time_of_day(units, units_per_second);
+                match self {
+                    TimeBackend::Time => format!(
+                        "time::Time::from_hms_{}({hour}, {minute}, {second}, {fraction}).expect(\"{IN_RANGE}\")",
+                        unit.trim_end_matches('s')
+                    ),
+                    TimeBackend::Jiff => format!(
+                        "jiff::civil::time({hour}, {minute}, {second}, {})",
+                        fraction * 1_000_000_000 / units_per_second
+                    ),
+                    _ => format!(
+                        "chrono::NaiveTime::from_hms_{}_opt({hour}, {minute}, {second}, {fraction}).expect(\"{IN_RANGE}\")",
+                        unit.trim_end_matches('s')
+                    ),
+                }
+            }
+            (TimeBackend::Chrono, _) => {
+                self.civil_date(units.div_euclid(units_per_day))
+                    .ok_or_else(out_of_range)?;
+                let naive = match logical_type {
+                    LogicalType::LocalTimestampMillis | LogicalType::LocalTimestampMicros => {
+                        ".naive_utc()"
+                    }
+                    _ => "",
+                };
+                format!("chrono::DateTime::from_timestamp_{unit}({units}).expect(\"{IN_RANGE}\"){naive}")
+            }
+            (TimeBackend::Jiff, LogicalType::TimestampMillis | LogicalType::TimestampMicros) => {
+                self.civil_date(units.div_euclid(units_per_day))
+                    .ok_or_else(out_of_range)?;
+                let method = match logical_type {
+                    LogicalType::TimestampMillis => "from_millisecond",
+                    _ => "from_microsecond",
+                };
+                format!("jiff::Timestamp::{method}({units}).expect(\"{IN_RANGE}\")")
+            }
+            (_, _) => {
+                // The timestamps of the time crate and the local timestamps of jiff are written with their date and their time
+                let (year, month, day) = self
+                    .civil_date(units.div_euclid(units_per_day))
+                    .ok_or_else(out_of_range)?;
+                let (hour, minute, second, fraction) =
+                    time_of_day(units.rem_euclid(units_per_day), units_per_second);
+                let nanosecond = fraction * 1_000_000_000 / units_per_second;
+                let date = self.date(year, month, day);
+                match (self, logical_type) {
+                    (TimeBackend::Jiff, _) => {
+                        format!("{date}.at({hour}, {minute}, {second}, {nanosecond})")
+                    }
+                    (_, LogicalType::TimestampMillis | LogicalType::TimestampMicros) => format!(
+                        "{date}.with_hms_nano({hour}, {minute}, {second}, {nanosecond}).expect(\"{IN_RANGE}\").assume_utc()"
+                    ),
+                    _ => format!(
+                        "{date}.with_hms_nano({hour}, {minute}, {second}, {nanosecond}).expect(\"{IN_RANGE}\")"
+                    ),
+                }
+            }
+        };
+
+        Ok(value)
+    }
+
+    /// Rust expression of a date
+    fn date(&self, year: i64, month: u32, day: u32) -> String {
+        match self {
+            TimeBackend::Time => format!(
+                "time::Date::from_calendar_date({year}, time::Month::{}, {day}).expect(\"{IN_RANGE}\")",
+                MONTHS[month as usize - 1]
+            ),
+            TimeBackend::Jiff => format!("jiff::civil::date({year}, {month}, {day})"),
+            _ => format!("chrono::NaiveDate::from_ymd_opt({year}, {month}, {day}).expect(\"{IN_RANGE}\")"),
+        }
+    }
+
+    /// Year, month and day of a number of days since the Unix epoch in the proleptic Gregorian calendar, None when the library can't represent it
+    fn civil_date(&self, days: i64) -> Option<(i64, u32, u32)> {
+        let days = days.checked_add(719_468)?;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+        let month = if month_index < 10 {
+            month_index + 3
+        } else {
+            month_index - 9
+        };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+        self.years()
+            .contains(&year)
+            .then_some((year, month as u32, day as u32))
+    }
+
+    /// Lines of the bodies of the methods serializing and deserializing the Rust type like the logical type
+    pub fn glue(&self, logical_type: LogicalType) -> (Vec<String>, Vec<String>) {
+        let (int, var, what) = encoding(logical_type);
+        let (unit, units_per_second) = logical_type.unit();
+        // The ints are widened to the i64 of the durations
+        let widened = match int {
+            "i32" => format!("i64::from({var})"),
+            _ => var.to_string(),
+        };
+        let read =
+            format!("let {var} = <{int} as serde::Deserialize>::deserialize(deserializer)?;");
+        let out_of_range = format!(
+            "    .ok_or_else(|| serde::de::Error::custom(format!(\"The {what} {{{var}}} is out of range\")))"
+        );
+        let write = |value: &str| {
+            match int {
+            "i32" => format!(
+                "serializer.serialize_i32(i32::try_from({value}).map_err(serde::ser::Error::custom)?)"
+            ),
+            _ => format!(
+                "serializer.serialize_i64(i64::try_from({value}).map_err(serde::ser::Error::custom)?)"
+            ),
+        }
+        };
+
+        let (serialize, deserialize): (Vec<String>, Vec<String>) = match (self, logical_type) {
+            (_, LogicalType::Uuid) => (
+                vec!["serializer.collect_str(self)".to_string()],
+                vec![
+                    read,
+                    "uuid::Uuid::parse_str(&uuid).map_err(serde::de::Error::custom)".to_string(),
+                ],
+            ),
+            (TimeBackend::Chrono, LogicalType::Date) => (
+                vec![
+                    "let days = self.signed_duration_since(chrono::NaiveDate::default()).num_days();".to_string(),
+                    write("days"),
+                ],
+                vec![
+                    read,
+                    "chrono::NaiveDate::default()".to_string(),
+                    "    .checked_add_signed(chrono::TimeDelta::days(days.into()))".to_string(),
+                    out_of_range,
+                ],
+            ),
+            (TimeBackend::Chrono, LogicalType::TimeMillis) => (
+                vec![
+                    "let seconds = chrono::Timelike::num_seconds_from_midnight(self);".to_string(),
+                    "let nanoseconds = chrono::Timelike::nanosecond(self);".to_string(),
+                    "let millis = i64::from(seconds) * 1_000 + i64::from(nanoseconds / 1_000_000);".to_string(),
+                    write("millis"),
+                ],
+                vec![
+                    read,
+                    "u32::try_from(millis)".to_string(),
+                    "    .ok()".to_string(),
+                    "    .and_then(|millis| chrono::NaiveTime::from_num_seconds_from_midnight_opt(millis / 1_000, millis % 1_000 * 1_000_000))".to_string(),
+                    out_of_range,
+                ],
+            ),
+            (TimeBackend::Chrono, LogicalType::TimeMicros) => (
+                vec![
+                    "let seconds = chrono::Timelike::num_seconds_from_midnight(self);".to_string(),
+                    "let nanoseconds = chrono::Timelike::nanosecond(self);".to_string(),
+                    "serializer.serialize_i64(i64::from(seconds) * 1_000_000 + i64::from(nanoseconds / 1_000))".to_string(),
+                ],
+                vec![
+                    read,
+                    "u64::try_from(micros)".to_string(),
+                    "    .ok()".to_string(),
+                    "    .and_then(|micros| u32::try_from(micros / 1_000_000).ok().zip(u32::try_from(micros % 1_000_000 * 1_000).ok()))".to_string(),
+                    "    .and_then(|(seconds, nanoseconds)| chrono::NaiveTime::from_num_seconds_from_midnight_opt(seconds, nanoseconds))".to_string(),
+                    out_of_range,
+                ],
+            ),
+            (TimeBackend::Chrono, LogicalType::TimestampMillis | LogicalType::TimestampMicros) => (
+                vec![format!("serializer.serialize_i64(self.timestamp_{unit}())")],
+                vec![
+                    read,
+                    format!("chrono::DateTime::from_timestamp_{unit}(timestamp)"),
+                    out_of_range,
+                ],
+            ),
+            (TimeBackend::Chrono, _) => (
+                vec![format!("serializer.serialize_i64(self.and_utc().timestamp_{unit}())")],
+                vec![
+                    read,
+                    format!("chrono::DateTime::from_timestamp_{unit}(timestamp)"),
+                    "    .map(|date_time| date_time.naive_utc())".to_string(),
+                    out_of_range,
+                ],
+            ),
+            (TimeBackend::Time, LogicalType::Date) => (
+                vec![
+                    "serializer.serialize_i32(self.to_julian_day() - time::OffsetDateTime::UNIX_EPOCH.date().to_julian_day())".to_string(),
+                ],
+                vec![
+                    read,
+                    "days.checked_add(time::OffsetDateTime::UNIX_EPOCH.date().to_julian_day())".to_string(),
+                    "    .and_then(|julian_day| time::Date::from_julian_day(julian_day).ok())".to_string(),
+                    out_of_range,
+                ],
+            ),
+            (TimeBackend::Time, LogicalType::TimeMillis | LogicalType::TimeMicros) => (
+                vec![write(&format!(
+                    "(*self - time::Time::MIDNIGHT).whole_{}()",
+                    whole_unit(unit)
+                ))],
+                vec![
+                    read,
+                    format!("(0..{}).contains(&{var})", group_digits(86_400 * units_per_second)),
+                    format!(
+                        "    .then(|| time::Time::MIDNIGHT + time::Duration::{}({widened}))",
+                        whole_unit(unit)
+                    ),
+                    out_of_range,
+                ],
+            ),
+            (TimeBackend::Time, LogicalType::TimestampMillis | LogicalType::TimestampMicros) => (
+                vec![write(&format!(
+                    "self.unix_timestamp_nanos().div_euclid({})",
+                    group_digits(1_000_000_000 / units_per_second)
+                ))],
+                vec![
+                    read,
+                    format!(
+                        "time::OffsetDateTime::from_unix_timestamp_nanos(i128::from(timestamp) * {})",
+                        group_digits(1_000_000_000 / units_per_second)
+                    ),
+                    "    .ok()".to_string(),
+                    out_of_range,
+                ],
+            ),
+            (TimeBackend::Time, _) => (
+                vec![write(&format!(
+                    "self.assume_utc().unix_timestamp_nanos().div_euclid({})",
+                    group_digits(1_000_000_000 / units_per_second)
+                ))],
+                vec![
+                    read,
+                    format!(
+                        "time::OffsetDateTime::from_unix_timestamp_nanos(i128::from(timestamp) * {})",
+                        group_digits(1_000_000_000 / units_per_second)
+                    ),
+                    "    .ok()".to_string(),
+                    "    .map(|date_time| time::PrimitiveDateTime::new(date_time.date(), date_time.time()))".to_string(),
+                    out_of_range,
+                ],
+            ),
+            (TimeBackend::Jiff, LogicalType::Date) => (
+                vec![write(
+                    "self.duration_since(jiff::civil::date(1970, 1, 1)).as_secs() / 86_400",
+                )],
+                vec![
+                    read,
+                    "jiff::civil::date(1970, 1, 1)".to_string(),
+                    "    .checked_add(jiff::SignedDuration::from_hours(i64::from(days) * 24))".to_string(),
+                    "    .ok()".to_string(),
+                    out_of_range,
+                ],
+            ),
+            (TimeBackend::Jiff, LogicalType::TimeMillis | LogicalType::TimeMicros) => (
+                vec![write(&format!(
+                    "self.duration_since(jiff::civil::Time::midnight()).as_{unit}()"
+                ))],
+                vec![
+                    read,
+                    "jiff::civil::Time::midnight()".to_string(),
+                    format!("    .checked_add(jiff::SignedDuration::from_{unit}({widened}))"),
+                    "    .ok()".to_string(),
+                    out_of_range,
+                ],
+            ),
+            (TimeBackend::Jiff, LogicalType::TimestampMillis | LogicalType::TimestampMicros) => (
+                vec![format!(
+                    "serializer.serialize_i64(self.as_{}())",
+                    jiff_unit(unit)
+                )],
+                vec![
+                    read,
+                    format!("jiff::Timestamp::from_{}(timestamp)", jiff_unit(unit)),
+                    "    .ok()".to_string(),
+                    out_of_range,
+                ],
+            ),
+            (TimeBackend::Jiff, _) => (
+                vec![
+                    "let timestamp = jiff::tz::Offset::UTC.to_timestamp(*self).map_err(serde::ser::Error::custom)?;".to_string(),
+                    format!("serializer.serialize_i64(timestamp.as_{}())", jiff_unit(unit)),
+                ],
+                vec![
+                    read,
+                    format!("jiff::Timestamp::from_{}(timestamp)", jiff_unit(unit)),
+                    "    .ok()".to_string(),
+                    "    .map(|timestamp| jiff::tz::Offset::UTC.to_datetime(timestamp))".to_string(),
+                    out_of_range,
+                ],
+            ),
+            (TimeBackend::Std, LogicalType::Date) => (
+                vec!["serializer.serialize_i32(*self)".to_string()],
+                vec!["<i32 as serde::Deserialize>::deserialize(deserializer)".to_string()],
+            ),
+            (TimeBackend::Std, LogicalType::TimeMillis | LogicalType::TimeMicros) => (
+                vec![
+                    format!("let {var} = self.as_{unit}();"),
+                    format!("if {var} >= {} {{", group_digits(86_400 * units_per_second)),
+                    format!("    return Err(serde::ser::Error::custom(format!(\"The time {{{var}}} is out of range\")));"),
+                    "}".to_string(),
+                    write(var),
+                ],
+                vec![
+                    read,
+                    format!("u64::try_from({var})"),
+                    "    .ok()".to_string(),
+                    format!(
+                        "    .filter(|{var}| *{var} < {})",
+                        group_digits(86_400 * units_per_second)
+                    ),
+                    format!("    .map(std::time::Duration::from_{unit})"),
+                    out_of_range,
+                ],
+            ),
+            (TimeBackend::Std, LogicalType::TimestampMillis | LogicalType::TimestampMicros) => (
+                vec![
+                    "let nanoseconds = match self.duration_since(std::time::UNIX_EPOCH) {".to_string(),
+                    "    Ok(after) => i128::try_from(after.as_nanos()),".to_string(),
+                    "    Err(before) => i128::try_from(before.duration().as_nanos()).map(|nanoseconds| -nanoseconds),".to_string(),
+                    "};".to_string(),
+                    write(&format!(
+                        "nanoseconds.map_err(serde::ser::Error::custom)?.div_euclid({})",
+                        group_digits(1_000_000_000 / units_per_second)
+                    )),
+                ],
+                vec![
+                    read,
+                    format!("let since_epoch = std::time::Duration::from_{unit}(timestamp.unsigned_abs());"),
+                    "let date_time = if timestamp < 0 {".to_string(),
+                    "    std::time::UNIX_EPOCH.checked_sub(since_epoch)".to_string(),
+                    "} else {".to_string(),
+                    "    std::time::UNIX_EPOCH.checked_add(since_epoch)".to_string(),
+                    "};".to_string(),
+                    "date_time".to_string(),
+                    out_of_range,
+                ],
+            ),
+            (TimeBackend::Std, _) => (
+                vec!["serializer.serialize_i64(*self)".to_string()],
+                vec!["<i64 as serde::Deserialize>::deserialize(deserializer)".to_string()],
+            ),
+        };
+
+        (serialize, deserialize)
+    }
+}
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Avro integer of the logical type, name of the variable holding it and description of the value in the errors
+fn encoding(logical_type: LogicalType) -> (&'static str, &'static str, &'static str) {
+    match logical_type {
+        LogicalType::Date => ("i32", "days", "date"),
+        LogicalType::TimeMillis => ("i32", "millis", "time"),
+        LogicalType::TimeMicros => ("i64", "micros", "time"),
+        LogicalType::Uuid => ("String", "uuid", "uuid"),
+        _ => ("i64", "timestamp", "timestamp"),
+    }
+}
+
+/// Hours, minutes, seconds and fraction of second of a number of units after midnight
+fn time_of_day(units: i64, units_per_second: i64) -> (i64, i64, i64, i64) {
+    let seconds = units / units_per_second;
+    (
+        seconds / 3_600,
+        seconds / 60 % 60,
+        seconds % 60,
+        units % units_per_second,
+    )
+}
+
+/// Name of the unit in the methods of the time crate
+fn whole_unit(unit: &str) -> &'static str {
+    match unit {
+        "millis" => "milliseconds",
+        _ => "microseconds",
+    }
+}
+
+/// Name of the unit in the methods of jiff
+fn jiff_unit(unit: &str) -> &'static str {
+    match unit {
+        "millis" => "millisecond",
+        _ => "microsecond",
+    }
+}
+
+/// Integer literal with its digits grouped by three, like the literals of the generated code
+fn group_digits(value: i64) -> String {
+    let digits = value.to_string();
+    let mut grouped = String::new();
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            grouped.push('_');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
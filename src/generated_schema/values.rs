@@ -0,0 +1,240 @@
+use std::fmt::Write;
+
+use super::module_paths::ModulePaths;
+use crate::Result;
+
+/// Namespace of the conversions shared by the records using a fixed or a generated union enum
+pub const VALUES_NAMESPACE: &str = "values";
+
+const RESOLVE_NAME: &str = "resolve";
+
+const UNION_VARIANTS_NAME: &str = "union_variants";
+
+/// Conversions between the values serialized by serde and the Avro values of the schema, generated once
+#[derive(Debug)]
+pub struct GeneratedValues {}
+
+impl GeneratedValues {
+    pub fn produce_content(&self) -> Result<String> {
+        let mut content_string = String::new();
+        writeln!(content_string, "/// Resolve a value serialized by serde with the schema, like `Value::resolve`: the bytes of the fixed become fixed values,")?;
+        writeln!(content_string, "/// and the variants of the generated union enums are written with their own type of the union, the schema resolution")?;
+        writeln!(content_string, "/// of `apache_avro` finds the type of an union value from the value and can choose another one")?;
+        writeln!(content_string, "pub fn {RESOLVE_NAME}(value: apache_avro::types::Value, schema: &apache_avro::Schema) -> Result<apache_avro::types::Value, apache_avro::Error> {{")?;
+        writeln!(
+            content_string,
+            "    let resolved = apache_avro::schema::ResolvedSchema::try_from(schema)?;"
+        )?;
+        writeln!(
+            content_string,
+            "    resolve_value(value, schema, resolved.get_names())"
+        )?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        writeln!(content_string, "fn resolve_value(value: apache_avro::types::Value, schema: &apache_avro::Schema, names: &apache_avro::schema::NamesRef) -> Result<apache_avro::types::Value, apache_avro::Error> {{")?;
+        writeln!(content_string, "    use apache_avro::types::Value;")?;
+        writeln!(content_string, "    use apache_avro::Schema;")?;
+        writeln!(content_string)?;
+        writeln!(content_string, "    match (value, schema) {{")?;
+        writeln!(
+            content_string,
+            "        (value, Schema::Ref {{ name }}) => match names.get(name) {{"
+        )?;
+        writeln!(
+            content_string,
+            "            Some(definition) => resolve_value(value, definition, names),"
+        )?;
+        writeln!(content_string, "            None => value.resolve(schema),")?;
+        writeln!(content_string, "        }},")?;
+        writeln!(
+            content_string,
+            "        (Value::Record(fields), Schema::Record(record_schema)) => {{"
+        )?;
+        writeln!(
+            content_string,
+            "            let mut resolved_fields = Vec::with_capacity(fields.len());"
+        )?;
+        writeln!(content_string, "            for (name, value) in fields {{")?;
+        writeln!(
+            content_string,
+            "                let value = match record_schema.lookup.get(&name) {{"
+        )?;
+        writeln!(content_string, "                    Some(position) => resolve_value(value, &record_schema.fields[*position].schema, names)?,")?;
+        writeln!(content_string, "                    None => value,")?;
+        writeln!(content_string, "                }};")?;
+        writeln!(
+            content_string,
+            "                resolved_fields.push((name, value));"
+        )?;
+        writeln!(content_string, "            }}")?;
+        writeln!(
+            content_string,
+            "            Ok(Value::Record(resolved_fields))"
+        )?;
+        writeln!(content_string, "        }}")?;
+        writeln!(content_string, "        (Value::Array(items), Schema::Array(items_schema)) => items.into_iter().map(|item| resolve_value(item, items_schema, names)).collect::<Result<_, _>>().map(Value::Array),")?;
+        writeln!(content_string, "        (Value::Map(values), Schema::Map(values_schema)) => values.into_iter().map(|(key, value)| resolve_value(value, values_schema, names).map(|value| (key, value))).collect::<Result<_, _>>().map(Value::Map),")?;
+        writeln!(
+            content_string,
+            "        (value, Schema::Union(union_schema)) => {{"
+        )?;
+        writeln!(content_string, "            // The options are serialized as union values, without the index of their type in the schema")?;
+        writeln!(content_string, "            let value = match value {{")?;
+        writeln!(
+            content_string,
+            "                Value::Union(_, value) => *value,"
+        )?;
+        writeln!(content_string, "                value => value,")?;
+        writeln!(content_string, "            }};")?;
+        writeln!(content_string, "            let types: Vec<usize> = (0..union_schema.variants().len()).filter(|index| !matches!(union_schema.variants()[*index], Schema::Null)).collect();")?;
+        writeln!(
+            content_string,
+            "            let (index, value) = match (value, types.as_slice()) {{"
+        )?;
+        writeln!(content_string, "                (Value::Null, _) => (union_schema.variants().iter().position(|variant| matches!(variant, Schema::Null)), Value::Null),")?;
+        writeln!(
+            content_string,
+            "                (value, [index]) => (Some(*index), value),"
+        )?;
+        writeln!(content_string, "                // A generated enum serializes its variant with the index of its type among the types of the union without null")?;
+        writeln!(content_string, "                (Value::Record(fields), _) => match <[(String, Value); 2]>::try_from(fields) {{")?;
+        writeln!(content_string, "                    Ok([(_, Value::Enum(variant, _)), (_, Value::Union(_, value))]) => (types.get(variant as usize).copied(), *value),")?;
+        writeln!(
+            content_string,
+            "                    _ => (None, Value::Null),"
+        )?;
+        writeln!(content_string, "                }},")?;
+        writeln!(content_string, "                _ => (None, Value::Null),")?;
+        writeln!(content_string, "            }};")?;
+        writeln!(
+            content_string,
+            "            let index = index.ok_or(apache_avro::Error::FindUnionVariant)?;"
+        )?;
+        writeln!(content_string, "            let value = resolve_value(value, &union_schema.variants()[index], names)?;")?;
+        writeln!(
+            content_string,
+            "            Ok(Value::Union(index as u32, Box::new(value)))"
+        )?;
+        writeln!(content_string, "        }}")?;
+        writeln!(content_string, "        (Value::Bytes(bytes), Schema::Fixed(fixed_schema)) => Value::Fixed(fixed_schema.size, bytes).resolve(schema),")?;
+        writeln!(content_string, "        (Value::Bytes(bytes), Schema::Duration) => Value::Fixed(12, bytes).resolve(schema),")?;
+        writeln!(
+            content_string,
+            "        (value, schema) => value.resolve(schema),"
+        )?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        writeln!(content_string, "/// Replace the union values of the generated union enums with the representation of their variant read by the serde")?;
+        writeln!(content_string, "/// deserializer of `apache_avro`: a record with the index of the type among the types of the union without null, then the value")?;
+        writeln!(content_string, "pub fn {UNION_VARIANTS_NAME}(value: apache_avro::types::Value, schema: &apache_avro::Schema) -> apache_avro::types::Value {{")?;
+        writeln!(
+            content_string,
+            "    match apache_avro::schema::ResolvedSchema::try_from(schema) {{"
+        )?;
+        writeln!(
+            content_string,
+            "        Ok(resolved) => variant_values(value, schema, resolved.get_names()),"
+        )?;
+        writeln!(content_string, "        Err(_) => value,")?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        writeln!(content_string, "fn variant_values(value: apache_avro::types::Value, schema: &apache_avro::Schema, names: &apache_avro::schema::NamesRef) -> apache_avro::types::Value {{")?;
+        writeln!(content_string, "    use apache_avro::types::Value;")?;
+        writeln!(content_string, "    use apache_avro::Schema;")?;
+        writeln!(content_string)?;
+        writeln!(content_string, "    match (value, schema) {{")?;
+        writeln!(
+            content_string,
+            "        (value, Schema::Ref {{ name }}) => match names.get(name) {{"
+        )?;
+        writeln!(
+            content_string,
+            "            Some(definition) => variant_values(value, definition, names),"
+        )?;
+        writeln!(content_string, "            None => value,")?;
+        writeln!(content_string, "        }},")?;
+        writeln!(
+            content_string,
+            "        (Value::Record(fields), Schema::Record(record_schema)) => Value::Record("
+        )?;
+        writeln!(content_string, "            fields")?;
+        writeln!(content_string, "                .into_iter()")?;
+        writeln!(
+            content_string,
+            "                .map(|(name, value)| match record_schema.lookup.get(&name) {{"
+        )?;
+        writeln!(content_string, "                    Some(position) => {{")?;
+        writeln!(content_string, "                        let value = variant_values(value, &record_schema.fields[*position].schema, names);")?;
+        writeln!(content_string, "                        (name, value)")?;
+        writeln!(content_string, "                    }}")?;
+        writeln!(content_string, "                    None => (name, value),")?;
+        writeln!(content_string, "                }})")?;
+        writeln!(content_string, "                .collect(),")?;
+        writeln!(content_string, "        ),")?;
+        writeln!(content_string, "        (Value::Array(items), Schema::Array(items_schema)) => Value::Array(items.into_iter().map(|item| variant_values(item, items_schema, names)).collect()),")?;
+        writeln!(content_string, "        (Value::Map(values), Schema::Map(values_schema)) => Value::Map(values.into_iter().map(|(key, value)| (key, variant_values(value, values_schema, names))).collect()),")?;
+        writeln!(
+            content_string,
+            "        (Value::Union(index, value), Schema::Union(union_schema)) => {{"
+        )?;
+        writeln!(
+            content_string,
+            "            let variants = union_schema.variants();"
+        )?;
+        writeln!(
+            content_string,
+            "            let value = match variants.get(index as usize) {{"
+        )?;
+        writeln!(
+            content_string,
+            "                Some(variant) => variant_values(*value, variant, names),"
+        )?;
+        writeln!(content_string, "                None => *value,")?;
+        writeln!(content_string, "            }};")?;
+        writeln!(
+            content_string,
+            "            let is_type = |variant: &&Schema| !matches!(variant, Schema::Null);"
+        )?;
+        writeln!(content_string, "            if variants.iter().filter(is_type).count() < 2 || matches!(value, Value::Null) {{")?;
+        writeln!(
+            content_string,
+            "                return Value::Union(index, Box::new(value));"
+        )?;
+        writeln!(content_string, "            }}")?;
+        writeln!(content_string, "            let variant = variants.iter().take(index as usize).filter(is_type).count();")?;
+        writeln!(content_string, "            let variant = Value::Record(vec![(\"type\".to_string(), Value::String(variant.to_string())), (\"value\".to_string(), value)]);")?;
+        writeln!(
+            content_string,
+            "            match union_schema.is_nullable() {{"
+        )?;
+        writeln!(content_string, "                // The value of an option")?;
+        writeln!(
+            content_string,
+            "                true => Value::Union(index, Box::new(variant)),"
+        )?;
+        writeln!(content_string, "                false => variant,")?;
+        writeln!(content_string, "            }}")?;
+        writeln!(content_string, "        }}")?;
+        writeln!(content_string, "        (value, _) => value,")?;
+        writeln!(content_string, "    }}")?;
+        write!(content_string, "}}\r\n\r\n")?;
+
+        Ok(content_string)
+    }
+
+    pub fn schema_name(&self) -> String {
+        RESOLVE_NAME.to_owned()
+    }
+}
+
+/// Path of the schema resolution of the serialized values, used to encode
+pub fn resolve_path(module_paths: &ModulePaths) -> Result<String> {
+    Ok(module_paths.helper_path(VALUES_NAMESPACE, RESOLVE_NAME))
+}
+
+/// Path of the conversion of the union values to the variants of the generated enums, used to decode
+pub fn union_variants_path(module_paths: &ModulePaths) -> Result<String> {
+    Ok(module_paths.helper_path(VALUES_NAMESPACE, UNION_VARIANTS_NAME))
+}
@@ -0,0 +1,141 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde_json::{Map, Value};
+
+use crate::error::AvrogenError;
+use crate::Result;
+use parser::IdlParser;
+
+mod lexer;
+mod parser;
+
+pub use parser::type_full_name;
+
+/// Content of an Avro IDL file, converted to the JSON representation used in avsc and avpr files
+#[derive(Debug, Default)]
+pub struct IdlFile {
+    /// Protocol name, None when the file only declares schemas
+    pub protocol: Option<String>,
+
+    pub namespace: Option<String>,
+
+    pub doc: Option<String>,
+
+    /// Named types declared in the file and in the imported files. Each type has its full name.
+    pub types: Vec<Value>,
+
+    pub messages: Map<String, Value>,
+}
+
+pub enum ImportKind {
+    Idl,
+    Schema,
+    Protocol,
+}
+
+/// Parse the content of an Avro IDL file, imports are read relatively to the file path
+pub fn parse_idl(content: &str, file_path: &str) -> Result<IdlFile> {
+    let mut imported_files = HashSet::new();
+    parse_idl_with_imports(content, file_path, &mut imported_files)
+}
+
+fn parse_idl_with_imports(
+    content: &str,
+    file_path: &str,
+    imported_files: &mut HashSet<PathBuf>,
+) -> Result<IdlFile> {
+    let tokens = lexer::tokenize(content).map_err(|e| e.in_file(file_path))?;
+
+    log::debug!("IDL {file_path} read");
+
+    IdlParser::new(tokens, file_path, imported_files).parse()
+}
+
+/// Parse the JSON content of a protocol (avpr file). Types without namespace get the protocol namespace.
+pub fn parse_protocol_json(content: &str, file_path: &str) -> Result<IdlFile> {
+    let json_content: Value =
+        serde_json::from_str(content).map_err(|e| AvrogenError::json(file_path, &e))?;
+
+    let protocol = json_content
+        .get("protocol")
+        .and_then(Value::as_str)
+        .ok_or_else(|| AvrogenError::parse(file_path, None, "protocol name is missing"))?;
+
+    let namespace = json_content
+        .get("namespace")
+        .and_then(Value::as_str)
+        .map(str::to_string);
+
+    let types = json_content
+        .get("types")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|mut t| {
+            if let (Some(ns), Value::Object(object)) = (&namespace, &mut t) {
+                if !object.contains_key("namespace") {
+                    object.insert("namespace".to_string(), Value::String(ns.to_owned()));
+                }
+            }
+            t
+        })
+        .collect();
+
+    Ok(IdlFile {
+        protocol: Some(protocol.to_string()),
+        namespace,
+        doc: json_content
+            .get("doc")
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        types,
+        messages: json_content
+            .get("messages")
+            .and_then(Value::as_object)
+            .cloned()
+            .unwrap_or_default(),
+    })
+}
+
+/// Read a file imported by an IDL file. A file already imported return an empty content.
+fn import_file(
+    kind: ImportKind,
+    import_path: &str,
+    from_file: &str,
+    imported_files: &mut HashSet<PathBuf>,
+) -> Result<IdlFile> {
+    let path = Path::new(from_file)
+        .parent()
+        .unwrap_or(Path::new(""))
+        .join(import_path);
+
+    let content = fs::read_to_string(&path).map_err(|e| {
+        AvrogenError::parse(
+            from_file,
+            None,
+            format!("impossible to import {}: {e}", path.display()),
+        )
+    })?;
+
+    if !imported_files.insert(fs::canonicalize(&path)?) {
+        return Ok(IdlFile::default());
+    }
+
+    let file_path = path.display().to_string();
+
+    match kind {
+        ImportKind::Idl => parse_idl_with_imports(&content, &file_path, imported_files),
+        ImportKind::Protocol => parse_protocol_json(&content, &file_path),
+        ImportKind::Schema => {
+            let schema: Value =
+                serde_json::from_str(&content).map_err(|e| AvrogenError::json(&file_path, &e))?;
+            Ok(IdlFile {
+                types: vec![schema],
+                ..Default::default()
+            })
+        }
+    }
+}
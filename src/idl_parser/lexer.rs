@@ -0,0 +1,225 @@
+use crate::error::{AvrogenError, Location};
+use crate::Result;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenKind {
+    /// Identifier, can contains dots for full names. Backquoted identifiers are returned without the backquotes.
+    Identifier(String),
+    /// Annotation name, without the `@`
+    Annotation(String),
+    String(String),
+    Number(serde_json::Number),
+    /// One of `{ } ( ) < > [ ] , ; = ? :`
+    Symbol(char),
+    End,
+}
+
+#[derive(Debug, Clone)]
+pub struct Token {
+    pub kind: TokenKind,
+    /// Documentation comment (`/** ... */`) found just before the token
+    pub doc: Option<String>,
+    pub line: usize,
+    pub column: usize,
+}
+
+struct Lexer<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+    line: usize,
+    column: usize,
+}
+
+pub fn tokenize(content: &str) -> Result<Vec<Token>> {
+    let mut lexer = Lexer {
+        chars: content.chars().peekable(),
+        line: 1,
+        column: 1,
+    };
+
+    let mut tokens = Vec::new();
+    loop {
+        let token = lexer.next_token()?;
+        let is_end = token.kind == TokenKind::End;
+        tokens.push(token);
+        if is_end {
+            return Ok(tokens);
+        }
+    }
+}
+
+fn is_identifier_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+fn is_identifier_part(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+impl<'a> Lexer<'a> {
+    fn error(&self, message: &str) -> AvrogenError {
+        // The file is added by the parser of the file
+        AvrogenError::Parse {
+            location: Location {
+                position: Some((self.line, self.column)),
+                ..Default::default()
+            },
+            message: message.to_string(),
+        }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    fn next_token(&mut self) -> Result<Token> {
+        let mut doc = None;
+
+        loop {
+            match self.peek() {
+                Some(c) if c.is_whitespace() => {
+                    self.bump();
+                }
+                Some('/') => {
+                    self.bump();
+                    match self.bump() {
+                        Some('/') => {
+                            while !matches!(self.peek(), None | Some('\n')) {
+                                self.bump();
+                            }
+                        }
+                        Some('*') => {
+                            let comment = self.read_block_comment()?;
+                            if let Some(doc_content) = comment.strip_prefix('*') {
+                                doc = Some(clean_doc_comment(doc_content));
+                            }
+                        }
+                        _ => return Err(self.error("unexpected character '/'")),
+                    }
+                }
+                _ => break,
+            }
+        }
+
+        let (line, column) = (self.line, self.column);
+
+        let kind = match self.peek() {
+            None => TokenKind::End,
+            Some('"') => TokenKind::String(self.read_string()?),
+            Some('`') => {
+                self.bump();
+                let identifier = self.read_while(|c| c != '`');
+                if self.bump().is_none() {
+                    return Err(self.error("unterminated quoted identifier"));
+                }
+                TokenKind::Identifier(identifier)
+            }
+            Some('@') => {
+                self.bump();
+                let name = self.read_while(|c| is_identifier_part(c) || c == '-' || c == '.');
+                if name.is_empty() {
+                    return Err(self.error("annotation without name"));
+                }
+                TokenKind::Annotation(name)
+            }
+            Some(c) if c == '-' || c.is_ascii_digit() => TokenKind::Number(self.read_number()?),
+            Some(c) if is_identifier_start(c) => {
+                TokenKind::Identifier(self.read_while(|c| is_identifier_part(c) || c == '.'))
+            }
+            Some(c) if "{}()<>[],;=?:".contains(c) => {
+                self.bump();
+                TokenKind::Symbol(c)
+            }
+            Some(c) => return Err(self.error(&format!("unexpected character '{c}'"))),
+        };
+
+        Ok(Token {
+            kind,
+            doc,
+            line,
+            column,
+        })
+    }
+
+    fn read_while(&mut self, predicate: impl Fn(char) -> bool) -> String {
+        let mut content = String::new();
+        while let Some(c) = self.peek() {
+            if !predicate(c) {
+                break;
+            }
+            content.push(c);
+            self.bump();
+        }
+        content
+    }
+
+    /// Read a comment after the `/*`, return the content without the final `*/`
+    fn read_block_comment(&mut self) -> Result<String> {
+        let mut content = String::new();
+        loop {
+            match self.bump() {
+                None => return Err(self.error("unterminated comment")),
+                Some('*') if self.peek() == Some('/') => {
+                    self.bump();
+                    return Ok(content);
+                }
+                Some(c) => content.push(c),
+            }
+        }
+    }
+
+    /// Read a string literal, escape sequences are the JSON ones
+    fn read_string(&mut self) -> Result<String> {
+        let mut literal = String::new();
+        literal.push(self.bump().unwrap_or('"'));
+        loop {
+            match self.bump() {
+                None | Some('\n') => return Err(self.error("unterminated string")),
+                Some('\\') => {
+                    literal.push('\\');
+                    match self.bump() {
+                        None => return Err(self.error("unterminated string")),
+                        Some(c) => literal.push(c),
+                    }
+                }
+                Some('"') => {
+                    literal.push('"');
+                    break;
+                }
+                Some(c) => literal.push(c),
+            }
+        }
+        serde_json::from_str(&literal).map_err(|e| self.error(&format!("invalid string: {e}")))
+    }
+
+    fn read_number(&mut self) -> Result<serde_json::Number> {
+        let literal = self.read_while(|c| {
+            c.is_ascii_digit() || c == '-' || c == '+' || c == '.' || c == 'e' || c == 'E'
+        });
+        serde_json::from_str(&literal).map_err(|_| self.error(&format!("invalid number {literal}")))
+    }
+}
+
+/// Remove the comment decoration: the leading stars and spaces of each line
+fn clean_doc_comment(content: &str) -> String {
+    content
+        .lines()
+        .map(|line| {
+            let line = line.trim();
+            line.strip_prefix('*').map(str::trim_start).unwrap_or(line)
+        })
+        .collect::<Vec<&str>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
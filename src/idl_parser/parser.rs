@@ -0,0 +1,660 @@
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
+use serde_json::{json, Map, Value};
+
+use super::lexer::{Token, TokenKind};
+use super::{import_file, IdlFile, ImportKind};
+use crate::error::AvrogenError;
+use crate::Result;
+
+/// Convert the tokens of an IDL file to the JSON representation of its protocol (or of its schemas)
+pub struct IdlParser<'a> {
+    tokens: Vec<Token>,
+    position: usize,
+    file_path: &'a str,
+    namespace: Option<String>,
+    /// Full name of the declared types, indexed by their short name
+    declared_types: HashMap<String, String>,
+    /// Files already imported, an import of the same file is ignored
+    imported_files: &'a mut HashSet<PathBuf>,
+    /// Index of the types and name of the messages declared by this file,
+    /// their references are resolved once all the types of the file are declared
+    local_types: Vec<usize>,
+    local_messages: Vec<String>,
+    result: IdlFile,
+}
+
+/// Annotations (`@name(value)`) found before a declaration
+type Annotations = Vec<(String, Value)>;
+
+impl<'a> IdlParser<'a> {
+    pub fn new(
+        tokens: Vec<Token>,
+        file_path: &'a str,
+        imported_files: &'a mut HashSet<PathBuf>,
+    ) -> Self {
+        IdlParser {
+            tokens,
+            position: 0,
+            file_path,
+            namespace: None,
+            declared_types: HashMap::new(),
+            imported_files,
+            local_types: Vec::new(),
+            local_messages: Vec::new(),
+            result: IdlFile::default(),
+        }
+    }
+
+    pub fn parse(mut self) -> Result<IdlFile> {
+        loop {
+            let doc = self.current().doc.clone();
+            let annotations = self.parse_annotations()?;
+
+            match self.current().kind.clone() {
+                TokenKind::End => break,
+                TokenKind::Identifier(keyword) => match keyword.as_str() {
+                    "protocol" => self.parse_protocol(doc, annotations)?,
+                    "namespace" => {
+                        self.next();
+                        self.namespace = Some(self.expect_identifier()?);
+                        self.expect_symbol(';')?;
+                    }
+                    "schema" => {
+                        // The main schema is a reference or an anonymous type, named types are already declared
+                        self.next();
+                        self.parse_type()?;
+                        self.expect_symbol(';')?;
+                    }
+                    _ => self.parse_declaration(doc, annotations)?,
+                },
+                _ => return Err(self.unexpected("a declaration")),
+            }
+        }
+
+        self.resolve_references();
+        self.result.namespace = self.namespace.to_owned();
+        Ok(self.result)
+    }
+
+    fn parse_protocol(&mut self, doc: Option<String>, annotations: Annotations) -> Result<()> {
+        self.expect_keyword("protocol")?;
+
+        for (name, value) in annotations {
+            if name == "namespace" {
+                self.namespace = value.as_str().map(str::to_string);
+            }
+        }
+
+        self.result.protocol = Some(self.expect_identifier()?);
+        self.result.doc = doc;
+
+        self.expect_symbol('{')?;
+        while !self.is_symbol('}') {
+            let doc = self.current().doc.clone();
+            let annotations = self.parse_annotations()?;
+            match self.current().kind {
+                TokenKind::Identifier(ref keyword)
+                    if ["import", "record", "error", "enum", "fixed"]
+                        .contains(&keyword.as_str()) =>
+                {
+                    self.parse_declaration(doc, annotations)?
+                }
+                _ => self.parse_message(doc)?,
+            }
+        }
+        self.expect_symbol('}')
+    }
+
+    /// Parse an import or a named type declaration
+    fn parse_declaration(&mut self, doc: Option<String>, annotations: Annotations) -> Result<()> {
+        let keyword = self.expect_identifier()?;
+        match keyword.as_str() {
+            "import" => self.parse_import(),
+            "record" | "error" => self.parse_record(&keyword, doc, annotations),
+            "enum" => self.parse_enum(doc, annotations),
+            "fixed" => self.parse_fixed(doc, annotations),
+            _ => {
+                self.position -= 1;
+                Err(self.unexpected("record, error, enum, fixed or import"))
+            }
+        }
+    }
+
+    fn parse_import(&mut self) -> Result<()> {
+        let kind = match self.expect_identifier()?.as_str() {
+            "idl" => ImportKind::Idl,
+            "schema" => ImportKind::Schema,
+            "protocol" => ImportKind::Protocol,
+            _ => {
+                self.position -= 1;
+                return Err(self.unexpected("idl, schema or protocol"));
+            }
+        };
+        let import_path = self.expect_string()?;
+        self.expect_symbol(';')?;
+
+        let imported = import_file(kind, &import_path, self.file_path, self.imported_files)?;
+
+        for imported_type in imported.types {
+            if let Some(full_name) = type_full_name(&imported_type) {
+                let short_name = full_name.rsplit('.').next().unwrap_or(&full_name);
+                self.declared_types
+                    .insert(short_name.to_string(), full_name.to_owned());
+            }
+            self.result.types.push(imported_type);
+        }
+        self.result.messages.extend(imported.messages);
+
+        Ok(())
+    }
+
+    fn parse_record(
+        &mut self,
+        keyword: &str,
+        doc: Option<String>,
+        annotations: Annotations,
+    ) -> Result<()> {
+        let mut record = self.named_type(keyword, doc, annotations)?;
+
+        let mut fields = Vec::new();
+        self.expect_symbol('{')?;
+        while !self.is_symbol('}') {
+            fields.append(&mut self.parse_fields()?);
+        }
+        self.expect_symbol('}')?;
+
+        record.insert("fields".to_string(), Value::Array(fields));
+        self.push_type(record);
+        Ok(())
+    }
+
+    /// Parse a field declaration, which can declare multiple variables with the same type
+    fn parse_fields(&mut self) -> Result<Vec<Value>> {
+        let type_doc = self.current().doc.clone();
+        let (field_type, is_optional) = self.parse_type()?;
+
+        let mut fields = Vec::new();
+        loop {
+            let doc = self.current().doc.clone().or(type_doc.to_owned());
+            let annotations = self.parse_annotations()?;
+            let name = self.expect_identifier()?;
+
+            let mut field = Map::new();
+            field.insert("name".to_string(), Value::String(name));
+            field.insert("type".to_string(), field_type.clone());
+            if let Some(doc) = doc {
+                field.insert("doc".to_string(), Value::String(doc));
+            }
+            if self.is_symbol('=') {
+                self.next();
+                let default = self.parse_json()?;
+                // The default value of an optional type must match the first type of the union
+                if is_optional && default != Value::Null {
+                    if let Value::Array(variants) = &field_type {
+                        let reversed: Vec<Value> = variants.iter().rev().cloned().collect();
+                        field.insert("type".to_string(), Value::Array(reversed));
+                    }
+                }
+                field.insert("default".to_string(), default);
+            }
+            field.extend(annotations);
+            fields.push(Value::Object(field));
+
+            if self.is_symbol(',') {
+                self.next();
+            } else {
+                break;
+            }
+        }
+        self.expect_symbol(';')?;
+
+        Ok(fields)
+    }
+
+    fn parse_enum(&mut self, doc: Option<String>, annotations: Annotations) -> Result<()> {
+        let mut enum_type = self.named_type("enum", doc, annotations)?;
+
+        let mut symbols = Vec::new();
+        self.expect_symbol('{')?;
+        while !self.is_symbol('}') {
+            symbols.push(Value::String(self.expect_identifier()?));
+            if !self.is_symbol(',') {
+                break;
+            }
+            self.next();
+        }
+        self.expect_symbol('}')?;
+        enum_type.insert("symbols".to_string(), Value::Array(symbols));
+
+        if self.is_symbol('=') {
+            self.next();
+            enum_type.insert(
+                "default".to_string(),
+                Value::String(self.expect_identifier()?),
+            );
+            self.expect_symbol(';')?;
+        }
+
+        self.push_type(enum_type);
+        Ok(())
+    }
+
+    fn parse_fixed(&mut self, doc: Option<String>, annotations: Annotations) -> Result<()> {
+        let mut fixed_type = self.named_type("fixed", doc, annotations)?;
+
+        self.expect_symbol('(')?;
+        fixed_type.insert("size".to_string(), Value::Number(self.expect_number()?));
+        self.expect_symbol(')')?;
+        self.expect_symbol(';')?;
+
+        self.push_type(fixed_type);
+        Ok(())
+    }
+
+    /// Parse the name of a named type declaration and build the common attributes
+    fn named_type(
+        &mut self,
+        type_name: &str,
+        doc: Option<String>,
+        annotations: Annotations,
+    ) -> Result<Map<String, Value>> {
+        let declared_name = self.expect_identifier()?;
+
+        let mut namespace = self.namespace.to_owned();
+        let mut properties = Map::new();
+        for (name, value) in annotations {
+            match name.as_str() {
+                "namespace" => namespace = value.as_str().map(str::to_string),
+                _ => {
+                    properties.insert(name, value);
+                }
+            }
+        }
+
+        let (namespace, name) = match declared_name.rsplit_once('.') {
+            Some((ns, name)) => (Some(ns.to_string()), name.to_string()),
+            None => (namespace, declared_name),
+        };
+
+        let full_name = match &namespace {
+            Some(ns) if !ns.is_empty() => format!("{ns}.{name}"),
+            _ => name.to_owned(),
+        };
+        self.declared_types.insert(name.to_owned(), full_name);
+
+        let mut named_type = Map::new();
+        named_type.insert("type".to_string(), Value::String(type_name.to_string()));
+        named_type.insert("name".to_string(), Value::String(name));
+        if let Some(ns) = namespace {
+            named_type.insert("namespace".to_string(), Value::String(ns));
+        }
+        if let Some(doc) = doc {
+            named_type.insert("doc".to_string(), Value::String(doc));
+        }
+        named_type.extend(properties);
+
+        Ok(named_type)
+    }
+
+    fn parse_message(&mut self, doc: Option<String>) -> Result<()> {
+        let response = if self.is_keyword("void") {
+            self.next();
+            Value::String("null".to_string())
+        } else {
+            self.parse_type()?.0
+        };
+
+        let name = self.expect_identifier()?;
+
+        let mut request = Vec::new();
+        self.expect_symbol('(')?;
+        while !self.is_symbol(')') {
+            let (parameter_type, _) = self.parse_type()?;
+            let mut parameter = Map::new();
+            parameter.extend(self.parse_annotations()?);
+            parameter.insert("name".to_string(), Value::String(self.expect_identifier()?));
+            parameter.insert("type".to_string(), parameter_type);
+            if self.is_symbol('=') {
+                self.next();
+                parameter.insert("default".to_string(), self.parse_json()?);
+            }
+            request.push(Value::Object(parameter));
+
+            if !self.is_symbol(',') {
+                break;
+            }
+            self.next();
+        }
+        self.expect_symbol(')')?;
+
+        let mut message = Map::new();
+        if let Some(doc) = doc {
+            message.insert("doc".to_string(), Value::String(doc));
+        }
+        message.insert("request".to_string(), Value::Array(request));
+        message.insert("response".to_string(), response);
+
+        if self.is_keyword("oneway") {
+            self.next();
+            message.insert("one-way".to_string(), Value::Bool(true));
+        } else if self.is_keyword("throws") {
+            self.next();
+            let mut errors = Vec::new();
+            loop {
+                let error_name = self.expect_identifier()?;
+                errors.push(Value::String(error_name));
+                if !self.is_symbol(',') {
+                    break;
+                }
+                self.next();
+            }
+            message.insert("errors".to_string(), Value::Array(errors));
+        }
+        self.expect_symbol(';')?;
+
+        self.local_messages.push(name.to_owned());
+        self.result.messages.insert(name, Value::Object(message));
+        Ok(())
+    }
+
+    /// Parse a type, return its JSON representation and if it was declared optional with `?`
+    fn parse_type(&mut self) -> Result<(Value, bool)> {
+        let annotations = self.parse_annotations()?;
+
+        let type_name = self.expect_identifier()?;
+        let base_type = match type_name.as_str() {
+            "null" | "boolean" | "int" | "long" | "float" | "double" | "bytes" | "string" => {
+                Value::String(type_name)
+            }
+            "array" | "map" => {
+                self.expect_symbol('<')?;
+                let (inner_type, _) = self.parse_type()?;
+                self.expect_symbol('>')?;
+                match type_name.as_str() {
+                    "array" => json!({"type": "array", "items": inner_type}),
+                    _ => json!({"type": "map", "values": inner_type}),
+                }
+            }
+            "union" => {
+                let mut variants = Vec::new();
+                self.expect_symbol('{')?;
+                while !self.is_symbol('}') {
+                    variants.push(self.parse_type()?.0);
+                    if !self.is_symbol(',') {
+                        break;
+                    }
+                    self.next();
+                }
+                self.expect_symbol('}')?;
+                Value::Array(variants)
+            }
+            "decimal" => {
+                self.expect_symbol('(')?;
+                let precision = self.expect_number()?;
+                self.expect_symbol(',')?;
+                let scale = self.expect_number()?;
+                self.expect_symbol(')')?;
+                json!({"type": "bytes", "logicalType": "decimal", "precision": precision, "scale": scale})
+            }
+            "date" => json!({"type": "int", "logicalType": "date"}),
+            "time_ms" => json!({"type": "int", "logicalType": "time-millis"}),
+            "timestamp_ms" => json!({"type": "long", "logicalType": "timestamp-millis"}),
+            "local_timestamp_ms" => {
+                json!({"type": "long", "logicalType": "local-timestamp-millis"})
+            }
+            "uuid" => json!({"type": "string", "logicalType": "uuid"}),
+            // References are resolved at the end of the file, the type can be declared later
+            _ => Value::String(type_name),
+        };
+
+        let with_annotations = match base_type {
+            _ if annotations.is_empty() => base_type,
+            Value::String(ref name) if is_primitive(name) => {
+                let mut object = Map::new();
+                object.insert("type".to_string(), base_type.clone());
+                object.extend(annotations);
+                Value::Object(object)
+            }
+            Value::Object(mut object) => {
+                object.extend(annotations);
+                Value::Object(object)
+            }
+            // Annotations can't be applied on unions and references
+            _ => base_type,
+        };
+
+        if self.is_symbol('?') {
+            self.next();
+            return Ok((json!(["null", with_annotations]), true));
+        }
+        Ok((with_annotations, false))
+    }
+
+    fn push_type(&mut self, named_type: Map<String, Value>) {
+        self.local_types.push(self.result.types.len());
+        self.result.types.push(Value::Object(named_type));
+    }
+
+    /// Replace the references of the types and messages declared by this file with the full names.
+    /// The imported types are already resolved against their own file
+    fn resolve_references(&mut self) {
+        let mut types = std::mem::take(&mut self.result.types);
+        for index in &self.local_types {
+            self.resolve_type_references(&mut types[*index]);
+        }
+        self.result.types = types;
+
+        let mut messages = std::mem::take(&mut self.result.messages);
+        for name in &self.local_messages {
+            let Some(Value::Object(message)) = messages.get_mut(name) else {
+                continue;
+            };
+            if let Some(Value::Array(request)) = message.get_mut("request") {
+                for parameter in request {
+                    if let Some(parameter_type) = parameter.get_mut("type") {
+                        self.resolve_type_references(parameter_type);
+                    }
+                }
+            }
+            for key in ["response", "errors"] {
+                if let Some(json_type) = message.get_mut(key) {
+                    self.resolve_type_references(json_type);
+                }
+            }
+        }
+        self.result.messages = messages;
+    }
+
+    fn resolve_type_references(&self, json_type: &mut Value) {
+        match json_type {
+            Value::String(name) if !is_primitive(name) => *name = self.resolve_reference(name),
+            Value::Array(variants) => {
+                for variant in variants {
+                    self.resolve_type_references(variant);
+                }
+            }
+            Value::Object(object) => {
+                for key in ["items", "values"] {
+                    if let Some(inner_type) = object.get_mut(key) {
+                        self.resolve_type_references(inner_type);
+                    }
+                }
+                if let Some(Value::Array(fields)) = object.get_mut("fields") {
+                    for field in fields {
+                        if let Some(field_type) = field.get_mut("type") {
+                            self.resolve_type_references(field_type);
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn resolve_reference(&self, name: &str) -> String {
+        if name.contains('.') {
+            return name.to_string();
+        }
+        if let Some(full_name) = self.declared_types.get(name) {
+            return full_name.to_owned();
+        }
+        match &self.namespace {
+            Some(ns) if !ns.is_empty() => format!("{ns}.{name}"),
+            _ => name.to_string(),
+        }
+    }
+
+    fn parse_annotations(&mut self) -> Result<Annotations> {
+        let mut annotations = Vec::new();
+        while let TokenKind::Annotation(name) = self.current().kind.clone() {
+            self.next();
+            self.expect_symbol('(')?;
+            let value = self.parse_json()?;
+            self.expect_symbol(')')?;
+            annotations.push((name, value));
+        }
+        Ok(annotations)
+    }
+
+    fn parse_json(&mut self) -> Result<Value> {
+        let token = self.current().clone();
+        self.next();
+        match token.kind {
+            TokenKind::String(s) => Ok(Value::String(s)),
+            TokenKind::Number(n) => Ok(Value::Number(n)),
+            TokenKind::Identifier(ref i) if i == "true" => Ok(Value::Bool(true)),
+            TokenKind::Identifier(ref i) if i == "false" => Ok(Value::Bool(false)),
+            TokenKind::Identifier(ref i) if i == "null" => Ok(Value::Null),
+            TokenKind::Symbol('[') => {
+                let mut values = Vec::new();
+                while !self.is_symbol(']') {
+                    values.push(self.parse_json()?);
+                    if !self.is_symbol(',') {
+                        break;
+                    }
+                    self.next();
+                }
+                self.expect_symbol(']')?;
+                Ok(Value::Array(values))
+            }
+            TokenKind::Symbol('{') => {
+                let mut values = Map::new();
+                while !self.is_symbol('}') {
+                    let key = self.expect_string()?;
+                    self.expect_symbol(':')?;
+                    values.insert(key, self.parse_json()?);
+                    if !self.is_symbol(',') {
+                        break;
+                    }
+                    self.next();
+                }
+                self.expect_symbol('}')?;
+                Ok(Value::Object(values))
+            }
+            _ => {
+                self.position -= 1;
+                Err(self.unexpected("a JSON value"))
+            }
+        }
+    }
+
+    fn current(&self) -> &Token {
+        // The last token is always the end token
+        &self.tokens[self.position.min(self.tokens.len() - 1)]
+    }
+
+    fn next(&mut self) {
+        if self.position < self.tokens.len() - 1 {
+            self.position += 1;
+        }
+    }
+
+    fn is_symbol(&self, symbol: char) -> bool {
+        self.current().kind == TokenKind::Symbol(symbol)
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(&self.current().kind, TokenKind::Identifier(i) if i == keyword)
+    }
+
+    fn expect_symbol(&mut self, symbol: char) -> Result<()> {
+        if !self.is_symbol(symbol) {
+            return Err(self.unexpected(&format!("'{symbol}'")));
+        }
+        self.next();
+        Ok(())
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<()> {
+        if !self.is_keyword(keyword) {
+            return Err(self.unexpected(keyword));
+        }
+        self.next();
+        Ok(())
+    }
+
+    fn expect_identifier(&mut self) -> Result<String> {
+        match self.current().kind.clone() {
+            TokenKind::Identifier(identifier) => {
+                self.next();
+                Ok(identifier)
+            }
+            _ => Err(self.unexpected("an identifier")),
+        }
+    }
+
+    fn expect_string(&mut self) -> Result<String> {
+        match self.current().kind.clone() {
+            TokenKind::String(s) => {
+                self.next();
+                Ok(s)
+            }
+            _ => Err(self.unexpected("a string")),
+        }
+    }
+
+    fn expect_number(&mut self) -> Result<serde_json::Number> {
+        match self.current().kind.clone() {
+            TokenKind::Number(n) => {
+                self.next();
+                Ok(n)
+            }
+            _ => Err(self.unexpected("a number")),
+        }
+    }
+
+    fn unexpected(&self, expected: &str) -> AvrogenError {
+        let token = self.current();
+        let found = match &token.kind {
+            TokenKind::Identifier(i) => format!("'{i}'"),
+            TokenKind::Annotation(a) => format!("'@{a}'"),
+            TokenKind::String(s) => format!("\"{s}\""),
+            TokenKind::Number(n) => format!("{n}"),
+            TokenKind::Symbol(s) => format!("'{s}'"),
+            TokenKind::End => "end of file".to_string(),
+        };
+        AvrogenError::parse(
+            self.file_path,
+            Some((token.line, token.column)),
+            format!("expected {expected}, found {found}"),
+        )
+    }
+}
+
+fn is_primitive(name: &str) -> bool {
+    [
+        "null", "boolean", "int", "long", "float", "double", "bytes", "string",
+    ]
+    .contains(&name)
+}
+
+/// Full name of a named type in its JSON representation
+pub fn type_full_name(json_type: &Value) -> Option<String> {
+    let name = json_type.get("name")?.as_str()?;
+    match json_type.get("namespace").and_then(Value::as_str) {
+        Some(ns) if !ns.is_empty() && !name.contains('.') => Some(format!("{ns}.{name}")),
+        _ => Some(name.to_string()),
+    }
+}
@@ -2,6 +2,7 @@
 
 use file_parser::parse_schemas;
 use generated_schema::namespace::NamespaceInfo;
+use generated_schema::{ModulePaths, RootPath, SchemaDefinitions, TypeMapping};
 use std::{path::PathBuf, str::FromStr};
 
 use crate::error::Result;
@@ -10,12 +11,18 @@ use clap_verbosity::Verbosity;
 use log::{debug, info, LevelFilter};
 
 mod browse_sub_schemas;
+mod default_validation;
 mod error;
 mod file_parser;
 mod generated_schema;
+mod idl_parser;
+mod protocol_parser;
 mod source;
 mod writers;
 
+pub use error::{AvrogenError, Location};
+pub use generated_schema::{DecimalBackend, Fingerprint, ModuleLayout, NamespaceRule, TimeBackend};
+
 /// The Avrogen stucture is the main part of the utility.
 /// You need to create an instance of this object and execute it to generate rust files from your avsc files
 /// # example
@@ -36,6 +43,8 @@ pub struct Avrogen {
     /// Source to use by the generator, use stdin if source isn't specified.
     ///
     /// The source use glob format, you can use multiple source arguments. For simple search: ./MyFolder/*.avsc. For recursive search: ./MyFolder/**/*.avsc
+    ///
+    /// Avro IDL files (*.avdl) and protocol files (*.avpr) are also supported. For object container files (*.avro), the schema of the file header is used.
     #[arg(short='s', long, num_args=..)]
     source: Vec<String>,
 
@@ -46,12 +55,72 @@ pub struct Avrogen {
     #[arg(long, short='o', default_value="./", aliases=&["output-folder","outputfolder"])]
     output_folder: PathBuf,
 
+    /// Write all the generated modules in this file of the output folder, as inline modules instead of a file per module.
+    ///
+    /// In a build script, the file is included with: include!(concat!(env!("OUT_DIR"), "/schemas.rs"));
+    #[arg(long, value_name="FILE")]
+    single_file: Option<PathBuf>,
+
+    /// Write the generated code to the standard output instead of the output folder.
+    ///
+    /// With --single-file, the content of the single file is written. Otherwise the module files are written one after the other, each one after a line // file: path
+    #[arg(long)]
+    stdout: bool,
+
+    /// Fingerprints generated as constants for each record and enum, in addition to the Rabin fingerprint.
+    #[arg(long, value_enum, num_args=..)]
+    fingerprint: Vec<Fingerprint>,
+
+    /// Generate the methods to encode and decode records with the Avro single-object encoding.
+    #[arg(long)]
+    single_object: bool,
+
+    /// Generate the methods to encode and decode records with the Confluent Schema Registry wire format.
+    ///
+    /// The schema resolver trait and the error are generated in the confluent module.
+    #[arg(long)]
+    confluent: bool,
+
+    /// Rust type of the Avro decimals. The decimal fields are serialized with the precision and the scale of their schema.
+    #[arg(long, value_enum, default_value="apache-avro")]
+    decimal: DecimalBackend,
+
+    /// Rust types of the Avro dates, times and timestamps. The fields are serialized like their Avro logical type.
+    #[arg(long, value_enum, default_value="chrono")]
+    time: TimeBackend,
+
+    /// Modules of the generated types. With a flat layout, the types are generated in the module of the default namespace, which is needed unless --single-file is used.
+    #[arg(long, value_enum, default_value="nested")]
+    layout: ModuleLayout,
+
+    /// Move the types of an Avro namespace to another module, like com.mysite.machines=machines, or of a namespace and its sub namespaces, like org.apache.*=vendor::apache.
+    ///
+    /// The rule of the longest namespace is used. The modules are in the default namespace, an empty module moves the types to the default namespace.
+    #[arg(long, value_name="NAMESPACE=MODULE", num_args=..)]
+    map_namespace: Vec<NamespaceRule>,
+
+    /// Path of the root module of the generated code, used by the references between the generated types, like crate::gen or ::my_schemas.
+    ///
+    /// The path is written as is in every module. With --relative-paths, the paths start from the module which uses the type instead.
+    #[arg(long, default_value="crate", conflicts_with="relative_paths")]
+    root_path: String,
+
+    /// The references between the generated types are relative to the module which uses them, like super::machines::Machine. The generated modules can be placed anywhere.
+    #[arg(long)]
+    relative_paths: bool,
+
     #[command(flatten)]
     verbose: Verbosity,
 
     log_level: Option<LevelFilter>,
 }
 
+impl Default for Avrogen {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Avrogen {
     /// Create a new Avrogen instance
     /// # example
@@ -64,6 +133,17 @@ impl Avrogen {
             source: vec![],
             default_namespace: None,
             output_folder: PathBuf::from("./"),
+            single_file: None,
+            stdout: false,
+            fingerprint: vec![],
+            single_object: false,
+            confluent: false,
+            decimal: DecimalBackend::ApacheAvro,
+            time: TimeBackend::Chrono,
+            layout: ModuleLayout::Nested,
+            map_namespace: vec![],
+            root_path: "crate".to_string(),
+            relative_paths: false,
             verbose: Verbosity::default(),
             log_level: None,
         }
@@ -124,6 +204,128 @@ impl Avrogen {
         self
     }
 
+    /// For builder syntax, allow to write all the generated modules in one file of the output folder
+    /// # example
+    /// ```
+    /// let builder=avrogen::Avrogen::new();
+    /// builder.single_file("schemas.rs");
+    /// ```
+    pub fn single_file(mut self, file_name: &str) -> Self {
+        self.single_file = Some(PathBuf::from(file_name));
+        self
+    }
+
+    /// For builder syntax, allow to write the generated code to the standard output instead of the output folder
+    /// # example
+    /// ```
+    /// let builder=avrogen::Avrogen::new();
+    /// builder.single_file("schemas.rs").stdout();
+    /// ```
+    pub fn stdout(mut self) -> Self {
+        self.stdout = true;
+        self
+    }
+
+    /// For builder syntax, allow to generate a fingerprint in addition to the Rabin fingerprint
+    /// # example
+    /// ```
+    /// let builder=avrogen::Avrogen::new();
+    /// builder.add_fingerprint(avrogen::Fingerprint::Sha256);
+    /// ```
+    pub fn add_fingerprint(mut self, fingerprint: Fingerprint) -> Self {
+        self.fingerprint.push(fingerprint);
+        self
+    }
+
+    /// For builder syntax, allow to generate the single-object encoding methods of the records
+    /// # example
+    /// ```
+    /// let builder=avrogen::Avrogen::new();
+    /// builder.single_object_encoding();
+    /// ```
+    pub fn single_object_encoding(mut self) -> Self {
+        self.single_object = true;
+        self
+    }
+
+    /// For builder syntax, allow to generate the Confluent wire format methods of the records
+    /// # example
+    /// ```
+    /// let builder=avrogen::Avrogen::new();
+    /// builder.confluent_wire_format();
+    /// ```
+    pub fn confluent_wire_format(mut self) -> Self {
+        self.confluent = true;
+        self
+    }
+
+    /// For builder syntax, allow to choose the Rust type of the Avro decimals
+    /// # example
+    /// ```
+    /// let builder=avrogen::Avrogen::new();
+    /// builder.decimal_backend(avrogen::DecimalBackend::RustDecimal);
+    /// ```
+    pub fn decimal_backend(mut self, decimal_backend: DecimalBackend) -> Self {
+        self.decimal = decimal_backend;
+        self
+    }
+
+    /// For builder syntax, allow to choose the Rust types of the Avro dates, times and timestamps
+    /// # example
+    /// ```
+    /// let builder=avrogen::Avrogen::new();
+    /// builder.time_backend(avrogen::TimeBackend::Jiff);
+    /// ```
+    pub fn time_backend(mut self, time_backend: TimeBackend) -> Self {
+        self.time = time_backend;
+        self
+    }
+
+    /// For builder syntax, allow to choose the modules of the generated types
+    /// # example
+    /// ```
+    /// let builder=avrogen::Avrogen::new();
+    /// builder.default_namespace("schemas").module_layout(avrogen::ModuleLayout::FlatPrefixed);
+    /// ```
+    pub fn module_layout(mut self, layout: ModuleLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// For builder syntax, allow to move the types of an Avro namespace to another module, or of its sub namespaces too when it ends with `.*`
+    /// # example
+    /// ```
+    /// let builder=avrogen::Avrogen::new();
+    /// builder.map_namespace("com.mysite.machines", "machines").map_namespace("org.apache.*", "vendor::apache");
+    /// ```
+    pub fn map_namespace(mut self, namespace: &str, module: &str) -> Self {
+        self.map_namespace.push(NamespaceRule::new(namespace, module));
+        self
+    }
+
+    /// For builder syntax, allow to specify the path of the root module of the generated code, `crate` by default
+    /// # example
+    /// ```
+    /// let builder=avrogen::Avrogen::new();
+    /// builder.root_path("crate::gen");
+    /// ```
+    pub fn root_path(mut self, root_path: &str) -> Self {
+        self.root_path = root_path.to_string();
+        self.relative_paths = false;
+        self
+    }
+
+    /// For builder syntax, allow to write the references between the generated types relative to the module which uses them
+    /// # example
+    /// ```
+    /// let builder=avrogen::Avrogen::new();
+    /// builder.relative_paths();
+    /// ```
+    pub fn relative_paths(mut self) -> Self {
+        self.relative_paths = true;
+        self
+    }
+
     /// For builder syntax, allow to specify verbosity to Off
     /// # example
     /// ```
@@ -209,6 +411,11 @@ impl Avrogen {
 
         let _ = builder.try_init();
 
+        // The module files are written from the module of the default namespace, the types of the root module are only written in a single file
+        if self.layout != ModuleLayout::Nested && self.default_namespace.is_none() && self.single_file.is_none() {
+            return Err(AvrogenError::generation("the flat layout needs a default namespace, the module of the generated types, or a single file"));
+        }
+
         info!("1) Browse source to get content");
 
         // We get a list of string. Each string is the content of a file.
@@ -216,30 +423,102 @@ impl Avrogen {
 
         info!("2) Parsing file to get schemas...");
 
-        let root_schemas = parse_schemas(file_contents)?;
+        let (root_schemas, protocols, type_files, type_definitions) = parse_schemas(file_contents)?;
 
-        let mut root_ns = NamespaceInfo::root(self.default_namespace);
+        let type_mapping = TypeMapping {
+            decimal: self.decimal,
+            time: self.time,
+        };
 
         debug!(
             "{} root schemas found, browse sub schemas...",
             root_schemas.len()
         );
 
-        let root_schemas = root_schemas.iter().map(|s| s).collect();
+        let root_schemas = root_schemas.iter().collect();
 
-        let all_schemas = browse_sub_schemas::all_schemas_to_generate(root_schemas);
+        let mut all_schemas = browse_sub_schemas::all_schemas_to_generate(root_schemas);
+
+        // Types declared inside the message responses must be generated too
+        all_schemas.extend(
+            protocols
+                .iter()
+                .flat_map(|p| p.messages.iter())
+                .flat_map(|m| browse_sub_schemas::schemas_to_generate(&m.response)),
+        );
 
         debug!("Total of {} schemas found", all_schemas.len());
 
+        let type_names: Vec<_> = all_schemas.iter().filter_map(|s| s.name()).collect();
+        let root_path = match self.relative_paths {
+            true => RootPath::Relative,
+            false => RootPath::Path(self.root_path),
+        };
+        // The unions of the message responses are generated in the namespace of their protocol
+        let mut unions = browse_sub_schemas::unions_with_namespace(&all_schemas);
+        unions.extend(protocols.iter().flat_map(|p| {
+            p.messages
+                .iter()
+                .flat_map(|m| browse_sub_schemas::unions_to_generate(&m.response))
+                .map(|u| (p.namespace.to_owned(), u))
+        }));
+        let module_paths = ModulePaths::new(self.default_namespace, self.layout, self.map_namespace, root_path, &type_names, &unions)?;
+
+        let mut root_ns = NamespaceInfo::root(module_paths, type_mapping);
+
+        let schema_definitions = SchemaDefinitions::new(
+            &all_schemas,
+            &type_definitions,
+            self.fingerprint,
+            self.single_object,
+            self.confluent,
+        );
+
         info!("3) Process schemas to get informations...");
 
-        for schema in all_schemas {
-            root_ns.process_schema(schema)?;
+        for schema in all_schemas.iter() {
+            root_ns.process_schema(schema, &schema_definitions)
+            .map_err(|e| match schema.name().and_then(|name| type_files.get(name)) {
+                Some(file_path) => e.in_file(file_path),
+                None => e,
+            })?;
+        }
+
+        for protocol in protocols.iter() {
+            root_ns.process_protocol(protocol, &schema_definitions)?;
+        }
+
+        if self.confluent {
+            root_ns.process_confluent()?;
+        }
+
+        if all_schemas.iter().any(|s| browse_sub_schemas::uses_bytes(s)) {
+            root_ns.process_bytes()?;
+        }
+
+        if all_schemas.iter().any(|s| browse_sub_schemas::uses_decimal(s)) {
+            root_ns.process_decimal()?;
+        }
+
+        if all_schemas.iter().any(|s| browse_sub_schemas::uses_duration(s)) {
+            root_ns.process_duration()?;
+        }
+
+        // The union enums are always converted, the fixed only by the single-object and Confluent writers
+        if all_schemas.iter().any(|s| browse_sub_schemas::uses_union(s))
+            || ((self.single_object || self.confluent) && all_schemas.iter().any(|s| browse_sub_schemas::uses_fixed(s)))
+        {
+            root_ns.process_values()?;
+        }
+
+        let logical_types: Vec<_> = all_schemas.iter().flat_map(|s| browse_sub_schemas::logical_types(s)).collect();
+        if !logical_types.is_empty() {
+            root_ns.process_logical_types(&logical_types)?;
         }
 
         info!("4) Write to files");
 
-        writers::write(self.output_folder, root_ns)?;
+        writers::write(self.output_folder, self.single_file, self.stdout, root_ns)?;
 
         info!("Done!");
 
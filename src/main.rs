@@ -1,12 +1,17 @@
 use avrogen::Avrogen;
 use clap::Parser;
-pub mod error;
+use std::process::ExitCode;
 
-pub fn main() -> core::result::Result<(),Box<dyn std::error::Error>>{
+pub fn main() -> ExitCode{
 
     let avrogen = Avrogen::parse();
-    
-    avrogen.execute()?;
-    Ok(())
-}
 
+    // The errors are displayed with their location, like the compiler diagnostics
+    match avrogen.execute() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
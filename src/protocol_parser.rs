@@ -0,0 +1,169 @@
+use apache_avro::schema::{Name, Namespace};
+use apache_avro::Schema;
+use heck::ToUpperCamelCase;
+use serde_json::{json, Value};
+
+use crate::error::AvrogenError;
+use crate::idl_parser::IdlFile;
+use crate::Result;
+
+/// Protocol read from an avpr or an IDL file
+#[derive(Debug)]
+pub struct ProtocolDefinition {
+    pub name: String,
+
+    pub namespace: Namespace,
+
+    pub doc: Option<String>,
+
+    pub messages: Vec<MessageDefinition>,
+}
+
+#[derive(Debug)]
+pub struct MessageDefinition {
+    pub name: String,
+
+    pub doc: Option<String>,
+
+    /// Name of the record generated with the message parameters
+    pub request: Name,
+
+    /// Schema of the response
+    pub response: Schema,
+
+    /// Full names of the errors declared by the message
+    pub errors: Vec<Name>,
+
+    pub one_way: bool,
+}
+
+/// Name of the record which contains the parameters of a message
+fn request_record_name(message_name: &str) -> String {
+    format!("{}Request", message_name.to_upper_camel_case())
+}
+
+/// Name of the record used to parse the response of a message, this record is never generated
+fn response_record_name(message_name: &str) -> String {
+    format!("__{}Response", message_name.to_upper_camel_case())
+}
+
+fn full_name(namespace: &Namespace, name: &str) -> String {
+    match namespace {
+        Some(ns) if !ns.is_empty() && !name.contains('.') => format!("{ns}.{name}"),
+        _ => name.to_string(),
+    }
+}
+
+/// Return the records declared for the messages of a protocol.
+///
+/// Parameters and responses of the messages are declared as records, so they are parsed with the other types.
+pub fn messages_records(protocol: &IdlFile) -> Vec<Value> {
+    protocol
+        .messages
+        .iter()
+        .flat_map(|(message_name, message)| {
+            let request_fields = message.get("request").cloned().unwrap_or(json!([]));
+            let response_type = message.get("response").cloned().unwrap_or(json!("null"));
+
+            [
+                json!({
+                    "type": "record",
+                    "name": request_record_name(message_name),
+                    "namespace": protocol.namespace,
+                    "doc": format!("Parameters of the message {message_name}"),
+                    "fields": request_fields,
+                }),
+                json!({
+                    "type": "record",
+                    "name": response_record_name(message_name),
+                    "namespace": protocol.namespace,
+                    "fields": [{"name": "response", "type": response_type}],
+                }),
+            ]
+        })
+        .collect()
+}
+
+/// Build the protocol definitions from the parsed schemas.
+///
+/// Response records are only used to get the response schemas, they are removed from the schema list.
+pub fn build_protocols(
+    protocols: &[IdlFile],
+    schemas: &mut Vec<Schema>,
+) -> Result<Vec<ProtocolDefinition>> {
+    let definitions: Result<Vec<ProtocolDefinition>> = protocols
+        .iter()
+        .map(|p| build_protocol(p, schemas))
+        .collect();
+
+    let response_names: Vec<Name> = protocols
+        .iter()
+        .flat_map(|p| {
+            p.messages
+                .keys()
+                .map(|m| Name::new(&full_name(&p.namespace, &response_record_name(m))))
+        })
+        .collect::<std::result::Result<_, _>>()?;
+
+    schemas.retain(|s| !s.name().is_some_and(|n| response_names.contains(n)));
+
+    definitions
+}
+
+fn build_protocol(protocol: &IdlFile, schemas: &[Schema]) -> Result<ProtocolDefinition> {
+    let protocol_name = protocol
+        .protocol
+        .to_owned()
+        .ok_or_else(|| AvrogenError::generation("a protocol without name can't be generated"))?;
+
+    let mut messages = Vec::new();
+
+    for (message_name, message) in protocol.messages.iter() {
+        let response_name = Name::new(&full_name(
+            &protocol.namespace,
+            &response_record_name(message_name),
+        ))?;
+
+        let response = match schemas.iter().find(|s| s.name() == Some(&response_name)) {
+            Some(Schema::Record(record)) => record.fields[0].schema.to_owned(),
+            _ => {
+                return Err(AvrogenError::generation(format!("the response of the message {message_name} of the protocol {protocol_name} is not found")))
+            }
+        };
+
+        let errors: Result<Vec<Name>> = message
+            .get("errors")
+            .and_then(Value::as_array)
+            .cloned()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(Value::as_str)
+            .map(|e| Ok(Name::new(&full_name(&protocol.namespace, e))?))
+            .collect();
+
+        messages.push(MessageDefinition {
+            name: message_name.to_owned(),
+            doc: message
+                .get("doc")
+                .and_then(Value::as_str)
+                .map(str::to_string),
+            request: Name::new(&full_name(
+                &protocol.namespace,
+                &request_record_name(message_name),
+            ))?,
+            response,
+            errors: errors?,
+            one_way: message
+                .get("one-way")
+                .and_then(Value::as_bool)
+                .unwrap_or(false),
+        });
+    }
+
+    Ok(ProtocolDefinition {
+        name: protocol_name,
+        namespace: protocol.namespace.to_owned(),
+        doc: protocol.doc.to_owned(),
+        messages,
+    })
+}
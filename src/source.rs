@@ -1,6 +1,7 @@
 use std::{ fs, io::Read, path::PathBuf};
 use glob::glob;
 
+use crate::error::AvrogenError;
 use crate::Result;
 
 pub struct AvroFile{
@@ -8,6 +9,19 @@ pub struct AvroFile{
     pub file_path: String,
 }
 
+const STDIN_FILE_PATH: &str = "<stdin>";
+
+impl AvroFile{
+    /// IDL files are found with their extension, the standard input is an IDL content when it's not a JSON content
+    pub fn is_idl(&self) -> bool
+    {
+        if self.file_path == STDIN_FILE_PATH {
+            return !self.content.trim_start().starts_with(['{', '[', '"']);
+        }
+        self.file_path.ends_with(".avdl")
+    }
+}
+
 pub fn read_files(source: Vec<String>)-> Result<Vec<AvroFile>>
 {
     if source.is_empty() {
@@ -19,15 +33,14 @@ pub fn read_files(source: Vec<String>)-> Result<Vec<AvroFile>>
                     .iter()
                     .map(|s|
                         glob(s.as_ref())
-                        .map_err(|e|crate::error::AvrogenError::GlobPattern(e))
+                        .map_err(crate::error::AvrogenError::GlobPattern)
                     )
                     .collect();
 
     let file_contents: Result<Vec<AvroFile>>=all_paths?
                 .into_iter()
                 .flat_map(|f| f.filter_map(glob::GlobResult::ok))
-                .map(|f| read_file(f))
-                .into_iter()
+                .map(read_file)
                 .collect();
     
     file_contents
@@ -41,14 +54,110 @@ pub fn read_files(source: Vec<String>)-> Result<Vec<AvroFile>>
 
     std::io::stdin().read_to_string(&mut stdin_content)?;
 
-    Ok(AvroFile{ content: stdin_content, file_path: "<stdin>".to_string()})
+    Ok(AvroFile{ content: stdin_content, file_path: STDIN_FILE_PATH.to_string()})
 }
 
  fn read_file(file_path: PathBuf)-> Result<AvroFile> {
 
     log::debug!("Reading file {}",file_path.display());
+
+    if file_path.extension().is_some_and(|e| e == "avro") {
+        return read_container_file(file_path);
+    }
     
-     let file_content=fs::read_to_string(file_path.to_owned())?;
+     let file_content=fs::read_to_string(&file_path)?;
 
      Ok(AvroFile{content: file_content, file_path: file_path.display().to_string()})
 }
+
+/// Object container files (*.avro) contain the writer schema in the `avro.schema` entry of their header
+fn read_container_file(file_path: PathBuf)-> Result<AvroFile> {
+
+    let file_name = file_path.display().to_string();
+    let file = fs::File::open(&file_path)
+        .map_err(|e| AvrogenError::parse(&file_name, None, e))?;
+    // The lengths of the header are checked against the size of the file, a corrupted length can't allocate more
+    let file_size = file.metadata()
+        .map_err(|e| AvrogenError::parse(&file_name, None, e))?.len();
+    let mut reader = ContainerHeader{ reader: std::io::BufReader::new(file), remaining: file_size, file_name: &file_name };
+
+    let magic = reader.read_bytes(4)?;
+    if magic != b"Obj\x01" {
+        return Err(AvrogenError::parse(&file_name, None, "the file is not an Avro object container file"));
+    }
+
+    // The header metadata is a map of bytes: blocks of entries, ended by an empty block
+    loop {
+        let mut entries_count = reader.read_long()?;
+        if entries_count == 0 {
+            break;
+        }
+        if entries_count < 0 {
+            // A negative count is followed by the block size in bytes
+            entries_count = entries_count.checked_neg().ok_or_else(|| reader.error("invalid block count"))?;
+            reader.read_long()?;
+        }
+
+        for _ in 0..entries_count {
+            let key = reader.read_string_bytes()?;
+            let value = reader.read_string_bytes()?;
+
+            if key == b"avro.schema" {
+                let content = String::from_utf8(value)
+                    .map_err(|e| reader.error(format!("invalid avro.schema entry: {e}")))?;
+
+                return Ok(AvroFile{content, file_path: file_name});
+            }
+        }
+    }
+
+    Err(AvrogenError::parse(&file_name, None, "the header doesn't contain the avro.schema entry"))
+}
+
+/// Reader of the header of a container file, which reports its errors in the file
+struct ContainerHeader<'a, R: Read> {
+    reader: R,
+    remaining: u64,
+    file_name: &'a str,
+}
+
+impl<R: Read> ContainerHeader<'_, R> {
+
+    fn error(&self, message: impl std::fmt::Display) -> AvrogenError {
+        AvrogenError::parse(self.file_name, None, message)
+    }
+
+    /// Read a long with the variable-length zig-zag encoding
+    fn read_long(&mut self) -> Result<i64> {
+        let mut value: u64 = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = self.read_bytes(1)?[0];
+            value |= u64::from(byte & 0x7F) << shift;
+            if byte & 0x80 == 0 {
+                return Ok((value >> 1) as i64 ^ -((value & 1) as i64));
+            }
+        }
+        Err(self.error("invalid long in the container header"))
+    }
+
+    /// Read bytes prefixed by their length, as the keys and the values of the metadata
+    fn read_string_bytes(&mut self) -> Result<Vec<u8>> {
+        let length = self.read_long()?;
+        let length = u64::try_from(length).map_err(|_| self.error(format!("invalid length {length} in the container header")))?;
+        self.read_bytes(length)
+    }
+
+    fn read_bytes(&mut self, length: u64) -> Result<Vec<u8>> {
+        if length > self.remaining {
+            return Err(self.error(format!("the container header is truncated: {length} bytes are expected, {} bytes remain", self.remaining)));
+        }
+        let mut bytes = Vec::new();
+        (&mut self.reader).take(length).read_to_end(&mut bytes)
+            .map_err(|e| self.error(format!("the container header can't be read: {e}")))?;
+        if bytes.len() as u64 != length {
+            return Err(self.error("the container header is truncated"));
+        }
+        self.remaining -= length;
+        Ok(bytes)
+    }
+}
@@ -1,17 +1,46 @@
+use std::io::Write;
 use std::path::PathBuf;
 
-use structured_file_writer::write_to_structured_files;
+use single_file_writer::{single_file_content, write_to_single_file};
+use structured_file_writer::{structured_files_content, write_to_structured_files};
 
 use crate::generated_schema::namespace::NamespaceInfo;
 use crate::Result;
 
+mod single_file_writer;
 mod structured_file_writer;
 
 
-pub fn write(output_folder: PathBuf,root_namespace: NamespaceInfo) -> Result<()>{
+/// Write a file per namespace in the output folder, or all the namespaces in one file of the output folder as inline modules
+///
+/// With `stdout`, the output folder isn't used: the single file is written to the standard output,
+/// or the module files one after the other, each one after a `// file: path` line.
+pub fn write(output_folder: PathBuf, single_file: Option<PathBuf>, stdout: bool, root_namespace: NamespaceInfo) -> Result<()>{
     
-    // Currently only one writer exist
-    write_to_structured_files(output_folder, root_namespace)?;
+    match (stdout, single_file) {
+        (true, Some(_)) => write_to_stdout(&single_file_content(&root_namespace)?)?,
+        (true, None) => {
+            let mut files = Vec::new();
+            structured_files_content(PathBuf::new(), &root_namespace, &mut files)?;
+
+            let content: String = files
+            .iter()
+            .map(|(file_path, content)| format!("// file: {}\r\n{content}", file_path.display()))
+            .collect();
+
+            write_to_stdout(&content)?;
+        }
+        (false, Some(file_name)) => write_to_single_file(output_folder.join(file_name), root_namespace)?,
+        (false, None) => write_to_structured_files(output_folder, root_namespace)?,
+    }
     
+    Ok(())
+}
+
+fn write_to_stdout(content: &str) -> Result<()>{
+    let mut stdout = std::io::stdout().lock();
+    stdout.write_all(content.as_bytes())?;
+    stdout.flush()?;
+
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,57 @@
+use itertools::Itertools;
+use log::debug;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::PathBuf;
+
+use crate::generated_schema::namespace::NamespaceInfo;
+use crate::Result;
+
+/*
+Write all the namespaces in one file. Each namespace is an inline module
+which contains its sub modules and its class contents.
+*/
+pub fn write_to_single_file(file_path: PathBuf, namespace: NamespaceInfo) -> Result<()> {
+    if let Some(parent_folder) = file_path.parent() {
+        if !parent_folder.as_os_str().is_empty() && !parent_folder.exists() {
+            fs::create_dir_all(parent_folder)?;
+
+            debug!("Folder created: {}", parent_folder.display());
+        }
+    }
+
+    debug!("Will create file: {}", file_path.display());
+
+    let mut file = File::options()
+        .create(true)
+        .write(true)
+        .truncate(true)
+        .open(file_path)?;
+
+    file.write_all(single_file_content(&namespace)?.as_bytes())?;
+
+    Ok(())
+}
+
+/// Content of the namespace with its sub namespaces as inline modules, the root namespace isn't a module
+pub fn single_file_content(namespace: &NamespaceInfo) -> Result<String> {
+    let mut content = String::new();
+
+    for (_, child) in namespace.children.iter().sorted_by_key(|p| p.0) {
+        if has_content(child) {
+            content.push_str(&format!("pub mod {} {{\r\n", child.name.sanitized_name));
+            content.push_str(&single_file_content(child)?);
+            content.push_str("}\r\n\r\n");
+        }
+    }
+
+    for (_, generated_type) in namespace.generated_types.iter().sorted_by_key(|p| p.0) {
+        content.push_str(&generated_type.produce_content()?);
+    }
+
+    Ok(content)
+}
+
+fn has_content(namespace: &NamespaceInfo) -> bool {
+    !namespace.generated_types.is_empty() || namespace.children.values().any(has_content)
+}
@@ -13,9 +13,9 @@ Return the module file name. This module can contains:
 - the class contents
 */
 fn module_filename(parent_folder: PathBuf, namespace: &NamespaceInfo) -> PathBuf {
-    return parent_folder
-        .join(namespace.name.sanitized_name.to_string())
-        .with_extension("rs");
+    parent_folder
+        .join(&namespace.name.sanitized_name)
+        .with_extension("rs")
 }
 
 pub fn write_to_structured_files(parent_folder: PathBuf, namespace: NamespaceInfo) -> Result<()> {
@@ -36,9 +36,7 @@ pub fn write_to_structured_files(parent_folder: PathBuf, namespace: NamespaceInf
     }
 
     for (_, child) in namespace.children.into_iter() {
-        let sub_folder: PathBuf = parent_folder
-            .clone()
-            .join(namespace.name.sanitized_name.to_string());
+        let sub_folder: PathBuf = parent_folder.clone().join(&namespace.name.sanitized_name);
 
         write_to_structured_files(sub_folder, child)?;
     }
@@ -46,6 +44,32 @@ pub fn write_to_structured_files(parent_folder: PathBuf, namespace: NamespaceInf
     Ok(())
 }
 
+/// Path and content of the module files, like `write_to_structured_files` would write them in the parent folder
+pub fn structured_files_content(
+    parent_folder: PathBuf,
+    namespace: &NamespaceInfo,
+    files: &mut Vec<(PathBuf, String)>,
+) -> Result<()> {
+    if namespace.children.is_empty() && namespace.generated_types.is_empty() {
+        return Ok(());
+    }
+
+    if !namespace.is_root {
+        files.push((
+            module_filename(parent_folder.to_owned(), namespace),
+            module_content(namespace)?,
+        ));
+    }
+
+    for (_, child) in namespace.children.iter().sorted_by_key(|p| p.0) {
+        let sub_folder: PathBuf = parent_folder.join(&namespace.name.sanitized_name);
+
+        structured_files_content(sub_folder, child, files)?;
+    }
+
+    Ok(())
+}
+
 fn create_current_file(namespace: &NamespaceInfo, file_path: PathBuf) -> Result<()> {
     debug!(
         "Will create file: {}",
@@ -58,21 +82,26 @@ fn create_current_file(namespace: &NamespaceInfo, file_path: PathBuf) -> Result<
         .truncate(true)
         .open(file_path)?;
 
+    file.write_all(module_content(namespace)?.as_bytes())?;
+
+    Ok(())
+}
+
+/// Content of the module file: the submodule declarations, then the class contents
+fn module_content(namespace: &NamespaceInfo) -> Result<String> {
+    let mut content = String::new();
+
     if !namespace.children.is_empty() {
-        for (_, child) in namespace.children.iter() {
-            write!(
-                file,
-                "pub mod {};\r\n",
-                child.name.sanitized_name.to_string()
-            )?;
+        for (_, child) in namespace.children.iter().sorted_by_key(|p| p.0) {
+            content.push_str(&format!("pub mod {};\r\n", child.name.sanitized_name));
         }
 
-        file.write("\r\n".as_bytes())?;
+        content.push_str("\r\n");
     }
 
-    for (_, content) in namespace.generated_types.iter().sorted_by_key(|p| p.0) {
-        file.write(content.produce_content()?.as_bytes())?;
+    for (_, generated_type) in namespace.generated_types.iter().sorted_by_key(|p| p.0) {
+        content.push_str(&generated_type.produce_content()?);
     }
 
-    Ok(())
+    Ok(content)
 }
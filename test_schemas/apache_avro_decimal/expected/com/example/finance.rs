@@ -0,0 +1,85 @@
+/// Invoice with decimal amounts
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Invoice {
+    #[serde(with = "crate::decimal::PrecisionScale::<10, 2>")]
+    pub amount: apache_avro::Decimal,
+    #[serde(with = "crate::decimal::PrecisionScale::<18, 6>")]
+    pub rate: apache_avro::Decimal,
+    #[serde(with = "crate::decimal::PrecisionScale::<5, 2>")]
+    #[serde(default = "Invoice::default_discount")]
+    pub discount: Option<apache_avro::Decimal>,
+    #[serde(with = "crate::decimal::PrecisionScale::<10, 2>")]
+    pub lines: Vec<apache_avro::Decimal>,
+    #[serde(with = "crate::decimal::PrecisionScale::<10, 2>")]
+    pub taxes: std::collections::HashMap<String, apache_avro::Decimal>,
+    pub adjustment: crate::com::example::finance::UnionDecimalString,
+}
+
+impl Invoice {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Invoice","namespace":"com.example.finance","doc":"Invoice with decimal amounts","fields":[{"name":"amount","type":{"type":"bytes","logicalType":"decimal","precision":10,"scale":2}},{"name":"rate","type":{"type":"fixed","name":"Rate","size":8,"logicalType":"decimal","precision":18,"scale":6}},{"name":"discount","type":["null",{"type":"bytes","logicalType":"decimal","precision":5,"scale":2}],"default":null},{"name":"lines","type":{"type":"array","items":{"type":"bytes","logicalType":"decimal","precision":10,"scale":2}}},{"name":"taxes","type":{"type":"map","values":{"type":"bytes","logicalType":"decimal","precision":10,"scale":2}}},{"name":"adjustment","type":[{"type":"bytes","logicalType":"decimal","precision":10,"scale":2},"string"]}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x40, 0x52, 0x18, 0x13, 0xa9, 0x59, 0x15, 0x81];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    #[inline(always)]
+    pub fn default_discount() -> Option<apache_avro::Decimal> {
+        None
+    }
+}
+
+impl Default for Invoice {
+    fn default() -> Self {
+        Self {
+            amount: apache_avro::Decimal::from([0x00]),
+            rate: apache_avro::Decimal::from([0x00]),
+            discount: Self::default_discount(),
+            lines: Default::default(),
+            taxes: Default::default(),
+            adjustment: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Invoice {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionDecimalString {
+    #[serde(alias = "0")]
+    Decimal(#[serde(with = "crate::decimal::PrecisionScale::<10, 2>")] apache_avro::Decimal),
+    #[serde(alias = "1")]
+    String(String),
+}
+
+impl Default for UnionDecimalString {
+    fn default() -> Self {
+        Self::Decimal(apache_avro::Decimal::from([0x00]))
+    }
+}
+
+impl UnionDecimalString {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"[{"type":"bytes","logicalType":"decimal","scale":2,"precision":10},"string"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionDecimalString {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
@@ -0,0 +1,3 @@
+pub mod mail;
+pub mod smtp;
+
@@ -0,0 +1,268 @@
+/// Errors of the protocol Mail: the system error or one of the declared errors
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(untagged)]
+pub enum MailProtocolError {
+    System(String),
+    MailMailError(crate::com::example::mail::MailError),
+    SmtpMailError(crate::com::example::smtp::MailError),
+}
+
+impl std::fmt::Display for MailProtocolError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl std::error::Error for MailProtocolError {}
+
+/// Protocol to send mails
+pub trait Mail {
+    /// Send a message
+    fn send(&self, request: crate::com::example::mail::SendRequest) -> Result<String, MailProtocolError>;
+    /// Relay a message to the SMTP server
+    fn relay(&self, request: crate::com::example::mail::RelayRequest) -> Result<(), MailProtocolError>;
+    fn status(&self, request: crate::com::example::mail::StatusRequest) -> Result<Option<crate::com::example::mail::UnionStringLong>, MailProtocolError>;
+    fn notify(&self, request: crate::com::example::mail::NotifyRequest);
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct MailError {
+    pub reason: String,
+}
+
+impl MailError {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"MailError","fields":[{"name":"reason","type":"string"}],"namespace":"com.example.mail"}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x0c, 0xf3, 0xd9, 0x1d, 0xa1, 0xa7, 0x3b, 0x17];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl Default for MailError {
+    fn default() -> Self {
+        Self {
+            reason: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for MailError {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Message {
+    pub to: String,
+    pub from: String,
+    pub body: String,
+}
+
+impl Message {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Message","fields":[{"name":"to","type":"string"},{"name":"from","type":"string"},{"name":"body","type":"string"}],"namespace":"com.example.mail"}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x0b, 0xbb, 0x6e, 0x22, 0xfc, 0x0e, 0xf0, 0x84];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl Default for Message {
+    fn default() -> Self {
+        Self {
+            to: Default::default(),
+            from: Default::default(),
+            body: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Message {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Parameters of the message notify
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct NotifyRequest {
+    pub message: crate::com::example::mail::Message,
+}
+
+impl NotifyRequest {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"NotifyRequest","namespace":"com.example.mail","doc":"Parameters of the message notify","fields":[{"name":"message","type":{"type":"record","name":"Message","fields":[{"name":"to","type":"string"},{"name":"from","type":"string"},{"name":"body","type":"string"}],"namespace":"com.example.mail"}}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xbd, 0x41, 0x57, 0xc7, 0x7b, 0xf9, 0x08, 0xbe];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl Default for NotifyRequest {
+    fn default() -> Self {
+        Self {
+            message: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for NotifyRequest {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Parameters of the message relay
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct RelayRequest {
+    pub message: crate::com::example::mail::Message,
+}
+
+impl RelayRequest {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"RelayRequest","namespace":"com.example.mail","doc":"Parameters of the message relay","fields":[{"name":"message","type":{"type":"record","name":"Message","fields":[{"name":"to","type":"string"},{"name":"from","type":"string"},{"name":"body","type":"string"}],"namespace":"com.example.mail"}}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x67, 0x84, 0x03, 0xde, 0x32, 0x4c, 0x25, 0xcc];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl Default for RelayRequest {
+    fn default() -> Self {
+        Self {
+            message: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for RelayRequest {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Parameters of the message send
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct SendRequest {
+    pub message: crate::com::example::mail::Message,
+}
+
+impl SendRequest {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"SendRequest","namespace":"com.example.mail","doc":"Parameters of the message send","fields":[{"name":"message","type":{"type":"record","name":"Message","fields":[{"name":"to","type":"string"},{"name":"from","type":"string"},{"name":"body","type":"string"}],"namespace":"com.example.mail"}}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xa5, 0xc0, 0x9f, 0x87, 0xac, 0x0d, 0xdb, 0x07];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl Default for SendRequest {
+    fn default() -> Self {
+        Self {
+            message: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for SendRequest {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Parameters of the message status
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct StatusRequest {
+    pub id: String,
+}
+
+impl StatusRequest {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"StatusRequest","namespace":"com.example.mail","doc":"Parameters of the message status","fields":[{"name":"id","type":"string"}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xbe, 0xcf, 0x8d, 0xfa, 0xb7, 0xab, 0x5f, 0x59];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl Default for StatusRequest {
+    fn default() -> Self {
+        Self {
+            id: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for StatusRequest {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionStringLong {
+    #[serde(alias = "0")]
+    String(String),
+    #[serde(alias = "1")]
+    Long(i64),
+}
+
+impl Default for UnionStringLong {
+    fn default() -> Self {
+        Self::String(Default::default())
+    }
+}
+
+impl UnionStringLong {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"["string","long"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionStringLong {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
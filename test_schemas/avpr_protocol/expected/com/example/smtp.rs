@@ -0,0 +1,34 @@
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct MailError {
+    pub code: i32,
+}
+
+impl MailError {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"MailError","namespace":"com.example.smtp","fields":[{"name":"code","type":"int"}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xe7, 0x7b, 0x3d, 0x9f, 0x4e, 0xa0, 0xdf, 0x19];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl Default for MailError {
+    fn default() -> Self {
+        Self {
+            code: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for MailError {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
@@ -0,0 +1,197 @@
+/// Serde glue of the decimal fields, the Avro decimals are the big-endian two's-complement bytes of their unscaled value.
+///
+/// The fields use the precision and the scale of their schema: `#[serde(with = "PrecisionScale::<10, 2>")]`
+pub struct PrecisionScale<const PRECISION: u32, const SCALE: u32>;
+
+impl<const PRECISION: u32, const SCALE: u32> PrecisionScale<PRECISION, SCALE> {
+    pub fn serialize<T: AvroDecimal, S: serde::Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize_decimal(PRECISION, SCALE, serializer)
+    }
+
+    pub fn deserialize<'de, T: AvroDecimal, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_decimal(SCALE, deserializer)
+    }
+}
+
+/// A decimal, or a container of decimals, serialized with the precision and the scale of its schema
+pub trait AvroDecimal: Sized {
+    fn serialize_decimal<S: serde::Serializer>(&self, precision: u32, scale: u32, serializer: S) -> Result<S::Ok, S::Error>;
+
+    fn deserialize_decimal<'de, D: serde::Deserializer<'de>>(scale: u32, deserializer: D) -> Result<Self, D::Error>;
+}
+
+impl AvroDecimal for bigdecimal::BigDecimal {
+    fn serialize_decimal<S: serde::Serializer>(&self, precision: u32, scale: u32, serializer: S) -> Result<S::Ok, S::Error> {
+        let value = self.with_scale(scale.into());
+        if value != *self {
+            return Err(serde::ser::Error::custom(format!("The decimal {self} has more than {scale} digits after the decimal point")));
+        }
+        if value.digits() > precision.into() {
+            return Err(serde::ser::Error::custom(format!("The decimal {self} has more than {precision} digits")));
+        }
+        let (unscaled, _) = value.into_bigint_and_exponent();
+        serializer.serialize_bytes(&precision_bytes(&unscaled.to_signed_bytes_be(), precision))
+    }
+
+    fn deserialize_decimal<'de, D: serde::Deserializer<'de>>(scale: u32, deserializer: D) -> Result<Self, D::Error> {
+        let bytes = deserializer.deserialize_byte_buf(BytesVisitor)?;
+        let unscaled = bigdecimal::num_bigint::BigInt::from_signed_bytes_be(&bytes);
+        Ok(bigdecimal::BigDecimal::new(unscaled, scale.into()))
+    }
+}
+
+impl<T: AvroDecimal> AvroDecimal for Option<T> {
+    fn serialize_decimal<S: serde::Serializer>(&self, precision: u32, scale: u32, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Some(value) => serializer.serialize_some(&ScaledDecimal(value, precision, scale)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    fn deserialize_decimal<'de, D: serde::Deserializer<'de>>(scale: u32, deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_option(DecimalVisitor::<Self>(scale, std::marker::PhantomData))
+    }
+}
+
+impl<T: AvroDecimal> AvroDecimal for Vec<T> {
+    fn serialize_decimal<S: serde::Serializer>(&self, precision: u32, scale: u32, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter().map(|value| ScaledDecimal(value, precision, scale)))
+    }
+
+    fn deserialize_decimal<'de, D: serde::Deserializer<'de>>(scale: u32, deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(DecimalVisitor::<Self>(scale, std::marker::PhantomData))
+    }
+}
+
+impl<T: AvroDecimal> AvroDecimal for std::collections::HashMap<String, T> {
+    fn serialize_decimal<S: serde::Serializer>(&self, precision: u32, scale: u32, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter().map(|(key, value)| (key, ScaledDecimal(value, precision, scale))))
+    }
+
+    fn deserialize_decimal<'de, D: serde::Deserializer<'de>>(scale: u32, deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(DecimalVisitor::<Self>(scale, std::marker::PhantomData))
+    }
+}
+
+/// Sign-extend or trim the bytes of an unscaled value to the size needed by the precision, apache_avro checks this size
+fn precision_bytes(bytes: &[u8], precision: u32) -> Vec<u8> {
+    let mut size = 1;
+    while (2.0_f64.powi(8 * size - 1) - 1.0).log10().floor() < f64::from(precision) {
+        size += 1;
+    }
+    let size = size as usize;
+    let sign_byte = if bytes.first().is_some_and(|b| *b >= 0x80) { 0xff } else { 0x00 };
+    let mut start = 0;
+    while bytes.len() - start > size && bytes[start] == sign_byte && (bytes[start + 1] >= 0x80) == (sign_byte == 0xff) {
+        start += 1;
+    }
+    let mut precision_bytes = vec![sign_byte; size.saturating_sub(bytes.len() - start)];
+    precision_bytes.extend_from_slice(&bytes[start..]);
+    precision_bytes
+}
+
+/// Serialize a decimal inside a container
+struct ScaledDecimal<'a, T>(&'a T, u32, u32);
+
+impl<T: AvroDecimal> serde::Serialize for ScaledDecimal<'_, T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize_decimal(self.1, self.2, serializer)
+    }
+}
+
+impl<'de, T: AvroDecimal> serde::de::DeserializeSeed<'de> for DecimalVisitor<T> {
+    type Value = T;
+
+    fn deserialize<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_decimal(self.0, deserializer)
+    }
+}
+
+/// Deserialize the decimals of a container with the scale
+struct DecimalVisitor<T>(u32, std::marker::PhantomData<T>);
+
+impl<'de, T: AvroDecimal> serde::de::Visitor<'de> for DecimalVisitor<Option<T>> {
+    type Value = Option<T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("an optional decimal")
+    }
+
+    fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        T::deserialize_decimal(self.0, deserializer).map(Some)
+    }
+}
+
+impl<'de, T: AvroDecimal> serde::de::Visitor<'de> for DecimalVisitor<Vec<T>> {
+    type Value = Vec<T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("an array of decimals")
+    }
+
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut values = Vec::new();
+        while let Some(value) = seq.next_element_seed(DecimalVisitor::<T>(self.0, std::marker::PhantomData))? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+impl<'de, T: AvroDecimal> serde::de::Visitor<'de> for DecimalVisitor<std::collections::HashMap<String, T>> {
+    type Value = std::collections::HashMap<String, T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a map of decimals")
+    }
+
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut values = std::collections::HashMap::new();
+        while let Some(key) = map.next_key::<String>()? {
+            values.insert(key, map.next_value_seed(DecimalVisitor::<T>(self.0, std::marker::PhantomData))?);
+        }
+        Ok(values)
+    }
+}
+
+/// Read the bytes of a decimal
+struct BytesVisitor;
+
+impl<'de> serde::de::Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("the bytes of a decimal")
+    }
+
+    fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+        Ok(bytes.to_vec())
+    }
+
+    fn visit_byte_buf<E: serde::de::Error>(self, bytes: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(bytes)
+    }
+}
+
+/// Replace the decimals of a value read by apache_avro with their bytes, the serde deserializer of apache_avro doesn't read `Value::Decimal`
+pub fn decimals_as_bytes(value: apache_avro::types::Value) -> apache_avro::types::Value {
+    use apache_avro::types::Value;
+
+    match value {
+        Value::Decimal(decimal) => Value::Bytes(Vec::<u8>::try_from(decimal).unwrap_or_default()),
+        Value::Union(index, value) => Value::Union(index, Box::new(decimals_as_bytes(*value))),
+        Value::Array(values) => Value::Array(values.into_iter().map(decimals_as_bytes).collect()),
+        Value::Map(values) => Value::Map(values.into_iter().map(|(key, value)| (key, decimals_as_bytes(value))).collect()),
+        Value::Record(fields) => Value::Record(fields.into_iter().map(|(name, value)| (name, decimals_as_bytes(value))).collect()),
+        value => value,
+    }
+}
+
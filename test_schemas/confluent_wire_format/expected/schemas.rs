@@ -0,0 +1,3 @@
+pub mod com;
+pub mod confluent;
+
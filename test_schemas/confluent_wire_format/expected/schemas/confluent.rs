@@ -0,0 +1,40 @@
+/// Find the writer schemas registered in the Confluent Schema Registry from their ids
+pub trait SchemaResolver {
+    /// Return the schema registered with the id, None when the id is unknown
+    fn resolve_schema(&self, schema_id: u32) -> Option<apache_avro::Schema>;
+}
+
+impl SchemaResolver for std::collections::HashMap<u32, apache_avro::Schema> {
+    fn resolve_schema(&self, schema_id: u32) -> Option<apache_avro::Schema> {
+        self.get(&schema_id).cloned()
+    }
+}
+
+/// Errors returned when a record is decoded from the Confluent wire format
+#[derive(Debug)]
+pub enum ConfluentError {
+    /// The bytes don't start with the magic byte and the schema id
+    InvalidHeader,
+    /// The resolver doesn't know the schema id
+    UnknownSchemaId(u32),
+    Avro(apache_avro::Error),
+}
+
+impl From<apache_avro::Error> for ConfluentError {
+    fn from(error: apache_avro::Error) -> Self {
+        Self::Avro(error)
+    }
+}
+
+impl std::fmt::Display for ConfluentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidHeader => write!(f, "Invalid Confluent wire format header"),
+            Self::UnknownSchemaId(schema_id) => write!(f, "Unknown schema id {schema_id}"),
+            Self::Avro(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfluentError {}
+
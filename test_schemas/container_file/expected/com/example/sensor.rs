@@ -0,0 +1,93 @@
+/// A measure sent by a sensor
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Measure {
+    pub sensor: String,
+    pub value: f64,
+    pub unit: crate::com::example::sensor::Unit,
+}
+
+impl Measure {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","namespace":"com.example.sensor","name":"Measure","doc":"A measure sent by a sensor","fields":[{"name":"sensor","type":"string"},{"name":"value","type":"double"},{"name":"unit","type":{"type":"enum","namespace":"com.example.sensor","name":"Unit","symbols":["CELSIUS","FAHRENHEIT"]}}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xc7, 0x04, 0x9c, 0x76, 0x5b, 0xdf, 0x2c, 0xf8];
+    pub const FINGERPRINT_MD5: [u8; 16] = [0xa2, 0xa2, 0x84, 0x97, 0xcb, 0x69, 0x0a, 0xd5, 0x51, 0x64, 0x6a, 0xb8, 0x82, 0x01, 0x3a, 0x16];
+    pub const FINGERPRINT_SHA256: [u8; 32] = [0x60, 0x71, 0x81, 0xa7, 0x19, 0xe5, 0x14, 0xd5, 0xfc, 0x27, 0xe7, 0x94, 0x7d, 0x34, 0x5b, 0xe4, 0x1d, 0x16, 0x79, 0xd0, 0xbf, 0xb8, 0xce, 0x21, 0xdb, 0xb3, 0x74, 0xd1, 0x0b, 0x1b, 0x0f, 0x6a];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    /// Header of the single-object encoding: the C3 01 marker and the Rabin fingerprint
+    pub const SINGLE_OBJECT_HEADER: [u8; 10] = [0xc3, 0x01, 0xc7, 0x04, 0x9c, 0x76, 0x5b, 0xdf, 0x2c, 0xf8];
+
+    /// Encode the record with the single-object encoding: the header then the binary encoded record
+    pub fn to_single_object(&self) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = apache_avro::to_value(self)?.resolve(Self::schema())?;
+        let mut bytes = Self::SINGLE_OBJECT_HEADER.to_vec();
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the single-object encoding, a different header returns a `SingleObjectHeaderMismatch` error
+    pub fn from_single_object(bytes: &[u8]) -> Result<Self, apache_avro::Error> {
+        let header: [u8; 10] = bytes.get(..10).and_then(|h| h.try_into().ok()).unwrap_or_default();
+        if header != Self::SINGLE_OBJECT_HEADER {
+            return Err(apache_avro::Error::SingleObjectHeaderMismatch(Self::SINGLE_OBJECT_HEADER, header));
+        }
+        let value = apache_avro::from_avro_datum(Self::schema(), &mut &bytes[10..], None)?;
+        apache_avro::from_value(&value)
+    }
+}
+
+impl Default for Measure {
+    fn default() -> Self {
+        Self {
+            sensor: Default::default(),
+            value: Default::default(),
+            unit: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Measure {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize, Default)]
+pub enum Unit {
+    #[default]
+    #[serde(rename = "CELSIUS")]
+    Celsius,
+    #[serde(rename = "FAHRENHEIT")]
+    Fahrenheit,
+}
+
+impl Unit {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"enum","namespace":"com.example.sensor","name":"Unit","symbols":["CELSIUS","FAHRENHEIT"]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xe3, 0xb4, 0x8c, 0x90, 0xdf, 0x54, 0x74, 0x8f];
+    pub const FINGERPRINT_MD5: [u8; 16] = [0x66, 0x2e, 0x65, 0xca, 0xf7, 0x10, 0x58, 0x79, 0xfc, 0xb6, 0x1e, 0xd6, 0x18, 0xd1, 0xdd, 0xf1];
+    pub const FINGERPRINT_SHA256: [u8; 32] = [0xb3, 0xdf, 0x03, 0xbe, 0x69, 0xcb, 0x75, 0xdc, 0xab, 0x5a, 0xfe, 0xfa, 0x63, 0x2c, 0x55, 0xd3, 0xe7, 0xb1, 0x63, 0x90, 0xc1, 0x24, 0x09, 0x21, 0x02, 0x92, 0x87, 0x7b, 0xdc, 0x7d, 0xf5, 0x2b];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl apache_avro::AvroSchema for Unit {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
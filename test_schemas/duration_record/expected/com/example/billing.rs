@@ -0,0 +1,81 @@
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Subscription {
+    pub plan: String,
+    pub period: crate::duration::Duration,
+    #[serde(default = "Subscription::default_trial")]
+    pub trial: Option<crate::duration::Duration>,
+    #[serde(default = "Subscription::default_grace_periods")]
+    pub grace_periods: Vec<crate::duration::Duration>,
+    #[serde(default = "Subscription::default_reminders")]
+    pub reminders: std::collections::HashMap<String, crate::duration::Duration>,
+}
+
+impl Subscription {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Subscription","namespace":"com.example.billing","fields":[{"name":"plan","type":"string"},{"name":"period","type":{"type":"fixed","name":"Period","size":12,"logicalType":"duration"}},{"name":"trial","type":["null",{"type":"fixed","name":"Trial","size":12,"logicalType":"duration"}],"default":null},{"name":"grace_periods","type":{"type":"array","items":{"type":"fixed","name":"Grace","size":12,"logicalType":"duration"}},"default":[]},{"name":"reminders","type":{"type":"map","values":{"type":"fixed","name":"Reminder","size":12,"logicalType":"duration"}},"default":{}}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x4a, 0x07, 0xda, 0x76, 0xbc, 0x29, 0x47, 0xb0];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    /// Header of the single-object encoding: the C3 01 marker and the Rabin fingerprint
+    pub const SINGLE_OBJECT_HEADER: [u8; 10] = [0xc3, 0x01, 0x4a, 0x07, 0xda, 0x76, 0xbc, 0x29, 0x47, 0xb0];
+
+    /// Encode the record with the single-object encoding: the header then the binary encoded record
+    pub fn to_single_object(&self) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = crate::values::resolve(apache_avro::to_value(self)?, Self::schema())?;
+        let mut bytes = Self::SINGLE_OBJECT_HEADER.to_vec();
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the single-object encoding, a different header returns a `SingleObjectHeaderMismatch` error
+    pub fn from_single_object(bytes: &[u8]) -> Result<Self, apache_avro::Error> {
+        let header: [u8; 10] = bytes.get(..10).and_then(|h| h.try_into().ok()).unwrap_or_default();
+        if header != Self::SINGLE_OBJECT_HEADER {
+            return Err(apache_avro::Error::SingleObjectHeaderMismatch(Self::SINGLE_OBJECT_HEADER, header));
+        }
+        let value = apache_avro::from_avro_datum(Self::schema(), &mut &bytes[10..], None)?;
+        apache_avro::from_value(&crate::duration::durations_as_fixed(value))
+    }
+
+    #[inline(always)]
+    pub fn default_trial() -> Option<crate::duration::Duration> {
+        None
+    }
+
+    #[inline(always)]
+    pub fn default_grace_periods() -> Vec<crate::duration::Duration> {
+        Vec::new()
+    }
+
+    #[inline(always)]
+    pub fn default_reminders() -> std::collections::HashMap<String, crate::duration::Duration> {
+        std::collections::HashMap::new()
+    }
+}
+
+impl Default for Subscription {
+    fn default() -> Self {
+        Self {
+            plan: Default::default(),
+            period: Default::default(),
+            trial: Self::default_trial(),
+            grace_periods: Self::default_grace_periods(),
+            reminders: Self::default_reminders(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Subscription {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
@@ -0,0 +1,44 @@
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Renewal {
+    pub period: crate::duration::Duration,
+    pub next_period: crate::duration::Duration,
+    #[serde(default = "Renewal::default_notice")]
+    pub notice: Option<crate::duration::Duration>,
+}
+
+impl Renewal {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Renewal","namespace":"com.example.billing","fields":[{"name":"period","type":{"type":"fixed","name":"Period","size":12,"logicalType":"duration"}},{"name":"next_period","type":{"type":"fixed","name":"Period","namespace":"com.example.billing","size":12,"logicalType":"duration"}},{"name":"notice","type":["null",{"type":"fixed","name":"Period","namespace":"com.example.billing","size":12,"logicalType":"duration"}],"default":null}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x9b, 0xef, 0x90, 0xa1, 0xf0, 0x99, 0x0a, 0xc9];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    #[inline(always)]
+    pub fn default_notice() -> Option<crate::duration::Duration> {
+        None
+    }
+}
+
+impl Default for Renewal {
+    fn default() -> Self {
+        Self {
+            period: Default::default(),
+            next_period: Default::default(),
+            notice: Self::default_notice(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Renewal {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
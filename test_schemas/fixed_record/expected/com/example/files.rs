@@ -0,0 +1,176 @@
+/// A downloaded file and its checksums
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Download {
+    pub path: String,
+    #[serde(default = "Download::default_checksum")]
+    pub checksum: crate::com::example::files::Md5,
+    pub signature: crate::com::example::crypto::Signature,
+    #[serde(default = "Download::default_previous_checksum")]
+    pub previous_checksum: Option<crate::com::example::files::Md5>,
+    #[serde(default = "Download::default_chunk_checksums")]
+    pub chunk_checksums: Vec<crate::com::example::files::Md5>,
+    #[serde(default = "Download::default_mirror_checksums")]
+    pub mirror_checksums: std::collections::HashMap<String, crate::com::example::files::Md5>,
+}
+
+impl Download {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Download","namespace":"com.example.files","doc":"A downloaded file and its checksums","fields":[{"name":"path","type":"string"},{"name":"checksum","type":{"type":"fixed","name":"Md5","size":16,"doc":"MD5 digest of the content"},"default":"\u0000\u0001\u0002\u0003\u0004\u0005\u0006\u0007\b\t\n\u000b\f\r\u000e\u000f"},{"name":"signature","type":{"type":"fixed","name":"Signature","namespace":"com.example.crypto","size":64}},{"name":"previous_checksum","type":["null","Md5"],"default":null},{"name":"chunk_checksums","type":{"type":"array","items":"Md5"},"default":[]},{"name":"mirror_checksums","type":{"type":"map","values":"Md5"},"default":{}}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x8d, 0x0f, 0xd6, 0x47, 0x4f, 0x5a, 0xbb, 0xcb];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    /// Header of the single-object encoding: the C3 01 marker and the Rabin fingerprint
+    pub const SINGLE_OBJECT_HEADER: [u8; 10] = [0xc3, 0x01, 0x8d, 0x0f, 0xd6, 0x47, 0x4f, 0x5a, 0xbb, 0xcb];
+
+    /// Encode the record with the single-object encoding: the header then the binary encoded record
+    pub fn to_single_object(&self) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = crate::values::resolve(apache_avro::to_value(self)?, Self::schema())?;
+        let mut bytes = Self::SINGLE_OBJECT_HEADER.to_vec();
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the single-object encoding, a different header returns a `SingleObjectHeaderMismatch` error
+    pub fn from_single_object(bytes: &[u8]) -> Result<Self, apache_avro::Error> {
+        let header: [u8; 10] = bytes.get(..10).and_then(|h| h.try_into().ok()).unwrap_or_default();
+        if header != Self::SINGLE_OBJECT_HEADER {
+            return Err(apache_avro::Error::SingleObjectHeaderMismatch(Self::SINGLE_OBJECT_HEADER, header));
+        }
+        let value = apache_avro::from_avro_datum(Self::schema(), &mut &bytes[10..], None)?;
+        apache_avro::from_value(&value)
+    }
+
+    #[inline(always)]
+    pub fn default_checksum() -> crate::com::example::files::Md5 {
+        crate::com::example::files::Md5([0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f])
+    }
+
+    #[inline(always)]
+    pub fn default_previous_checksum() -> Option<crate::com::example::files::Md5> {
+        None
+    }
+
+    #[inline(always)]
+    pub fn default_chunk_checksums() -> Vec<crate::com::example::files::Md5> {
+        Vec::new()
+    }
+
+    #[inline(always)]
+    pub fn default_mirror_checksums() -> std::collections::HashMap<String, crate::com::example::files::Md5> {
+        std::collections::HashMap::new()
+    }
+}
+
+impl Default for Download {
+    fn default() -> Self {
+        Self {
+            path: Default::default(),
+            checksum: Self::default_checksum(),
+            signature: Default::default(),
+            previous_checksum: Self::default_previous_checksum(),
+            chunk_checksums: Self::default_chunk_checksums(),
+            mirror_checksums: Self::default_mirror_checksums(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Download {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// MD5 digest of the content
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub struct Md5(pub [u8; 16]);
+
+impl Md5 {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"fixed","name":"Md5","namespace":"com.example.files","size":16,"doc":"MD5 digest of the content"}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x0b, 0xf3, 0x1d, 0x6e, 0x48, 0x1e, 0x9e, 0xe9];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl apache_avro::AvroSchema for Md5 {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+impl AsRef<[u8]> for Md5 {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<[u8; 16]> for Md5 {
+    fn from(bytes: [u8; 16]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl TryFrom<&[u8]> for Md5 {
+    type Error = std::array::TryFromSliceError;
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        bytes.try_into().map(Self)
+    }
+}
+
+impl std::fmt::Display for Md5 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+impl serde::Serialize for Md5 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.0)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Md5 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FixedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for FixedVisitor {
+            type Value = Md5;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("16 bytes")
+            }
+
+            fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<Md5, E> {
+                Md5::try_from(bytes).map_err(|_| E::invalid_length(bytes.len(), &self))
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Md5, A::Error> {
+                let mut bytes = [0; 16];
+                for (index, byte) in bytes.iter_mut().enumerate() {
+                    *byte = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(index, &self))?;
+                }
+                if seq.next_element::<u8>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(17, &self));
+                }
+                Ok(Md5(bytes))
+            }
+        }
+
+        deserializer.deserialize_bytes(FixedVisitor)
+    }
+}
+
@@ -0,0 +1,61 @@
+/// The fixed are serialized as bytes, which the schema resolution of `apache_avro` doesn't accept for a fixed:
+/// the bytes of the fixed are converted to fixed values, guided by the schema, before the resolution
+pub fn bytes_as_fixed(value: apache_avro::types::Value, schema: &apache_avro::Schema) -> apache_avro::types::Value {
+    match apache_avro::schema::ResolvedSchema::try_from(schema) {
+        Ok(resolved) => fixed_values(value, schema, resolved.get_names()),
+        Err(_) => value,
+    }
+}
+
+fn fixed_values(value: apache_avro::types::Value, schema: &apache_avro::Schema, names: &apache_avro::schema::NamesRef) -> apache_avro::types::Value {
+    use apache_avro::types::Value;
+    use apache_avro::Schema;
+
+    match (value, schema) {
+        (value, Schema::Ref { name }) => match names.get(name) {
+            Some(definition) => fixed_values(value, definition, names),
+            None => value,
+        },
+        (Value::Bytes(bytes), Schema::Fixed(fixed_schema)) => Value::Fixed(fixed_schema.size, bytes),
+        (Value::Bytes(bytes), Schema::Duration) => Value::Fixed(12, bytes),
+        (Value::Record(fields), Schema::Record(record_schema)) => Value::Record(
+            fields
+                .into_iter()
+                .map(|(name, value)| match record_schema.lookup.get(&name) {
+                    Some(position) => {
+                        let value = fixed_values(value, &record_schema.fields[*position].schema, names);
+                        (name, value)
+                    }
+                    None => (name, value),
+                })
+                .collect(),
+        ),
+        (Value::Array(items), Schema::Array(items_schema)) => Value::Array(items.into_iter().map(|item| fixed_values(item, items_schema, names)).collect()),
+        (Value::Map(values), Schema::Map(values_schema)) => Value::Map(values.into_iter().map(|(key, value)| (key, fixed_values(value, values_schema, names))).collect()),
+        (Value::Union(index, value), Schema::Union(_)) => {
+            Value::Union(index, Box::new(fixed_values(*value, schema, names)))
+        }
+        // The value of an union is converted with the first type which accepts it
+        (value, Schema::Union(union_schema)) => {
+            let variant = union_schema.variants().iter().find(|variant| {
+                let variant = match *variant {
+                    Schema::Ref { name } => names.get(name).copied(),
+                    variant => Some(variant),
+                };
+                match (&value, variant) {
+                    (Value::Bytes(bytes), Some(Schema::Fixed(fixed_schema))) => bytes.len() == fixed_schema.size,
+                    (Value::Bytes(bytes), Some(Schema::Duration)) => bytes.len() == 12,
+                    (Value::Record(fields), Some(Schema::Record(record_schema))) => fields.iter().all(|(name, _)| record_schema.lookup.contains_key(name)),
+                    (Value::Bytes(_), Some(Schema::Bytes)) | (Value::Array(_), Some(Schema::Array(_))) | (Value::Map(_), Some(Schema::Map(_))) => true,
+                    _ => false,
+                }
+            });
+            match variant {
+                Some(variant) => fixed_values(value, variant, names),
+                None => value,
+            }
+        }
+        (value, _) => value,
+    }
+}
+
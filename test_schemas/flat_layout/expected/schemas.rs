@@ -0,0 +1,193 @@
+pub mod values;
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Machine {
+    pub id: i64,
+    #[serde(default = "Machine::default_status")]
+    pub status: crate::schemas::MachinesStatus,
+    #[serde(default = "Machine::default_reading")]
+    pub reading: Option<crate::schemas::UnionLongDouble>,
+}
+
+impl Machine {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Machine","namespace":"com.mysite.machines","fields":[{"name":"id","type":"long"},{"name":"status","type":{"type":"enum","name":"Status","symbols":["RUNNING","STOPPED"]},"default":"STOPPED"},{"name":"reading","type":["null","long","double"],"default":null}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xf5, 0x98, 0x2c, 0x4a, 0x72, 0xf2, 0x44, 0xb1];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    #[inline(always)]
+    pub fn default_status() -> crate::schemas::MachinesStatus {
+        crate::schemas::MachinesStatus::Stopped
+    }
+
+    #[inline(always)]
+    pub fn default_reading() -> Option<crate::schemas::UnionLongDouble> {
+        None
+    }
+}
+
+impl Default for Machine {
+    fn default() -> Self {
+        Self {
+            id: Default::default(),
+            status: Self::default_status(),
+            reading: Self::default_reading(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Machine {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize, Default)]
+#[serde(rename = "Status")]
+pub enum MachinesStatus {
+    #[default]
+    #[serde(rename = "RUNNING")]
+    Running,
+    #[serde(rename = "STOPPED")]
+    Stopped,
+}
+
+impl MachinesStatus {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"enum","name":"Status","namespace":"com.mysite.machines","symbols":["RUNNING","STOPPED"]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x06, 0xd6, 0x8c, 0x50, 0x91, 0xb5, 0x39, 0x3e];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl apache_avro::AvroSchema for MachinesStatus {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Order {
+    pub id: i64,
+    #[serde(default = "Order::default_status")]
+    pub status: crate::schemas::ShopStatus,
+    pub machine: crate::schemas::Machine,
+    #[serde(default = "Order::default_amount")]
+    pub amount: Option<crate::schemas::UnionLongDouble>,
+}
+
+impl Order {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Order","namespace":"com.mysite.shop","fields":[{"name":"id","type":"long"},{"name":"status","type":{"type":"enum","name":"Status","symbols":["OPEN","SHIPPED"]},"default":"OPEN"},{"name":"machine","type":{"type":"record","name":"Machine","namespace":"com.mysite.machines","fields":[{"name":"id","type":"long"},{"name":"status","type":{"type":"enum","name":"Status","symbols":["RUNNING","STOPPED"]},"default":"STOPPED"},{"name":"reading","type":["null","long","double"],"default":null}]}},{"name":"amount","type":["null","long","double"],"default":null}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xd4, 0xbb, 0x06, 0x46, 0x85, 0xe6, 0x9a, 0xd2];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    #[inline(always)]
+    pub fn default_status() -> crate::schemas::ShopStatus {
+        crate::schemas::ShopStatus::Open
+    }
+
+    #[inline(always)]
+    pub fn default_amount() -> Option<crate::schemas::UnionLongDouble> {
+        None
+    }
+}
+
+impl Default for Order {
+    fn default() -> Self {
+        Self {
+            id: Default::default(),
+            status: Self::default_status(),
+            machine: Default::default(),
+            amount: Self::default_amount(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Order {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize, Default)]
+#[serde(rename = "Status")]
+pub enum ShopStatus {
+    #[default]
+    #[serde(rename = "OPEN")]
+    Open,
+    #[serde(rename = "SHIPPED")]
+    Shipped,
+}
+
+impl ShopStatus {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"enum","name":"Status","namespace":"com.mysite.shop","symbols":["OPEN","SHIPPED"]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xd7, 0xd0, 0xdb, 0xc4, 0x66, 0xb4, 0xf6, 0xc9];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl apache_avro::AvroSchema for ShopStatus {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionLongDouble {
+    #[serde(alias = "0")]
+    Long(i64),
+    #[serde(alias = "1")]
+    Double(f64),
+}
+
+impl Default for UnionLongDouble {
+    fn default() -> Self {
+        Self::Long(Default::default())
+    }
+}
+
+impl UnionLongDouble {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"["long","double"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionLongDouble {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
@@ -0,0 +1,316 @@
+pub mod values {
+/// Resolve a value serialized by serde with the schema, like `Value::resolve`: the bytes of the fixed become fixed values,
+/// and the variants of the generated union enums are written with their own type of the union, the schema resolution
+/// of `apache_avro` finds the type of an union value from the value and can choose another one
+pub fn resolve(value: apache_avro::types::Value, schema: &apache_avro::Schema) -> Result<apache_avro::types::Value, apache_avro::Error> {
+    let resolved = apache_avro::schema::ResolvedSchema::try_from(schema)?;
+    resolve_value(value, schema, resolved.get_names())
+}
+
+fn resolve_value(value: apache_avro::types::Value, schema: &apache_avro::Schema, names: &apache_avro::schema::NamesRef) -> Result<apache_avro::types::Value, apache_avro::Error> {
+    use apache_avro::types::Value;
+    use apache_avro::Schema;
+
+    match (value, schema) {
+        (value, Schema::Ref { name }) => match names.get(name) {
+            Some(definition) => resolve_value(value, definition, names),
+            None => value.resolve(schema),
+        },
+        (Value::Record(fields), Schema::Record(record_schema)) => {
+            let mut resolved_fields = Vec::with_capacity(fields.len());
+            for (name, value) in fields {
+                let value = match record_schema.lookup.get(&name) {
+                    Some(position) => resolve_value(value, &record_schema.fields[*position].schema, names)?,
+                    None => value,
+                };
+                resolved_fields.push((name, value));
+            }
+            Ok(Value::Record(resolved_fields))
+        }
+        (Value::Array(items), Schema::Array(items_schema)) => items.into_iter().map(|item| resolve_value(item, items_schema, names)).collect::<Result<_, _>>().map(Value::Array),
+        (Value::Map(values), Schema::Map(values_schema)) => values.into_iter().map(|(key, value)| resolve_value(value, values_schema, names).map(|value| (key, value))).collect::<Result<_, _>>().map(Value::Map),
+        (value, Schema::Union(union_schema)) => {
+            // The options are serialized as union values, without the index of their type in the schema
+            let value = match value {
+                Value::Union(_, value) => *value,
+                value => value,
+            };
+            let types: Vec<usize> = (0..union_schema.variants().len()).filter(|index| !matches!(union_schema.variants()[*index], Schema::Null)).collect();
+            let (index, value) = match (value, types.as_slice()) {
+                (Value::Null, _) => (union_schema.variants().iter().position(|variant| matches!(variant, Schema::Null)), Value::Null),
+                (value, [index]) => (Some(*index), value),
+                // A generated enum serializes its variant with the index of its type among the types of the union without null
+                (Value::Record(fields), _) => match <[(String, Value); 2]>::try_from(fields) {
+                    Ok([(_, Value::Enum(variant, _)), (_, Value::Union(_, value))]) => (types.get(variant as usize).copied(), *value),
+                    _ => (None, Value::Null),
+                },
+                _ => (None, Value::Null),
+            };
+            let index = index.ok_or(apache_avro::Error::FindUnionVariant)?;
+            let value = resolve_value(value, &union_schema.variants()[index], names)?;
+            Ok(Value::Union(index as u32, Box::new(value)))
+        }
+        (Value::Bytes(bytes), Schema::Fixed(fixed_schema)) => Value::Fixed(fixed_schema.size, bytes).resolve(schema),
+        (Value::Bytes(bytes), Schema::Duration) => Value::Fixed(12, bytes).resolve(schema),
+        (value, schema) => value.resolve(schema),
+    }
+}
+
+/// Replace the union values of the generated union enums with the representation of their variant read by the serde
+/// deserializer of `apache_avro`: a record with the index of the type among the types of the union without null, then the value
+pub fn union_variants(value: apache_avro::types::Value, schema: &apache_avro::Schema) -> apache_avro::types::Value {
+    match apache_avro::schema::ResolvedSchema::try_from(schema) {
+        Ok(resolved) => variant_values(value, schema, resolved.get_names()),
+        Err(_) => value,
+    }
+}
+
+fn variant_values(value: apache_avro::types::Value, schema: &apache_avro::Schema, names: &apache_avro::schema::NamesRef) -> apache_avro::types::Value {
+    use apache_avro::types::Value;
+    use apache_avro::Schema;
+
+    match (value, schema) {
+        (value, Schema::Ref { name }) => match names.get(name) {
+            Some(definition) => variant_values(value, definition, names),
+            None => value,
+        },
+        (Value::Record(fields), Schema::Record(record_schema)) => Value::Record(
+            fields
+                .into_iter()
+                .map(|(name, value)| match record_schema.lookup.get(&name) {
+                    Some(position) => {
+                        let value = variant_values(value, &record_schema.fields[*position].schema, names);
+                        (name, value)
+                    }
+                    None => (name, value),
+                })
+                .collect(),
+        ),
+        (Value::Array(items), Schema::Array(items_schema)) => Value::Array(items.into_iter().map(|item| variant_values(item, items_schema, names)).collect()),
+        (Value::Map(values), Schema::Map(values_schema)) => Value::Map(values.into_iter().map(|(key, value)| (key, variant_values(value, values_schema, names))).collect()),
+        (Value::Union(index, value), Schema::Union(union_schema)) => {
+            let variants = union_schema.variants();
+            let value = match variants.get(index as usize) {
+                Some(variant) => variant_values(*value, variant, names),
+                None => *value,
+            };
+            let is_type = |variant: &&Schema| !matches!(variant, Schema::Null);
+            if variants.iter().filter(is_type).count() < 2 || matches!(value, Value::Null) {
+                return Value::Union(index, Box::new(value));
+            }
+            let variant = variants.iter().take(index as usize).filter(is_type).count();
+            let variant = Value::Record(vec![("type".to_string(), Value::String(variant.to_string())), ("value".to_string(), value)]);
+            match union_schema.is_nullable() {
+                // The value of an option
+                true => Value::Union(index, Box::new(variant)),
+                false => variant,
+            }
+        }
+        (value, _) => value,
+    }
+}
+
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Machine {
+    pub status: crate::MachinesStatus,
+    #[serde(default = "Machine::default_last_status")]
+    pub last_status: crate::UnionLongMachinesStatus,
+}
+
+impl Machine {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Machine","namespace":"com.x.machines","fields":[{"name":"status","type":{"type":"enum","name":"Status","symbols":["RUNNING","STOPPED"]}},{"name":"last_status","type":["long","Status"],"default":0}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x9f, 0x54, 0xbb, 0x8f, 0x30, 0xda, 0xed, 0x91];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    #[inline(always)]
+    pub fn default_last_status() -> crate::UnionLongMachinesStatus {
+        crate::UnionLongMachinesStatus::Long(0)
+    }
+}
+
+impl Default for Machine {
+    fn default() -> Self {
+        Self {
+            status: Default::default(),
+            last_status: Self::default_last_status(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Machine {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize, Default)]
+#[serde(rename = "Status")]
+pub enum MachinesStatus {
+    #[default]
+    #[serde(rename = "RUNNING")]
+    Running,
+    #[serde(rename = "STOPPED")]
+    Stopped,
+}
+
+impl MachinesStatus {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"enum","name":"Status","namespace":"com.x.machines","symbols":["RUNNING","STOPPED"]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xcb, 0x59, 0xda, 0x37, 0x6d, 0xb4, 0xa0, 0x9c];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl apache_avro::AvroSchema for MachinesStatus {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Order {
+    pub status: crate::ShopStatus,
+    #[serde(default = "Order::default_last_status")]
+    pub last_status: crate::UnionLongShopStatus,
+}
+
+impl Order {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Order","namespace":"com.x.shop","fields":[{"name":"status","type":{"type":"enum","name":"Status","symbols":["OPEN","SHIPPED"]}},{"name":"last_status","type":["long","Status"],"default":0}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xc8, 0x37, 0x82, 0x8e, 0xdd, 0x50, 0x5a, 0xc6];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    #[inline(always)]
+    pub fn default_last_status() -> crate::UnionLongShopStatus {
+        crate::UnionLongShopStatus::Long(0)
+    }
+}
+
+impl Default for Order {
+    fn default() -> Self {
+        Self {
+            status: Default::default(),
+            last_status: Self::default_last_status(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Order {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize, Default)]
+#[serde(rename = "Status")]
+pub enum ShopStatus {
+    #[default]
+    #[serde(rename = "OPEN")]
+    Open,
+    #[serde(rename = "SHIPPED")]
+    Shipped,
+}
+
+impl ShopStatus {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"enum","name":"Status","namespace":"com.x.shop","symbols":["OPEN","SHIPPED"]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x91, 0xd4, 0xd8, 0xca, 0x87, 0x40, 0xa5, 0x2f];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl apache_avro::AvroSchema for ShopStatus {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionLongMachinesStatus {
+    #[serde(alias = "0")]
+    Long(i64),
+    #[serde(alias = "1")]
+    MachinesStatus(crate::MachinesStatus),
+}
+
+impl Default for UnionLongMachinesStatus {
+    fn default() -> Self {
+        Self::Long(Default::default())
+    }
+}
+
+impl UnionLongMachinesStatus {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"["long",{"type":"enum","name":"Status","namespace":"com.x.machines","symbols":["RUNNING","STOPPED"]}]"#;
+}
+
+impl apache_avro::AvroSchema for UnionLongMachinesStatus {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionLongShopStatus {
+    #[serde(alias = "0")]
+    Long(i64),
+    #[serde(alias = "1")]
+    ShopStatus(crate::ShopStatus),
+}
+
+impl Default for UnionLongShopStatus {
+    fn default() -> Self {
+        Self::Long(Default::default())
+    }
+}
+
+impl UnionLongShopStatus {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"["long",{"type":"enum","name":"Status","namespace":"com.x.shop","symbols":["OPEN","SHIPPED"]}]"#;
+}
+
+impl apache_avro::AvroSchema for UnionLongShopStatus {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
@@ -0,0 +1,4 @@
+pub mod logistics;
+pub mod people;
+pub mod shop;
+
@@ -0,0 +1,34 @@
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Delivery {
+    pub address: String,
+}
+
+impl Delivery {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Delivery","namespace":"org.example.logistics","fields":[{"name":"address","type":"string"}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xb0, 0x49, 0x94, 0xe2, 0x18, 0x87, 0x43, 0x7a];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl Default for Delivery {
+    fn default() -> Self {
+        Self {
+            address: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Delivery {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
@@ -0,0 +1,68 @@
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Customer {
+    pub name: String,
+}
+
+impl Customer {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Customer","namespace":"org.example.people","fields":[{"name":"name","type":"string"}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x46, 0xd6, 0xc1, 0x16, 0x55, 0x0d, 0xd5, 0x17];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl Default for Customer {
+    fn default() -> Self {
+        Self {
+            name: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Customer {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Refused {
+    pub reason: String,
+}
+
+impl Refused {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Refused","namespace":"org.example.people","fields":[{"name":"reason","type":"string"}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x58, 0xb3, 0x27, 0x6a, 0x7e, 0x6e, 0xec, 0x72];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl Default for Refused {
+    fn default() -> Self {
+        Self {
+            reason: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Refused {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
@@ -0,0 +1,138 @@
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Line {
+    pub product: String,
+    pub quantity: i32,
+}
+
+impl Line {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Line","namespace":"org.example.shop","fields":[{"name":"product","type":"string"},{"name":"quantity","type":"int"}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x02, 0x9d, 0xbf, 0xd7, 0x89, 0x9b, 0x0e, 0x55];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl Default for Line {
+    fn default() -> Self {
+        Self {
+            product: Default::default(),
+            quantity: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Line {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Order {
+    /// Declared later in another namespace
+    pub customer: crate::org::example::people::Customer,
+    /// Declared later in the protocol namespace
+    pub lines: Vec<crate::org::example::shop::Line>,
+    #[serde(default = "Order::default_delivery")]
+    pub delivery: Option<crate::org::example::logistics::Delivery>,
+}
+
+impl Order {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Order","namespace":"org.example.shop","fields":[{"name":"customer","type":{"type":"record","name":"Customer","namespace":"org.example.people","fields":[{"name":"name","type":"string"}]},"doc":"Declared later in another namespace"},{"name":"lines","type":{"type":"array","items":{"type":"record","name":"Line","namespace":"org.example.shop","fields":[{"name":"product","type":"string"},{"name":"quantity","type":"int"}]}},"doc":"Declared later in the protocol namespace"},{"name":"delivery","type":["null",{"type":"record","name":"Delivery","namespace":"org.example.logistics","fields":[{"name":"address","type":"string"}]}],"default":null}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xbd, 0xe1, 0x82, 0x37, 0x9a, 0xdc, 0x56, 0x8e];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    #[inline(always)]
+    pub fn default_delivery() -> Option<crate::org::example::logistics::Delivery> {
+        None
+    }
+}
+
+impl Default for Order {
+    fn default() -> Self {
+        Self {
+            customer: Default::default(),
+            lines: Default::default(),
+            delivery: Self::default_delivery(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Order {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Parameters of the message order
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct OrderRequest {
+    pub customer: crate::org::example::people::Customer,
+}
+
+impl OrderRequest {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"OrderRequest","namespace":"org.example.shop","doc":"Parameters of the message order","fields":[{"name":"customer","type":{"type":"record","name":"Customer","namespace":"org.example.people","fields":[{"name":"name","type":"string"}]}}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xdd, 0xd2, 0x69, 0x74, 0x1e, 0x21, 0x03, 0xd2];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl Default for OrderRequest {
+    fn default() -> Self {
+        Self {
+            customer: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for OrderRequest {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Errors of the protocol Shop: the system error or one of the declared errors
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(untagged)]
+pub enum ShopProtocolError {
+    System(String),
+    Refused(crate::org::example::people::Refused),
+}
+
+impl std::fmt::Display for ShopProtocolError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl std::error::Error for ShopProtocolError {}
+
+/// Types referenced before their declaration, some of them in another namespace
+pub trait Shop {
+    fn order(&self, request: crate::org::example::shop::OrderRequest) -> Result<crate::org::example::shop::Order, ShopProtocolError>;
+}
+
@@ -0,0 +1,28 @@
+/// Status shared by the protocols
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize, Default)]
+pub enum Status {
+    #[default]
+    Active,
+    Inactive,
+}
+
+impl Status {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"enum","name":"Status","namespace":"org.example.common","doc":"Status shared by the protocols","symbols":["Active","Inactive"]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xab, 0x5c, 0xd5, 0xde, 0xdd, 0x77, 0x9c, 0xe6];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl apache_avro::AvroSchema for Status {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
@@ -0,0 +1,477 @@
+/// Parameters of the message add
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct AddRequest {
+    pub arg1: i32,
+    #[serde(default = "AddRequest::default_arg2")]
+    pub arg2: i32,
+}
+
+impl AddRequest {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"AddRequest","namespace":"org.example.simple","doc":"Parameters of the message add","fields":[{"name":"arg1","type":"int"},{"name":"arg2","type":"int","default":0}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x84, 0x98, 0x34, 0xda, 0xe2, 0x65, 0x16, 0x1a];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    #[inline(always)]
+    pub fn default_arg2() -> i32 {
+        0
+    }
+}
+
+impl Default for AddRequest {
+    fn default() -> Self {
+        Self {
+            arg1: Default::default(),
+            arg2: Self::default_arg2(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for AddRequest {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Parameters of the message echo
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct EchoRequest {
+    pub record: crate::org::example::simple::TestRecord,
+}
+
+impl EchoRequest {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"EchoRequest","namespace":"org.example.simple","doc":"Parameters of the message echo","fields":[{"name":"record","type":{"type":"record","name":"TestRecord","namespace":"org.example.simple","fields":[{"name":"name","type":"string","doc":"Record name; has no intrinsic order","order":"ignore"},{"name":"kind","type":{"type":"enum","name":"Kind","namespace":"org.example.simple","doc":"Documentation for the enum type Kind","aliases":["org.foo.KindOf"],"symbols":["FOO","BAR","BAZ"],"default":"FOO"},"order":"descending"},{"name":"hash","type":{"type":"fixed","name":"MD5","namespace":"org.example.simple","doc":"MD5 hash; good enough to avoid most collisions.","size":16}},{"name":"nullableHash","type":["null","org.example.simple.MD5"],"doc":"Optional field","default":null,"aliases":["hash"]},{"name":"arrayOfLongs","type":{"type":"array","items":"long"}},{"name":"otherLongs","type":{"type":"array","items":"long"},"default":[]},{"name":"nickname","type":["null","string"]},{"name":"level","type":["int","null"],"default":3},{"name":"updatedAt","type":{"type":"long","logicalType":"timestamp-micros"}},{"name":"birthDate","type":{"type":"int","logicalType":"date"}},{"name":"status","type":{"type":"enum","name":"Status","namespace":"org.example.common","doc":"Status shared by the protocols","symbols":["Active","Inactive"]}},{"name":"location","type":{"type":"record","name":"Location","namespace":"org.example.simple","fields":[{"name":"Latitude","type":"double"},{"name":"Longitude","type":"double"}]}}]}}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xd9, 0x6c, 0x5f, 0x97, 0x05, 0x62, 0xb5, 0x72];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl Default for EchoRequest {
+    fn default() -> Self {
+        Self {
+            record: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for EchoRequest {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Parameters of the message error
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct ErrorRequest {
+}
+
+impl ErrorRequest {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"ErrorRequest","namespace":"org.example.simple","doc":"Parameters of the message error","fields":[]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xc3, 0x40, 0x2a, 0x80, 0xf4, 0x42, 0xe1, 0x4c];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl Default for ErrorRequest {
+    fn default() -> Self {
+        Self {}
+    }
+}
+
+impl apache_avro::AvroSchema for ErrorRequest {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Parameters of the message hello
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct HelloRequest {
+    pub greeting: String,
+}
+
+impl HelloRequest {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"HelloRequest","namespace":"org.example.simple","doc":"Parameters of the message hello","fields":[{"name":"greeting","type":"string"}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xe0, 0x13, 0x38, 0xba, 0x22, 0xa5, 0xb5, 0xe0];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl Default for HelloRequest {
+    fn default() -> Self {
+        Self {
+            greeting: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for HelloRequest {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Documentation for the enum type Kind
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize, Default)]
+pub enum Kind {
+    #[default]
+    #[serde(rename = "FOO")]
+    Foo,
+    #[serde(rename = "BAR")]
+    Bar,
+    #[serde(rename = "BAZ")]
+    Baz,
+}
+
+impl Kind {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"enum","name":"Kind","namespace":"org.example.simple","doc":"Documentation for the enum type Kind","aliases":["org.foo.KindOf"],"symbols":["FOO","BAR","BAZ"],"default":"FOO"}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x3a, 0xd1, 0x8c, 0x38, 0x48, 0xcf, 0x22, 0x2d];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl apache_avro::AvroSchema for Kind {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Location {
+    #[serde(rename = "Latitude")]
+    pub latitude: f64,
+    #[serde(rename = "Longitude")]
+    pub longitude: f64,
+}
+
+impl Location {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Location","namespace":"org.example.simple","fields":[{"name":"Latitude","type":"double"},{"name":"Longitude","type":"double"}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xac, 0x05, 0x85, 0x18, 0x2d, 0x64, 0x6f, 0x3c];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl Default for Location {
+    fn default() -> Self {
+        Self {
+            latitude: Default::default(),
+            longitude: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Location {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// MD5 hash; good enough to avoid most collisions.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub struct Md5(pub [u8; 16]);
+
+impl Md5 {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"fixed","name":"MD5","namespace":"org.example.simple","doc":"MD5 hash; good enough to avoid most collisions.","size":16}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xcc, 0xad, 0xd4, 0x6d, 0x77, 0x4f, 0x0d, 0x49];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl apache_avro::AvroSchema for Md5 {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+impl AsRef<[u8]> for Md5 {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<[u8; 16]> for Md5 {
+    fn from(bytes: [u8; 16]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl TryFrom<&[u8]> for Md5 {
+    type Error = std::array::TryFromSliceError;
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        bytes.try_into().map(Self)
+    }
+}
+
+impl std::fmt::Display for Md5 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+impl serde::Serialize for Md5 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.0)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Md5 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FixedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for FixedVisitor {
+            type Value = Md5;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("16 bytes")
+            }
+
+            fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<Md5, E> {
+                Md5::try_from(bytes).map_err(|_| E::invalid_length(bytes.len(), &self))
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Md5, A::Error> {
+                let mut bytes = [0; 16];
+                for (index, byte) in bytes.iter_mut().enumerate() {
+                    *byte = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(index, &self))?;
+                }
+                if seq.next_element::<u8>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(17, &self));
+                }
+                Ok(Md5(bytes))
+            }
+        }
+
+        deserializer.deserialize_bytes(FixedVisitor)
+    }
+}
+
+/// Parameters of the message ping
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct PingRequest {
+}
+
+impl PingRequest {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"PingRequest","namespace":"org.example.simple","doc":"Parameters of the message ping","fields":[]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x2a, 0x30, 0x33, 0x5b, 0x68, 0x75, 0x1d, 0x4a];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl Default for PingRequest {
+    fn default() -> Self {
+        Self {}
+    }
+}
+
+impl apache_avro::AvroSchema for PingRequest {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Errors of the protocol Simple: the system error or one of the declared errors
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(untagged)]
+pub enum SimpleProtocolError {
+    System(String),
+    TestError(crate::org::example::simple::TestError),
+}
+
+impl std::fmt::Display for SimpleProtocolError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl std::error::Error for SimpleProtocolError {}
+
+/// An example protocol in Avro IDL
+pub trait Simple {
+    fn hello(&self, request: crate::org::example::simple::HelloRequest) -> Result<String, SimpleProtocolError>;
+    /// Return what was given
+    fn echo(&self, request: crate::org::example::simple::EchoRequest) -> Result<crate::org::example::simple::TestRecord, SimpleProtocolError>;
+    fn add(&self, request: crate::org::example::simple::AddRequest) -> Result<i32, SimpleProtocolError>;
+    fn error(&self, request: crate::org::example::simple::ErrorRequest) -> Result<(), SimpleProtocolError>;
+    fn ping(&self, request: crate::org::example::simple::PingRequest);
+}
+
+/// Errors are records that can be thrown from a method
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct TestError {
+    pub message: String,
+}
+
+impl TestError {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"TestError","namespace":"org.example.simple","doc":"Errors are records that can be thrown from a method","fields":[{"name":"message","type":"string"}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xb9, 0x0a, 0x59, 0x33, 0x2b, 0x79, 0x8b, 0x93];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl Default for TestError {
+    fn default() -> Self {
+        Self {
+            message: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for TestError {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct TestRecord {
+    /// Record name; has no intrinsic order
+    pub name: String,
+    pub kind: crate::org::example::simple::Kind,
+    pub hash: crate::org::example::simple::Md5,
+    /// Optional field
+    #[serde(rename = "nullableHash")]
+    #[serde(default = "TestRecord::default_nullable_hash")]
+    pub nullable_hash: Option<crate::org::example::simple::Md5>,
+    #[serde(rename = "arrayOfLongs")]
+    pub array_of_longs: Vec<i64>,
+    #[serde(rename = "otherLongs")]
+    #[serde(default = "TestRecord::default_other_longs")]
+    pub other_longs: Vec<i64>,
+    pub nickname: Option<String>,
+    #[serde(default = "TestRecord::default_level")]
+    pub level: Option<i32>,
+    #[serde(with = "crate::logical_types::TimestampMicros")]
+    #[serde(rename = "updatedAt")]
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+    #[serde(with = "crate::logical_types::Date")]
+    #[serde(rename = "birthDate")]
+    pub birth_date: chrono::NaiveDate,
+    pub status: crate::org::example::common::Status,
+    pub location: crate::org::example::simple::Location,
+}
+
+impl TestRecord {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"TestRecord","namespace":"org.example.simple","fields":[{"name":"name","type":"string","doc":"Record name; has no intrinsic order","order":"ignore"},{"name":"kind","type":{"type":"enum","name":"Kind","namespace":"org.example.simple","doc":"Documentation for the enum type Kind","aliases":["org.foo.KindOf"],"symbols":["FOO","BAR","BAZ"],"default":"FOO"},"order":"descending"},{"name":"hash","type":{"type":"fixed","name":"MD5","namespace":"org.example.simple","doc":"MD5 hash; good enough to avoid most collisions.","size":16}},{"name":"nullableHash","type":["null","org.example.simple.MD5"],"doc":"Optional field","default":null,"aliases":["hash"]},{"name":"arrayOfLongs","type":{"type":"array","items":"long"}},{"name":"otherLongs","type":{"type":"array","items":"long"},"default":[]},{"name":"nickname","type":["null","string"]},{"name":"level","type":["int","null"],"default":3},{"name":"updatedAt","type":{"type":"long","logicalType":"timestamp-micros"}},{"name":"birthDate","type":{"type":"int","logicalType":"date"}},{"name":"status","type":{"type":"enum","name":"Status","namespace":"org.example.common","doc":"Status shared by the protocols","symbols":["Active","Inactive"]}},{"name":"location","type":{"type":"record","name":"Location","namespace":"org.example.simple","fields":[{"name":"Latitude","type":"double"},{"name":"Longitude","type":"double"}]}}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xfc, 0xa5, 0x4c, 0x00, 0xa6, 0x63, 0x27, 0xc9];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    #[inline(always)]
+    pub fn default_nullable_hash() -> Option<crate::org::example::simple::Md5> {
+        None
+    }
+
+    #[inline(always)]
+    pub fn default_other_longs() -> Vec<i64> {
+        Vec::new()
+    }
+
+    #[inline(always)]
+    pub fn default_level() -> Option<i32> {
+        Some(3)
+    }
+}
+
+impl Default for TestRecord {
+    fn default() -> Self {
+        Self {
+            name: Default::default(),
+            kind: Default::default(),
+            hash: Default::default(),
+            nullable_hash: Self::default_nullable_hash(),
+            array_of_longs: Default::default(),
+            other_longs: Self::default_other_longs(),
+            nickname: Default::default(),
+            level: Self::default_level(),
+            updated_at: Default::default(),
+            birth_date: Default::default(),
+            status: Default::default(),
+            location: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for TestRecord {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
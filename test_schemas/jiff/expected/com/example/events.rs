@@ -0,0 +1,133 @@
+/// Event with dates, times and identifiers
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Event {
+    #[serde(with = "crate::logical_types::Uuid")]
+    #[serde(default = "Event::default_id")]
+    pub id: uuid::Uuid,
+    #[serde(with = "crate::logical_types::Date")]
+    #[serde(default = "Event::default_day")]
+    pub day: jiff::civil::Date,
+    #[serde(with = "crate::logical_types::TimeMillis")]
+    pub opening: jiff::civil::Time,
+    #[serde(with = "crate::logical_types::TimeMicros")]
+    #[serde(default = "Event::default_closing")]
+    pub closing: jiff::civil::Time,
+    #[serde(with = "crate::logical_types::TimestampMillis")]
+    #[serde(default = "Event::default_created")]
+    pub created: jiff::Timestamp,
+    #[serde(with = "crate::logical_types::TimestampMicros")]
+    #[serde(default = "Event::default_updated")]
+    pub updated: Option<jiff::Timestamp>,
+    #[serde(with = "crate::logical_types::LocalTimestampMillis")]
+    #[serde(default = "Event::default_local_created")]
+    pub local_created: jiff::civil::DateTime,
+    #[serde(with = "crate::logical_types::LocalTimestampMicros")]
+    pub local_updated: jiff::civil::DateTime,
+    #[serde(with = "crate::logical_types::Date")]
+    pub holidays: Vec<jiff::civil::Date>,
+    #[serde(with = "crate::logical_types::TimestampMillis")]
+    pub checkpoints: std::collections::HashMap<String, jiff::Timestamp>,
+    pub reference: crate::com::example::events::UnionUuidLong,
+}
+
+impl Event {
+    /// JSON of the Avro schema
+    ///
+    /// The defaults of `com.example.events.Event.day` are removed, apache_avro rejects them: the `Default` impl still uses them,
+    /// and the fingerprints of the Parsing Canonical Form don't depend on the defaults
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Event","namespace":"com.example.events","doc":"Event with dates, times and identifiers","fields":[{"name":"id","type":{"type":"string","logicalType":"uuid"},"default":"5f0c6a2e-8d1b-4c3e-9a7f-2b6d4e8f1a3c"},{"name":"day","type":{"type":"int","logicalType":"date"}},{"name":"opening","type":{"type":"int","logicalType":"time-millis"}},{"name":"closing","type":{"type":"long","logicalType":"time-micros"},"default":64800000000},{"name":"created","type":{"type":"long","logicalType":"timestamp-millis"},"default":1705312800000},{"name":"updated","type":["null",{"type":"long","logicalType":"timestamp-micros"}],"default":null},{"name":"local_created","type":{"type":"long","logicalType":"local-timestamp-millis"},"default":1705312800000},{"name":"local_updated","type":{"type":"long","logicalType":"local-timestamp-micros"}},{"name":"holidays","type":{"type":"array","items":{"type":"int","logicalType":"date"}}},{"name":"checkpoints","type":{"type":"map","values":{"type":"long","logicalType":"timestamp-millis"}}},{"name":"reference","type":[{"type":"string","logicalType":"uuid"},"long"]}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xb8, 0x66, 0x3a, 0x09, 0x30, 0x58, 0xa9, 0x1c];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    #[inline(always)]
+    pub fn default_id() -> uuid::Uuid {
+        uuid::uuid!("5f0c6a2e-8d1b-4c3e-9a7f-2b6d4e8f1a3c")
+    }
+
+    #[inline(always)]
+    pub fn default_day() -> jiff::civil::Date {
+        jiff::civil::date(2024, 1, 15)
+    }
+
+    #[inline(always)]
+    pub fn default_closing() -> jiff::civil::Time {
+        jiff::civil::time(18, 0, 0, 0)
+    }
+
+    #[inline(always)]
+    pub fn default_created() -> jiff::Timestamp {
+        jiff::Timestamp::from_millisecond(1705312800000).expect("The default value is in range")
+    }
+
+    #[inline(always)]
+    pub fn default_updated() -> Option<jiff::Timestamp> {
+        None
+    }
+
+    #[inline(always)]
+    pub fn default_local_created() -> jiff::civil::DateTime {
+        jiff::civil::date(2024, 1, 15).at(10, 0, 0, 0)
+    }
+}
+
+impl Default for Event {
+    fn default() -> Self {
+        Self {
+            id: Self::default_id(),
+            day: Self::default_day(),
+            opening: Default::default(),
+            closing: Self::default_closing(),
+            created: Self::default_created(),
+            updated: Self::default_updated(),
+            local_created: Self::default_local_created(),
+            local_updated: jiff::civil::date(1970, 1, 1).at(0, 0, 0, 0),
+            holidays: Default::default(),
+            checkpoints: Default::default(),
+            reference: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Event {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionUuidLong {
+    #[serde(alias = "0")]
+    Uuid(#[serde(with = "crate::logical_types::Uuid")] uuid::Uuid),
+    #[serde(alias = "1")]
+    Long(i64),
+}
+
+impl Default for UnionUuidLong {
+    fn default() -> Self {
+        Self::Uuid(Default::default())
+    }
+}
+
+impl UnionUuidLong {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"[{"type":"string","logicalType":"uuid"},"long"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionUuidLong {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
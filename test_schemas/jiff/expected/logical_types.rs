@@ -0,0 +1,333 @@
+/// A value of a logical type, or a container of values, serialized like the Avro logical type
+pub trait AvroLogicalType<Unit>: Sized {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+}
+
+/// `date`: the number of days since the Unix epoch, an int
+/// The fields use it with `#[serde(with = "Date")]`
+pub struct Date;
+
+impl Date {
+    pub fn serialize<T: AvroLogicalType<Self>, S: serde::Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize_logical(serializer)
+    }
+
+    pub fn deserialize<'de, T: AvroLogicalType<Self>, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_logical(deserializer)
+    }
+}
+
+impl AvroLogicalType<Date> for jiff::civil::Date {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(i32::try_from(self.duration_since(jiff::civil::date(1970, 1, 1)).as_secs() / 86_400).map_err(serde::ser::Error::custom)?)
+    }
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let days = <i32 as serde::Deserialize>::deserialize(deserializer)?;
+        jiff::civil::date(1970, 1, 1)
+            .checked_add(jiff::SignedDuration::from_hours(i64::from(days) * 24))
+            .ok()
+            .ok_or_else(|| serde::de::Error::custom(format!("The date {days} is out of range")))
+    }
+}
+
+/// `time-millis`: the number of milliseconds after midnight, an int
+/// The fields use it with `#[serde(with = "TimeMillis")]`
+pub struct TimeMillis;
+
+impl TimeMillis {
+    pub fn serialize<T: AvroLogicalType<Self>, S: serde::Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize_logical(serializer)
+    }
+
+    pub fn deserialize<'de, T: AvroLogicalType<Self>, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_logical(deserializer)
+    }
+}
+
+impl AvroLogicalType<TimeMillis> for jiff::civil::Time {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(i32::try_from(self.duration_since(jiff::civil::Time::midnight()).as_millis()).map_err(serde::ser::Error::custom)?)
+    }
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let millis = <i32 as serde::Deserialize>::deserialize(deserializer)?;
+        jiff::civil::Time::midnight()
+            .checked_add(jiff::SignedDuration::from_millis(i64::from(millis)))
+            .ok()
+            .ok_or_else(|| serde::de::Error::custom(format!("The time {millis} is out of range")))
+    }
+}
+
+/// `time-micros`: the number of microseconds after midnight, a long
+/// The fields use it with `#[serde(with = "TimeMicros")]`
+pub struct TimeMicros;
+
+impl TimeMicros {
+    pub fn serialize<T: AvroLogicalType<Self>, S: serde::Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize_logical(serializer)
+    }
+
+    pub fn deserialize<'de, T: AvroLogicalType<Self>, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_logical(deserializer)
+    }
+}
+
+impl AvroLogicalType<TimeMicros> for jiff::civil::Time {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(i64::try_from(self.duration_since(jiff::civil::Time::midnight()).as_micros()).map_err(serde::ser::Error::custom)?)
+    }
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let micros = <i64 as serde::Deserialize>::deserialize(deserializer)?;
+        jiff::civil::Time::midnight()
+            .checked_add(jiff::SignedDuration::from_micros(micros))
+            .ok()
+            .ok_or_else(|| serde::de::Error::custom(format!("The time {micros} is out of range")))
+    }
+}
+
+/// `timestamp-millis`: the number of milliseconds since the Unix epoch in UTC, a long
+/// The fields use it with `#[serde(with = "TimestampMillis")]`
+pub struct TimestampMillis;
+
+impl TimestampMillis {
+    pub fn serialize<T: AvroLogicalType<Self>, S: serde::Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize_logical(serializer)
+    }
+
+    pub fn deserialize<'de, T: AvroLogicalType<Self>, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_logical(deserializer)
+    }
+}
+
+impl AvroLogicalType<TimestampMillis> for jiff::Timestamp {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(self.as_millisecond())
+    }
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let timestamp = <i64 as serde::Deserialize>::deserialize(deserializer)?;
+        jiff::Timestamp::from_millisecond(timestamp)
+            .ok()
+            .ok_or_else(|| serde::de::Error::custom(format!("The timestamp {timestamp} is out of range")))
+    }
+}
+
+/// `timestamp-micros`: the number of microseconds since the Unix epoch in UTC, a long
+/// The fields use it with `#[serde(with = "TimestampMicros")]`
+pub struct TimestampMicros;
+
+impl TimestampMicros {
+    pub fn serialize<T: AvroLogicalType<Self>, S: serde::Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize_logical(serializer)
+    }
+
+    pub fn deserialize<'de, T: AvroLogicalType<Self>, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_logical(deserializer)
+    }
+}
+
+impl AvroLogicalType<TimestampMicros> for jiff::Timestamp {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(self.as_microsecond())
+    }
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let timestamp = <i64 as serde::Deserialize>::deserialize(deserializer)?;
+        jiff::Timestamp::from_microsecond(timestamp)
+            .ok()
+            .ok_or_else(|| serde::de::Error::custom(format!("The timestamp {timestamp} is out of range")))
+    }
+}
+
+/// `local-timestamp-millis`: the number of milliseconds since the Unix epoch in local time, a long
+/// The fields use it with `#[serde(with = "LocalTimestampMillis")]`
+pub struct LocalTimestampMillis;
+
+impl LocalTimestampMillis {
+    pub fn serialize<T: AvroLogicalType<Self>, S: serde::Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize_logical(serializer)
+    }
+
+    pub fn deserialize<'de, T: AvroLogicalType<Self>, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_logical(deserializer)
+    }
+}
+
+impl AvroLogicalType<LocalTimestampMillis> for jiff::civil::DateTime {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let timestamp = jiff::tz::Offset::UTC.to_timestamp(*self).map_err(serde::ser::Error::custom)?;
+        serializer.serialize_i64(timestamp.as_millisecond())
+    }
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let timestamp = <i64 as serde::Deserialize>::deserialize(deserializer)?;
+        jiff::Timestamp::from_millisecond(timestamp)
+            .ok()
+            .map(|timestamp| jiff::tz::Offset::UTC.to_datetime(timestamp))
+            .ok_or_else(|| serde::de::Error::custom(format!("The timestamp {timestamp} is out of range")))
+    }
+}
+
+/// `local-timestamp-micros`: the number of microseconds since the Unix epoch in local time, a long
+/// The fields use it with `#[serde(with = "LocalTimestampMicros")]`
+pub struct LocalTimestampMicros;
+
+impl LocalTimestampMicros {
+    pub fn serialize<T: AvroLogicalType<Self>, S: serde::Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize_logical(serializer)
+    }
+
+    pub fn deserialize<'de, T: AvroLogicalType<Self>, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_logical(deserializer)
+    }
+}
+
+impl AvroLogicalType<LocalTimestampMicros> for jiff::civil::DateTime {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let timestamp = jiff::tz::Offset::UTC.to_timestamp(*self).map_err(serde::ser::Error::custom)?;
+        serializer.serialize_i64(timestamp.as_microsecond())
+    }
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let timestamp = <i64 as serde::Deserialize>::deserialize(deserializer)?;
+        jiff::Timestamp::from_microsecond(timestamp)
+            .ok()
+            .map(|timestamp| jiff::tz::Offset::UTC.to_datetime(timestamp))
+            .ok_or_else(|| serde::de::Error::custom(format!("The timestamp {timestamp} is out of range")))
+    }
+}
+
+/// `uuid`: the string representation of the UUID
+/// The fields use it with `#[serde(with = "Uuid")]`
+pub struct Uuid;
+
+impl Uuid {
+    pub fn serialize<T: AvroLogicalType<Self>, S: serde::Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize_logical(serializer)
+    }
+
+    pub fn deserialize<'de, T: AvroLogicalType<Self>, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_logical(deserializer)
+    }
+}
+
+impl AvroLogicalType<Uuid> for uuid::Uuid {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let uuid = <String as serde::Deserialize>::deserialize(deserializer)?;
+        uuid::Uuid::parse_str(&uuid).map_err(serde::de::Error::custom)
+    }
+}
+
+impl<U, T: AvroLogicalType<U>> AvroLogicalType<U> for Option<T> {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Some(value) => serializer.serialize_some(&LogicalValue::<U, T>(value, std::marker::PhantomData)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_option(LogicalVisitor::<U, Self>(std::marker::PhantomData))
+    }
+}
+
+impl<U, T: AvroLogicalType<U>> AvroLogicalType<U> for Vec<T> {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter().map(|value| LogicalValue::<U, T>(value, std::marker::PhantomData)))
+    }
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(LogicalVisitor::<U, Self>(std::marker::PhantomData))
+    }
+}
+
+impl<U, T: AvroLogicalType<U>> AvroLogicalType<U> for std::collections::HashMap<String, T> {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter().map(|(key, value)| (key, LogicalValue::<U, T>(value, std::marker::PhantomData))))
+    }
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(LogicalVisitor::<U, Self>(std::marker::PhantomData))
+    }
+}
+
+/// Serialize a value inside a container
+struct LogicalValue<'a, U, T>(&'a T, std::marker::PhantomData<U>);
+
+impl<U, T: AvroLogicalType<U>> serde::Serialize for LogicalValue<'_, U, T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize_logical(serializer)
+    }
+}
+
+/// Deserialize the values of a container
+struct LogicalVisitor<U, T>(std::marker::PhantomData<(U, T)>);
+
+impl<'de, U, T: AvroLogicalType<U>> serde::de::DeserializeSeed<'de> for LogicalVisitor<U, T> {
+    type Value = T;
+
+    fn deserialize<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_logical(deserializer)
+    }
+}
+
+impl<'de, U, T: AvroLogicalType<U>> serde::de::Visitor<'de> for LogicalVisitor<U, Option<T>> {
+    type Value = Option<T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("an optional value")
+    }
+
+    fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        T::deserialize_logical(deserializer).map(Some)
+    }
+}
+
+impl<'de, U, T: AvroLogicalType<U>> serde::de::Visitor<'de> for LogicalVisitor<U, Vec<T>> {
+    type Value = Vec<T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("an array")
+    }
+
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut values = Vec::new();
+        while let Some(value) = seq.next_element_seed(LogicalVisitor::<U, T>(std::marker::PhantomData))? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+impl<'de, U, T: AvroLogicalType<U>> serde::de::Visitor<'de> for LogicalVisitor<U, std::collections::HashMap<String, T>> {
+    type Value = std::collections::HashMap<String, T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a map")
+    }
+
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut values = std::collections::HashMap::new();
+        while let Some(key) = map.next_key::<String>()? {
+            values.insert(key, map.next_value_seed(LogicalVisitor::<U, T>(std::marker::PhantomData))?);
+        }
+        Ok(values)
+    }
+}
+
@@ -0,0 +1,108 @@
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Machine {
+    pub id: i64,
+    #[serde(default = "Machine::default_status")]
+    pub status: crate::machines::Status,
+    #[serde(default = "Machine::default_reading")]
+    pub reading: Option<crate::machines::UnionLongDouble>,
+}
+
+impl Machine {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Machine","namespace":"com.mysite.machines","fields":[{"name":"id","type":"long"},{"name":"status","type":{"type":"enum","name":"Status","symbols":["RUNNING","STOPPED"]},"default":"STOPPED"},{"name":"reading","type":["null","long","double"],"default":null}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xf5, 0x98, 0x2c, 0x4a, 0x72, 0xf2, 0x44, 0xb1];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    #[inline(always)]
+    pub fn default_status() -> crate::machines::Status {
+        crate::machines::Status::Stopped
+    }
+
+    #[inline(always)]
+    pub fn default_reading() -> Option<crate::machines::UnionLongDouble> {
+        None
+    }
+}
+
+impl Default for Machine {
+    fn default() -> Self {
+        Self {
+            id: Default::default(),
+            status: Self::default_status(),
+            reading: Self::default_reading(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Machine {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize, Default)]
+pub enum Status {
+    #[default]
+    #[serde(rename = "RUNNING")]
+    Running,
+    #[serde(rename = "STOPPED")]
+    Stopped,
+}
+
+impl Status {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"enum","name":"Status","namespace":"com.mysite.machines","symbols":["RUNNING","STOPPED"]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x06, 0xd6, 0x8c, 0x50, 0x91, 0xb5, 0x39, 0x3e];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl apache_avro::AvroSchema for Status {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionLongDouble {
+    #[serde(alias = "0")]
+    Long(i64),
+    #[serde(alias = "1")]
+    Double(f64),
+}
+
+impl Default for UnionLongDouble {
+    fn default() -> Self {
+        Self::Long(Default::default())
+    }
+}
+
+impl UnionLongDouble {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"["long","double"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionLongDouble {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
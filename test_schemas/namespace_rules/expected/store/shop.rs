@@ -0,0 +1,110 @@
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Order {
+    pub id: i64,
+    #[serde(default = "Order::default_status")]
+    pub status: crate::store::shop::Status,
+    pub machine: crate::machines::Machine,
+    #[serde(default = "Order::default_amount")]
+    pub amount: Option<crate::store::shop::UnionLongDouble>,
+}
+
+impl Order {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Order","namespace":"com.mysite.shop","fields":[{"name":"id","type":"long"},{"name":"status","type":{"type":"enum","name":"Status","symbols":["OPEN","SHIPPED"]},"default":"OPEN"},{"name":"machine","type":{"type":"record","name":"Machine","namespace":"com.mysite.machines","fields":[{"name":"id","type":"long"},{"name":"status","type":{"type":"enum","name":"Status","symbols":["RUNNING","STOPPED"]},"default":"STOPPED"},{"name":"reading","type":["null","long","double"],"default":null}]}},{"name":"amount","type":["null","long","double"],"default":null}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xd4, 0xbb, 0x06, 0x46, 0x85, 0xe6, 0x9a, 0xd2];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    #[inline(always)]
+    pub fn default_status() -> crate::store::shop::Status {
+        crate::store::shop::Status::Open
+    }
+
+    #[inline(always)]
+    pub fn default_amount() -> Option<crate::store::shop::UnionLongDouble> {
+        None
+    }
+}
+
+impl Default for Order {
+    fn default() -> Self {
+        Self {
+            id: Default::default(),
+            status: Self::default_status(),
+            machine: Default::default(),
+            amount: Self::default_amount(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Order {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize, Default)]
+pub enum Status {
+    #[default]
+    #[serde(rename = "OPEN")]
+    Open,
+    #[serde(rename = "SHIPPED")]
+    Shipped,
+}
+
+impl Status {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"enum","name":"Status","namespace":"com.mysite.shop","symbols":["OPEN","SHIPPED"]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xd7, 0xd0, 0xdb, 0xc4, 0x66, 0xb4, 0xf6, 0xc9];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl apache_avro::AvroSchema for Status {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionLongDouble {
+    #[serde(alias = "0")]
+    Long(i64),
+    #[serde(alias = "1")]
+    Double(f64),
+}
+
+impl Default for UnionLongDouble {
+    fn default() -> Self {
+        Self::Long(Default::default())
+    }
+}
+
+impl UnionLongDouble {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"["long","double"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionLongDouble {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
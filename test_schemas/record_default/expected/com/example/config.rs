@@ -0,0 +1,161 @@
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Backoff {
+    pub initial_ms: i64,
+    #[serde(default = "Backoff::default_multiplier")]
+    pub multiplier: f64,
+}
+
+impl Backoff {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Backoff","namespace":"com.example.config","fields":[{"name":"initial_ms","type":"long"},{"name":"multiplier","type":"double","default":2.0}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x1b, 0x11, 0xee, 0xf6, 0xf5, 0x3a, 0x0a, 0xbb];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    #[inline(always)]
+    pub fn default_multiplier() -> f64 {
+        2.0
+    }
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Self {
+            initial_ms: Default::default(),
+            multiplier: Self::default_multiplier(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Backoff {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct RetryPolicy {
+    #[serde(rename = "maxAttempts")]
+    #[serde(default = "RetryPolicy::default_max_attempts")]
+    pub max_attempts: i32,
+    pub backoff: crate::com::example::config::Backoff,
+    #[serde(default = "RetryPolicy::default_codes")]
+    pub codes: Vec<i32>,
+}
+
+impl RetryPolicy {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"RetryPolicy","namespace":"com.example.config","fields":[{"name":"maxAttempts","type":"int","default":3},{"name":"backoff","type":{"type":"record","name":"Backoff","fields":[{"name":"initial_ms","type":"long"},{"name":"multiplier","type":"double","default":2.0}]}},{"name":"codes","type":{"type":"array","items":"int"},"default":[]}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xcc, 0x6a, 0xee, 0xb8, 0xda, 0xec, 0x1c, 0xb9];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    #[inline(always)]
+    pub fn default_max_attempts() -> i32 {
+        3
+    }
+
+    #[inline(always)]
+    pub fn default_codes() -> Vec<i32> {
+        Vec::new()
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: Self::default_max_attempts(),
+            backoff: Default::default(),
+            codes: Self::default_codes(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for RetryPolicy {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Configuration of a service
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Service {
+    pub name: String,
+    #[serde(default = "Service::default_retry")]
+    pub retry: crate::com::example::config::RetryPolicy,
+    #[serde(default = "Service::default_fallback")]
+    pub fallback: Option<crate::com::example::config::RetryPolicy>,
+    #[serde(default = "Service::default_policies")]
+    pub policies: Vec<crate::com::example::config::RetryPolicy>,
+    #[serde(rename = "type")]
+    #[serde(default = "Service::default_field_type")]
+    pub field_type: String,
+}
+
+impl Service {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Service","namespace":"com.example.config","doc":"Configuration of a service","fields":[{"name":"name","type":"string"},{"name":"retry","type":{"type":"record","name":"RetryPolicy","fields":[{"name":"maxAttempts","type":"int","default":3},{"name":"backoff","type":{"type":"record","name":"Backoff","fields":[{"name":"initial_ms","type":"long"},{"name":"multiplier","type":"double","default":2.0}]}},{"name":"codes","type":{"type":"array","items":"int"},"default":[]}]},"default":{"maxAttempts":5,"backoff":{"initial_ms":100}}},{"name":"fallback","type":["RetryPolicy","null"],"default":{"backoff":{"initial_ms":10,"multiplier":1.5},"codes":[500,503]}},{"name":"policies","type":{"type":"array","items":"RetryPolicy"},"default":[{"backoff":{"initial_ms":1}}]},{"name":"type","type":"string","default":"web"}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xde, 0xd7, 0x23, 0x65, 0xa1, 0x0e, 0x01, 0xc7];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    #[inline(always)]
+    pub fn default_retry() -> crate::com::example::config::RetryPolicy {
+        crate::com::example::config::RetryPolicy { max_attempts: 5, backoff: crate::com::example::config::Backoff { initial_ms: 100, multiplier: 2.0 }, codes: Vec::new() }
+    }
+
+    #[inline(always)]
+    pub fn default_fallback() -> Option<crate::com::example::config::RetryPolicy> {
+        Some(crate::com::example::config::RetryPolicy { max_attempts: 3, backoff: crate::com::example::config::Backoff { initial_ms: 10, multiplier: 1.5 }, codes: vec![500, 503] })
+    }
+
+    #[inline(always)]
+    pub fn default_policies() -> Vec<crate::com::example::config::RetryPolicy> {
+        vec![crate::com::example::config::RetryPolicy { max_attempts: 3, backoff: crate::com::example::config::Backoff { initial_ms: 1, multiplier: 2.0 }, codes: Vec::new() }]
+    }
+
+    #[inline(always)]
+    pub fn default_field_type() -> String {
+        "web".to_string()
+    }
+}
+
+impl Default for Service {
+    fn default() -> Self {
+        Self {
+            name: Default::default(),
+            retry: Self::default_retry(),
+            fallback: Self::default_fallback(),
+            policies: Self::default_policies(),
+            field_type: Self::default_field_type(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Service {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
@@ -1,3 +1,2 @@
 pub mod my_site;
 
-
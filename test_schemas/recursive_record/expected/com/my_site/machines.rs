@@ -1,4 +1,4 @@
-#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize, Default)]
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
 #[serde(default)]
 pub struct Machine {
     /// Name of the machine, should not be null
@@ -8,25 +8,57 @@ pub struct Machine {
     #[serde(rename = "MachineIdentifier")]
     pub machine_identifier: i32,
     /// Date of the last update of this machine
-    #[serde(with = "chrono::naive::serde::ts_microseconds")]
+    #[serde(with = "crate::logical_types::TimestampMicros")]
     #[serde(rename = "UpdateDate")]
-    pub update_date: chrono::NaiveDateTime,
+    pub update_date: chrono::DateTime<chrono::Utc>,
     /// Content is null when the machine structure has not been validated
     #[serde(rename = "Content")]
     pub content: crate::com::my_site::machines::MachineContent,
 }
 
-impl Machine {}
+impl Machine {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Machine","namespace":"com.MySite.Machines","fields":[{"name":"MachineName","type":"string","doc":"Name of the machine, should not be null"},{"name":"MachineIdentifier","type":"int","doc":"technical identifier of the machine, should not be null"},{"name":"UpdateDate","type":{"type":"long","logicalType":"timestamp-micros"},"doc":"Date of the last update of this machine"},{"name":"Content","type":{"type":"record","name":"MachineContent","fields":[{"name":"Description","type":["null","string"]},{"name":"Length","type":["null",{"type":"bytes","logicalType":"decimal","precision":20,"scale":6}]},{"name":"Width","type":["null",{"type":"bytes","logicalType":"decimal","precision":20,"scale":6}]},{"name":"Height","type":["null",{"type":"bytes","logicalType":"decimal","precision":20,"scale":6}]},{"name":"Parameters","type":["null",{"type":"array","items":{"type":"record","name":"Parameter","fields":[{"name":"Tag","type":["null","string"]},{"name":"Name","type":"string"},{"name":"Identifier","type":"int","doc":"Parameter technical identfier"},{"name":"UnitOfMeasurement","type":["null","string"],"default":null},{"name":"Value","type":["null","double"],"doc":"Contains the value of the parameter in double type"},{"name":"OverrideMode","type":{"type":"enum","name":"OverrideMode","symbols":["Locked","WithPermission","Free"]}}]}}]},{"name":"Subsets","type":["null",{"type":"array","items":{"type":"record","name":"Subset","fields":[{"name":"Name","type":"string"},{"name":"Identifier","type":["null","string"],"doc":"Technical identifier, unique in the structure of the machine."},{"name":"Parameters","type":["null",{"type":"array","items":"Parameter"}]},{"name":"Subsets","type":["null",{"type":"array","items":"Subset"}]}]}}]}]},"doc":"Content is null when the machine structure has not been validated"}]}"#;
 
-#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize, Default)]
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xaa, 0x9e, 0x6b, 0x48, 0xd0, 0xd4, 0x85, 0xa5];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl Default for Machine {
+    fn default() -> Self {
+        Self {
+            machine_name: Default::default(),
+            machine_identifier: Default::default(),
+            update_date: Default::default(),
+            content: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Machine {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
 #[serde(default)]
 pub struct MachineContent {
     #[serde(rename = "Description")]
     pub description: Option<String>,
+    #[serde(with = "crate::decimal::PrecisionScale::<20, 6>")]
     #[serde(rename = "Length")]
     pub length: Option<apache_avro::Decimal>,
+    #[serde(with = "crate::decimal::PrecisionScale::<20, 6>")]
     #[serde(rename = "Width")]
     pub width: Option<apache_avro::Decimal>,
+    #[serde(with = "crate::decimal::PrecisionScale::<20, 6>")]
     #[serde(rename = "Height")]
     pub height: Option<apache_avro::Decimal>,
     #[serde(rename = "Parameters")]
@@ -35,7 +67,38 @@ pub struct MachineContent {
     pub subsets: Option<Vec<crate::com::my_site::machines::Subset>>,
 }
 
-impl MachineContent {}
+impl MachineContent {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"MachineContent","namespace":"com.MySite.Machines","fields":[{"name":"Description","type":["null","string"]},{"name":"Length","type":["null",{"type":"bytes","logicalType":"decimal","precision":20,"scale":6}]},{"name":"Width","type":["null",{"type":"bytes","logicalType":"decimal","precision":20,"scale":6}]},{"name":"Height","type":["null",{"type":"bytes","logicalType":"decimal","precision":20,"scale":6}]},{"name":"Parameters","type":["null",{"type":"array","items":{"type":"record","name":"Parameter","fields":[{"name":"Tag","type":["null","string"]},{"name":"Name","type":"string"},{"name":"Identifier","type":"int","doc":"Parameter technical identfier"},{"name":"UnitOfMeasurement","type":["null","string"],"default":null},{"name":"Value","type":["null","double"],"doc":"Contains the value of the parameter in double type"},{"name":"OverrideMode","type":{"type":"enum","name":"OverrideMode","symbols":["Locked","WithPermission","Free"]}}]}}]},{"name":"Subsets","type":["null",{"type":"array","items":{"type":"record","name":"Subset","fields":[{"name":"Name","type":"string"},{"name":"Identifier","type":["null","string"],"doc":"Technical identifier, unique in the structure of the machine."},{"name":"Parameters","type":["null",{"type":"array","items":"Parameter"}]},{"name":"Subsets","type":["null",{"type":"array","items":"Subset"}]}]}}]}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x73, 0xcc, 0x4a, 0x62, 0x35, 0x7a, 0x09, 0x74];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl Default for MachineContent {
+    fn default() -> Self {
+        Self {
+            description: Default::default(),
+            length: Default::default(),
+            width: Default::default(),
+            height: Default::default(),
+            parameters: Default::default(),
+            subsets: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for MachineContent {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
 
 #[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize, Default)]
 pub enum OverrideMode {
@@ -45,7 +108,27 @@ pub enum OverrideMode {
     Free,
 }
 
-#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize, Default)]
+impl OverrideMode {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"enum","name":"OverrideMode","namespace":"com.MySite.Machines","symbols":["Locked","WithPermission","Free"]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x35, 0xfa, 0xff, 0xa4, 0xc8, 0xf7, 0xac, 0x08];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl apache_avro::AvroSchema for OverrideMode {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
 #[serde(default)]
 pub struct Parameter {
     #[serde(rename = "Tag")]
@@ -66,13 +149,44 @@ pub struct Parameter {
 }
 
 impl Parameter {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Parameter","namespace":"com.MySite.Machines","fields":[{"name":"Tag","type":["null","string"]},{"name":"Name","type":"string"},{"name":"Identifier","type":"int","doc":"Parameter technical identfier"},{"name":"UnitOfMeasurement","type":["null","string"],"default":null},{"name":"Value","type":["null","double"],"doc":"Contains the value of the parameter in double type"},{"name":"OverrideMode","type":{"type":"enum","name":"OverrideMode","symbols":["Locked","WithPermission","Free"]}}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x15, 0xbe, 0x4d, 0x50, 0x51, 0xa9, 0x13, 0xb2];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
     #[inline(always)]
     pub fn default_unit_of_measurement() -> Option<String> {
         None
     }
 }
 
-#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize, Default)]
+impl Default for Parameter {
+    fn default() -> Self {
+        Self {
+            tag: Default::default(),
+            name: Default::default(),
+            identifier: Default::default(),
+            unit_of_measurement: Self::default_unit_of_measurement(),
+            value: Default::default(),
+            override_mode: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Parameter {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
 #[serde(default)]
 pub struct Subset {
     #[serde(rename = "Name")]
@@ -86,5 +200,34 @@ pub struct Subset {
     pub subsets: Option<Vec<crate::com::my_site::machines::Subset>>,
 }
 
-impl Subset {}
+impl Subset {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Subset","namespace":"com.MySite.Machines","fields":[{"name":"Name","type":"string"},{"name":"Identifier","type":["null","string"],"doc":"Technical identifier, unique in the structure of the machine."},{"name":"Parameters","type":["null",{"type":"array","items":{"type":"record","name":"Parameter","namespace":"com.MySite.Machines","fields":[{"name":"Tag","type":["null","string"]},{"name":"Name","type":"string"},{"name":"Identifier","type":"int","doc":"Parameter technical identfier"},{"name":"UnitOfMeasurement","type":["null","string"],"default":null},{"name":"Value","type":["null","double"],"doc":"Contains the value of the parameter in double type"},{"name":"OverrideMode","type":{"type":"enum","name":"OverrideMode","symbols":["Locked","WithPermission","Free"]}}]}}]},{"name":"Subsets","type":["null",{"type":"array","items":"Subset"}]}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x30, 0x9d, 0xcf, 0x2a, 0x08, 0x0c, 0xd5, 0x99];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl Default for Subset {
+    fn default() -> Self {
+        Self {
+            name: Default::default(),
+            identifier: Default::default(),
+            parameters: Default::default(),
+            subsets: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Subset {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
 
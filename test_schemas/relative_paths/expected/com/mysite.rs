@@ -0,0 +1,3 @@
+pub mod machines;
+pub mod shop;
+
@@ -0,0 +1,28 @@
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize, Default)]
+pub enum OrderStatus {
+    #[default]
+    Created,
+    Shipped,
+    Delivered,
+}
+
+impl OrderStatus {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"enum","name":"OrderStatus","namespace":"com.shop.status","symbols":["Created","Shipped","Delivered"]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x03, 0xa6, 0x3b, 0x0d, 0xde, 0x73, 0x0a, 0xc2];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl apache_avro::AvroSchema for OrderStatus {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
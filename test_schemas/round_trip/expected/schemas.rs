@@ -0,0 +1,3450 @@
+pub mod bytes {
+/// Serde glue of the bytes fields, serde serializes a `Vec<u8>` as a sequence and the serde deserializer of apache_avro doesn't read a sequence from `Value::Bytes`.
+///
+/// The fields use it with `#[serde(with = "Bytes")]`
+pub struct Bytes;
+
+impl Bytes {
+    pub fn serialize<T: AvroBytes, S: serde::Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize_avro_bytes(serializer)
+    }
+
+    pub fn deserialize<'de, T: AvroBytes, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_avro_bytes(deserializer)
+    }
+}
+
+/// Bytes, or a container of bytes, serialized as Avro bytes
+pub trait AvroBytes: Sized {
+    fn serialize_avro_bytes<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+
+    fn deserialize_avro_bytes<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+}
+
+impl AvroBytes for Vec<u8> {
+    fn serialize_avro_bytes<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self)
+    }
+
+    fn deserialize_avro_bytes<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_byte_buf(BytesVisitor::<Self>(std::marker::PhantomData))
+    }
+}
+
+impl<T: AvroBytes> AvroBytes for Option<T> {
+    fn serialize_avro_bytes<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Some(value) => serializer.serialize_some(&BytesValue(value)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    fn deserialize_avro_bytes<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_option(BytesVisitor::<Self>(std::marker::PhantomData))
+    }
+}
+
+impl<T: AvroBytes> AvroBytes for Vec<T> {
+    fn serialize_avro_bytes<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter().map(BytesValue))
+    }
+
+    fn deserialize_avro_bytes<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(BytesVisitor::<Self>(std::marker::PhantomData))
+    }
+}
+
+impl<T: AvroBytes> AvroBytes for std::collections::HashMap<String, T> {
+    fn serialize_avro_bytes<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter().map(|(key, value)| (key, BytesValue(value))))
+    }
+
+    fn deserialize_avro_bytes<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(BytesVisitor::<Self>(std::marker::PhantomData))
+    }
+}
+
+/// Serialize bytes inside a container
+struct BytesValue<'a, T>(&'a T);
+
+impl<T: AvroBytes> serde::Serialize for BytesValue<'_, T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize_avro_bytes(serializer)
+    }
+}
+
+/// Deserialize bytes, or the bytes of a container
+struct BytesVisitor<T>(std::marker::PhantomData<T>);
+
+impl<'de, T: AvroBytes> serde::de::DeserializeSeed<'de> for BytesVisitor<T> {
+    type Value = T;
+
+    fn deserialize<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_avro_bytes(deserializer)
+    }
+}
+
+impl<'de> serde::de::Visitor<'de> for BytesVisitor<Vec<u8>> {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("bytes")
+    }
+
+    fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+        Ok(bytes.to_vec())
+    }
+
+    fn visit_byte_buf<E: serde::de::Error>(self, bytes: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(bytes)
+    }
+}
+
+impl<'de, T: AvroBytes> serde::de::Visitor<'de> for BytesVisitor<Option<T>> {
+    type Value = Option<T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("optional bytes")
+    }
+
+    fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        T::deserialize_avro_bytes(deserializer).map(Some)
+    }
+}
+
+impl<'de, T: AvroBytes> serde::de::Visitor<'de> for BytesVisitor<Vec<T>> {
+    type Value = Vec<T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("an array of bytes")
+    }
+
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut values = Vec::new();
+        while let Some(value) = seq.next_element_seed(BytesVisitor::<T>(std::marker::PhantomData))? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+impl<'de, T: AvroBytes> serde::de::Visitor<'de> for BytesVisitor<std::collections::HashMap<String, T>> {
+    type Value = std::collections::HashMap<String, T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a map of bytes")
+    }
+
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut values = std::collections::HashMap::new();
+        while let Some(key) = map.next_key::<String>()? {
+            values.insert(key, map.next_value_seed(BytesVisitor::<T>(std::marker::PhantomData))?);
+        }
+        Ok(values)
+    }
+}
+
+}
+
+pub mod com {
+pub mod example {
+pub mod billing {
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Subscription {
+    pub plan: String,
+    pub period: crate::generated::duration::Duration,
+    #[serde(default = "Subscription::default_trial")]
+    pub trial: Option<crate::generated::duration::Duration>,
+    #[serde(default = "Subscription::default_grace_periods")]
+    pub grace_periods: Vec<crate::generated::duration::Duration>,
+    #[serde(default = "Subscription::default_reminders")]
+    pub reminders: std::collections::HashMap<String, crate::generated::duration::Duration>,
+}
+
+impl Subscription {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Subscription","namespace":"com.example.billing","fields":[{"name":"plan","type":"string"},{"name":"period","type":{"type":"fixed","name":"Period","size":12,"logicalType":"duration"}},{"name":"trial","type":["null",{"type":"fixed","name":"Trial","size":12,"logicalType":"duration"}],"default":null},{"name":"grace_periods","type":{"type":"array","items":{"type":"fixed","name":"Grace","size":12,"logicalType":"duration"}},"default":[]},{"name":"reminders","type":{"type":"map","values":{"type":"fixed","name":"Reminder","size":12,"logicalType":"duration"}},"default":{}}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x4a, 0x07, 0xda, 0x76, 0xbc, 0x29, 0x47, 0xb0];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    /// Header of the single-object encoding: the C3 01 marker and the Rabin fingerprint
+    pub const SINGLE_OBJECT_HEADER: [u8; 10] = [0xc3, 0x01, 0x4a, 0x07, 0xda, 0x76, 0xbc, 0x29, 0x47, 0xb0];
+
+    /// Encode the record with the single-object encoding: the header then the binary encoded record
+    pub fn to_single_object(&self) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = crate::generated::values::resolve(apache_avro::to_value(self)?, Self::schema())?;
+        let mut bytes = Self::SINGLE_OBJECT_HEADER.to_vec();
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the single-object encoding, a different header returns a `SingleObjectHeaderMismatch` error
+    pub fn from_single_object(bytes: &[u8]) -> Result<Self, apache_avro::Error> {
+        let header: [u8; 10] = bytes.get(..10).and_then(|h| h.try_into().ok()).unwrap_or_default();
+        if header != Self::SINGLE_OBJECT_HEADER {
+            return Err(apache_avro::Error::SingleObjectHeaderMismatch(Self::SINGLE_OBJECT_HEADER, header));
+        }
+        let value = apache_avro::from_avro_datum(Self::schema(), &mut &bytes[10..], None)?;
+        apache_avro::from_value(&crate::generated::duration::durations_as_fixed(value))
+    }
+
+    /// Encode the record with the Confluent wire format: the magic byte 0, the schema id in big endian then the binary encoded record
+    pub fn to_confluent_bytes(&self, schema_id: u32) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = crate::generated::values::resolve(apache_avro::to_value(self)?, Self::schema())?;
+        let mut bytes = vec![0];
+        bytes.extend_from_slice(&schema_id.to_be_bytes());
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the Confluent wire format, the writer schema is found by the resolver from the schema id
+    pub fn from_confluent_bytes(bytes: &[u8], resolver: &impl crate::generated::confluent::SchemaResolver) -> Result<Self, crate::generated::confluent::ConfluentError> {
+        let (schema_id, mut body) = match bytes {
+            [0, b1, b2, b3, b4, body @ ..] => (u32::from_be_bytes([*b1, *b2, *b3, *b4]), body),
+            _ => return Err(crate::generated::confluent::ConfluentError::InvalidHeader),
+        };
+        let writer_schema = resolver.resolve_schema(schema_id).ok_or(crate::generated::confluent::ConfluentError::UnknownSchemaId(schema_id))?;
+        let reader_schema = (writer_schema != *Self::schema()).then(Self::schema);
+        let value = apache_avro::from_avro_datum(&writer_schema, &mut body, reader_schema)?;
+        Ok(apache_avro::from_value(&crate::generated::duration::durations_as_fixed(value))?)
+    }
+
+    #[inline(always)]
+    pub fn default_trial() -> Option<crate::generated::duration::Duration> {
+        None
+    }
+
+    #[inline(always)]
+    pub fn default_grace_periods() -> Vec<crate::generated::duration::Duration> {
+        Vec::new()
+    }
+
+    #[inline(always)]
+    pub fn default_reminders() -> std::collections::HashMap<String, crate::generated::duration::Duration> {
+        std::collections::HashMap::new()
+    }
+}
+
+impl Default for Subscription {
+    fn default() -> Self {
+        Self {
+            plan: Default::default(),
+            period: Default::default(),
+            trial: Self::default_trial(),
+            grace_periods: Self::default_grace_periods(),
+            reminders: Self::default_reminders(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Subscription {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+}
+
+pub mod books {
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Item {
+    pub title: String,
+}
+
+impl Item {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Item","namespace":"com.example.books","fields":[{"name":"title","type":"string"}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xfa, 0xf1, 0x5c, 0x31, 0xde, 0xba, 0xe6, 0x80];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    /// Header of the single-object encoding: the C3 01 marker and the Rabin fingerprint
+    pub const SINGLE_OBJECT_HEADER: [u8; 10] = [0xc3, 0x01, 0xfa, 0xf1, 0x5c, 0x31, 0xde, 0xba, 0xe6, 0x80];
+
+    /// Encode the record with the single-object encoding: the header then the binary encoded record
+    pub fn to_single_object(&self) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = apache_avro::to_value(self)?.resolve(Self::schema())?;
+        let mut bytes = Self::SINGLE_OBJECT_HEADER.to_vec();
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the single-object encoding, a different header returns a `SingleObjectHeaderMismatch` error
+    pub fn from_single_object(bytes: &[u8]) -> Result<Self, apache_avro::Error> {
+        let header: [u8; 10] = bytes.get(..10).and_then(|h| h.try_into().ok()).unwrap_or_default();
+        if header != Self::SINGLE_OBJECT_HEADER {
+            return Err(apache_avro::Error::SingleObjectHeaderMismatch(Self::SINGLE_OBJECT_HEADER, header));
+        }
+        let value = apache_avro::from_avro_datum(Self::schema(), &mut &bytes[10..], None)?;
+        apache_avro::from_value(&value)
+    }
+
+    /// Encode the record with the Confluent wire format: the magic byte 0, the schema id in big endian then the binary encoded record
+    pub fn to_confluent_bytes(&self, schema_id: u32) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = apache_avro::to_value(self)?.resolve(Self::schema())?;
+        let mut bytes = vec![0];
+        bytes.extend_from_slice(&schema_id.to_be_bytes());
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the Confluent wire format, the writer schema is found by the resolver from the schema id
+    pub fn from_confluent_bytes(bytes: &[u8], resolver: &impl crate::generated::confluent::SchemaResolver) -> Result<Self, crate::generated::confluent::ConfluentError> {
+        let (schema_id, mut body) = match bytes {
+            [0, b1, b2, b3, b4, body @ ..] => (u32::from_be_bytes([*b1, *b2, *b3, *b4]), body),
+            _ => return Err(crate::generated::confluent::ConfluentError::InvalidHeader),
+        };
+        let writer_schema = resolver.resolve_schema(schema_id).ok_or(crate::generated::confluent::ConfluentError::UnknownSchemaId(schema_id))?;
+        let reader_schema = (writer_schema != *Self::schema()).then(Self::schema);
+        let value = apache_avro::from_avro_datum(&writer_schema, &mut body, reader_schema)?;
+        Ok(apache_avro::from_value(&value)?)
+    }
+}
+
+impl Default for Item {
+    fn default() -> Self {
+        Self {
+            title: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Item {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+}
+
+pub mod catalog {
+/// Unions with the same types in their names
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Catalog {
+    pub first: crate::generated::com::example::catalog::UnionStringItem,
+    pub second: crate::generated::com::example::catalog::UnionStringItem2,
+    pub counts: crate::generated::com::example::catalog::UnionStringArray,
+    pub totals: crate::generated::com::example::catalog::UnionStringArray2,
+    #[serde(default = "Catalog::default_other_counts")]
+    pub other_counts: Option<crate::generated::com::example::catalog::UnionStringArray>,
+}
+
+impl Catalog {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Catalog","namespace":"com.example.catalog","doc":"Unions with the same types in their names","fields":[{"name":"first","type":["string",{"type":"record","name":"Item","namespace":"com.example.books","fields":[{"name":"title","type":"string"}]}]},{"name":"second","type":["string",{"type":"record","name":"Item","namespace":"com.example.games","fields":[{"name":"players","type":"int"}]}]},{"name":"counts","type":["string",{"type":"array","items":"int"}]},{"name":"totals","type":["string",{"type":"array","items":"long"}]},{"name":"other_counts","type":["null","string",{"type":"array","items":"int"}],"default":null}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xeb, 0xf6, 0x1d, 0x61, 0x16, 0x55, 0xef, 0x3e];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    /// Header of the single-object encoding: the C3 01 marker and the Rabin fingerprint
+    pub const SINGLE_OBJECT_HEADER: [u8; 10] = [0xc3, 0x01, 0xeb, 0xf6, 0x1d, 0x61, 0x16, 0x55, 0xef, 0x3e];
+
+    /// Encode the record with the single-object encoding: the header then the binary encoded record
+    pub fn to_single_object(&self) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = crate::generated::values::resolve(apache_avro::to_value(self)?, Self::schema())?;
+        let mut bytes = Self::SINGLE_OBJECT_HEADER.to_vec();
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the single-object encoding, a different header returns a `SingleObjectHeaderMismatch` error
+    pub fn from_single_object(bytes: &[u8]) -> Result<Self, apache_avro::Error> {
+        let header: [u8; 10] = bytes.get(..10).and_then(|h| h.try_into().ok()).unwrap_or_default();
+        if header != Self::SINGLE_OBJECT_HEADER {
+            return Err(apache_avro::Error::SingleObjectHeaderMismatch(Self::SINGLE_OBJECT_HEADER, header));
+        }
+        let value = apache_avro::from_avro_datum(Self::schema(), &mut &bytes[10..], None)?;
+        apache_avro::from_value(&crate::generated::values::union_variants(value, Self::schema()))
+    }
+
+    /// Encode the record with the Confluent wire format: the magic byte 0, the schema id in big endian then the binary encoded record
+    pub fn to_confluent_bytes(&self, schema_id: u32) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = crate::generated::values::resolve(apache_avro::to_value(self)?, Self::schema())?;
+        let mut bytes = vec![0];
+        bytes.extend_from_slice(&schema_id.to_be_bytes());
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the Confluent wire format, the writer schema is found by the resolver from the schema id
+    pub fn from_confluent_bytes(bytes: &[u8], resolver: &impl crate::generated::confluent::SchemaResolver) -> Result<Self, crate::generated::confluent::ConfluentError> {
+        let (schema_id, mut body) = match bytes {
+            [0, b1, b2, b3, b4, body @ ..] => (u32::from_be_bytes([*b1, *b2, *b3, *b4]), body),
+            _ => return Err(crate::generated::confluent::ConfluentError::InvalidHeader),
+        };
+        let writer_schema = resolver.resolve_schema(schema_id).ok_or(crate::generated::confluent::ConfluentError::UnknownSchemaId(schema_id))?;
+        let reader_schema = (writer_schema != *Self::schema()).then(Self::schema);
+        let value = apache_avro::from_avro_datum(&writer_schema, &mut body, reader_schema)?;
+        Ok(apache_avro::from_value(&crate::generated::values::union_variants(value, Self::schema()))?)
+    }
+
+    #[inline(always)]
+    pub fn default_other_counts() -> Option<crate::generated::com::example::catalog::UnionStringArray> {
+        None
+    }
+}
+
+impl Default for Catalog {
+    fn default() -> Self {
+        Self {
+            first: Default::default(),
+            second: Default::default(),
+            counts: Default::default(),
+            totals: Default::default(),
+            other_counts: Self::default_other_counts(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Catalog {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionStringArray {
+    #[serde(alias = "0")]
+    String(String),
+    #[serde(alias = "1")]
+    Array(Vec<i32>),
+}
+
+impl Default for UnionStringArray {
+    fn default() -> Self {
+        Self::String(Default::default())
+    }
+}
+
+impl UnionStringArray {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"["string",{"type":"array","items":"int"}]"#;
+}
+
+impl apache_avro::AvroSchema for UnionStringArray {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionStringArray2 {
+    #[serde(alias = "0")]
+    String(String),
+    #[serde(alias = "1")]
+    Array(Vec<i64>),
+}
+
+impl Default for UnionStringArray2 {
+    fn default() -> Self {
+        Self::String(Default::default())
+    }
+}
+
+impl UnionStringArray2 {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"["string",{"type":"array","items":"long"}]"#;
+}
+
+impl apache_avro::AvroSchema for UnionStringArray2 {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionStringItem {
+    #[serde(alias = "0")]
+    String(String),
+    #[serde(alias = "1")]
+    Item(crate::generated::com::example::books::Item),
+}
+
+impl Default for UnionStringItem {
+    fn default() -> Self {
+        Self::String(Default::default())
+    }
+}
+
+impl UnionStringItem {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"["string",{"type":"record","name":"Item","namespace":"com.example.books","fields":[{"name":"title","type":"string"}]}]"#;
+}
+
+impl apache_avro::AvroSchema for UnionStringItem {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionStringItem2 {
+    #[serde(alias = "0")]
+    String(String),
+    #[serde(alias = "1")]
+    Item(crate::generated::com::example::games::Item),
+}
+
+impl Default for UnionStringItem2 {
+    fn default() -> Self {
+        Self::String(Default::default())
+    }
+}
+
+impl UnionStringItem2 {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"["string",{"type":"record","name":"Item","namespace":"com.example.games","fields":[{"name":"players","type":"int"}]}]"#;
+}
+
+impl apache_avro::AvroSchema for UnionStringItem2 {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+}
+
+pub mod config {
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Backoff {
+    pub initial_ms: i64,
+    #[serde(default = "Backoff::default_multiplier")]
+    pub multiplier: f64,
+}
+
+impl Backoff {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Backoff","namespace":"com.example.config","fields":[{"name":"initial_ms","type":"long"},{"name":"multiplier","type":"double","default":2.0}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x1b, 0x11, 0xee, 0xf6, 0xf5, 0x3a, 0x0a, 0xbb];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    /// Header of the single-object encoding: the C3 01 marker and the Rabin fingerprint
+    pub const SINGLE_OBJECT_HEADER: [u8; 10] = [0xc3, 0x01, 0x1b, 0x11, 0xee, 0xf6, 0xf5, 0x3a, 0x0a, 0xbb];
+
+    /// Encode the record with the single-object encoding: the header then the binary encoded record
+    pub fn to_single_object(&self) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = apache_avro::to_value(self)?.resolve(Self::schema())?;
+        let mut bytes = Self::SINGLE_OBJECT_HEADER.to_vec();
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the single-object encoding, a different header returns a `SingleObjectHeaderMismatch` error
+    pub fn from_single_object(bytes: &[u8]) -> Result<Self, apache_avro::Error> {
+        let header: [u8; 10] = bytes.get(..10).and_then(|h| h.try_into().ok()).unwrap_or_default();
+        if header != Self::SINGLE_OBJECT_HEADER {
+            return Err(apache_avro::Error::SingleObjectHeaderMismatch(Self::SINGLE_OBJECT_HEADER, header));
+        }
+        let value = apache_avro::from_avro_datum(Self::schema(), &mut &bytes[10..], None)?;
+        apache_avro::from_value(&value)
+    }
+
+    /// Encode the record with the Confluent wire format: the magic byte 0, the schema id in big endian then the binary encoded record
+    pub fn to_confluent_bytes(&self, schema_id: u32) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = apache_avro::to_value(self)?.resolve(Self::schema())?;
+        let mut bytes = vec![0];
+        bytes.extend_from_slice(&schema_id.to_be_bytes());
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the Confluent wire format, the writer schema is found by the resolver from the schema id
+    pub fn from_confluent_bytes(bytes: &[u8], resolver: &impl crate::generated::confluent::SchemaResolver) -> Result<Self, crate::generated::confluent::ConfluentError> {
+        let (schema_id, mut body) = match bytes {
+            [0, b1, b2, b3, b4, body @ ..] => (u32::from_be_bytes([*b1, *b2, *b3, *b4]), body),
+            _ => return Err(crate::generated::confluent::ConfluentError::InvalidHeader),
+        };
+        let writer_schema = resolver.resolve_schema(schema_id).ok_or(crate::generated::confluent::ConfluentError::UnknownSchemaId(schema_id))?;
+        let reader_schema = (writer_schema != *Self::schema()).then(Self::schema);
+        let value = apache_avro::from_avro_datum(&writer_schema, &mut body, reader_schema)?;
+        Ok(apache_avro::from_value(&value)?)
+    }
+
+    #[inline(always)]
+    pub fn default_multiplier() -> f64 {
+        2.0
+    }
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Self {
+            initial_ms: Default::default(),
+            multiplier: Self::default_multiplier(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Backoff {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct RetryPolicy {
+    #[serde(rename = "maxAttempts")]
+    #[serde(default = "RetryPolicy::default_max_attempts")]
+    pub max_attempts: i32,
+    pub backoff: crate::generated::com::example::config::Backoff,
+    #[serde(default = "RetryPolicy::default_codes")]
+    pub codes: Vec<i32>,
+}
+
+impl RetryPolicy {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"RetryPolicy","namespace":"com.example.config","fields":[{"name":"maxAttempts","type":"int","default":3},{"name":"backoff","type":{"type":"record","name":"Backoff","fields":[{"name":"initial_ms","type":"long"},{"name":"multiplier","type":"double","default":2.0}]}},{"name":"codes","type":{"type":"array","items":"int"},"default":[]}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xcc, 0x6a, 0xee, 0xb8, 0xda, 0xec, 0x1c, 0xb9];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    /// Header of the single-object encoding: the C3 01 marker and the Rabin fingerprint
+    pub const SINGLE_OBJECT_HEADER: [u8; 10] = [0xc3, 0x01, 0xcc, 0x6a, 0xee, 0xb8, 0xda, 0xec, 0x1c, 0xb9];
+
+    /// Encode the record with the single-object encoding: the header then the binary encoded record
+    pub fn to_single_object(&self) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = apache_avro::to_value(self)?.resolve(Self::schema())?;
+        let mut bytes = Self::SINGLE_OBJECT_HEADER.to_vec();
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the single-object encoding, a different header returns a `SingleObjectHeaderMismatch` error
+    pub fn from_single_object(bytes: &[u8]) -> Result<Self, apache_avro::Error> {
+        let header: [u8; 10] = bytes.get(..10).and_then(|h| h.try_into().ok()).unwrap_or_default();
+        if header != Self::SINGLE_OBJECT_HEADER {
+            return Err(apache_avro::Error::SingleObjectHeaderMismatch(Self::SINGLE_OBJECT_HEADER, header));
+        }
+        let value = apache_avro::from_avro_datum(Self::schema(), &mut &bytes[10..], None)?;
+        apache_avro::from_value(&value)
+    }
+
+    /// Encode the record with the Confluent wire format: the magic byte 0, the schema id in big endian then the binary encoded record
+    pub fn to_confluent_bytes(&self, schema_id: u32) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = apache_avro::to_value(self)?.resolve(Self::schema())?;
+        let mut bytes = vec![0];
+        bytes.extend_from_slice(&schema_id.to_be_bytes());
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the Confluent wire format, the writer schema is found by the resolver from the schema id
+    pub fn from_confluent_bytes(bytes: &[u8], resolver: &impl crate::generated::confluent::SchemaResolver) -> Result<Self, crate::generated::confluent::ConfluentError> {
+        let (schema_id, mut body) = match bytes {
+            [0, b1, b2, b3, b4, body @ ..] => (u32::from_be_bytes([*b1, *b2, *b3, *b4]), body),
+            _ => return Err(crate::generated::confluent::ConfluentError::InvalidHeader),
+        };
+        let writer_schema = resolver.resolve_schema(schema_id).ok_or(crate::generated::confluent::ConfluentError::UnknownSchemaId(schema_id))?;
+        let reader_schema = (writer_schema != *Self::schema()).then(Self::schema);
+        let value = apache_avro::from_avro_datum(&writer_schema, &mut body, reader_schema)?;
+        Ok(apache_avro::from_value(&value)?)
+    }
+
+    #[inline(always)]
+    pub fn default_max_attempts() -> i32 {
+        3
+    }
+
+    #[inline(always)]
+    pub fn default_codes() -> Vec<i32> {
+        Vec::new()
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: Self::default_max_attempts(),
+            backoff: Default::default(),
+            codes: Self::default_codes(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for RetryPolicy {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Configuration of a service
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Service {
+    pub name: String,
+    #[serde(default = "Service::default_retry")]
+    pub retry: crate::generated::com::example::config::RetryPolicy,
+    #[serde(default = "Service::default_fallback")]
+    pub fallback: Option<crate::generated::com::example::config::RetryPolicy>,
+    #[serde(default = "Service::default_policies")]
+    pub policies: Vec<crate::generated::com::example::config::RetryPolicy>,
+    #[serde(rename = "type")]
+    #[serde(default = "Service::default_field_type")]
+    pub field_type: String,
+}
+
+impl Service {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Service","namespace":"com.example.config","doc":"Configuration of a service","fields":[{"name":"name","type":"string"},{"name":"retry","type":{"type":"record","name":"RetryPolicy","fields":[{"name":"maxAttempts","type":"int","default":3},{"name":"backoff","type":{"type":"record","name":"Backoff","fields":[{"name":"initial_ms","type":"long"},{"name":"multiplier","type":"double","default":2.0}]}},{"name":"codes","type":{"type":"array","items":"int"},"default":[]}]},"default":{"maxAttempts":5,"backoff":{"initial_ms":100}}},{"name":"fallback","type":["RetryPolicy","null"],"default":{"backoff":{"initial_ms":10,"multiplier":1.5},"codes":[500,503]}},{"name":"policies","type":{"type":"array","items":"RetryPolicy"},"default":[{"backoff":{"initial_ms":1}}]},{"name":"type","type":"string","default":"web"}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xde, 0xd7, 0x23, 0x65, 0xa1, 0x0e, 0x01, 0xc7];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    /// Header of the single-object encoding: the C3 01 marker and the Rabin fingerprint
+    pub const SINGLE_OBJECT_HEADER: [u8; 10] = [0xc3, 0x01, 0xde, 0xd7, 0x23, 0x65, 0xa1, 0x0e, 0x01, 0xc7];
+
+    /// Encode the record with the single-object encoding: the header then the binary encoded record
+    pub fn to_single_object(&self) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = apache_avro::to_value(self)?.resolve(Self::schema())?;
+        let mut bytes = Self::SINGLE_OBJECT_HEADER.to_vec();
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the single-object encoding, a different header returns a `SingleObjectHeaderMismatch` error
+    pub fn from_single_object(bytes: &[u8]) -> Result<Self, apache_avro::Error> {
+        let header: [u8; 10] = bytes.get(..10).and_then(|h| h.try_into().ok()).unwrap_or_default();
+        if header != Self::SINGLE_OBJECT_HEADER {
+            return Err(apache_avro::Error::SingleObjectHeaderMismatch(Self::SINGLE_OBJECT_HEADER, header));
+        }
+        let value = apache_avro::from_avro_datum(Self::schema(), &mut &bytes[10..], None)?;
+        apache_avro::from_value(&value)
+    }
+
+    /// Encode the record with the Confluent wire format: the magic byte 0, the schema id in big endian then the binary encoded record
+    pub fn to_confluent_bytes(&self, schema_id: u32) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = apache_avro::to_value(self)?.resolve(Self::schema())?;
+        let mut bytes = vec![0];
+        bytes.extend_from_slice(&schema_id.to_be_bytes());
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the Confluent wire format, the writer schema is found by the resolver from the schema id
+    pub fn from_confluent_bytes(bytes: &[u8], resolver: &impl crate::generated::confluent::SchemaResolver) -> Result<Self, crate::generated::confluent::ConfluentError> {
+        let (schema_id, mut body) = match bytes {
+            [0, b1, b2, b3, b4, body @ ..] => (u32::from_be_bytes([*b1, *b2, *b3, *b4]), body),
+            _ => return Err(crate::generated::confluent::ConfluentError::InvalidHeader),
+        };
+        let writer_schema = resolver.resolve_schema(schema_id).ok_or(crate::generated::confluent::ConfluentError::UnknownSchemaId(schema_id))?;
+        let reader_schema = (writer_schema != *Self::schema()).then(Self::schema);
+        let value = apache_avro::from_avro_datum(&writer_schema, &mut body, reader_schema)?;
+        Ok(apache_avro::from_value(&value)?)
+    }
+
+    #[inline(always)]
+    pub fn default_retry() -> crate::generated::com::example::config::RetryPolicy {
+        crate::generated::com::example::config::RetryPolicy { max_attempts: 5, backoff: crate::generated::com::example::config::Backoff { initial_ms: 100, multiplier: 2.0 }, codes: Vec::new() }
+    }
+
+    #[inline(always)]
+    pub fn default_fallback() -> Option<crate::generated::com::example::config::RetryPolicy> {
+        Some(crate::generated::com::example::config::RetryPolicy { max_attempts: 3, backoff: crate::generated::com::example::config::Backoff { initial_ms: 10, multiplier: 1.5 }, codes: vec![500, 503] })
+    }
+
+    #[inline(always)]
+    pub fn default_policies() -> Vec<crate::generated::com::example::config::RetryPolicy> {
+        vec![crate::generated::com::example::config::RetryPolicy { max_attempts: 3, backoff: crate::generated::com::example::config::Backoff { initial_ms: 1, multiplier: 2.0 }, codes: Vec::new() }]
+    }
+
+    #[inline(always)]
+    pub fn default_field_type() -> String {
+        "web".to_string()
+    }
+}
+
+impl Default for Service {
+    fn default() -> Self {
+        Self {
+            name: Default::default(),
+            retry: Self::default_retry(),
+            fallback: Self::default_fallback(),
+            policies: Self::default_policies(),
+            field_type: Self::default_field_type(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Service {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+}
+
+pub mod crypto {
+/// Avro fixed of 64 bytes
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Signature(pub [u8; 64]);
+
+impl Signature {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"fixed","name":"Signature","namespace":"com.example.crypto","size":64}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x9b, 0x30, 0xb5, 0xa4, 0xe8, 0x98, 0x4a, 0x29];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl apache_avro::AvroSchema for Signature {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+impl Default for Signature {
+    fn default() -> Self {
+        Self([0; 64])
+    }
+}
+
+impl AsRef<[u8]> for Signature {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<[u8; 64]> for Signature {
+    fn from(bytes: [u8; 64]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl TryFrom<&[u8]> for Signature {
+    type Error = std::array::TryFromSliceError;
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        bytes.try_into().map(Self)
+    }
+}
+
+impl std::fmt::Display for Signature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+impl serde::Serialize for Signature {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.0)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Signature {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FixedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for FixedVisitor {
+            type Value = Signature;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("64 bytes")
+            }
+
+            fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<Signature, E> {
+                Signature::try_from(bytes).map_err(|_| E::invalid_length(bytes.len(), &self))
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Signature, A::Error> {
+                let mut bytes = [0; 64];
+                for (index, byte) in bytes.iter_mut().enumerate() {
+                    *byte = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(index, &self))?;
+                }
+                if seq.next_element::<u8>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(65, &self));
+                }
+                Ok(Signature(bytes))
+            }
+        }
+
+        deserializer.deserialize_bytes(FixedVisitor)
+    }
+}
+
+}
+
+pub mod devices {
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Device {
+    #[serde(with = "crate::generated::bytes::Bytes")]
+    #[serde(default = "Device::default_firmware")]
+    pub firmware: Vec<u8>,
+    #[serde(with = "crate::generated::bytes::Bytes")]
+    #[serde(default = "Device::default_empty_bytes")]
+    pub empty_bytes: Vec<u8>,
+    #[serde(default = "Device::default_mac")]
+    pub mac: crate::generated::com::example::devices::Mac,
+    #[serde(default = "Device::default_state")]
+    pub state: crate::generated::com::example::devices::State,
+    #[serde(default = "Device::default_labels")]
+    pub labels: std::collections::HashMap<String, String>,
+    #[serde(default = "Device::default_states")]
+    pub states: std::collections::HashMap<String, crate::generated::com::example::devices::State>,
+    #[serde(default = "Device::default_ratio")]
+    pub ratio: f32,
+    #[serde(default = "Device::default_description")]
+    pub description: String,
+    #[serde(default = "Device::default_alias")]
+    pub alias: Option<String>,
+    #[serde(default = "Device::default_level")]
+    pub level: Option<i32>,
+    #[serde(default = "Device::default_value")]
+    pub value: Option<crate::generated::com::example::devices::UnionLongString>,
+    #[serde(default = "Device::default_reading")]
+    pub reading: crate::generated::com::example::devices::UnionStateDouble,
+    #[serde(default = "Device::default_states_history")]
+    pub states_history: Vec<crate::generated::com::example::devices::State>,
+}
+
+impl Device {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Device","namespace":"com.example.devices","fields":[{"name":"firmware","type":"bytes","default":"ÿ\u0001A"},{"name":"empty_bytes","type":"bytes","default":""},{"name":"mac","type":{"type":"fixed","name":"Mac","size":6},"default":"\u0000\u001a+<Mÿ"},{"name":"state","type":{"type":"enum","name":"State","symbols":["ON","OFF","standby"]},"default":"standby"},{"name":"labels","type":{"type":"map","values":"string"},"default":{"room":"kitchen","floor":"1"}},{"name":"states","type":{"type":"map","values":"State"},"default":{"main":"ON"}},{"name":"ratio","type":"float","default":1},{"name":"description","type":"string","default":"A \"quoted\" text\n"},{"name":"alias","type":["null","string"],"default":null},{"name":"level","type":["int","null"],"default":3},{"name":"value","type":["long","string","null"],"default":12},{"name":"reading","type":["State","double"],"default":"OFF"},{"name":"states_history","type":{"type":"array","items":"State"},"default":["ON","OFF"]}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x77, 0xf0, 0x75, 0xcf, 0x51, 0x3e, 0xb9, 0xcf];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    /// Header of the single-object encoding: the C3 01 marker and the Rabin fingerprint
+    pub const SINGLE_OBJECT_HEADER: [u8; 10] = [0xc3, 0x01, 0x77, 0xf0, 0x75, 0xcf, 0x51, 0x3e, 0xb9, 0xcf];
+
+    /// Encode the record with the single-object encoding: the header then the binary encoded record
+    pub fn to_single_object(&self) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = crate::generated::values::resolve(apache_avro::to_value(self)?, Self::schema())?;
+        let mut bytes = Self::SINGLE_OBJECT_HEADER.to_vec();
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the single-object encoding, a different header returns a `SingleObjectHeaderMismatch` error
+    pub fn from_single_object(bytes: &[u8]) -> Result<Self, apache_avro::Error> {
+        let header: [u8; 10] = bytes.get(..10).and_then(|h| h.try_into().ok()).unwrap_or_default();
+        if header != Self::SINGLE_OBJECT_HEADER {
+            return Err(apache_avro::Error::SingleObjectHeaderMismatch(Self::SINGLE_OBJECT_HEADER, header));
+        }
+        let value = apache_avro::from_avro_datum(Self::schema(), &mut &bytes[10..], None)?;
+        apache_avro::from_value(&crate::generated::values::union_variants(value, Self::schema()))
+    }
+
+    /// Encode the record with the Confluent wire format: the magic byte 0, the schema id in big endian then the binary encoded record
+    pub fn to_confluent_bytes(&self, schema_id: u32) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = crate::generated::values::resolve(apache_avro::to_value(self)?, Self::schema())?;
+        let mut bytes = vec![0];
+        bytes.extend_from_slice(&schema_id.to_be_bytes());
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the Confluent wire format, the writer schema is found by the resolver from the schema id
+    pub fn from_confluent_bytes(bytes: &[u8], resolver: &impl crate::generated::confluent::SchemaResolver) -> Result<Self, crate::generated::confluent::ConfluentError> {
+        let (schema_id, mut body) = match bytes {
+            [0, b1, b2, b3, b4, body @ ..] => (u32::from_be_bytes([*b1, *b2, *b3, *b4]), body),
+            _ => return Err(crate::generated::confluent::ConfluentError::InvalidHeader),
+        };
+        let writer_schema = resolver.resolve_schema(schema_id).ok_or(crate::generated::confluent::ConfluentError::UnknownSchemaId(schema_id))?;
+        let reader_schema = (writer_schema != *Self::schema()).then(Self::schema);
+        let value = apache_avro::from_avro_datum(&writer_schema, &mut body, reader_schema)?;
+        Ok(apache_avro::from_value(&crate::generated::values::union_variants(value, Self::schema()))?)
+    }
+
+    #[inline(always)]
+    pub fn default_firmware() -> Vec<u8> {
+        vec![0xff, 0x01, 0x41]
+    }
+
+    #[inline(always)]
+    pub fn default_empty_bytes() -> Vec<u8> {
+        Vec::new()
+    }
+
+    #[inline(always)]
+    pub fn default_mac() -> crate::generated::com::example::devices::Mac {
+        crate::generated::com::example::devices::Mac([0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0xff])
+    }
+
+    #[inline(always)]
+    pub fn default_state() -> crate::generated::com::example::devices::State {
+        crate::generated::com::example::devices::State::Standby
+    }
+
+    #[inline(always)]
+    pub fn default_labels() -> std::collections::HashMap<String, String> {
+        std::collections::HashMap::from([("room".to_string(), "kitchen".to_string()), ("floor".to_string(), "1".to_string())])
+    }
+
+    #[inline(always)]
+    pub fn default_states() -> std::collections::HashMap<String, crate::generated::com::example::devices::State> {
+        std::collections::HashMap::from([("main".to_string(), crate::generated::com::example::devices::State::On)])
+    }
+
+    #[inline(always)]
+    pub fn default_ratio() -> f32 {
+        1.0
+    }
+
+    #[inline(always)]
+    pub fn default_description() -> String {
+        "A \"quoted\" text\n".to_string()
+    }
+
+    #[inline(always)]
+    pub fn default_alias() -> Option<String> {
+        None
+    }
+
+    #[inline(always)]
+    pub fn default_level() -> Option<i32> {
+        Some(3)
+    }
+
+    #[inline(always)]
+    pub fn default_value() -> Option<crate::generated::com::example::devices::UnionLongString> {
+        Some(crate::generated::com::example::devices::UnionLongString::Long(12))
+    }
+
+    #[inline(always)]
+    pub fn default_reading() -> crate::generated::com::example::devices::UnionStateDouble {
+        crate::generated::com::example::devices::UnionStateDouble::State(crate::generated::com::example::devices::State::Off)
+    }
+
+    #[inline(always)]
+    pub fn default_states_history() -> Vec<crate::generated::com::example::devices::State> {
+        vec![crate::generated::com::example::devices::State::On, crate::generated::com::example::devices::State::Off]
+    }
+}
+
+impl Default for Device {
+    fn default() -> Self {
+        Self {
+            firmware: Self::default_firmware(),
+            empty_bytes: Self::default_empty_bytes(),
+            mac: Self::default_mac(),
+            state: Self::default_state(),
+            labels: Self::default_labels(),
+            states: Self::default_states(),
+            ratio: Self::default_ratio(),
+            description: Self::default_description(),
+            alias: Self::default_alias(),
+            level: Self::default_level(),
+            value: Self::default_value(),
+            reading: Self::default_reading(),
+            states_history: Self::default_states_history(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Device {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Avro fixed of 6 bytes
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub struct Mac(pub [u8; 6]);
+
+impl Mac {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"fixed","name":"Mac","namespace":"com.example.devices","size":6}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x2b, 0x52, 0xc6, 0xdd, 0xf4, 0xb6, 0xc7, 0x57];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl apache_avro::AvroSchema for Mac {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+impl AsRef<[u8]> for Mac {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<[u8; 6]> for Mac {
+    fn from(bytes: [u8; 6]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl TryFrom<&[u8]> for Mac {
+    type Error = std::array::TryFromSliceError;
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        bytes.try_into().map(Self)
+    }
+}
+
+impl std::fmt::Display for Mac {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+impl serde::Serialize for Mac {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.0)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Mac {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FixedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for FixedVisitor {
+            type Value = Mac;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("6 bytes")
+            }
+
+            fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<Mac, E> {
+                Mac::try_from(bytes).map_err(|_| E::invalid_length(bytes.len(), &self))
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Mac, A::Error> {
+                let mut bytes = [0; 6];
+                for (index, byte) in bytes.iter_mut().enumerate() {
+                    *byte = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(index, &self))?;
+                }
+                if seq.next_element::<u8>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(7, &self));
+                }
+                Ok(Mac(bytes))
+            }
+        }
+
+        deserializer.deserialize_bytes(FixedVisitor)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize, Default)]
+pub enum State {
+    #[default]
+    #[serde(rename = "ON")]
+    On,
+    #[serde(rename = "OFF")]
+    Off,
+    #[serde(rename = "standby")]
+    Standby,
+}
+
+impl State {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"enum","name":"State","namespace":"com.example.devices","symbols":["ON","OFF","standby"]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x08, 0xd9, 0x99, 0x0f, 0x10, 0xbc, 0xd7, 0x01];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl apache_avro::AvroSchema for State {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionLongString {
+    #[serde(alias = "0")]
+    Long(i64),
+    #[serde(alias = "1")]
+    String(String),
+}
+
+impl Default for UnionLongString {
+    fn default() -> Self {
+        Self::Long(Default::default())
+    }
+}
+
+impl UnionLongString {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"["long","string"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionLongString {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionStateDouble {
+    #[serde(alias = "0")]
+    State(crate::generated::com::example::devices::State),
+    #[serde(alias = "1")]
+    Double(f64),
+}
+
+impl Default for UnionStateDouble {
+    fn default() -> Self {
+        Self::State(Default::default())
+    }
+}
+
+impl UnionStateDouble {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"[{"type":"enum","name":"State","namespace":"com.example.devices","symbols":["ON","OFF","standby"]},"double"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionStateDouble {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+}
+
+pub mod events {
+/// Event with dates, times and identifiers
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Event {
+    #[serde(with = "crate::generated::logical_types::Uuid")]
+    #[serde(default = "Event::default_id")]
+    pub id: uuid::Uuid,
+    #[serde(with = "crate::generated::logical_types::Date")]
+    #[serde(default = "Event::default_day")]
+    pub day: chrono::NaiveDate,
+    #[serde(with = "crate::generated::logical_types::TimeMillis")]
+    pub opening: chrono::NaiveTime,
+    #[serde(with = "crate::generated::logical_types::TimeMicros")]
+    #[serde(default = "Event::default_closing")]
+    pub closing: chrono::NaiveTime,
+    #[serde(with = "crate::generated::logical_types::TimestampMillis")]
+    #[serde(default = "Event::default_created")]
+    pub created: chrono::DateTime<chrono::Utc>,
+    #[serde(with = "crate::generated::logical_types::TimestampMicros")]
+    #[serde(default = "Event::default_updated")]
+    pub updated: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(with = "crate::generated::logical_types::LocalTimestampMillis")]
+    #[serde(default = "Event::default_local_created")]
+    pub local_created: chrono::NaiveDateTime,
+    #[serde(with = "crate::generated::logical_types::LocalTimestampMicros")]
+    pub local_updated: chrono::NaiveDateTime,
+    #[serde(with = "crate::generated::logical_types::Date")]
+    pub holidays: Vec<chrono::NaiveDate>,
+    #[serde(with = "crate::generated::logical_types::TimestampMillis")]
+    pub checkpoints: std::collections::HashMap<String, chrono::DateTime<chrono::Utc>>,
+    pub reference: crate::generated::com::example::events::UnionUuidLong,
+}
+
+impl Event {
+    /// JSON of the Avro schema
+    ///
+    /// The defaults of `com.example.events.Event.day` are removed, apache_avro rejects them: the `Default` impl still uses them,
+    /// and the fingerprints of the Parsing Canonical Form don't depend on the defaults
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Event","namespace":"com.example.events","doc":"Event with dates, times and identifiers","fields":[{"name":"id","type":{"type":"string","logicalType":"uuid"},"default":"5f0c6a2e-8d1b-4c3e-9a7f-2b6d4e8f1a3c"},{"name":"day","type":{"type":"int","logicalType":"date"}},{"name":"opening","type":{"type":"int","logicalType":"time-millis"}},{"name":"closing","type":{"type":"long","logicalType":"time-micros"},"default":64800000000},{"name":"created","type":{"type":"long","logicalType":"timestamp-millis"},"default":1705312800000},{"name":"updated","type":["null",{"type":"long","logicalType":"timestamp-micros"}],"default":null},{"name":"local_created","type":{"type":"long","logicalType":"local-timestamp-millis"},"default":1705312800000},{"name":"local_updated","type":{"type":"long","logicalType":"local-timestamp-micros"}},{"name":"holidays","type":{"type":"array","items":{"type":"int","logicalType":"date"}}},{"name":"checkpoints","type":{"type":"map","values":{"type":"long","logicalType":"timestamp-millis"}}},{"name":"reference","type":[{"type":"string","logicalType":"uuid"},"long"]}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xb8, 0x66, 0x3a, 0x09, 0x30, 0x58, 0xa9, 0x1c];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    /// Header of the single-object encoding: the C3 01 marker and the Rabin fingerprint
+    pub const SINGLE_OBJECT_HEADER: [u8; 10] = [0xc3, 0x01, 0xb8, 0x66, 0x3a, 0x09, 0x30, 0x58, 0xa9, 0x1c];
+
+    /// Encode the record with the single-object encoding: the header then the binary encoded record
+    pub fn to_single_object(&self) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = crate::generated::values::resolve(apache_avro::to_value(self)?, Self::schema())?;
+        let mut bytes = Self::SINGLE_OBJECT_HEADER.to_vec();
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the single-object encoding, a different header returns a `SingleObjectHeaderMismatch` error
+    pub fn from_single_object(bytes: &[u8]) -> Result<Self, apache_avro::Error> {
+        let header: [u8; 10] = bytes.get(..10).and_then(|h| h.try_into().ok()).unwrap_or_default();
+        if header != Self::SINGLE_OBJECT_HEADER {
+            return Err(apache_avro::Error::SingleObjectHeaderMismatch(Self::SINGLE_OBJECT_HEADER, header));
+        }
+        let value = apache_avro::from_avro_datum(Self::schema(), &mut &bytes[10..], None)?;
+        apache_avro::from_value(&crate::generated::values::union_variants(value, Self::schema()))
+    }
+
+    /// Encode the record with the Confluent wire format: the magic byte 0, the schema id in big endian then the binary encoded record
+    pub fn to_confluent_bytes(&self, schema_id: u32) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = crate::generated::values::resolve(apache_avro::to_value(self)?, Self::schema())?;
+        let mut bytes = vec![0];
+        bytes.extend_from_slice(&schema_id.to_be_bytes());
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the Confluent wire format, the writer schema is found by the resolver from the schema id
+    pub fn from_confluent_bytes(bytes: &[u8], resolver: &impl crate::generated::confluent::SchemaResolver) -> Result<Self, crate::generated::confluent::ConfluentError> {
+        let (schema_id, mut body) = match bytes {
+            [0, b1, b2, b3, b4, body @ ..] => (u32::from_be_bytes([*b1, *b2, *b3, *b4]), body),
+            _ => return Err(crate::generated::confluent::ConfluentError::InvalidHeader),
+        };
+        let writer_schema = resolver.resolve_schema(schema_id).ok_or(crate::generated::confluent::ConfluentError::UnknownSchemaId(schema_id))?;
+        let reader_schema = (writer_schema != *Self::schema()).then(Self::schema);
+        let value = apache_avro::from_avro_datum(&writer_schema, &mut body, reader_schema)?;
+        Ok(apache_avro::from_value(&crate::generated::values::union_variants(value, Self::schema()))?)
+    }
+
+    #[inline(always)]
+    pub fn default_id() -> uuid::Uuid {
+        uuid::uuid!("5f0c6a2e-8d1b-4c3e-9a7f-2b6d4e8f1a3c")
+    }
+
+    #[inline(always)]
+    pub fn default_day() -> chrono::NaiveDate {
+        chrono::NaiveDate::from_ymd_opt(2024, 1, 15).expect("The default value is in range")
+    }
+
+    #[inline(always)]
+    pub fn default_closing() -> chrono::NaiveTime {
+        chrono::NaiveTime::from_hms_micro_opt(18, 0, 0, 0).expect("The default value is in range")
+    }
+
+    #[inline(always)]
+    pub fn default_created() -> chrono::DateTime<chrono::Utc> {
+        chrono::DateTime::from_timestamp_millis(1705312800000).expect("The default value is in range")
+    }
+
+    #[inline(always)]
+    pub fn default_updated() -> Option<chrono::DateTime<chrono::Utc>> {
+        None
+    }
+
+    #[inline(always)]
+    pub fn default_local_created() -> chrono::NaiveDateTime {
+        chrono::DateTime::from_timestamp_millis(1705312800000).expect("The default value is in range").naive_utc()
+    }
+}
+
+impl Default for Event {
+    fn default() -> Self {
+        Self {
+            id: Self::default_id(),
+            day: Self::default_day(),
+            opening: Default::default(),
+            closing: Self::default_closing(),
+            created: Self::default_created(),
+            updated: Self::default_updated(),
+            local_created: Self::default_local_created(),
+            local_updated: Default::default(),
+            holidays: Default::default(),
+            checkpoints: Default::default(),
+            reference: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Event {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionUuidLong {
+    #[serde(alias = "0")]
+    Uuid(#[serde(with = "crate::generated::logical_types::Uuid")] uuid::Uuid),
+    #[serde(alias = "1")]
+    Long(i64),
+}
+
+impl Default for UnionUuidLong {
+    fn default() -> Self {
+        Self::Uuid(Default::default())
+    }
+}
+
+impl UnionUuidLong {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"[{"type":"string","logicalType":"uuid"},"long"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionUuidLong {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+}
+
+pub mod files {
+/// A downloaded file and its checksums
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Download {
+    pub path: String,
+    #[serde(default = "Download::default_checksum")]
+    pub checksum: crate::generated::com::example::files::Md5,
+    pub signature: crate::generated::com::example::crypto::Signature,
+    #[serde(default = "Download::default_previous_checksum")]
+    pub previous_checksum: Option<crate::generated::com::example::files::Md5>,
+    #[serde(default = "Download::default_chunk_checksums")]
+    pub chunk_checksums: Vec<crate::generated::com::example::files::Md5>,
+    #[serde(default = "Download::default_mirror_checksums")]
+    pub mirror_checksums: std::collections::HashMap<String, crate::generated::com::example::files::Md5>,
+}
+
+impl Download {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Download","namespace":"com.example.files","doc":"A downloaded file and its checksums","fields":[{"name":"path","type":"string"},{"name":"checksum","type":{"type":"fixed","name":"Md5","size":16,"doc":"MD5 digest of the content"},"default":"\u0000\u0001\u0002\u0003\u0004\u0005\u0006\u0007\b\t\n\u000b\f\r\u000e\u000f"},{"name":"signature","type":{"type":"fixed","name":"Signature","namespace":"com.example.crypto","size":64}},{"name":"previous_checksum","type":["null","Md5"],"default":null},{"name":"chunk_checksums","type":{"type":"array","items":"Md5"},"default":[]},{"name":"mirror_checksums","type":{"type":"map","values":"Md5"},"default":{}}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x8d, 0x0f, 0xd6, 0x47, 0x4f, 0x5a, 0xbb, 0xcb];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    /// Header of the single-object encoding: the C3 01 marker and the Rabin fingerprint
+    pub const SINGLE_OBJECT_HEADER: [u8; 10] = [0xc3, 0x01, 0x8d, 0x0f, 0xd6, 0x47, 0x4f, 0x5a, 0xbb, 0xcb];
+
+    /// Encode the record with the single-object encoding: the header then the binary encoded record
+    pub fn to_single_object(&self) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = crate::generated::values::resolve(apache_avro::to_value(self)?, Self::schema())?;
+        let mut bytes = Self::SINGLE_OBJECT_HEADER.to_vec();
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the single-object encoding, a different header returns a `SingleObjectHeaderMismatch` error
+    pub fn from_single_object(bytes: &[u8]) -> Result<Self, apache_avro::Error> {
+        let header: [u8; 10] = bytes.get(..10).and_then(|h| h.try_into().ok()).unwrap_or_default();
+        if header != Self::SINGLE_OBJECT_HEADER {
+            return Err(apache_avro::Error::SingleObjectHeaderMismatch(Self::SINGLE_OBJECT_HEADER, header));
+        }
+        let value = apache_avro::from_avro_datum(Self::schema(), &mut &bytes[10..], None)?;
+        apache_avro::from_value(&value)
+    }
+
+    /// Encode the record with the Confluent wire format: the magic byte 0, the schema id in big endian then the binary encoded record
+    pub fn to_confluent_bytes(&self, schema_id: u32) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = crate::generated::values::resolve(apache_avro::to_value(self)?, Self::schema())?;
+        let mut bytes = vec![0];
+        bytes.extend_from_slice(&schema_id.to_be_bytes());
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the Confluent wire format, the writer schema is found by the resolver from the schema id
+    pub fn from_confluent_bytes(bytes: &[u8], resolver: &impl crate::generated::confluent::SchemaResolver) -> Result<Self, crate::generated::confluent::ConfluentError> {
+        let (schema_id, mut body) = match bytes {
+            [0, b1, b2, b3, b4, body @ ..] => (u32::from_be_bytes([*b1, *b2, *b3, *b4]), body),
+            _ => return Err(crate::generated::confluent::ConfluentError::InvalidHeader),
+        };
+        let writer_schema = resolver.resolve_schema(schema_id).ok_or(crate::generated::confluent::ConfluentError::UnknownSchemaId(schema_id))?;
+        let reader_schema = (writer_schema != *Self::schema()).then(Self::schema);
+        let value = apache_avro::from_avro_datum(&writer_schema, &mut body, reader_schema)?;
+        Ok(apache_avro::from_value(&value)?)
+    }
+
+    #[inline(always)]
+    pub fn default_checksum() -> crate::generated::com::example::files::Md5 {
+        crate::generated::com::example::files::Md5([0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f])
+    }
+
+    #[inline(always)]
+    pub fn default_previous_checksum() -> Option<crate::generated::com::example::files::Md5> {
+        None
+    }
+
+    #[inline(always)]
+    pub fn default_chunk_checksums() -> Vec<crate::generated::com::example::files::Md5> {
+        Vec::new()
+    }
+
+    #[inline(always)]
+    pub fn default_mirror_checksums() -> std::collections::HashMap<String, crate::generated::com::example::files::Md5> {
+        std::collections::HashMap::new()
+    }
+}
+
+impl Default for Download {
+    fn default() -> Self {
+        Self {
+            path: Default::default(),
+            checksum: Self::default_checksum(),
+            signature: Default::default(),
+            previous_checksum: Self::default_previous_checksum(),
+            chunk_checksums: Self::default_chunk_checksums(),
+            mirror_checksums: Self::default_mirror_checksums(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Download {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// MD5 digest of the content
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub struct Md5(pub [u8; 16]);
+
+impl Md5 {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"fixed","name":"Md5","namespace":"com.example.files","size":16,"doc":"MD5 digest of the content"}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x0b, 0xf3, 0x1d, 0x6e, 0x48, 0x1e, 0x9e, 0xe9];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl apache_avro::AvroSchema for Md5 {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+impl AsRef<[u8]> for Md5 {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<[u8; 16]> for Md5 {
+    fn from(bytes: [u8; 16]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl TryFrom<&[u8]> for Md5 {
+    type Error = std::array::TryFromSliceError;
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        bytes.try_into().map(Self)
+    }
+}
+
+impl std::fmt::Display for Md5 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+impl serde::Serialize for Md5 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.0)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Md5 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FixedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for FixedVisitor {
+            type Value = Md5;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("16 bytes")
+            }
+
+            fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<Md5, E> {
+                Md5::try_from(bytes).map_err(|_| E::invalid_length(bytes.len(), &self))
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Md5, A::Error> {
+                let mut bytes = [0; 16];
+                for (index, byte) in bytes.iter_mut().enumerate() {
+                    *byte = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(index, &self))?;
+                }
+                if seq.next_element::<u8>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(17, &self));
+                }
+                Ok(Md5(bytes))
+            }
+        }
+
+        deserializer.deserialize_bytes(FixedVisitor)
+    }
+}
+
+}
+
+pub mod finance {
+/// Invoice with decimal amounts
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Invoice {
+    #[serde(with = "crate::generated::decimal::PrecisionScale::<10, 2>")]
+    pub amount: apache_avro::Decimal,
+    #[serde(with = "crate::generated::decimal::PrecisionScale::<18, 6>")]
+    pub rate: apache_avro::Decimal,
+    #[serde(with = "crate::generated::decimal::PrecisionScale::<5, 2>")]
+    #[serde(default = "Invoice::default_discount")]
+    pub discount: Option<apache_avro::Decimal>,
+    #[serde(with = "crate::generated::decimal::PrecisionScale::<10, 2>")]
+    pub lines: Vec<apache_avro::Decimal>,
+    #[serde(with = "crate::generated::decimal::PrecisionScale::<10, 2>")]
+    pub taxes: std::collections::HashMap<String, apache_avro::Decimal>,
+    pub adjustment: crate::generated::com::example::finance::UnionDecimalString,
+}
+
+impl Invoice {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Invoice","namespace":"com.example.finance","doc":"Invoice with decimal amounts","fields":[{"name":"amount","type":{"type":"bytes","logicalType":"decimal","precision":10,"scale":2}},{"name":"rate","type":{"type":"fixed","name":"Rate","size":8,"logicalType":"decimal","precision":18,"scale":6}},{"name":"discount","type":["null",{"type":"bytes","logicalType":"decimal","precision":5,"scale":2}],"default":null},{"name":"lines","type":{"type":"array","items":{"type":"bytes","logicalType":"decimal","precision":10,"scale":2}}},{"name":"taxes","type":{"type":"map","values":{"type":"bytes","logicalType":"decimal","precision":10,"scale":2}}},{"name":"adjustment","type":[{"type":"bytes","logicalType":"decimal","precision":10,"scale":2},"string"]}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x40, 0x52, 0x18, 0x13, 0xa9, 0x59, 0x15, 0x81];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    /// Header of the single-object encoding: the C3 01 marker and the Rabin fingerprint
+    pub const SINGLE_OBJECT_HEADER: [u8; 10] = [0xc3, 0x01, 0x40, 0x52, 0x18, 0x13, 0xa9, 0x59, 0x15, 0x81];
+
+    /// Encode the record with the single-object encoding: the header then the binary encoded record
+    pub fn to_single_object(&self) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = crate::generated::values::resolve(apache_avro::to_value(self)?, Self::schema())?;
+        let mut bytes = Self::SINGLE_OBJECT_HEADER.to_vec();
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the single-object encoding, a different header returns a `SingleObjectHeaderMismatch` error
+    pub fn from_single_object(bytes: &[u8]) -> Result<Self, apache_avro::Error> {
+        let header: [u8; 10] = bytes.get(..10).and_then(|h| h.try_into().ok()).unwrap_or_default();
+        if header != Self::SINGLE_OBJECT_HEADER {
+            return Err(apache_avro::Error::SingleObjectHeaderMismatch(Self::SINGLE_OBJECT_HEADER, header));
+        }
+        let value = apache_avro::from_avro_datum(Self::schema(), &mut &bytes[10..], None)?;
+        apache_avro::from_value(&crate::generated::decimal::decimals_as_bytes(crate::generated::values::union_variants(value, Self::schema())))
+    }
+
+    /// Encode the record with the Confluent wire format: the magic byte 0, the schema id in big endian then the binary encoded record
+    pub fn to_confluent_bytes(&self, schema_id: u32) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = crate::generated::values::resolve(apache_avro::to_value(self)?, Self::schema())?;
+        let mut bytes = vec![0];
+        bytes.extend_from_slice(&schema_id.to_be_bytes());
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the Confluent wire format, the writer schema is found by the resolver from the schema id
+    pub fn from_confluent_bytes(bytes: &[u8], resolver: &impl crate::generated::confluent::SchemaResolver) -> Result<Self, crate::generated::confluent::ConfluentError> {
+        let (schema_id, mut body) = match bytes {
+            [0, b1, b2, b3, b4, body @ ..] => (u32::from_be_bytes([*b1, *b2, *b3, *b4]), body),
+            _ => return Err(crate::generated::confluent::ConfluentError::InvalidHeader),
+        };
+        let writer_schema = resolver.resolve_schema(schema_id).ok_or(crate::generated::confluent::ConfluentError::UnknownSchemaId(schema_id))?;
+        let reader_schema = (writer_schema != *Self::schema()).then(Self::schema);
+        let value = apache_avro::from_avro_datum(&writer_schema, &mut body, reader_schema)?;
+        Ok(apache_avro::from_value(&crate::generated::decimal::decimals_as_bytes(crate::generated::values::union_variants(value, Self::schema())))?)
+    }
+
+    #[inline(always)]
+    pub fn default_discount() -> Option<apache_avro::Decimal> {
+        None
+    }
+}
+
+impl Default for Invoice {
+    fn default() -> Self {
+        Self {
+            amount: apache_avro::Decimal::from([0x00]),
+            rate: apache_avro::Decimal::from([0x00]),
+            discount: Self::default_discount(),
+            lines: Default::default(),
+            taxes: Default::default(),
+            adjustment: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Invoice {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionDecimalString {
+    #[serde(alias = "0")]
+    Decimal(#[serde(with = "crate::generated::decimal::PrecisionScale::<10, 2>")] apache_avro::Decimal),
+    #[serde(alias = "1")]
+    String(String),
+}
+
+impl Default for UnionDecimalString {
+    fn default() -> Self {
+        Self::Decimal(apache_avro::Decimal::from([0x00]))
+    }
+}
+
+impl UnionDecimalString {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"[{"type":"bytes","logicalType":"decimal","scale":2,"precision":10},"string"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionDecimalString {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+}
+
+pub mod games {
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Item {
+    pub players: i32,
+}
+
+impl Item {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Item","namespace":"com.example.games","fields":[{"name":"players","type":"int"}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xac, 0x94, 0x59, 0x94, 0x42, 0xd9, 0x67, 0x69];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    /// Header of the single-object encoding: the C3 01 marker and the Rabin fingerprint
+    pub const SINGLE_OBJECT_HEADER: [u8; 10] = [0xc3, 0x01, 0xac, 0x94, 0x59, 0x94, 0x42, 0xd9, 0x67, 0x69];
+
+    /// Encode the record with the single-object encoding: the header then the binary encoded record
+    pub fn to_single_object(&self) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = apache_avro::to_value(self)?.resolve(Self::schema())?;
+        let mut bytes = Self::SINGLE_OBJECT_HEADER.to_vec();
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the single-object encoding, a different header returns a `SingleObjectHeaderMismatch` error
+    pub fn from_single_object(bytes: &[u8]) -> Result<Self, apache_avro::Error> {
+        let header: [u8; 10] = bytes.get(..10).and_then(|h| h.try_into().ok()).unwrap_or_default();
+        if header != Self::SINGLE_OBJECT_HEADER {
+            return Err(apache_avro::Error::SingleObjectHeaderMismatch(Self::SINGLE_OBJECT_HEADER, header));
+        }
+        let value = apache_avro::from_avro_datum(Self::schema(), &mut &bytes[10..], None)?;
+        apache_avro::from_value(&value)
+    }
+
+    /// Encode the record with the Confluent wire format: the magic byte 0, the schema id in big endian then the binary encoded record
+    pub fn to_confluent_bytes(&self, schema_id: u32) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = apache_avro::to_value(self)?.resolve(Self::schema())?;
+        let mut bytes = vec![0];
+        bytes.extend_from_slice(&schema_id.to_be_bytes());
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the Confluent wire format, the writer schema is found by the resolver from the schema id
+    pub fn from_confluent_bytes(bytes: &[u8], resolver: &impl crate::generated::confluent::SchemaResolver) -> Result<Self, crate::generated::confluent::ConfluentError> {
+        let (schema_id, mut body) = match bytes {
+            [0, b1, b2, b3, b4, body @ ..] => (u32::from_be_bytes([*b1, *b2, *b3, *b4]), body),
+            _ => return Err(crate::generated::confluent::ConfluentError::InvalidHeader),
+        };
+        let writer_schema = resolver.resolve_schema(schema_id).ok_or(crate::generated::confluent::ConfluentError::UnknownSchemaId(schema_id))?;
+        let reader_schema = (writer_schema != *Self::schema()).then(Self::schema);
+        let value = apache_avro::from_avro_datum(&writer_schema, &mut body, reader_schema)?;
+        Ok(apache_avro::from_value(&value)?)
+    }
+}
+
+impl Default for Item {
+    fn default() -> Self {
+        Self {
+            players: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Item {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+}
+
+pub mod unions {
+/// An union for each kind of type
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Branches {
+    pub integer: crate::generated::com::example::unions::UnionIntLong,
+    pub real: crate::generated::com::example::unions::UnionFloatDouble,
+    pub color: crate::generated::com::example::unions::UnionColorString,
+    pub signal: crate::generated::com::example::unions::UnionColorLight,
+    pub content: crate::generated::com::example::unions::UnionStringBytes,
+    pub hash: crate::generated::com::example::unions::UnionHashBytes,
+    pub amount: crate::generated::com::example::unions::UnionDecimalString,
+    pub delay: crate::generated::com::example::unions::UnionDurationString,
+    pub day: crate::generated::com::example::unions::UnionDateString,
+    #[serde(default = "Branches::default_optional")]
+    pub optional: Option<crate::generated::com::example::unions::UnionIntLong>,
+    pub integers: Vec<crate::generated::com::example::unions::UnionIntLong>,
+    pub reals: std::collections::HashMap<String, crate::generated::com::example::unions::UnionFloatDouble>,
+}
+
+impl Branches {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Branches","namespace":"com.example.unions","doc":"An union for each kind of type","fields":[{"name":"integer","type":["int","long"]},{"name":"real","type":["float","double"]},{"name":"color","type":[{"type":"enum","name":"Color","symbols":["RED","GREEN"]},"string"]},{"name":"signal","type":["Color",{"type":"enum","name":"Light","symbols":["GREEN","RED"]}]},{"name":"content","type":["string","bytes"]},{"name":"hash","type":[{"type":"fixed","name":"Hash","size":4},"bytes"]},{"name":"amount","type":[{"type":"bytes","logicalType":"decimal","precision":10,"scale":2},"string"]},{"name":"delay","type":[{"type":"fixed","name":"Delay","size":12,"logicalType":"duration"},"string"]},{"name":"day","type":[{"type":"int","logicalType":"date"},"string"]},{"name":"optional","type":["null","int","long"],"default":null},{"name":"integers","type":{"type":"array","items":["int","long"]}},{"name":"reals","type":{"type":"map","values":["float","double"]}}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xd1, 0x3c, 0x1e, 0xea, 0x52, 0x2d, 0x81, 0x31];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    /// Header of the single-object encoding: the C3 01 marker and the Rabin fingerprint
+    pub const SINGLE_OBJECT_HEADER: [u8; 10] = [0xc3, 0x01, 0xd1, 0x3c, 0x1e, 0xea, 0x52, 0x2d, 0x81, 0x31];
+
+    /// Encode the record with the single-object encoding: the header then the binary encoded record
+    pub fn to_single_object(&self) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = crate::generated::values::resolve(apache_avro::to_value(self)?, Self::schema())?;
+        let mut bytes = Self::SINGLE_OBJECT_HEADER.to_vec();
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the single-object encoding, a different header returns a `SingleObjectHeaderMismatch` error
+    pub fn from_single_object(bytes: &[u8]) -> Result<Self, apache_avro::Error> {
+        let header: [u8; 10] = bytes.get(..10).and_then(|h| h.try_into().ok()).unwrap_or_default();
+        if header != Self::SINGLE_OBJECT_HEADER {
+            return Err(apache_avro::Error::SingleObjectHeaderMismatch(Self::SINGLE_OBJECT_HEADER, header));
+        }
+        let value = apache_avro::from_avro_datum(Self::schema(), &mut &bytes[10..], None)?;
+        apache_avro::from_value(&crate::generated::decimal::decimals_as_bytes(crate::generated::duration::durations_as_fixed(crate::generated::values::union_variants(value, Self::schema()))))
+    }
+
+    /// Encode the record with the Confluent wire format: the magic byte 0, the schema id in big endian then the binary encoded record
+    pub fn to_confluent_bytes(&self, schema_id: u32) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = crate::generated::values::resolve(apache_avro::to_value(self)?, Self::schema())?;
+        let mut bytes = vec![0];
+        bytes.extend_from_slice(&schema_id.to_be_bytes());
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the Confluent wire format, the writer schema is found by the resolver from the schema id
+    pub fn from_confluent_bytes(bytes: &[u8], resolver: &impl crate::generated::confluent::SchemaResolver) -> Result<Self, crate::generated::confluent::ConfluentError> {
+        let (schema_id, mut body) = match bytes {
+            [0, b1, b2, b3, b4, body @ ..] => (u32::from_be_bytes([*b1, *b2, *b3, *b4]), body),
+            _ => return Err(crate::generated::confluent::ConfluentError::InvalidHeader),
+        };
+        let writer_schema = resolver.resolve_schema(schema_id).ok_or(crate::generated::confluent::ConfluentError::UnknownSchemaId(schema_id))?;
+        let reader_schema = (writer_schema != *Self::schema()).then(Self::schema);
+        let value = apache_avro::from_avro_datum(&writer_schema, &mut body, reader_schema)?;
+        Ok(apache_avro::from_value(&crate::generated::decimal::decimals_as_bytes(crate::generated::duration::durations_as_fixed(crate::generated::values::union_variants(value, Self::schema()))))?)
+    }
+
+    #[inline(always)]
+    pub fn default_optional() -> Option<crate::generated::com::example::unions::UnionIntLong> {
+        None
+    }
+}
+
+impl Default for Branches {
+    fn default() -> Self {
+        Self {
+            integer: Default::default(),
+            real: Default::default(),
+            color: Default::default(),
+            signal: Default::default(),
+            content: Default::default(),
+            hash: Default::default(),
+            amount: Default::default(),
+            delay: Default::default(),
+            day: Default::default(),
+            optional: Self::default_optional(),
+            integers: Default::default(),
+            reals: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Branches {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize, Default)]
+pub enum Color {
+    #[default]
+    #[serde(rename = "RED")]
+    Red,
+    #[serde(rename = "GREEN")]
+    Green,
+}
+
+impl Color {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"enum","name":"Color","namespace":"com.example.unions","symbols":["RED","GREEN"]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xd0, 0xb0, 0x2c, 0xf7, 0x6f, 0x53, 0xeb, 0x10];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl apache_avro::AvroSchema for Color {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Avro fixed of 4 bytes
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub struct Hash(pub [u8; 4]);
+
+impl Hash {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"fixed","name":"Hash","namespace":"com.example.unions","size":4}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x3c, 0x89, 0xbd, 0x6e, 0x31, 0x87, 0xe0, 0x8a];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl apache_avro::AvroSchema for Hash {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+impl AsRef<[u8]> for Hash {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<[u8; 4]> for Hash {
+    fn from(bytes: [u8; 4]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl TryFrom<&[u8]> for Hash {
+    type Error = std::array::TryFromSliceError;
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        bytes.try_into().map(Self)
+    }
+}
+
+impl std::fmt::Display for Hash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+impl serde::Serialize for Hash {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.0)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Hash {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FixedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for FixedVisitor {
+            type Value = Hash;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("4 bytes")
+            }
+
+            fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<Hash, E> {
+                Hash::try_from(bytes).map_err(|_| E::invalid_length(bytes.len(), &self))
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Hash, A::Error> {
+                let mut bytes = [0; 4];
+                for (index, byte) in bytes.iter_mut().enumerate() {
+                    *byte = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(index, &self))?;
+                }
+                if seq.next_element::<u8>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(5, &self));
+                }
+                Ok(Hash(bytes))
+            }
+        }
+
+        deserializer.deserialize_bytes(FixedVisitor)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize, Default)]
+pub enum Light {
+    #[default]
+    #[serde(rename = "GREEN")]
+    Green,
+    #[serde(rename = "RED")]
+    Red,
+}
+
+impl Light {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"enum","name":"Light","namespace":"com.example.unions","symbols":["GREEN","RED"]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x2a, 0x05, 0x45, 0x06, 0xee, 0x1e, 0x1f, 0x95];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl apache_avro::AvroSchema for Light {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionColorLight {
+    #[serde(alias = "0")]
+    Color(crate::generated::com::example::unions::Color),
+    #[serde(alias = "1")]
+    Light(crate::generated::com::example::unions::Light),
+}
+
+impl Default for UnionColorLight {
+    fn default() -> Self {
+        Self::Color(Default::default())
+    }
+}
+
+impl UnionColorLight {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"[{"type":"enum","name":"Color","namespace":"com.example.unions","symbols":["RED","GREEN"]},{"type":"enum","name":"Light","namespace":"com.example.unions","symbols":["GREEN","RED"]}]"#;
+}
+
+impl apache_avro::AvroSchema for UnionColorLight {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionColorString {
+    #[serde(alias = "0")]
+    Color(crate::generated::com::example::unions::Color),
+    #[serde(alias = "1")]
+    String(String),
+}
+
+impl Default for UnionColorString {
+    fn default() -> Self {
+        Self::Color(Default::default())
+    }
+}
+
+impl UnionColorString {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"[{"type":"enum","name":"Color","namespace":"com.example.unions","symbols":["RED","GREEN"]},"string"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionColorString {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionDateString {
+    #[serde(alias = "0")]
+    Date(#[serde(with = "crate::generated::logical_types::Date")] chrono::NaiveDate),
+    #[serde(alias = "1")]
+    String(String),
+}
+
+impl Default for UnionDateString {
+    fn default() -> Self {
+        Self::Date(Default::default())
+    }
+}
+
+impl UnionDateString {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"[{"type":"int","logicalType":"date"},"string"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionDateString {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionDecimalString {
+    #[serde(alias = "0")]
+    Decimal(#[serde(with = "crate::generated::decimal::PrecisionScale::<10, 2>")] apache_avro::Decimal),
+    #[serde(alias = "1")]
+    String(String),
+}
+
+impl Default for UnionDecimalString {
+    fn default() -> Self {
+        Self::Decimal(apache_avro::Decimal::from([0x00]))
+    }
+}
+
+impl UnionDecimalString {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"[{"type":"bytes","logicalType":"decimal","scale":2,"precision":10},"string"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionDecimalString {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionDurationString {
+    #[serde(alias = "0")]
+    Duration(crate::generated::duration::Duration),
+    #[serde(alias = "1")]
+    String(String),
+}
+
+impl Default for UnionDurationString {
+    fn default() -> Self {
+        Self::Duration(Default::default())
+    }
+}
+
+impl UnionDurationString {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"[{"type":{"type":"fixed","name":"duration","size":12},"logicalType":"duration"},"string"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionDurationString {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionFloatDouble {
+    #[serde(alias = "0")]
+    Float(f32),
+    #[serde(alias = "1")]
+    Double(f64),
+}
+
+impl Default for UnionFloatDouble {
+    fn default() -> Self {
+        Self::Float(Default::default())
+    }
+}
+
+impl UnionFloatDouble {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"["float","double"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionFloatDouble {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionHashBytes {
+    #[serde(alias = "0")]
+    Hash(crate::generated::com::example::unions::Hash),
+    #[serde(alias = "1")]
+    Bytes(#[serde(with = "crate::generated::bytes::Bytes")] Vec<u8>),
+}
+
+impl Default for UnionHashBytes {
+    fn default() -> Self {
+        Self::Hash(Default::default())
+    }
+}
+
+impl UnionHashBytes {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"[{"type":"fixed","name":"Hash","namespace":"com.example.unions","size":4},"bytes"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionHashBytes {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionIntLong {
+    #[serde(alias = "0")]
+    Int(i32),
+    #[serde(alias = "1")]
+    Long(i64),
+}
+
+impl Default for UnionIntLong {
+    fn default() -> Self {
+        Self::Int(Default::default())
+    }
+}
+
+impl UnionIntLong {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"["int","long"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionIntLong {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionStringBytes {
+    #[serde(alias = "0")]
+    String(String),
+    #[serde(alias = "1")]
+    Bytes(#[serde(with = "crate::generated::bytes::Bytes")] Vec<u8>),
+}
+
+impl Default for UnionStringBytes {
+    fn default() -> Self {
+        Self::String(Default::default())
+    }
+}
+
+impl UnionStringBytes {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"["string","bytes"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionStringBytes {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+}
+
+}
+
+}
+
+pub mod confluent {
+/// Find the writer schemas registered in the Confluent Schema Registry from their ids
+pub trait SchemaResolver {
+    /// Return the schema registered with the id, None when the id is unknown
+    fn resolve_schema(&self, schema_id: u32) -> Option<apache_avro::Schema>;
+}
+
+impl SchemaResolver for std::collections::HashMap<u32, apache_avro::Schema> {
+    fn resolve_schema(&self, schema_id: u32) -> Option<apache_avro::Schema> {
+        self.get(&schema_id).cloned()
+    }
+}
+
+/// Errors returned when a record is decoded from the Confluent wire format
+#[derive(Debug)]
+pub enum ConfluentError {
+    /// The bytes don't start with the magic byte and the schema id
+    InvalidHeader,
+    /// The resolver doesn't know the schema id
+    UnknownSchemaId(u32),
+    Avro(apache_avro::Error),
+}
+
+impl From<apache_avro::Error> for ConfluentError {
+    fn from(error: apache_avro::Error) -> Self {
+        Self::Avro(error)
+    }
+}
+
+impl std::fmt::Display for ConfluentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidHeader => write!(f, "Invalid Confluent wire format header"),
+            Self::UnknownSchemaId(schema_id) => write!(f, "Unknown schema id {schema_id}"),
+            Self::Avro(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfluentError {}
+
+}
+
+pub mod decimal {
+/// Serde glue of the decimal fields, the Avro decimals are the big-endian two's-complement bytes of their unscaled value.
+///
+/// The fields use the precision and the scale of their schema: `#[serde(with = "PrecisionScale::<10, 2>")]`
+pub struct PrecisionScale<const PRECISION: u32, const SCALE: u32>;
+
+impl<const PRECISION: u32, const SCALE: u32> PrecisionScale<PRECISION, SCALE> {
+    pub fn serialize<T: AvroDecimal, S: serde::Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize_decimal(PRECISION, SCALE, serializer)
+    }
+
+    pub fn deserialize<'de, T: AvroDecimal, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_decimal(SCALE, deserializer)
+    }
+}
+
+/// A decimal, or a container of decimals, serialized with the precision and the scale of its schema
+pub trait AvroDecimal: Sized {
+    fn serialize_decimal<S: serde::Serializer>(&self, precision: u32, scale: u32, serializer: S) -> Result<S::Ok, S::Error>;
+
+    fn deserialize_decimal<'de, D: serde::Deserializer<'de>>(scale: u32, deserializer: D) -> Result<Self, D::Error>;
+}
+
+impl AvroDecimal for apache_avro::Decimal {
+    fn serialize_decimal<S: serde::Serializer>(&self, precision: u32, _scale: u32, serializer: S) -> Result<S::Ok, S::Error> {
+        let bytes = Vec::<u8>::try_from(self).map_err(serde::ser::Error::custom)?;
+        serializer.serialize_bytes(&precision_bytes(&bytes, precision))
+    }
+
+    fn deserialize_decimal<'de, D: serde::Deserializer<'de>>(_scale: u32, deserializer: D) -> Result<Self, D::Error> {
+        Ok(apache_avro::Decimal::from(deserializer.deserialize_byte_buf(BytesVisitor)?))
+    }
+}
+
+impl<T: AvroDecimal> AvroDecimal for Option<T> {
+    fn serialize_decimal<S: serde::Serializer>(&self, precision: u32, scale: u32, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Some(value) => serializer.serialize_some(&ScaledDecimal(value, precision, scale)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    fn deserialize_decimal<'de, D: serde::Deserializer<'de>>(scale: u32, deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_option(DecimalVisitor::<Self>(scale, std::marker::PhantomData))
+    }
+}
+
+impl<T: AvroDecimal> AvroDecimal for Vec<T> {
+    fn serialize_decimal<S: serde::Serializer>(&self, precision: u32, scale: u32, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter().map(|value| ScaledDecimal(value, precision, scale)))
+    }
+
+    fn deserialize_decimal<'de, D: serde::Deserializer<'de>>(scale: u32, deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(DecimalVisitor::<Self>(scale, std::marker::PhantomData))
+    }
+}
+
+impl<T: AvroDecimal> AvroDecimal for std::collections::HashMap<String, T> {
+    fn serialize_decimal<S: serde::Serializer>(&self, precision: u32, scale: u32, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter().map(|(key, value)| (key, ScaledDecimal(value, precision, scale))))
+    }
+
+    fn deserialize_decimal<'de, D: serde::Deserializer<'de>>(scale: u32, deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(DecimalVisitor::<Self>(scale, std::marker::PhantomData))
+    }
+}
+
+/// Sign-extend or trim the bytes of an unscaled value to the size needed by the precision, apache_avro checks this size
+fn precision_bytes(bytes: &[u8], precision: u32) -> Vec<u8> {
+    let mut size = 1;
+    while (2.0_f64.powi(8 * size - 1) - 1.0).log10().floor() < f64::from(precision) {
+        size += 1;
+    }
+    let size = size as usize;
+    let sign_byte = if bytes.first().is_some_and(|b| *b >= 0x80) { 0xff } else { 0x00 };
+    let mut start = 0;
+    while bytes.len() - start > size && bytes[start] == sign_byte && (bytes[start + 1] >= 0x80) == (sign_byte == 0xff) {
+        start += 1;
+    }
+    let mut precision_bytes = vec![sign_byte; size.saturating_sub(bytes.len() - start)];
+    precision_bytes.extend_from_slice(&bytes[start..]);
+    precision_bytes
+}
+
+/// Serialize a decimal inside a container
+struct ScaledDecimal<'a, T>(&'a T, u32, u32);
+
+impl<T: AvroDecimal> serde::Serialize for ScaledDecimal<'_, T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize_decimal(self.1, self.2, serializer)
+    }
+}
+
+impl<'de, T: AvroDecimal> serde::de::DeserializeSeed<'de> for DecimalVisitor<T> {
+    type Value = T;
+
+    fn deserialize<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_decimal(self.0, deserializer)
+    }
+}
+
+/// Deserialize the decimals of a container with the scale
+struct DecimalVisitor<T>(u32, std::marker::PhantomData<T>);
+
+impl<'de, T: AvroDecimal> serde::de::Visitor<'de> for DecimalVisitor<Option<T>> {
+    type Value = Option<T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("an optional decimal")
+    }
+
+    fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        T::deserialize_decimal(self.0, deserializer).map(Some)
+    }
+}
+
+impl<'de, T: AvroDecimal> serde::de::Visitor<'de> for DecimalVisitor<Vec<T>> {
+    type Value = Vec<T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("an array of decimals")
+    }
+
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut values = Vec::new();
+        while let Some(value) = seq.next_element_seed(DecimalVisitor::<T>(self.0, std::marker::PhantomData))? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+impl<'de, T: AvroDecimal> serde::de::Visitor<'de> for DecimalVisitor<std::collections::HashMap<String, T>> {
+    type Value = std::collections::HashMap<String, T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a map of decimals")
+    }
+
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut values = std::collections::HashMap::new();
+        while let Some(key) = map.next_key::<String>()? {
+            values.insert(key, map.next_value_seed(DecimalVisitor::<T>(self.0, std::marker::PhantomData))?);
+        }
+        Ok(values)
+    }
+}
+
+/// Read the bytes of a decimal
+struct BytesVisitor;
+
+impl<'de> serde::de::Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("the bytes of a decimal")
+    }
+
+    fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+        Ok(bytes.to_vec())
+    }
+
+    fn visit_byte_buf<E: serde::de::Error>(self, bytes: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(bytes)
+    }
+}
+
+/// Replace the decimals of a value read by apache_avro with their bytes, the serde deserializer of apache_avro doesn't read `Value::Decimal`
+pub fn decimals_as_bytes(value: apache_avro::types::Value) -> apache_avro::types::Value {
+    use apache_avro::types::Value;
+
+    match value {
+        Value::Decimal(decimal) => Value::Bytes(Vec::<u8>::try_from(decimal).unwrap_or_default()),
+        Value::Union(index, value) => Value::Union(index, Box::new(decimals_as_bytes(*value))),
+        Value::Array(values) => Value::Array(values.into_iter().map(decimals_as_bytes).collect()),
+        Value::Map(values) => Value::Map(values.into_iter().map(|(key, value)| (key, decimals_as_bytes(value))).collect()),
+        Value::Record(fields) => Value::Record(fields.into_iter().map(|(name, value)| (name, decimals_as_bytes(value))).collect()),
+        value => value,
+    }
+}
+
+}
+
+pub mod duration {
+/// Avro duration: an amount of months, days and milliseconds, independent of each other
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub struct Duration {
+    pub months: u32,
+    pub days: u32,
+    pub millis: u32,
+}
+
+impl From<[u8; 12]> for Duration {
+    fn from(bytes: [u8; 12]) -> Self {
+        let number = |index: usize| u32::from_le_bytes([bytes[index], bytes[index + 1], bytes[index + 2], bytes[index + 3]]);
+        Self {
+            months: number(0),
+            days: number(4),
+            millis: number(8),
+        }
+    }
+}
+
+impl From<Duration> for [u8; 12] {
+    fn from(duration: Duration) -> Self {
+        let mut bytes = [0; 12];
+        bytes[0..4].copy_from_slice(&duration.months.to_le_bytes());
+        bytes[4..8].copy_from_slice(&duration.days.to_le_bytes());
+        bytes[8..12].copy_from_slice(&duration.millis.to_le_bytes());
+        bytes
+    }
+}
+
+impl From<apache_avro::Duration> for Duration {
+    fn from(duration: apache_avro::Duration) -> Self {
+        Self::from(<[u8; 12]>::from(duration))
+    }
+}
+
+impl From<Duration> for apache_avro::Duration {
+    fn from(duration: Duration) -> Self {
+        Self::from(<[u8; 12]>::from(duration))
+    }
+}
+
+/// A day is 24 hours, a duration with months has no fixed length and returns an error
+impl TryFrom<Duration> for std::time::Duration {
+    type Error = DurationError;
+    fn try_from(duration: Duration) -> Result<Self, Self::Error> {
+        if duration.months != 0 {
+            return Err(DurationError::Months(duration.months));
+        }
+        Ok(std::time::Duration::from_secs(u64::from(duration.days) * 86_400) + std::time::Duration::from_millis(u64::from(duration.millis)))
+    }
+}
+
+/// The whole days are the days of the duration, the rest is truncated to milliseconds
+impl TryFrom<std::time::Duration> for Duration {
+    type Error = DurationError;
+    fn try_from(duration: std::time::Duration) -> Result<Self, Self::Error> {
+        let days = u32::try_from(duration.as_secs() / 86_400).map_err(|_| DurationError::OutOfRange(duration))?;
+        let millis = (duration.as_secs() % 86_400) * 1_000 + u64::from(duration.subsec_millis());
+        Ok(Self {
+            months: 0,
+            days,
+            millis: u32::try_from(millis).map_err(|_| DurationError::OutOfRange(duration))?,
+        })
+    }
+}
+
+impl serde::Serialize for Duration {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&<[u8; 12]>::from(*self))
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Duration {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct DurationVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for DurationVisitor {
+            type Value = Duration;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("the 12 bytes of a duration")
+            }
+
+            fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<Duration, E> {
+                <[u8; 12]>::try_from(bytes).map(Duration::from).map_err(|_| E::invalid_length(bytes.len(), &self))
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Duration, A::Error> {
+                let mut bytes = [0; 12];
+                for (index, byte) in bytes.iter_mut().enumerate() {
+                    *byte = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(index, &self))?;
+                }
+                if seq.next_element::<u8>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(13, &self));
+                }
+                Ok(Duration::from(bytes))
+            }
+        }
+
+        deserializer.deserialize_bytes(DurationVisitor)
+    }
+}
+
+/// Error of the conversions between the Avro durations and `std::time::Duration`
+#[derive(Debug, PartialEq)]
+pub enum DurationError {
+    /// The Avro duration has months, their number of days is unknown
+    Months(u32),
+    /// The days or the milliseconds don't fit in an Avro duration
+    OutOfRange(std::time::Duration),
+}
+
+impl std::fmt::Display for DurationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Months(months) => write!(f, "The duration has {months} months, which have no fixed length"),
+            Self::OutOfRange(duration) => write!(f, "The duration {duration:?} is out of the range of an Avro duration"),
+        }
+    }
+}
+
+impl std::error::Error for DurationError {}
+
+/// Replace the durations of a value read by apache_avro with their fixed, the serde deserializer of apache_avro doesn't read `Value::Duration`
+pub fn durations_as_fixed(value: apache_avro::types::Value) -> apache_avro::types::Value {
+    use apache_avro::types::Value;
+
+    match value {
+        Value::Duration(duration) => Value::Fixed(12, <[u8; 12]>::from(duration).to_vec()),
+        Value::Union(index, value) => Value::Union(index, Box::new(durations_as_fixed(*value))),
+        Value::Array(values) => Value::Array(values.into_iter().map(durations_as_fixed).collect()),
+        Value::Map(values) => Value::Map(values.into_iter().map(|(key, value)| (key, durations_as_fixed(value))).collect()),
+        Value::Record(fields) => Value::Record(fields.into_iter().map(|(name, value)| (name, durations_as_fixed(value))).collect()),
+        value => value,
+    }
+}
+
+}
+
+pub mod events {
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Event {
+    /// Identifier can be a text or a number
+    #[serde(rename = "Identifier")]
+    pub identifier: crate::generated::events::UnionStringLong,
+    #[serde(rename = "Payload")]
+    pub payload: Option<crate::generated::events::UnionStringLongSomeRecord>,
+    #[serde(rename = "History")]
+    pub history: Vec<crate::generated::events::UnionStringLongSomeRecord>,
+    #[serde(rename = "Comment")]
+    pub comment: Option<String>,
+}
+
+impl Event {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Event","namespace":"events","fields":[{"name":"Identifier","type":["string","long"],"doc":"Identifier can be a text or a number"},{"name":"Payload","type":["null","string","long",{"type":"record","name":"SomeRecord","fields":[{"name":"Label","type":"string"}]}]},{"name":"History","type":{"type":"array","items":["string","long","SomeRecord"]}},{"name":"Comment","type":["null","string"]}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xb9, 0x8e, 0xd0, 0x85, 0x6b, 0x8f, 0x70, 0x41];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    /// Header of the single-object encoding: the C3 01 marker and the Rabin fingerprint
+    pub const SINGLE_OBJECT_HEADER: [u8; 10] = [0xc3, 0x01, 0xb9, 0x8e, 0xd0, 0x85, 0x6b, 0x8f, 0x70, 0x41];
+
+    /// Encode the record with the single-object encoding: the header then the binary encoded record
+    pub fn to_single_object(&self) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = crate::generated::values::resolve(apache_avro::to_value(self)?, Self::schema())?;
+        let mut bytes = Self::SINGLE_OBJECT_HEADER.to_vec();
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the single-object encoding, a different header returns a `SingleObjectHeaderMismatch` error
+    pub fn from_single_object(bytes: &[u8]) -> Result<Self, apache_avro::Error> {
+        let header: [u8; 10] = bytes.get(..10).and_then(|h| h.try_into().ok()).unwrap_or_default();
+        if header != Self::SINGLE_OBJECT_HEADER {
+            return Err(apache_avro::Error::SingleObjectHeaderMismatch(Self::SINGLE_OBJECT_HEADER, header));
+        }
+        let value = apache_avro::from_avro_datum(Self::schema(), &mut &bytes[10..], None)?;
+        apache_avro::from_value(&crate::generated::values::union_variants(value, Self::schema()))
+    }
+
+    /// Encode the record with the Confluent wire format: the magic byte 0, the schema id in big endian then the binary encoded record
+    pub fn to_confluent_bytes(&self, schema_id: u32) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = crate::generated::values::resolve(apache_avro::to_value(self)?, Self::schema())?;
+        let mut bytes = vec![0];
+        bytes.extend_from_slice(&schema_id.to_be_bytes());
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the Confluent wire format, the writer schema is found by the resolver from the schema id
+    pub fn from_confluent_bytes(bytes: &[u8], resolver: &impl crate::generated::confluent::SchemaResolver) -> Result<Self, crate::generated::confluent::ConfluentError> {
+        let (schema_id, mut body) = match bytes {
+            [0, b1, b2, b3, b4, body @ ..] => (u32::from_be_bytes([*b1, *b2, *b3, *b4]), body),
+            _ => return Err(crate::generated::confluent::ConfluentError::InvalidHeader),
+        };
+        let writer_schema = resolver.resolve_schema(schema_id).ok_or(crate::generated::confluent::ConfluentError::UnknownSchemaId(schema_id))?;
+        let reader_schema = (writer_schema != *Self::schema()).then(Self::schema);
+        let value = apache_avro::from_avro_datum(&writer_schema, &mut body, reader_schema)?;
+        Ok(apache_avro::from_value(&crate::generated::values::union_variants(value, Self::schema()))?)
+    }
+}
+
+impl Default for Event {
+    fn default() -> Self {
+        Self {
+            identifier: Default::default(),
+            payload: Default::default(),
+            history: Default::default(),
+            comment: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Event {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct SomeRecord {
+    #[serde(rename = "Label")]
+    pub label: String,
+}
+
+impl SomeRecord {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"SomeRecord","namespace":"events","fields":[{"name":"Label","type":"string"}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x90, 0x7b, 0x4a, 0x0c, 0xdb, 0x28, 0x3b, 0xf5];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    /// Header of the single-object encoding: the C3 01 marker and the Rabin fingerprint
+    pub const SINGLE_OBJECT_HEADER: [u8; 10] = [0xc3, 0x01, 0x90, 0x7b, 0x4a, 0x0c, 0xdb, 0x28, 0x3b, 0xf5];
+
+    /// Encode the record with the single-object encoding: the header then the binary encoded record
+    pub fn to_single_object(&self) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = apache_avro::to_value(self)?.resolve(Self::schema())?;
+        let mut bytes = Self::SINGLE_OBJECT_HEADER.to_vec();
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the single-object encoding, a different header returns a `SingleObjectHeaderMismatch` error
+    pub fn from_single_object(bytes: &[u8]) -> Result<Self, apache_avro::Error> {
+        let header: [u8; 10] = bytes.get(..10).and_then(|h| h.try_into().ok()).unwrap_or_default();
+        if header != Self::SINGLE_OBJECT_HEADER {
+            return Err(apache_avro::Error::SingleObjectHeaderMismatch(Self::SINGLE_OBJECT_HEADER, header));
+        }
+        let value = apache_avro::from_avro_datum(Self::schema(), &mut &bytes[10..], None)?;
+        apache_avro::from_value(&value)
+    }
+
+    /// Encode the record with the Confluent wire format: the magic byte 0, the schema id in big endian then the binary encoded record
+    pub fn to_confluent_bytes(&self, schema_id: u32) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = apache_avro::to_value(self)?.resolve(Self::schema())?;
+        let mut bytes = vec![0];
+        bytes.extend_from_slice(&schema_id.to_be_bytes());
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the Confluent wire format, the writer schema is found by the resolver from the schema id
+    pub fn from_confluent_bytes(bytes: &[u8], resolver: &impl crate::generated::confluent::SchemaResolver) -> Result<Self, crate::generated::confluent::ConfluentError> {
+        let (schema_id, mut body) = match bytes {
+            [0, b1, b2, b3, b4, body @ ..] => (u32::from_be_bytes([*b1, *b2, *b3, *b4]), body),
+            _ => return Err(crate::generated::confluent::ConfluentError::InvalidHeader),
+        };
+        let writer_schema = resolver.resolve_schema(schema_id).ok_or(crate::generated::confluent::ConfluentError::UnknownSchemaId(schema_id))?;
+        let reader_schema = (writer_schema != *Self::schema()).then(Self::schema);
+        let value = apache_avro::from_avro_datum(&writer_schema, &mut body, reader_schema)?;
+        Ok(apache_avro::from_value(&value)?)
+    }
+}
+
+impl Default for SomeRecord {
+    fn default() -> Self {
+        Self {
+            label: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for SomeRecord {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionStringLong {
+    #[serde(alias = "0")]
+    String(String),
+    #[serde(alias = "1")]
+    Long(i64),
+}
+
+impl Default for UnionStringLong {
+    fn default() -> Self {
+        Self::String(Default::default())
+    }
+}
+
+impl UnionStringLong {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"["string","long"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionStringLong {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionStringLongSomeRecord {
+    #[serde(alias = "0")]
+    String(String),
+    #[serde(alias = "1")]
+    Long(i64),
+    #[serde(alias = "2")]
+    SomeRecord(crate::generated::events::SomeRecord),
+}
+
+impl Default for UnionStringLongSomeRecord {
+    fn default() -> Self {
+        Self::String(Default::default())
+    }
+}
+
+impl UnionStringLongSomeRecord {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"["string","long",{"type":"record","name":"SomeRecord","namespace":"events","fields":[{"name":"Label","type":"string"}]}]"#;
+}
+
+impl apache_avro::AvroSchema for UnionStringLongSomeRecord {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+}
+
+pub mod logical_types {
+/// A value of a logical type, or a container of values, serialized like the Avro logical type
+pub trait AvroLogicalType<Unit>: Sized {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+}
+
+/// `date`: the number of days since the Unix epoch, an int
+/// The fields use it with `#[serde(with = "Date")]`
+pub struct Date;
+
+impl Date {
+    pub fn serialize<T: AvroLogicalType<Self>, S: serde::Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize_logical(serializer)
+    }
+
+    pub fn deserialize<'de, T: AvroLogicalType<Self>, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_logical(deserializer)
+    }
+}
+
+impl AvroLogicalType<Date> for chrono::NaiveDate {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let days = self.signed_duration_since(chrono::NaiveDate::default()).num_days();
+        serializer.serialize_i32(i32::try_from(days).map_err(serde::ser::Error::custom)?)
+    }
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let days = <i32 as serde::Deserialize>::deserialize(deserializer)?;
+        chrono::NaiveDate::default()
+            .checked_add_signed(chrono::TimeDelta::days(days.into()))
+            .ok_or_else(|| serde::de::Error::custom(format!("The date {days} is out of range")))
+    }
+}
+
+/// `time-millis`: the number of milliseconds after midnight, an int
+/// The fields use it with `#[serde(with = "TimeMillis")]`
+pub struct TimeMillis;
+
+impl TimeMillis {
+    pub fn serialize<T: AvroLogicalType<Self>, S: serde::Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize_logical(serializer)
+    }
+
+    pub fn deserialize<'de, T: AvroLogicalType<Self>, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_logical(deserializer)
+    }
+}
+
+impl AvroLogicalType<TimeMillis> for chrono::NaiveTime {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let seconds = chrono::Timelike::num_seconds_from_midnight(self);
+        let nanoseconds = chrono::Timelike::nanosecond(self);
+        let millis = i64::from(seconds) * 1_000 + i64::from(nanoseconds / 1_000_000);
+        serializer.serialize_i32(i32::try_from(millis).map_err(serde::ser::Error::custom)?)
+    }
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let millis = <i32 as serde::Deserialize>::deserialize(deserializer)?;
+        u32::try_from(millis)
+            .ok()
+            .and_then(|millis| chrono::NaiveTime::from_num_seconds_from_midnight_opt(millis / 1_000, millis % 1_000 * 1_000_000))
+            .ok_or_else(|| serde::de::Error::custom(format!("The time {millis} is out of range")))
+    }
+}
+
+/// `time-micros`: the number of microseconds after midnight, a long
+/// The fields use it with `#[serde(with = "TimeMicros")]`
+pub struct TimeMicros;
+
+impl TimeMicros {
+    pub fn serialize<T: AvroLogicalType<Self>, S: serde::Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize_logical(serializer)
+    }
+
+    pub fn deserialize<'de, T: AvroLogicalType<Self>, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_logical(deserializer)
+    }
+}
+
+impl AvroLogicalType<TimeMicros> for chrono::NaiveTime {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let seconds = chrono::Timelike::num_seconds_from_midnight(self);
+        let nanoseconds = chrono::Timelike::nanosecond(self);
+        serializer.serialize_i64(i64::from(seconds) * 1_000_000 + i64::from(nanoseconds / 1_000))
+    }
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let micros = <i64 as serde::Deserialize>::deserialize(deserializer)?;
+        u64::try_from(micros)
+            .ok()
+            .and_then(|micros| u32::try_from(micros / 1_000_000).ok().zip(u32::try_from(micros % 1_000_000 * 1_000).ok()))
+            .and_then(|(seconds, nanoseconds)| chrono::NaiveTime::from_num_seconds_from_midnight_opt(seconds, nanoseconds))
+            .ok_or_else(|| serde::de::Error::custom(format!("The time {micros} is out of range")))
+    }
+}
+
+/// `timestamp-millis`: the number of milliseconds since the Unix epoch in UTC, a long
+/// The fields use it with `#[serde(with = "TimestampMillis")]`
+pub struct TimestampMillis;
+
+impl TimestampMillis {
+    pub fn serialize<T: AvroLogicalType<Self>, S: serde::Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize_logical(serializer)
+    }
+
+    pub fn deserialize<'de, T: AvroLogicalType<Self>, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_logical(deserializer)
+    }
+}
+
+impl AvroLogicalType<TimestampMillis> for chrono::DateTime<chrono::Utc> {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(self.timestamp_millis())
+    }
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let timestamp = <i64 as serde::Deserialize>::deserialize(deserializer)?;
+        chrono::DateTime::from_timestamp_millis(timestamp)
+            .ok_or_else(|| serde::de::Error::custom(format!("The timestamp {timestamp} is out of range")))
+    }
+}
+
+/// `timestamp-micros`: the number of microseconds since the Unix epoch in UTC, a long
+/// The fields use it with `#[serde(with = "TimestampMicros")]`
+pub struct TimestampMicros;
+
+impl TimestampMicros {
+    pub fn serialize<T: AvroLogicalType<Self>, S: serde::Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize_logical(serializer)
+    }
+
+    pub fn deserialize<'de, T: AvroLogicalType<Self>, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_logical(deserializer)
+    }
+}
+
+impl AvroLogicalType<TimestampMicros> for chrono::DateTime<chrono::Utc> {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(self.timestamp_micros())
+    }
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let timestamp = <i64 as serde::Deserialize>::deserialize(deserializer)?;
+        chrono::DateTime::from_timestamp_micros(timestamp)
+            .ok_or_else(|| serde::de::Error::custom(format!("The timestamp {timestamp} is out of range")))
+    }
+}
+
+/// `local-timestamp-millis`: the number of milliseconds since the Unix epoch in local time, a long
+/// The fields use it with `#[serde(with = "LocalTimestampMillis")]`
+pub struct LocalTimestampMillis;
+
+impl LocalTimestampMillis {
+    pub fn serialize<T: AvroLogicalType<Self>, S: serde::Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize_logical(serializer)
+    }
+
+    pub fn deserialize<'de, T: AvroLogicalType<Self>, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_logical(deserializer)
+    }
+}
+
+impl AvroLogicalType<LocalTimestampMillis> for chrono::NaiveDateTime {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(self.and_utc().timestamp_millis())
+    }
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let timestamp = <i64 as serde::Deserialize>::deserialize(deserializer)?;
+        chrono::DateTime::from_timestamp_millis(timestamp)
+            .map(|date_time| date_time.naive_utc())
+            .ok_or_else(|| serde::de::Error::custom(format!("The timestamp {timestamp} is out of range")))
+    }
+}
+
+/// `local-timestamp-micros`: the number of microseconds since the Unix epoch in local time, a long
+/// The fields use it with `#[serde(with = "LocalTimestampMicros")]`
+pub struct LocalTimestampMicros;
+
+impl LocalTimestampMicros {
+    pub fn serialize<T: AvroLogicalType<Self>, S: serde::Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize_logical(serializer)
+    }
+
+    pub fn deserialize<'de, T: AvroLogicalType<Self>, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_logical(deserializer)
+    }
+}
+
+impl AvroLogicalType<LocalTimestampMicros> for chrono::NaiveDateTime {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(self.and_utc().timestamp_micros())
+    }
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let timestamp = <i64 as serde::Deserialize>::deserialize(deserializer)?;
+        chrono::DateTime::from_timestamp_micros(timestamp)
+            .map(|date_time| date_time.naive_utc())
+            .ok_or_else(|| serde::de::Error::custom(format!("The timestamp {timestamp} is out of range")))
+    }
+}
+
+/// `uuid`: the string representation of the UUID
+/// The fields use it with `#[serde(with = "Uuid")]`
+pub struct Uuid;
+
+impl Uuid {
+    pub fn serialize<T: AvroLogicalType<Self>, S: serde::Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize_logical(serializer)
+    }
+
+    pub fn deserialize<'de, T: AvroLogicalType<Self>, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_logical(deserializer)
+    }
+}
+
+impl AvroLogicalType<Uuid> for uuid::Uuid {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let uuid = <String as serde::Deserialize>::deserialize(deserializer)?;
+        uuid::Uuid::parse_str(&uuid).map_err(serde::de::Error::custom)
+    }
+}
+
+impl<U, T: AvroLogicalType<U>> AvroLogicalType<U> for Option<T> {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Some(value) => serializer.serialize_some(&LogicalValue::<U, T>(value, std::marker::PhantomData)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_option(LogicalVisitor::<U, Self>(std::marker::PhantomData))
+    }
+}
+
+impl<U, T: AvroLogicalType<U>> AvroLogicalType<U> for Vec<T> {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter().map(|value| LogicalValue::<U, T>(value, std::marker::PhantomData)))
+    }
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(LogicalVisitor::<U, Self>(std::marker::PhantomData))
+    }
+}
+
+impl<U, T: AvroLogicalType<U>> AvroLogicalType<U> for std::collections::HashMap<String, T> {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter().map(|(key, value)| (key, LogicalValue::<U, T>(value, std::marker::PhantomData))))
+    }
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(LogicalVisitor::<U, Self>(std::marker::PhantomData))
+    }
+}
+
+/// Serialize a value inside a container
+struct LogicalValue<'a, U, T>(&'a T, std::marker::PhantomData<U>);
+
+impl<U, T: AvroLogicalType<U>> serde::Serialize for LogicalValue<'_, U, T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize_logical(serializer)
+    }
+}
+
+/// Deserialize the values of a container
+struct LogicalVisitor<U, T>(std::marker::PhantomData<(U, T)>);
+
+impl<'de, U, T: AvroLogicalType<U>> serde::de::DeserializeSeed<'de> for LogicalVisitor<U, T> {
+    type Value = T;
+
+    fn deserialize<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_logical(deserializer)
+    }
+}
+
+impl<'de, U, T: AvroLogicalType<U>> serde::de::Visitor<'de> for LogicalVisitor<U, Option<T>> {
+    type Value = Option<T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("an optional value")
+    }
+
+    fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        T::deserialize_logical(deserializer).map(Some)
+    }
+}
+
+impl<'de, U, T: AvroLogicalType<U>> serde::de::Visitor<'de> for LogicalVisitor<U, Vec<T>> {
+    type Value = Vec<T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("an array")
+    }
+
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut values = Vec::new();
+        while let Some(value) = seq.next_element_seed(LogicalVisitor::<U, T>(std::marker::PhantomData))? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+impl<'de, U, T: AvroLogicalType<U>> serde::de::Visitor<'de> for LogicalVisitor<U, std::collections::HashMap<String, T>> {
+    type Value = std::collections::HashMap<String, T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a map")
+    }
+
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut values = std::collections::HashMap::new();
+        while let Some(key) = map.next_key::<String>()? {
+            values.insert(key, map.next_value_seed(LogicalVisitor::<U, T>(std::marker::PhantomData))?);
+        }
+        Ok(values)
+    }
+}
+
+}
+
+pub mod values {
+/// Resolve a value serialized by serde with the schema, like `Value::resolve`: the bytes of the fixed become fixed values,
+/// and the variants of the generated union enums are written with their own type of the union, the schema resolution
+/// of `apache_avro` finds the type of an union value from the value and can choose another one
+pub fn resolve(value: apache_avro::types::Value, schema: &apache_avro::Schema) -> Result<apache_avro::types::Value, apache_avro::Error> {
+    let resolved = apache_avro::schema::ResolvedSchema::try_from(schema)?;
+    resolve_value(value, schema, resolved.get_names())
+}
+
+fn resolve_value(value: apache_avro::types::Value, schema: &apache_avro::Schema, names: &apache_avro::schema::NamesRef) -> Result<apache_avro::types::Value, apache_avro::Error> {
+    use apache_avro::types::Value;
+    use apache_avro::Schema;
+
+    match (value, schema) {
+        (value, Schema::Ref { name }) => match names.get(name) {
+            Some(definition) => resolve_value(value, definition, names),
+            None => value.resolve(schema),
+        },
+        (Value::Record(fields), Schema::Record(record_schema)) => {
+            let mut resolved_fields = Vec::with_capacity(fields.len());
+            for (name, value) in fields {
+                let value = match record_schema.lookup.get(&name) {
+                    Some(position) => resolve_value(value, &record_schema.fields[*position].schema, names)?,
+                    None => value,
+                };
+                resolved_fields.push((name, value));
+            }
+            Ok(Value::Record(resolved_fields))
+        }
+        (Value::Array(items), Schema::Array(items_schema)) => items.into_iter().map(|item| resolve_value(item, items_schema, names)).collect::<Result<_, _>>().map(Value::Array),
+        (Value::Map(values), Schema::Map(values_schema)) => values.into_iter().map(|(key, value)| resolve_value(value, values_schema, names).map(|value| (key, value))).collect::<Result<_, _>>().map(Value::Map),
+        (value, Schema::Union(union_schema)) => {
+            // The options are serialized as union values, without the index of their type in the schema
+            let value = match value {
+                Value::Union(_, value) => *value,
+                value => value,
+            };
+            let types: Vec<usize> = (0..union_schema.variants().len()).filter(|index| !matches!(union_schema.variants()[*index], Schema::Null)).collect();
+            let (index, value) = match (value, types.as_slice()) {
+                (Value::Null, _) => (union_schema.variants().iter().position(|variant| matches!(variant, Schema::Null)), Value::Null),
+                (value, [index]) => (Some(*index), value),
+                // A generated enum serializes its variant with the index of its type among the types of the union without null
+                (Value::Record(fields), _) => match <[(String, Value); 2]>::try_from(fields) {
+                    Ok([(_, Value::Enum(variant, _)), (_, Value::Union(_, value))]) => (types.get(variant as usize).copied(), *value),
+                    _ => (None, Value::Null),
+                },
+                _ => (None, Value::Null),
+            };
+            let index = index.ok_or(apache_avro::Error::FindUnionVariant)?;
+            let value = resolve_value(value, &union_schema.variants()[index], names)?;
+            Ok(Value::Union(index as u32, Box::new(value)))
+        }
+        (Value::Bytes(bytes), Schema::Fixed(fixed_schema)) => Value::Fixed(fixed_schema.size, bytes).resolve(schema),
+        (Value::Bytes(bytes), Schema::Duration) => Value::Fixed(12, bytes).resolve(schema),
+        (value, schema) => value.resolve(schema),
+    }
+}
+
+/// Replace the union values of the generated union enums with the representation of their variant read by the serde
+/// deserializer of `apache_avro`: a record with the index of the type among the types of the union without null, then the value
+pub fn union_variants(value: apache_avro::types::Value, schema: &apache_avro::Schema) -> apache_avro::types::Value {
+    match apache_avro::schema::ResolvedSchema::try_from(schema) {
+        Ok(resolved) => variant_values(value, schema, resolved.get_names()),
+        Err(_) => value,
+    }
+}
+
+fn variant_values(value: apache_avro::types::Value, schema: &apache_avro::Schema, names: &apache_avro::schema::NamesRef) -> apache_avro::types::Value {
+    use apache_avro::types::Value;
+    use apache_avro::Schema;
+
+    match (value, schema) {
+        (value, Schema::Ref { name }) => match names.get(name) {
+            Some(definition) => variant_values(value, definition, names),
+            None => value,
+        },
+        (Value::Record(fields), Schema::Record(record_schema)) => Value::Record(
+            fields
+                .into_iter()
+                .map(|(name, value)| match record_schema.lookup.get(&name) {
+                    Some(position) => {
+                        let value = variant_values(value, &record_schema.fields[*position].schema, names);
+                        (name, value)
+                    }
+                    None => (name, value),
+                })
+                .collect(),
+        ),
+        (Value::Array(items), Schema::Array(items_schema)) => Value::Array(items.into_iter().map(|item| variant_values(item, items_schema, names)).collect()),
+        (Value::Map(values), Schema::Map(values_schema)) => Value::Map(values.into_iter().map(|(key, value)| (key, variant_values(value, values_schema, names))).collect()),
+        (Value::Union(index, value), Schema::Union(union_schema)) => {
+            let variants = union_schema.variants();
+            let value = match variants.get(index as usize) {
+                Some(variant) => variant_values(*value, variant, names),
+                None => *value,
+            };
+            let is_type = |variant: &&Schema| !matches!(variant, Schema::Null);
+            if variants.iter().filter(is_type).count() < 2 || matches!(value, Value::Null) {
+                return Value::Union(index, Box::new(value));
+            }
+            let variant = variants.iter().take(index as usize).filter(is_type).count();
+            let variant = Value::Record(vec![("type".to_string(), Value::String(variant.to_string())), ("value".to_string(), value)]);
+            match union_schema.is_nullable() {
+                // The value of an option
+                true => Value::Union(index, Box::new(variant)),
+                false => variant,
+            }
+        }
+        (value, _) => value,
+    }
+}
+
+}
+
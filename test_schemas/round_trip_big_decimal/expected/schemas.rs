@@ -0,0 +1,493 @@
+pub mod com {
+pub mod example {
+pub mod finance {
+/// Invoice with decimal amounts
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Invoice {
+    #[serde(with = "crate::round_trip_big_decimal::decimal::PrecisionScale::<10, 2>")]
+    pub amount: bigdecimal::BigDecimal,
+    #[serde(with = "crate::round_trip_big_decimal::decimal::PrecisionScale::<18, 6>")]
+    pub rate: bigdecimal::BigDecimal,
+    #[serde(with = "crate::round_trip_big_decimal::decimal::PrecisionScale::<5, 2>")]
+    #[serde(default = "Invoice::default_discount")]
+    pub discount: Option<bigdecimal::BigDecimal>,
+    #[serde(with = "crate::round_trip_big_decimal::decimal::PrecisionScale::<10, 2>")]
+    pub lines: Vec<bigdecimal::BigDecimal>,
+    #[serde(with = "crate::round_trip_big_decimal::decimal::PrecisionScale::<10, 2>")]
+    pub taxes: std::collections::HashMap<String, bigdecimal::BigDecimal>,
+    pub adjustment: crate::round_trip_big_decimal::com::example::finance::UnionDecimalString,
+}
+
+impl Invoice {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Invoice","namespace":"com.example.finance","doc":"Invoice with decimal amounts","fields":[{"name":"amount","type":{"type":"bytes","logicalType":"decimal","precision":10,"scale":2}},{"name":"rate","type":{"type":"fixed","name":"Rate","size":8,"logicalType":"decimal","precision":18,"scale":6}},{"name":"discount","type":["null",{"type":"bytes","logicalType":"decimal","precision":5,"scale":2}],"default":null},{"name":"lines","type":{"type":"array","items":{"type":"bytes","logicalType":"decimal","precision":10,"scale":2}}},{"name":"taxes","type":{"type":"map","values":{"type":"bytes","logicalType":"decimal","precision":10,"scale":2}}},{"name":"adjustment","type":[{"type":"bytes","logicalType":"decimal","precision":10,"scale":2},"string"]}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x40, 0x52, 0x18, 0x13, 0xa9, 0x59, 0x15, 0x81];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    /// Header of the single-object encoding: the C3 01 marker and the Rabin fingerprint
+    pub const SINGLE_OBJECT_HEADER: [u8; 10] = [0xc3, 0x01, 0x40, 0x52, 0x18, 0x13, 0xa9, 0x59, 0x15, 0x81];
+
+    /// Encode the record with the single-object encoding: the header then the binary encoded record
+    pub fn to_single_object(&self) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = crate::round_trip_big_decimal::values::resolve(apache_avro::to_value(self)?, Self::schema())?;
+        let mut bytes = Self::SINGLE_OBJECT_HEADER.to_vec();
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the single-object encoding, a different header returns a `SingleObjectHeaderMismatch` error
+    pub fn from_single_object(bytes: &[u8]) -> Result<Self, apache_avro::Error> {
+        let header: [u8; 10] = bytes.get(..10).and_then(|h| h.try_into().ok()).unwrap_or_default();
+        if header != Self::SINGLE_OBJECT_HEADER {
+            return Err(apache_avro::Error::SingleObjectHeaderMismatch(Self::SINGLE_OBJECT_HEADER, header));
+        }
+        let value = apache_avro::from_avro_datum(Self::schema(), &mut &bytes[10..], None)?;
+        apache_avro::from_value(&crate::round_trip_big_decimal::decimal::decimals_as_bytes(crate::round_trip_big_decimal::values::union_variants(value, Self::schema())))
+    }
+
+    /// Encode the record with the Confluent wire format: the magic byte 0, the schema id in big endian then the binary encoded record
+    pub fn to_confluent_bytes(&self, schema_id: u32) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = crate::round_trip_big_decimal::values::resolve(apache_avro::to_value(self)?, Self::schema())?;
+        let mut bytes = vec![0];
+        bytes.extend_from_slice(&schema_id.to_be_bytes());
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the Confluent wire format, the writer schema is found by the resolver from the schema id
+    pub fn from_confluent_bytes(bytes: &[u8], resolver: &impl crate::round_trip_big_decimal::confluent::SchemaResolver) -> Result<Self, crate::round_trip_big_decimal::confluent::ConfluentError> {
+        let (schema_id, mut body) = match bytes {
+            [0, b1, b2, b3, b4, body @ ..] => (u32::from_be_bytes([*b1, *b2, *b3, *b4]), body),
+            _ => return Err(crate::round_trip_big_decimal::confluent::ConfluentError::InvalidHeader),
+        };
+        let writer_schema = resolver.resolve_schema(schema_id).ok_or(crate::round_trip_big_decimal::confluent::ConfluentError::UnknownSchemaId(schema_id))?;
+        let reader_schema = (writer_schema != *Self::schema()).then(Self::schema);
+        let value = apache_avro::from_avro_datum(&writer_schema, &mut body, reader_schema)?;
+        Ok(apache_avro::from_value(&crate::round_trip_big_decimal::decimal::decimals_as_bytes(crate::round_trip_big_decimal::values::union_variants(value, Self::schema())))?)
+    }
+
+    #[inline(always)]
+    pub fn default_discount() -> Option<bigdecimal::BigDecimal> {
+        None
+    }
+}
+
+impl Default for Invoice {
+    fn default() -> Self {
+        Self {
+            amount: Default::default(),
+            rate: Default::default(),
+            discount: Self::default_discount(),
+            lines: Default::default(),
+            taxes: Default::default(),
+            adjustment: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Invoice {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionDecimalString {
+    #[serde(alias = "0")]
+    Decimal(#[serde(with = "crate::round_trip_big_decimal::decimal::PrecisionScale::<10, 2>")] bigdecimal::BigDecimal),
+    #[serde(alias = "1")]
+    String(String),
+}
+
+impl Default for UnionDecimalString {
+    fn default() -> Self {
+        Self::Decimal(Default::default())
+    }
+}
+
+impl UnionDecimalString {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"[{"type":"bytes","logicalType":"decimal","scale":2,"precision":10},"string"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionDecimalString {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+}
+
+}
+
+}
+
+pub mod confluent {
+/// Find the writer schemas registered in the Confluent Schema Registry from their ids
+pub trait SchemaResolver {
+    /// Return the schema registered with the id, None when the id is unknown
+    fn resolve_schema(&self, schema_id: u32) -> Option<apache_avro::Schema>;
+}
+
+impl SchemaResolver for std::collections::HashMap<u32, apache_avro::Schema> {
+    fn resolve_schema(&self, schema_id: u32) -> Option<apache_avro::Schema> {
+        self.get(&schema_id).cloned()
+    }
+}
+
+/// Errors returned when a record is decoded from the Confluent wire format
+#[derive(Debug)]
+pub enum ConfluentError {
+    /// The bytes don't start with the magic byte and the schema id
+    InvalidHeader,
+    /// The resolver doesn't know the schema id
+    UnknownSchemaId(u32),
+    Avro(apache_avro::Error),
+}
+
+impl From<apache_avro::Error> for ConfluentError {
+    fn from(error: apache_avro::Error) -> Self {
+        Self::Avro(error)
+    }
+}
+
+impl std::fmt::Display for ConfluentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidHeader => write!(f, "Invalid Confluent wire format header"),
+            Self::UnknownSchemaId(schema_id) => write!(f, "Unknown schema id {schema_id}"),
+            Self::Avro(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfluentError {}
+
+}
+
+pub mod decimal {
+/// Serde glue of the decimal fields, the Avro decimals are the big-endian two's-complement bytes of their unscaled value.
+///
+/// The fields use the precision and the scale of their schema: `#[serde(with = "PrecisionScale::<10, 2>")]`
+pub struct PrecisionScale<const PRECISION: u32, const SCALE: u32>;
+
+impl<const PRECISION: u32, const SCALE: u32> PrecisionScale<PRECISION, SCALE> {
+    pub fn serialize<T: AvroDecimal, S: serde::Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize_decimal(PRECISION, SCALE, serializer)
+    }
+
+    pub fn deserialize<'de, T: AvroDecimal, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_decimal(SCALE, deserializer)
+    }
+}
+
+/// A decimal, or a container of decimals, serialized with the precision and the scale of its schema
+pub trait AvroDecimal: Sized {
+    fn serialize_decimal<S: serde::Serializer>(&self, precision: u32, scale: u32, serializer: S) -> Result<S::Ok, S::Error>;
+
+    fn deserialize_decimal<'de, D: serde::Deserializer<'de>>(scale: u32, deserializer: D) -> Result<Self, D::Error>;
+}
+
+impl AvroDecimal for bigdecimal::BigDecimal {
+    fn serialize_decimal<S: serde::Serializer>(&self, precision: u32, scale: u32, serializer: S) -> Result<S::Ok, S::Error> {
+        let value = self.with_scale(scale.into());
+        if value != *self {
+            return Err(serde::ser::Error::custom(format!("The decimal {self} has more than {scale} digits after the decimal point")));
+        }
+        if value.digits() > precision.into() {
+            return Err(serde::ser::Error::custom(format!("The decimal {self} has more than {precision} digits")));
+        }
+        let (unscaled, _) = value.into_bigint_and_exponent();
+        serializer.serialize_bytes(&precision_bytes(&unscaled.to_signed_bytes_be(), precision))
+    }
+
+    fn deserialize_decimal<'de, D: serde::Deserializer<'de>>(scale: u32, deserializer: D) -> Result<Self, D::Error> {
+        let bytes = deserializer.deserialize_byte_buf(BytesVisitor)?;
+        let unscaled = bigdecimal::num_bigint::BigInt::from_signed_bytes_be(&bytes);
+        Ok(bigdecimal::BigDecimal::new(unscaled, scale.into()))
+    }
+}
+
+impl<T: AvroDecimal> AvroDecimal for Option<T> {
+    fn serialize_decimal<S: serde::Serializer>(&self, precision: u32, scale: u32, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Some(value) => serializer.serialize_some(&ScaledDecimal(value, precision, scale)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    fn deserialize_decimal<'de, D: serde::Deserializer<'de>>(scale: u32, deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_option(DecimalVisitor::<Self>(scale, std::marker::PhantomData))
+    }
+}
+
+impl<T: AvroDecimal> AvroDecimal for Vec<T> {
+    fn serialize_decimal<S: serde::Serializer>(&self, precision: u32, scale: u32, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter().map(|value| ScaledDecimal(value, precision, scale)))
+    }
+
+    fn deserialize_decimal<'de, D: serde::Deserializer<'de>>(scale: u32, deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(DecimalVisitor::<Self>(scale, std::marker::PhantomData))
+    }
+}
+
+impl<T: AvroDecimal> AvroDecimal for std::collections::HashMap<String, T> {
+    fn serialize_decimal<S: serde::Serializer>(&self, precision: u32, scale: u32, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter().map(|(key, value)| (key, ScaledDecimal(value, precision, scale))))
+    }
+
+    fn deserialize_decimal<'de, D: serde::Deserializer<'de>>(scale: u32, deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(DecimalVisitor::<Self>(scale, std::marker::PhantomData))
+    }
+}
+
+/// Sign-extend or trim the bytes of an unscaled value to the size needed by the precision, apache_avro checks this size
+fn precision_bytes(bytes: &[u8], precision: u32) -> Vec<u8> {
+    let mut size = 1;
+    while (2.0_f64.powi(8 * size - 1) - 1.0).log10().floor() < f64::from(precision) {
+        size += 1;
+    }
+    let size = size as usize;
+    let sign_byte = if bytes.first().is_some_and(|b| *b >= 0x80) { 0xff } else { 0x00 };
+    let mut start = 0;
+    while bytes.len() - start > size && bytes[start] == sign_byte && (bytes[start + 1] >= 0x80) == (sign_byte == 0xff) {
+        start += 1;
+    }
+    let mut precision_bytes = vec![sign_byte; size.saturating_sub(bytes.len() - start)];
+    precision_bytes.extend_from_slice(&bytes[start..]);
+    precision_bytes
+}
+
+/// Serialize a decimal inside a container
+struct ScaledDecimal<'a, T>(&'a T, u32, u32);
+
+impl<T: AvroDecimal> serde::Serialize for ScaledDecimal<'_, T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize_decimal(self.1, self.2, serializer)
+    }
+}
+
+impl<'de, T: AvroDecimal> serde::de::DeserializeSeed<'de> for DecimalVisitor<T> {
+    type Value = T;
+
+    fn deserialize<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_decimal(self.0, deserializer)
+    }
+}
+
+/// Deserialize the decimals of a container with the scale
+struct DecimalVisitor<T>(u32, std::marker::PhantomData<T>);
+
+impl<'de, T: AvroDecimal> serde::de::Visitor<'de> for DecimalVisitor<Option<T>> {
+    type Value = Option<T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("an optional decimal")
+    }
+
+    fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        T::deserialize_decimal(self.0, deserializer).map(Some)
+    }
+}
+
+impl<'de, T: AvroDecimal> serde::de::Visitor<'de> for DecimalVisitor<Vec<T>> {
+    type Value = Vec<T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("an array of decimals")
+    }
+
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut values = Vec::new();
+        while let Some(value) = seq.next_element_seed(DecimalVisitor::<T>(self.0, std::marker::PhantomData))? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+impl<'de, T: AvroDecimal> serde::de::Visitor<'de> for DecimalVisitor<std::collections::HashMap<String, T>> {
+    type Value = std::collections::HashMap<String, T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a map of decimals")
+    }
+
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut values = std::collections::HashMap::new();
+        while let Some(key) = map.next_key::<String>()? {
+            values.insert(key, map.next_value_seed(DecimalVisitor::<T>(self.0, std::marker::PhantomData))?);
+        }
+        Ok(values)
+    }
+}
+
+/// Read the bytes of a decimal
+struct BytesVisitor;
+
+impl<'de> serde::de::Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("the bytes of a decimal")
+    }
+
+    fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+        Ok(bytes.to_vec())
+    }
+
+    fn visit_byte_buf<E: serde::de::Error>(self, bytes: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(bytes)
+    }
+}
+
+/// Replace the decimals of a value read by apache_avro with their bytes, the serde deserializer of apache_avro doesn't read `Value::Decimal`
+pub fn decimals_as_bytes(value: apache_avro::types::Value) -> apache_avro::types::Value {
+    use apache_avro::types::Value;
+
+    match value {
+        Value::Decimal(decimal) => Value::Bytes(Vec::<u8>::try_from(decimal).unwrap_or_default()),
+        Value::Union(index, value) => Value::Union(index, Box::new(decimals_as_bytes(*value))),
+        Value::Array(values) => Value::Array(values.into_iter().map(decimals_as_bytes).collect()),
+        Value::Map(values) => Value::Map(values.into_iter().map(|(key, value)| (key, decimals_as_bytes(value))).collect()),
+        Value::Record(fields) => Value::Record(fields.into_iter().map(|(name, value)| (name, decimals_as_bytes(value))).collect()),
+        value => value,
+    }
+}
+
+}
+
+pub mod values {
+/// Resolve a value serialized by serde with the schema, like `Value::resolve`: the bytes of the fixed become fixed values,
+/// and the variants of the generated union enums are written with their own type of the union, the schema resolution
+/// of `apache_avro` finds the type of an union value from the value and can choose another one
+pub fn resolve(value: apache_avro::types::Value, schema: &apache_avro::Schema) -> Result<apache_avro::types::Value, apache_avro::Error> {
+    let resolved = apache_avro::schema::ResolvedSchema::try_from(schema)?;
+    resolve_value(value, schema, resolved.get_names())
+}
+
+fn resolve_value(value: apache_avro::types::Value, schema: &apache_avro::Schema, names: &apache_avro::schema::NamesRef) -> Result<apache_avro::types::Value, apache_avro::Error> {
+    use apache_avro::types::Value;
+    use apache_avro::Schema;
+
+    match (value, schema) {
+        (value, Schema::Ref { name }) => match names.get(name) {
+            Some(definition) => resolve_value(value, definition, names),
+            None => value.resolve(schema),
+        },
+        (Value::Record(fields), Schema::Record(record_schema)) => {
+            let mut resolved_fields = Vec::with_capacity(fields.len());
+            for (name, value) in fields {
+                let value = match record_schema.lookup.get(&name) {
+                    Some(position) => resolve_value(value, &record_schema.fields[*position].schema, names)?,
+                    None => value,
+                };
+                resolved_fields.push((name, value));
+            }
+            Ok(Value::Record(resolved_fields))
+        }
+        (Value::Array(items), Schema::Array(items_schema)) => items.into_iter().map(|item| resolve_value(item, items_schema, names)).collect::<Result<_, _>>().map(Value::Array),
+        (Value::Map(values), Schema::Map(values_schema)) => values.into_iter().map(|(key, value)| resolve_value(value, values_schema, names).map(|value| (key, value))).collect::<Result<_, _>>().map(Value::Map),
+        (value, Schema::Union(union_schema)) => {
+            // The options are serialized as union values, without the index of their type in the schema
+            let value = match value {
+                Value::Union(_, value) => *value,
+                value => value,
+            };
+            let types: Vec<usize> = (0..union_schema.variants().len()).filter(|index| !matches!(union_schema.variants()[*index], Schema::Null)).collect();
+            let (index, value) = match (value, types.as_slice()) {
+                (Value::Null, _) => (union_schema.variants().iter().position(|variant| matches!(variant, Schema::Null)), Value::Null),
+                (value, [index]) => (Some(*index), value),
+                // A generated enum serializes its variant with the index of its type among the types of the union without null
+                (Value::Record(fields), _) => match <[(String, Value); 2]>::try_from(fields) {
+                    Ok([(_, Value::Enum(variant, _)), (_, Value::Union(_, value))]) => (types.get(variant as usize).copied(), *value),
+                    _ => (None, Value::Null),
+                },
+                _ => (None, Value::Null),
+            };
+            let index = index.ok_or(apache_avro::Error::FindUnionVariant)?;
+            let value = resolve_value(value, &union_schema.variants()[index], names)?;
+            Ok(Value::Union(index as u32, Box::new(value)))
+        }
+        (Value::Bytes(bytes), Schema::Fixed(fixed_schema)) => Value::Fixed(fixed_schema.size, bytes).resolve(schema),
+        (Value::Bytes(bytes), Schema::Duration) => Value::Fixed(12, bytes).resolve(schema),
+        (value, schema) => value.resolve(schema),
+    }
+}
+
+/// Replace the union values of the generated union enums with the representation of their variant read by the serde
+/// deserializer of `apache_avro`: a record with the index of the type among the types of the union without null, then the value
+pub fn union_variants(value: apache_avro::types::Value, schema: &apache_avro::Schema) -> apache_avro::types::Value {
+    match apache_avro::schema::ResolvedSchema::try_from(schema) {
+        Ok(resolved) => variant_values(value, schema, resolved.get_names()),
+        Err(_) => value,
+    }
+}
+
+fn variant_values(value: apache_avro::types::Value, schema: &apache_avro::Schema, names: &apache_avro::schema::NamesRef) -> apache_avro::types::Value {
+    use apache_avro::types::Value;
+    use apache_avro::Schema;
+
+    match (value, schema) {
+        (value, Schema::Ref { name }) => match names.get(name) {
+            Some(definition) => variant_values(value, definition, names),
+            None => value,
+        },
+        (Value::Record(fields), Schema::Record(record_schema)) => Value::Record(
+            fields
+                .into_iter()
+                .map(|(name, value)| match record_schema.lookup.get(&name) {
+                    Some(position) => {
+                        let value = variant_values(value, &record_schema.fields[*position].schema, names);
+                        (name, value)
+                    }
+                    None => (name, value),
+                })
+                .collect(),
+        ),
+        (Value::Array(items), Schema::Array(items_schema)) => Value::Array(items.into_iter().map(|item| variant_values(item, items_schema, names)).collect()),
+        (Value::Map(values), Schema::Map(values_schema)) => Value::Map(values.into_iter().map(|(key, value)| (key, variant_values(value, values_schema, names))).collect()),
+        (Value::Union(index, value), Schema::Union(union_schema)) => {
+            let variants = union_schema.variants();
+            let value = match variants.get(index as usize) {
+                Some(variant) => variant_values(*value, variant, names),
+                None => *value,
+            };
+            let is_type = |variant: &&Schema| !matches!(variant, Schema::Null);
+            if variants.iter().filter(is_type).count() < 2 || matches!(value, Value::Null) {
+                return Value::Union(index, Box::new(value));
+            }
+            let variant = variants.iter().take(index as usize).filter(is_type).count();
+            let variant = Value::Record(vec![("type".to_string(), Value::String(variant.to_string())), ("value".to_string(), value)]);
+            match union_schema.is_nullable() {
+                // The value of an option
+                true => Value::Union(index, Box::new(variant)),
+                false => variant,
+            }
+        }
+        (value, _) => value,
+    }
+}
+
+}
+
@@ -0,0 +1,677 @@
+pub mod com {
+pub mod example {
+pub mod events {
+/// Event with dates, times and identifiers
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Event {
+    #[serde(with = "crate::round_trip_jiff::logical_types::Uuid")]
+    #[serde(default = "Event::default_id")]
+    pub id: uuid::Uuid,
+    #[serde(with = "crate::round_trip_jiff::logical_types::Date")]
+    #[serde(default = "Event::default_day")]
+    pub day: jiff::civil::Date,
+    #[serde(with = "crate::round_trip_jiff::logical_types::TimeMillis")]
+    pub opening: jiff::civil::Time,
+    #[serde(with = "crate::round_trip_jiff::logical_types::TimeMicros")]
+    #[serde(default = "Event::default_closing")]
+    pub closing: jiff::civil::Time,
+    #[serde(with = "crate::round_trip_jiff::logical_types::TimestampMillis")]
+    #[serde(default = "Event::default_created")]
+    pub created: jiff::Timestamp,
+    #[serde(with = "crate::round_trip_jiff::logical_types::TimestampMicros")]
+    #[serde(default = "Event::default_updated")]
+    pub updated: Option<jiff::Timestamp>,
+    #[serde(with = "crate::round_trip_jiff::logical_types::LocalTimestampMillis")]
+    #[serde(default = "Event::default_local_created")]
+    pub local_created: jiff::civil::DateTime,
+    #[serde(with = "crate::round_trip_jiff::logical_types::LocalTimestampMicros")]
+    pub local_updated: jiff::civil::DateTime,
+    #[serde(with = "crate::round_trip_jiff::logical_types::Date")]
+    pub holidays: Vec<jiff::civil::Date>,
+    #[serde(with = "crate::round_trip_jiff::logical_types::TimestampMillis")]
+    pub checkpoints: std::collections::HashMap<String, jiff::Timestamp>,
+    pub reference: crate::round_trip_jiff::com::example::events::UnionUuidLong,
+}
+
+impl Event {
+    /// JSON of the Avro schema
+    ///
+    /// The defaults of `com.example.events.Event.day` are removed, apache_avro rejects them: the `Default` impl still uses them,
+    /// and the fingerprints of the Parsing Canonical Form don't depend on the defaults
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Event","namespace":"com.example.events","doc":"Event with dates, times and identifiers","fields":[{"name":"id","type":{"type":"string","logicalType":"uuid"},"default":"5f0c6a2e-8d1b-4c3e-9a7f-2b6d4e8f1a3c"},{"name":"day","type":{"type":"int","logicalType":"date"}},{"name":"opening","type":{"type":"int","logicalType":"time-millis"}},{"name":"closing","type":{"type":"long","logicalType":"time-micros"},"default":64800000000},{"name":"created","type":{"type":"long","logicalType":"timestamp-millis"},"default":1705312800000},{"name":"updated","type":["null",{"type":"long","logicalType":"timestamp-micros"}],"default":null},{"name":"local_created","type":{"type":"long","logicalType":"local-timestamp-millis"},"default":1705312800000},{"name":"local_updated","type":{"type":"long","logicalType":"local-timestamp-micros"}},{"name":"holidays","type":{"type":"array","items":{"type":"int","logicalType":"date"}}},{"name":"checkpoints","type":{"type":"map","values":{"type":"long","logicalType":"timestamp-millis"}}},{"name":"reference","type":[{"type":"string","logicalType":"uuid"},"long"]}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xb8, 0x66, 0x3a, 0x09, 0x30, 0x58, 0xa9, 0x1c];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    /// Header of the single-object encoding: the C3 01 marker and the Rabin fingerprint
+    pub const SINGLE_OBJECT_HEADER: [u8; 10] = [0xc3, 0x01, 0xb8, 0x66, 0x3a, 0x09, 0x30, 0x58, 0xa9, 0x1c];
+
+    /// Encode the record with the single-object encoding: the header then the binary encoded record
+    pub fn to_single_object(&self) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = crate::round_trip_jiff::values::resolve(apache_avro::to_value(self)?, Self::schema())?;
+        let mut bytes = Self::SINGLE_OBJECT_HEADER.to_vec();
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the single-object encoding, a different header returns a `SingleObjectHeaderMismatch` error
+    pub fn from_single_object(bytes: &[u8]) -> Result<Self, apache_avro::Error> {
+        let header: [u8; 10] = bytes.get(..10).and_then(|h| h.try_into().ok()).unwrap_or_default();
+        if header != Self::SINGLE_OBJECT_HEADER {
+            return Err(apache_avro::Error::SingleObjectHeaderMismatch(Self::SINGLE_OBJECT_HEADER, header));
+        }
+        let value = apache_avro::from_avro_datum(Self::schema(), &mut &bytes[10..], None)?;
+        apache_avro::from_value(&crate::round_trip_jiff::values::union_variants(value, Self::schema()))
+    }
+
+    /// Encode the record with the Confluent wire format: the magic byte 0, the schema id in big endian then the binary encoded record
+    pub fn to_confluent_bytes(&self, schema_id: u32) -> Result<Vec<u8>, apache_avro::Error> {
+        let value = crate::round_trip_jiff::values::resolve(apache_avro::to_value(self)?, Self::schema())?;
+        let mut bytes = vec![0];
+        bytes.extend_from_slice(&schema_id.to_be_bytes());
+        bytes.append(&mut apache_avro::to_avro_datum(Self::schema(), value)?);
+        Ok(bytes)
+    }
+
+    /// Decode a record encoded with the Confluent wire format, the writer schema is found by the resolver from the schema id
+    pub fn from_confluent_bytes(bytes: &[u8], resolver: &impl crate::round_trip_jiff::confluent::SchemaResolver) -> Result<Self, crate::round_trip_jiff::confluent::ConfluentError> {
+        let (schema_id, mut body) = match bytes {
+            [0, b1, b2, b3, b4, body @ ..] => (u32::from_be_bytes([*b1, *b2, *b3, *b4]), body),
+            _ => return Err(crate::round_trip_jiff::confluent::ConfluentError::InvalidHeader),
+        };
+        let writer_schema = resolver.resolve_schema(schema_id).ok_or(crate::round_trip_jiff::confluent::ConfluentError::UnknownSchemaId(schema_id))?;
+        let reader_schema = (writer_schema != *Self::schema()).then(Self::schema);
+        let value = apache_avro::from_avro_datum(&writer_schema, &mut body, reader_schema)?;
+        Ok(apache_avro::from_value(&crate::round_trip_jiff::values::union_variants(value, Self::schema()))?)
+    }
+
+    #[inline(always)]
+    pub fn default_id() -> uuid::Uuid {
+        uuid::uuid!("5f0c6a2e-8d1b-4c3e-9a7f-2b6d4e8f1a3c")
+    }
+
+    #[inline(always)]
+    pub fn default_day() -> jiff::civil::Date {
+        jiff::civil::date(2024, 1, 15)
+    }
+
+    #[inline(always)]
+    pub fn default_closing() -> jiff::civil::Time {
+        jiff::civil::time(18, 0, 0, 0)
+    }
+
+    #[inline(always)]
+    pub fn default_created() -> jiff::Timestamp {
+        jiff::Timestamp::from_millisecond(1705312800000).expect("The default value is in range")
+    }
+
+    #[inline(always)]
+    pub fn default_updated() -> Option<jiff::Timestamp> {
+        None
+    }
+
+    #[inline(always)]
+    pub fn default_local_created() -> jiff::civil::DateTime {
+        jiff::civil::date(2024, 1, 15).at(10, 0, 0, 0)
+    }
+}
+
+impl Default for Event {
+    fn default() -> Self {
+        Self {
+            id: Self::default_id(),
+            day: Self::default_day(),
+            opening: Default::default(),
+            closing: Self::default_closing(),
+            created: Self::default_created(),
+            updated: Self::default_updated(),
+            local_created: Self::default_local_created(),
+            local_updated: jiff::civil::date(1970, 1, 1).at(0, 0, 0, 0),
+            holidays: Default::default(),
+            checkpoints: Default::default(),
+            reference: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Event {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionUuidLong {
+    #[serde(alias = "0")]
+    Uuid(#[serde(with = "crate::round_trip_jiff::logical_types::Uuid")] uuid::Uuid),
+    #[serde(alias = "1")]
+    Long(i64),
+}
+
+impl Default for UnionUuidLong {
+    fn default() -> Self {
+        Self::Uuid(Default::default())
+    }
+}
+
+impl UnionUuidLong {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"[{"type":"string","logicalType":"uuid"},"long"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionUuidLong {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+}
+
+}
+
+}
+
+pub mod confluent {
+/// Find the writer schemas registered in the Confluent Schema Registry from their ids
+pub trait SchemaResolver {
+    /// Return the schema registered with the id, None when the id is unknown
+    fn resolve_schema(&self, schema_id: u32) -> Option<apache_avro::Schema>;
+}
+
+impl SchemaResolver for std::collections::HashMap<u32, apache_avro::Schema> {
+    fn resolve_schema(&self, schema_id: u32) -> Option<apache_avro::Schema> {
+        self.get(&schema_id).cloned()
+    }
+}
+
+/// Errors returned when a record is decoded from the Confluent wire format
+#[derive(Debug)]
+pub enum ConfluentError {
+    /// The bytes don't start with the magic byte and the schema id
+    InvalidHeader,
+    /// The resolver doesn't know the schema id
+    UnknownSchemaId(u32),
+    Avro(apache_avro::Error),
+}
+
+impl From<apache_avro::Error> for ConfluentError {
+    fn from(error: apache_avro::Error) -> Self {
+        Self::Avro(error)
+    }
+}
+
+impl std::fmt::Display for ConfluentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidHeader => write!(f, "Invalid Confluent wire format header"),
+            Self::UnknownSchemaId(schema_id) => write!(f, "Unknown schema id {schema_id}"),
+            Self::Avro(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfluentError {}
+
+}
+
+pub mod logical_types {
+/// A value of a logical type, or a container of values, serialized like the Avro logical type
+pub trait AvroLogicalType<Unit>: Sized {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+}
+
+/// `date`: the number of days since the Unix epoch, an int
+/// The fields use it with `#[serde(with = "Date")]`
+pub struct Date;
+
+impl Date {
+    pub fn serialize<T: AvroLogicalType<Self>, S: serde::Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize_logical(serializer)
+    }
+
+    pub fn deserialize<'de, T: AvroLogicalType<Self>, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_logical(deserializer)
+    }
+}
+
+impl AvroLogicalType<Date> for jiff::civil::Date {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(i32::try_from(self.duration_since(jiff::civil::date(1970, 1, 1)).as_secs() / 86_400).map_err(serde::ser::Error::custom)?)
+    }
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let days = <i32 as serde::Deserialize>::deserialize(deserializer)?;
+        jiff::civil::date(1970, 1, 1)
+            .checked_add(jiff::SignedDuration::from_hours(i64::from(days) * 24))
+            .ok()
+            .ok_or_else(|| serde::de::Error::custom(format!("The date {days} is out of range")))
+    }
+}
+
+/// `time-millis`: the number of milliseconds after midnight, an int
+/// The fields use it with `#[serde(with = "TimeMillis")]`
+pub struct TimeMillis;
+
+impl TimeMillis {
+    pub fn serialize<T: AvroLogicalType<Self>, S: serde::Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize_logical(serializer)
+    }
+
+    pub fn deserialize<'de, T: AvroLogicalType<Self>, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_logical(deserializer)
+    }
+}
+
+impl AvroLogicalType<TimeMillis> for jiff::civil::Time {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(i32::try_from(self.duration_since(jiff::civil::Time::midnight()).as_millis()).map_err(serde::ser::Error::custom)?)
+    }
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let millis = <i32 as serde::Deserialize>::deserialize(deserializer)?;
+        jiff::civil::Time::midnight()
+            .checked_add(jiff::SignedDuration::from_millis(i64::from(millis)))
+            .ok()
+            .ok_or_else(|| serde::de::Error::custom(format!("The time {millis} is out of range")))
+    }
+}
+
+/// `time-micros`: the number of microseconds after midnight, a long
+/// The fields use it with `#[serde(with = "TimeMicros")]`
+pub struct TimeMicros;
+
+impl TimeMicros {
+    pub fn serialize<T: AvroLogicalType<Self>, S: serde::Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize_logical(serializer)
+    }
+
+    pub fn deserialize<'de, T: AvroLogicalType<Self>, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_logical(deserializer)
+    }
+}
+
+impl AvroLogicalType<TimeMicros> for jiff::civil::Time {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(i64::try_from(self.duration_since(jiff::civil::Time::midnight()).as_micros()).map_err(serde::ser::Error::custom)?)
+    }
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let micros = <i64 as serde::Deserialize>::deserialize(deserializer)?;
+        jiff::civil::Time::midnight()
+            .checked_add(jiff::SignedDuration::from_micros(micros))
+            .ok()
+            .ok_or_else(|| serde::de::Error::custom(format!("The time {micros} is out of range")))
+    }
+}
+
+/// `timestamp-millis`: the number of milliseconds since the Unix epoch in UTC, a long
+/// The fields use it with `#[serde(with = "TimestampMillis")]`
+pub struct TimestampMillis;
+
+impl TimestampMillis {
+    pub fn serialize<T: AvroLogicalType<Self>, S: serde::Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize_logical(serializer)
+    }
+
+    pub fn deserialize<'de, T: AvroLogicalType<Self>, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_logical(deserializer)
+    }
+}
+
+impl AvroLogicalType<TimestampMillis> for jiff::Timestamp {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(self.as_millisecond())
+    }
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let timestamp = <i64 as serde::Deserialize>::deserialize(deserializer)?;
+        jiff::Timestamp::from_millisecond(timestamp)
+            .ok()
+            .ok_or_else(|| serde::de::Error::custom(format!("The timestamp {timestamp} is out of range")))
+    }
+}
+
+/// `timestamp-micros`: the number of microseconds since the Unix epoch in UTC, a long
+/// The fields use it with `#[serde(with = "TimestampMicros")]`
+pub struct TimestampMicros;
+
+impl TimestampMicros {
+    pub fn serialize<T: AvroLogicalType<Self>, S: serde::Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize_logical(serializer)
+    }
+
+    pub fn deserialize<'de, T: AvroLogicalType<Self>, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_logical(deserializer)
+    }
+}
+
+impl AvroLogicalType<TimestampMicros> for jiff::Timestamp {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(self.as_microsecond())
+    }
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let timestamp = <i64 as serde::Deserialize>::deserialize(deserializer)?;
+        jiff::Timestamp::from_microsecond(timestamp)
+            .ok()
+            .ok_or_else(|| serde::de::Error::custom(format!("The timestamp {timestamp} is out of range")))
+    }
+}
+
+/// `local-timestamp-millis`: the number of milliseconds since the Unix epoch in local time, a long
+/// The fields use it with `#[serde(with = "LocalTimestampMillis")]`
+pub struct LocalTimestampMillis;
+
+impl LocalTimestampMillis {
+    pub fn serialize<T: AvroLogicalType<Self>, S: serde::Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize_logical(serializer)
+    }
+
+    pub fn deserialize<'de, T: AvroLogicalType<Self>, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_logical(deserializer)
+    }
+}
+
+impl AvroLogicalType<LocalTimestampMillis> for jiff::civil::DateTime {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let timestamp = jiff::tz::Offset::UTC.to_timestamp(*self).map_err(serde::ser::Error::custom)?;
+        serializer.serialize_i64(timestamp.as_millisecond())
+    }
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let timestamp = <i64 as serde::Deserialize>::deserialize(deserializer)?;
+        jiff::Timestamp::from_millisecond(timestamp)
+            .ok()
+            .map(|timestamp| jiff::tz::Offset::UTC.to_datetime(timestamp))
+            .ok_or_else(|| serde::de::Error::custom(format!("The timestamp {timestamp} is out of range")))
+    }
+}
+
+/// `local-timestamp-micros`: the number of microseconds since the Unix epoch in local time, a long
+/// The fields use it with `#[serde(with = "LocalTimestampMicros")]`
+pub struct LocalTimestampMicros;
+
+impl LocalTimestampMicros {
+    pub fn serialize<T: AvroLogicalType<Self>, S: serde::Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize_logical(serializer)
+    }
+
+    pub fn deserialize<'de, T: AvroLogicalType<Self>, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_logical(deserializer)
+    }
+}
+
+impl AvroLogicalType<LocalTimestampMicros> for jiff::civil::DateTime {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let timestamp = jiff::tz::Offset::UTC.to_timestamp(*self).map_err(serde::ser::Error::custom)?;
+        serializer.serialize_i64(timestamp.as_microsecond())
+    }
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let timestamp = <i64 as serde::Deserialize>::deserialize(deserializer)?;
+        jiff::Timestamp::from_microsecond(timestamp)
+            .ok()
+            .map(|timestamp| jiff::tz::Offset::UTC.to_datetime(timestamp))
+            .ok_or_else(|| serde::de::Error::custom(format!("The timestamp {timestamp} is out of range")))
+    }
+}
+
+/// `uuid`: the string representation of the UUID
+/// The fields use it with `#[serde(with = "Uuid")]`
+pub struct Uuid;
+
+impl Uuid {
+    pub fn serialize<T: AvroLogicalType<Self>, S: serde::Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize_logical(serializer)
+    }
+
+    pub fn deserialize<'de, T: AvroLogicalType<Self>, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_logical(deserializer)
+    }
+}
+
+impl AvroLogicalType<Uuid> for uuid::Uuid {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let uuid = <String as serde::Deserialize>::deserialize(deserializer)?;
+        uuid::Uuid::parse_str(&uuid).map_err(serde::de::Error::custom)
+    }
+}
+
+impl<U, T: AvroLogicalType<U>> AvroLogicalType<U> for Option<T> {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Some(value) => serializer.serialize_some(&LogicalValue::<U, T>(value, std::marker::PhantomData)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_option(LogicalVisitor::<U, Self>(std::marker::PhantomData))
+    }
+}
+
+impl<U, T: AvroLogicalType<U>> AvroLogicalType<U> for Vec<T> {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter().map(|value| LogicalValue::<U, T>(value, std::marker::PhantomData)))
+    }
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(LogicalVisitor::<U, Self>(std::marker::PhantomData))
+    }
+}
+
+impl<U, T: AvroLogicalType<U>> AvroLogicalType<U> for std::collections::HashMap<String, T> {
+    fn serialize_logical<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter().map(|(key, value)| (key, LogicalValue::<U, T>(value, std::marker::PhantomData))))
+    }
+
+    fn deserialize_logical<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(LogicalVisitor::<U, Self>(std::marker::PhantomData))
+    }
+}
+
+/// Serialize a value inside a container
+struct LogicalValue<'a, U, T>(&'a T, std::marker::PhantomData<U>);
+
+impl<U, T: AvroLogicalType<U>> serde::Serialize for LogicalValue<'_, U, T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize_logical(serializer)
+    }
+}
+
+/// Deserialize the values of a container
+struct LogicalVisitor<U, T>(std::marker::PhantomData<(U, T)>);
+
+impl<'de, U, T: AvroLogicalType<U>> serde::de::DeserializeSeed<'de> for LogicalVisitor<U, T> {
+    type Value = T;
+
+    fn deserialize<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_logical(deserializer)
+    }
+}
+
+impl<'de, U, T: AvroLogicalType<U>> serde::de::Visitor<'de> for LogicalVisitor<U, Option<T>> {
+    type Value = Option<T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("an optional value")
+    }
+
+    fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        T::deserialize_logical(deserializer).map(Some)
+    }
+}
+
+impl<'de, U, T: AvroLogicalType<U>> serde::de::Visitor<'de> for LogicalVisitor<U, Vec<T>> {
+    type Value = Vec<T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("an array")
+    }
+
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut values = Vec::new();
+        while let Some(value) = seq.next_element_seed(LogicalVisitor::<U, T>(std::marker::PhantomData))? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+impl<'de, U, T: AvroLogicalType<U>> serde::de::Visitor<'de> for LogicalVisitor<U, std::collections::HashMap<String, T>> {
+    type Value = std::collections::HashMap<String, T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a map")
+    }
+
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut values = std::collections::HashMap::new();
+        while let Some(key) = map.next_key::<String>()? {
+            values.insert(key, map.next_value_seed(LogicalVisitor::<U, T>(std::marker::PhantomData))?);
+        }
+        Ok(values)
+    }
+}
+
+}
+
+pub mod values {
+/// Resolve a value serialized by serde with the schema, like `Value::resolve`: the bytes of the fixed become fixed values,
+/// and the variants of the generated union enums are written with their own type of the union, the schema resolution
+/// of `apache_avro` finds the type of an union value from the value and can choose another one
+pub fn resolve(value: apache_avro::types::Value, schema: &apache_avro::Schema) -> Result<apache_avro::types::Value, apache_avro::Error> {
+    let resolved = apache_avro::schema::ResolvedSchema::try_from(schema)?;
+    resolve_value(value, schema, resolved.get_names())
+}
+
+fn resolve_value(value: apache_avro::types::Value, schema: &apache_avro::Schema, names: &apache_avro::schema::NamesRef) -> Result<apache_avro::types::Value, apache_avro::Error> {
+    use apache_avro::types::Value;
+    use apache_avro::Schema;
+
+    match (value, schema) {
+        (value, Schema::Ref { name }) => match names.get(name) {
+            Some(definition) => resolve_value(value, definition, names),
+            None => value.resolve(schema),
+        },
+        (Value::Record(fields), Schema::Record(record_schema)) => {
+            let mut resolved_fields = Vec::with_capacity(fields.len());
+            for (name, value) in fields {
+                let value = match record_schema.lookup.get(&name) {
+                    Some(position) => resolve_value(value, &record_schema.fields[*position].schema, names)?,
+                    None => value,
+                };
+                resolved_fields.push((name, value));
+            }
+            Ok(Value::Record(resolved_fields))
+        }
+        (Value::Array(items), Schema::Array(items_schema)) => items.into_iter().map(|item| resolve_value(item, items_schema, names)).collect::<Result<_, _>>().map(Value::Array),
+        (Value::Map(values), Schema::Map(values_schema)) => values.into_iter().map(|(key, value)| resolve_value(value, values_schema, names).map(|value| (key, value))).collect::<Result<_, _>>().map(Value::Map),
+        (value, Schema::Union(union_schema)) => {
+            // The options are serialized as union values, without the index of their type in the schema
+            let value = match value {
+                Value::Union(_, value) => *value,
+                value => value,
+            };
+            let types: Vec<usize> = (0..union_schema.variants().len()).filter(|index| !matches!(union_schema.variants()[*index], Schema::Null)).collect();
+            let (index, value) = match (value, types.as_slice()) {
+                (Value::Null, _) => (union_schema.variants().iter().position(|variant| matches!(variant, Schema::Null)), Value::Null),
+                (value, [index]) => (Some(*index), value),
+                // A generated enum serializes its variant with the index of its type among the types of the union without null
+                (Value::Record(fields), _) => match <[(String, Value); 2]>::try_from(fields) {
+                    Ok([(_, Value::Enum(variant, _)), (_, Value::Union(_, value))]) => (types.get(variant as usize).copied(), *value),
+                    _ => (None, Value::Null),
+                },
+                _ => (None, Value::Null),
+            };
+            let index = index.ok_or(apache_avro::Error::FindUnionVariant)?;
+            let value = resolve_value(value, &union_schema.variants()[index], names)?;
+            Ok(Value::Union(index as u32, Box::new(value)))
+        }
+        (Value::Bytes(bytes), Schema::Fixed(fixed_schema)) => Value::Fixed(fixed_schema.size, bytes).resolve(schema),
+        (Value::Bytes(bytes), Schema::Duration) => Value::Fixed(12, bytes).resolve(schema),
+        (value, schema) => value.resolve(schema),
+    }
+}
+
+/// Replace the union values of the generated union enums with the representation of their variant read by the serde
+/// deserializer of `apache_avro`: a record with the index of the type among the types of the union without null, then the value
+pub fn union_variants(value: apache_avro::types::Value, schema: &apache_avro::Schema) -> apache_avro::types::Value {
+    match apache_avro::schema::ResolvedSchema::try_from(schema) {
+        Ok(resolved) => variant_values(value, schema, resolved.get_names()),
+        Err(_) => value,
+    }
+}
+
+fn variant_values(value: apache_avro::types::Value, schema: &apache_avro::Schema, names: &apache_avro::schema::NamesRef) -> apache_avro::types::Value {
+    use apache_avro::types::Value;
+    use apache_avro::Schema;
+
+    match (value, schema) {
+        (value, Schema::Ref { name }) => match names.get(name) {
+            Some(definition) => variant_values(value, definition, names),
+            None => value,
+        },
+        (Value::Record(fields), Schema::Record(record_schema)) => Value::Record(
+            fields
+                .into_iter()
+                .map(|(name, value)| match record_schema.lookup.get(&name) {
+                    Some(position) => {
+                        let value = variant_values(value, &record_schema.fields[*position].schema, names);
+                        (name, value)
+                    }
+                    None => (name, value),
+                })
+                .collect(),
+        ),
+        (Value::Array(items), Schema::Array(items_schema)) => Value::Array(items.into_iter().map(|item| variant_values(item, items_schema, names)).collect()),
+        (Value::Map(values), Schema::Map(values_schema)) => Value::Map(values.into_iter().map(|(key, value)| (key, variant_values(value, values_schema, names))).collect()),
+        (Value::Union(index, value), Schema::Union(union_schema)) => {
+            let variants = union_schema.variants();
+            let value = match variants.get(index as usize) {
+                Some(variant) => variant_values(*value, variant, names),
+                None => *value,
+            };
+            let is_type = |variant: &&Schema| !matches!(variant, Schema::Null);
+            if variants.iter().filter(is_type).count() < 2 || matches!(value, Value::Null) {
+                return Value::Union(index, Box::new(value));
+            }
+            let variant = variants.iter().take(index as usize).filter(is_type).count();
+            let variant = Value::Record(vec![("type".to_string(), Value::String(variant.to_string())), ("value".to_string(), value)]);
+            match union_schema.is_nullable() {
+                // The value of an option
+                true => Value::Union(index, Box::new(variant)),
+                false => variant,
+            }
+        }
+        (value, _) => value,
+    }
+}
+
+}
+
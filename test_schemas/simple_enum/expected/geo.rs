@@ -8,4 +8,23 @@ pub enum CardinalPoints {
     West,
 }
 
+impl CardinalPoints {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"enum","namespace":"geo","name":"CardinalPoints","doc":"Indicate the direction on the compass.","symbols":["North","South","East","West"]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xf1, 0x06, 0xb5, 0x41, 0xa0, 0xcd, 0x28, 0x8b];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl apache_avro::AvroSchema for CardinalPoints {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
 
@@ -1,4 +1,4 @@
-#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize, Default)]
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
 #[serde(default)]
 pub struct User {
     #[serde(rename = "as")]
@@ -8,8 +8,10 @@ pub struct User {
     pub favorite_number: i32,
     #[serde(default = "User::default_likes_pizza")]
     pub likes_pizza: bool,
+    #[serde(with = "crate::bytes::Bytes")]
     #[serde(default = "User::default_b")]
     pub b: Vec<u8>,
+    #[serde(with = "crate::bytes::Bytes")]
     #[serde(default = "User::default_union_b")]
     pub union_b: Option<Vec<u8>>,
     #[serde(rename = "A_Bool")]
@@ -22,6 +24,18 @@ pub struct User {
 }
 
 impl User {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","namespace":"first","name":"User","fields":[{"name":"as","type":"string"},{"name":"favoriteNumber","type":"int","default":7},{"name":"likes_pizza","type":"boolean","default":false},{"name":"b","type":"bytes","default":"ÿ"},{"name":"union_b","type":["null","bytes"],"default":null},{"name":"A_Bool","type":{"type":"array","items":"boolean"},"default":[true,false]},{"name":"SomeInteger","type":{"type":"array","items":"int"},"default":[12,-1]},{"name":"map_of_f64","type":{"type":"map","values":"double"}}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xde, 0x69, 0x37, 0xb5, 0x51, 0xb1, 0xb1, 0xf2];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
     #[inline(always)]
     pub fn default_favorite_number() -> i32 {
         7
@@ -34,7 +48,7 @@ impl User {
 
     #[inline(always)]
     pub fn default_b() -> Vec<u8> {
-        "ÿ".to_string()
+        vec![0xff]
     }
 
     #[inline(always)]
@@ -53,3 +67,24 @@ impl User {
     }
 }
 
+impl Default for User {
+    fn default() -> Self {
+        Self {
+            field_as: Default::default(),
+            favorite_number: Self::default_favorite_number(),
+            likes_pizza: Self::default_likes_pizza(),
+            b: Self::default_b(),
+            union_b: Self::default_union_b(),
+            a_bool: Self::default_a_bool(),
+            some_integer: Self::default_some_integer(),
+            map_of_f64: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for User {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
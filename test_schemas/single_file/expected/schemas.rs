@@ -0,0 +1,128 @@
+pub mod com {
+pub mod shop {
+pub mod status {
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize, Default)]
+pub enum OrderStatus {
+    #[default]
+    Created,
+    Shipped,
+    Delivered,
+}
+
+impl OrderStatus {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"enum","name":"OrderStatus","namespace":"com.shop.status","symbols":["Created","Shipped","Delivered"]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x03, 0xa6, 0x3b, 0x0d, 0xde, 0x73, 0x0a, 0xc2];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl apache_avro::AvroSchema for OrderStatus {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Order {
+    #[serde(rename = "Reference")]
+    pub reference: String,
+    #[serde(rename = "DeliveryAddress")]
+    pub delivery_address: crate::com::shop::ShippingAddress,
+    #[serde(rename = "BillingAddress")]
+    pub billing_address: Option<crate::com::shop::ShippingAddress>,
+    #[serde(rename = "Status")]
+    pub status: crate::com::shop::status::OrderStatus,
+}
+
+impl Order {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Order","namespace":"com.shop","fields":[{"name":"Reference","type":"string"},{"name":"DeliveryAddress","type":{"type":"record","name":"ShippingAddress","namespace":"com.shop","fields":[{"name":"Street","type":"string"},{"name":"City","type":"string"},{"name":"Country","type":"string","default":"FR"}]}},{"name":"BillingAddress","type":["null","ShippingAddress"]},{"name":"Status","type":{"type":"enum","name":"OrderStatus","namespace":"com.shop.status","symbols":["Created","Shipped","Delivered"]}}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xbe, 0x86, 0x7f, 0xc9, 0x26, 0x78, 0x15, 0x39];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl Default for Order {
+    fn default() -> Self {
+        Self {
+            reference: Default::default(),
+            delivery_address: Default::default(),
+            billing_address: Default::default(),
+            status: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Order {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct ShippingAddress {
+    #[serde(rename = "Street")]
+    pub street: String,
+    #[serde(rename = "City")]
+    pub city: String,
+    #[serde(rename = "Country")]
+    #[serde(default = "ShippingAddress::default_country")]
+    pub country: String,
+}
+
+impl ShippingAddress {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"ShippingAddress","namespace":"com.shop","fields":[{"name":"Street","type":"string"},{"name":"City","type":"string"},{"name":"Country","type":"string","default":"FR"}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x97, 0xe3, 0x7a, 0xf1, 0x63, 0xf8, 0x4c, 0xff];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    #[inline(always)]
+    pub fn default_country() -> String {
+        "FR".to_string()
+    }
+}
+
+impl Default for ShippingAddress {
+    fn default() -> Self {
+        Self {
+            street: Default::default(),
+            city: Default::default(),
+            country: Self::default_country(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for ShippingAddress {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+}
+
+}
+
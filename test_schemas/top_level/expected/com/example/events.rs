@@ -0,0 +1,128 @@
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Click {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Click {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Click","namespace":"com.example.events","fields":[{"name":"x","type":"int"},{"name":"y","type":"int"}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x03, 0x9e, 0x13, 0xaa, 0xab, 0xf4, 0xd7, 0x12];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl Default for Click {
+    fn default() -> Self {
+        Self {
+            x: Default::default(),
+            y: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Click {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Scroll {
+    pub offset: crate::com::example::events::UnionLongString,
+}
+
+impl Scroll {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Scroll","namespace":"com.example.events","fields":[{"name":"offset","type":["long","string"]}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x72, 0x53, 0x7a, 0xdf, 0x07, 0x4e, 0xc1, 0x23];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl Default for Scroll {
+    fn default() -> Self {
+        Self {
+            offset: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Scroll {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionClickScroll {
+    #[serde(alias = "0")]
+    Click(crate::com::example::events::Click),
+    #[serde(alias = "1")]
+    Scroll(crate::com::example::events::Scroll),
+}
+
+impl Default for UnionClickScroll {
+    fn default() -> Self {
+        Self::Click(Default::default())
+    }
+}
+
+impl UnionClickScroll {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"[{"type":"record","name":"Click","namespace":"com.example.events","fields":[{"name":"x","type":"int"},{"name":"y","type":"int"}]},{"type":"record","name":"Scroll","namespace":"com.example.events","fields":[{"name":"offset","type":["long","string"]}]}]"#;
+}
+
+impl apache_avro::AvroSchema for UnionClickScroll {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionLongString {
+    #[serde(alias = "0")]
+    Long(i64),
+    #[serde(alias = "1")]
+    String(String),
+}
+
+impl Default for UnionLongString {
+    fn default() -> Self {
+        Self::Long(Default::default())
+    }
+}
+
+impl UnionLongString {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"["long","string"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionLongString {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
@@ -0,0 +1,140 @@
+/// Type of a top level Avro array
+pub type ArrayTap = Vec<crate::com::example::gestures::Tap>;
+
+/// Type of a top level Avro array
+pub type ArrayUnionPinchString = Vec<crate::com::example::gestures::UnionPinchString>;
+
+/// Type of a top level Avro map
+pub type MapUnionSwipe = std::collections::HashMap<String, Option<crate::com::example::gestures::Swipe>>;
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Pinch {
+    pub scale: f32,
+}
+
+impl Pinch {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Pinch","namespace":"com.example.gestures","fields":[{"name":"scale","type":"float"}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xe2, 0x83, 0x8f, 0x4d, 0x90, 0xaa, 0x6c, 0x7a];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl Default for Pinch {
+    fn default() -> Self {
+        Self {
+            scale: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Pinch {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Swipe {
+    pub distance: f64,
+}
+
+impl Swipe {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Swipe","namespace":"com.example.gestures","fields":[{"name":"distance","type":"double"}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xa2, 0x7b, 0x59, 0xfb, 0xc5, 0x4d, 0x3d, 0x02];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl Default for Swipe {
+    fn default() -> Self {
+        Self {
+            distance: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Swipe {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Tap {
+    pub fingers: i32,
+}
+
+impl Tap {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Tap","namespace":"com.example.gestures","fields":[{"name":"fingers","type":"int"}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x43, 0x13, 0x98, 0xf0, 0x67, 0xf1, 0x9e, 0x4e];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl Default for Tap {
+    fn default() -> Self {
+        Self {
+            fingers: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Tap {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionPinchString {
+    #[serde(alias = "0")]
+    Pinch(crate::com::example::gestures::Pinch),
+    #[serde(alias = "1")]
+    String(String),
+}
+
+impl Default for UnionPinchString {
+    fn default() -> Self {
+        Self::Pinch(Default::default())
+    }
+}
+
+impl UnionPinchString {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"[{"type":"record","name":"Pinch","namespace":"com.example.gestures","fields":[{"name":"scale","type":"float"}]},"string"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionPinchString {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
@@ -0,0 +1,153 @@
+/// Serde glue of the bytes fields, serde serializes a `Vec<u8>` as a sequence and the serde deserializer of apache_avro doesn't read a sequence from `Value::Bytes`.
+///
+/// The fields use it with `#[serde(with = "Bytes")]`
+pub struct Bytes;
+
+impl Bytes {
+    pub fn serialize<T: AvroBytes, S: serde::Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize_avro_bytes(serializer)
+    }
+
+    pub fn deserialize<'de, T: AvroBytes, D: serde::Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_avro_bytes(deserializer)
+    }
+}
+
+/// Bytes, or a container of bytes, serialized as Avro bytes
+pub trait AvroBytes: Sized {
+    fn serialize_avro_bytes<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+
+    fn deserialize_avro_bytes<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+}
+
+impl AvroBytes for Vec<u8> {
+    fn serialize_avro_bytes<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self)
+    }
+
+    fn deserialize_avro_bytes<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_byte_buf(BytesVisitor::<Self>(std::marker::PhantomData))
+    }
+}
+
+impl<T: AvroBytes> AvroBytes for Option<T> {
+    fn serialize_avro_bytes<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Some(value) => serializer.serialize_some(&BytesValue(value)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    fn deserialize_avro_bytes<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_option(BytesVisitor::<Self>(std::marker::PhantomData))
+    }
+}
+
+impl<T: AvroBytes> AvroBytes for Vec<T> {
+    fn serialize_avro_bytes<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter().map(BytesValue))
+    }
+
+    fn deserialize_avro_bytes<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(BytesVisitor::<Self>(std::marker::PhantomData))
+    }
+}
+
+impl<T: AvroBytes> AvroBytes for std::collections::HashMap<String, T> {
+    fn serialize_avro_bytes<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter().map(|(key, value)| (key, BytesValue(value))))
+    }
+
+    fn deserialize_avro_bytes<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(BytesVisitor::<Self>(std::marker::PhantomData))
+    }
+}
+
+/// Serialize bytes inside a container
+struct BytesValue<'a, T>(&'a T);
+
+impl<T: AvroBytes> serde::Serialize for BytesValue<'_, T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize_avro_bytes(serializer)
+    }
+}
+
+/// Deserialize bytes, or the bytes of a container
+struct BytesVisitor<T>(std::marker::PhantomData<T>);
+
+impl<'de, T: AvroBytes> serde::de::DeserializeSeed<'de> for BytesVisitor<T> {
+    type Value = T;
+
+    fn deserialize<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_avro_bytes(deserializer)
+    }
+}
+
+impl<'de> serde::de::Visitor<'de> for BytesVisitor<Vec<u8>> {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("bytes")
+    }
+
+    fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+        Ok(bytes.to_vec())
+    }
+
+    fn visit_byte_buf<E: serde::de::Error>(self, bytes: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(bytes)
+    }
+}
+
+impl<'de, T: AvroBytes> serde::de::Visitor<'de> for BytesVisitor<Option<T>> {
+    type Value = Option<T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("optional bytes")
+    }
+
+    fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        T::deserialize_avro_bytes(deserializer).map(Some)
+    }
+}
+
+impl<'de, T: AvroBytes> serde::de::Visitor<'de> for BytesVisitor<Vec<T>> {
+    type Value = Vec<T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("an array of bytes")
+    }
+
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut values = Vec::new();
+        while let Some(value) = seq.next_element_seed(BytesVisitor::<T>(std::marker::PhantomData))? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+impl<'de, T: AvroBytes> serde::de::Visitor<'de> for BytesVisitor<std::collections::HashMap<String, T>> {
+    type Value = std::collections::HashMap<String, T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a map of bytes")
+    }
+
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut values = std::collections::HashMap::new();
+        while let Some(key) = map.next_key::<String>()? {
+            values.insert(key, map.next_value_seed(BytesVisitor::<T>(std::marker::PhantomData))?);
+        }
+        Ok(values)
+    }
+}
+
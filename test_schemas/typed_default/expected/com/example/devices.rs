@@ -0,0 +1,313 @@
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Device {
+    #[serde(with = "crate::bytes::Bytes")]
+    #[serde(default = "Device::default_firmware")]
+    pub firmware: Vec<u8>,
+    #[serde(with = "crate::bytes::Bytes")]
+    #[serde(default = "Device::default_empty_bytes")]
+    pub empty_bytes: Vec<u8>,
+    #[serde(default = "Device::default_mac")]
+    pub mac: crate::com::example::devices::Mac,
+    #[serde(default = "Device::default_state")]
+    pub state: crate::com::example::devices::State,
+    #[serde(default = "Device::default_labels")]
+    pub labels: std::collections::HashMap<String, String>,
+    #[serde(default = "Device::default_states")]
+    pub states: std::collections::HashMap<String, crate::com::example::devices::State>,
+    #[serde(default = "Device::default_ratio")]
+    pub ratio: f32,
+    #[serde(default = "Device::default_description")]
+    pub description: String,
+    #[serde(default = "Device::default_alias")]
+    pub alias: Option<String>,
+    #[serde(default = "Device::default_level")]
+    pub level: Option<i32>,
+    #[serde(default = "Device::default_value")]
+    pub value: Option<crate::com::example::devices::UnionLongString>,
+    #[serde(default = "Device::default_reading")]
+    pub reading: crate::com::example::devices::UnionStateDouble,
+    #[serde(default = "Device::default_states_history")]
+    pub states_history: Vec<crate::com::example::devices::State>,
+}
+
+impl Device {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Device","namespace":"com.example.devices","fields":[{"name":"firmware","type":"bytes","default":"ÿ\u0001A"},{"name":"empty_bytes","type":"bytes","default":""},{"name":"mac","type":{"type":"fixed","name":"Mac","size":6},"default":"\u0000\u001a+<Mÿ"},{"name":"state","type":{"type":"enum","name":"State","symbols":["ON","OFF","standby"]},"default":"standby"},{"name":"labels","type":{"type":"map","values":"string"},"default":{"room":"kitchen","floor":"1"}},{"name":"states","type":{"type":"map","values":"State"},"default":{"main":"ON"}},{"name":"ratio","type":"float","default":1},{"name":"description","type":"string","default":"A \"quoted\" text\n"},{"name":"alias","type":["null","string"],"default":null},{"name":"level","type":["int","null"],"default":3},{"name":"value","type":["long","string","null"],"default":12},{"name":"reading","type":["State","double"],"default":"OFF"},{"name":"states_history","type":{"type":"array","items":"State"},"default":["ON","OFF"]}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x77, 0xf0, 0x75, 0xcf, 0x51, 0x3e, 0xb9, 0xcf];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    #[inline(always)]
+    pub fn default_firmware() -> Vec<u8> {
+        vec![0xff, 0x01, 0x41]
+    }
+
+    #[inline(always)]
+    pub fn default_empty_bytes() -> Vec<u8> {
+        Vec::new()
+    }
+
+    #[inline(always)]
+    pub fn default_mac() -> crate::com::example::devices::Mac {
+        crate::com::example::devices::Mac([0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0xff])
+    }
+
+    #[inline(always)]
+    pub fn default_state() -> crate::com::example::devices::State {
+        crate::com::example::devices::State::Standby
+    }
+
+    #[inline(always)]
+    pub fn default_labels() -> std::collections::HashMap<String, String> {
+        std::collections::HashMap::from([("room".to_string(), "kitchen".to_string()), ("floor".to_string(), "1".to_string())])
+    }
+
+    #[inline(always)]
+    pub fn default_states() -> std::collections::HashMap<String, crate::com::example::devices::State> {
+        std::collections::HashMap::from([("main".to_string(), crate::com::example::devices::State::On)])
+    }
+
+    #[inline(always)]
+    pub fn default_ratio() -> f32 {
+        1.0
+    }
+
+    #[inline(always)]
+    pub fn default_description() -> String {
+        "A \"quoted\" text\n".to_string()
+    }
+
+    #[inline(always)]
+    pub fn default_alias() -> Option<String> {
+        None
+    }
+
+    #[inline(always)]
+    pub fn default_level() -> Option<i32> {
+        Some(3)
+    }
+
+    #[inline(always)]
+    pub fn default_value() -> Option<crate::com::example::devices::UnionLongString> {
+        Some(crate::com::example::devices::UnionLongString::Long(12))
+    }
+
+    #[inline(always)]
+    pub fn default_reading() -> crate::com::example::devices::UnionStateDouble {
+        crate::com::example::devices::UnionStateDouble::State(crate::com::example::devices::State::Off)
+    }
+
+    #[inline(always)]
+    pub fn default_states_history() -> Vec<crate::com::example::devices::State> {
+        vec![crate::com::example::devices::State::On, crate::com::example::devices::State::Off]
+    }
+}
+
+impl Default for Device {
+    fn default() -> Self {
+        Self {
+            firmware: Self::default_firmware(),
+            empty_bytes: Self::default_empty_bytes(),
+            mac: Self::default_mac(),
+            state: Self::default_state(),
+            labels: Self::default_labels(),
+            states: Self::default_states(),
+            ratio: Self::default_ratio(),
+            description: Self::default_description(),
+            alias: Self::default_alias(),
+            level: Self::default_level(),
+            value: Self::default_value(),
+            reading: Self::default_reading(),
+            states_history: Self::default_states_history(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Device {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Avro fixed of 6 bytes
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub struct Mac(pub [u8; 6]);
+
+impl Mac {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"fixed","name":"Mac","namespace":"com.example.devices","size":6}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x2b, 0x52, 0xc6, 0xdd, 0xf4, 0xb6, 0xc7, 0x57];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl apache_avro::AvroSchema for Mac {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+impl AsRef<[u8]> for Mac {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<[u8; 6]> for Mac {
+    fn from(bytes: [u8; 6]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl TryFrom<&[u8]> for Mac {
+    type Error = std::array::TryFromSliceError;
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        bytes.try_into().map(Self)
+    }
+}
+
+impl std::fmt::Display for Mac {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+impl serde::Serialize for Mac {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.0)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Mac {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FixedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for FixedVisitor {
+            type Value = Mac;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("6 bytes")
+            }
+
+            fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<Mac, E> {
+                Mac::try_from(bytes).map_err(|_| E::invalid_length(bytes.len(), &self))
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Mac, A::Error> {
+                let mut bytes = [0; 6];
+                for (index, byte) in bytes.iter_mut().enumerate() {
+                    *byte = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(index, &self))?;
+                }
+                if seq.next_element::<u8>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(7, &self));
+                }
+                Ok(Mac(bytes))
+            }
+        }
+
+        deserializer.deserialize_bytes(FixedVisitor)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize, Default)]
+pub enum State {
+    #[default]
+    #[serde(rename = "ON")]
+    On,
+    #[serde(rename = "OFF")]
+    Off,
+    #[serde(rename = "standby")]
+    Standby,
+}
+
+impl State {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"enum","name":"State","namespace":"com.example.devices","symbols":["ON","OFF","standby"]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x08, 0xd9, 0x99, 0x0f, 0x10, 0xbc, 0xd7, 0x01];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl apache_avro::AvroSchema for State {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionLongString {
+    #[serde(alias = "0")]
+    Long(i64),
+    #[serde(alias = "1")]
+    String(String),
+}
+
+impl Default for UnionLongString {
+    fn default() -> Self {
+        Self::Long(Default::default())
+    }
+}
+
+impl UnionLongString {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"["long","string"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionLongString {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionStateDouble {
+    #[serde(alias = "0")]
+    State(crate::com::example::devices::State),
+    #[serde(alias = "1")]
+    Double(f64),
+}
+
+impl Default for UnionStateDouble {
+    fn default() -> Self {
+        Self::State(Default::default())
+    }
+}
+
+impl UnionStateDouble {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"[{"type":"enum","name":"State","namespace":"com.example.devices","symbols":["ON","OFF","standby"]},"double"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionStateDouble {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
@@ -0,0 +1,468 @@
+/// An union for each kind of type
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Branches {
+    pub integer: crate::com::example::unions::UnionIntLong,
+    pub real: crate::com::example::unions::UnionFloatDouble,
+    pub color: crate::com::example::unions::UnionColorString,
+    pub signal: crate::com::example::unions::UnionColorLight,
+    pub content: crate::com::example::unions::UnionStringBytes,
+    pub hash: crate::com::example::unions::UnionHashBytes,
+    pub amount: crate::com::example::unions::UnionDecimalString,
+    pub delay: crate::com::example::unions::UnionDurationString,
+    pub day: crate::com::example::unions::UnionDateString,
+    #[serde(default = "Branches::default_optional")]
+    pub optional: Option<crate::com::example::unions::UnionIntLong>,
+    pub integers: Vec<crate::com::example::unions::UnionIntLong>,
+    pub reals: std::collections::HashMap<String, crate::com::example::unions::UnionFloatDouble>,
+}
+
+impl Branches {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Branches","namespace":"com.example.unions","doc":"An union for each kind of type","fields":[{"name":"integer","type":["int","long"]},{"name":"real","type":["float","double"]},{"name":"color","type":[{"type":"enum","name":"Color","symbols":["RED","GREEN"]},"string"]},{"name":"signal","type":["Color",{"type":"enum","name":"Light","symbols":["GREEN","RED"]}]},{"name":"content","type":["string","bytes"]},{"name":"hash","type":[{"type":"fixed","name":"Hash","size":4},"bytes"]},{"name":"amount","type":[{"type":"bytes","logicalType":"decimal","precision":10,"scale":2},"string"]},{"name":"delay","type":[{"type":"fixed","name":"Delay","size":12,"logicalType":"duration"},"string"]},{"name":"day","type":[{"type":"int","logicalType":"date"},"string"]},{"name":"optional","type":["null","int","long"],"default":null},{"name":"integers","type":{"type":"array","items":["int","long"]}},{"name":"reals","type":{"type":"map","values":["float","double"]}}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xd1, 0x3c, 0x1e, 0xea, 0x52, 0x2d, 0x81, 0x31];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    #[inline(always)]
+    pub fn default_optional() -> Option<crate::com::example::unions::UnionIntLong> {
+        None
+    }
+}
+
+impl Default for Branches {
+    fn default() -> Self {
+        Self {
+            integer: Default::default(),
+            real: Default::default(),
+            color: Default::default(),
+            signal: Default::default(),
+            content: Default::default(),
+            hash: Default::default(),
+            amount: Default::default(),
+            delay: Default::default(),
+            day: Default::default(),
+            optional: Self::default_optional(),
+            integers: Default::default(),
+            reals: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Branches {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize, Default)]
+pub enum Color {
+    #[default]
+    #[serde(rename = "RED")]
+    Red,
+    #[serde(rename = "GREEN")]
+    Green,
+}
+
+impl Color {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"enum","name":"Color","namespace":"com.example.unions","symbols":["RED","GREEN"]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xd0, 0xb0, 0x2c, 0xf7, 0x6f, 0x53, 0xeb, 0x10];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl apache_avro::AvroSchema for Color {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Avro fixed of 4 bytes
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub struct Hash(pub [u8; 4]);
+
+impl Hash {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"fixed","name":"Hash","namespace":"com.example.unions","size":4}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x3c, 0x89, 0xbd, 0x6e, 0x31, 0x87, 0xe0, 0x8a];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl apache_avro::AvroSchema for Hash {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+impl AsRef<[u8]> for Hash {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<[u8; 4]> for Hash {
+    fn from(bytes: [u8; 4]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl TryFrom<&[u8]> for Hash {
+    type Error = std::array::TryFromSliceError;
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        bytes.try_into().map(Self)
+    }
+}
+
+impl std::fmt::Display for Hash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+impl serde::Serialize for Hash {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.0)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Hash {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FixedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for FixedVisitor {
+            type Value = Hash;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("4 bytes")
+            }
+
+            fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<Hash, E> {
+                Hash::try_from(bytes).map_err(|_| E::invalid_length(bytes.len(), &self))
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Hash, A::Error> {
+                let mut bytes = [0; 4];
+                for (index, byte) in bytes.iter_mut().enumerate() {
+                    *byte = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(index, &self))?;
+                }
+                if seq.next_element::<u8>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(5, &self));
+                }
+                Ok(Hash(bytes))
+            }
+        }
+
+        deserializer.deserialize_bytes(FixedVisitor)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize, Default)]
+pub enum Light {
+    #[default]
+    #[serde(rename = "GREEN")]
+    Green,
+    #[serde(rename = "RED")]
+    Red,
+}
+
+impl Light {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"enum","name":"Light","namespace":"com.example.unions","symbols":["GREEN","RED"]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x2a, 0x05, 0x45, 0x06, 0xee, 0x1e, 0x1f, 0x95];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl apache_avro::AvroSchema for Light {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionColorLight {
+    #[serde(alias = "0")]
+    Color(crate::com::example::unions::Color),
+    #[serde(alias = "1")]
+    Light(crate::com::example::unions::Light),
+}
+
+impl Default for UnionColorLight {
+    fn default() -> Self {
+        Self::Color(Default::default())
+    }
+}
+
+impl UnionColorLight {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"[{"type":"enum","name":"Color","namespace":"com.example.unions","symbols":["RED","GREEN"]},{"type":"enum","name":"Light","namespace":"com.example.unions","symbols":["GREEN","RED"]}]"#;
+}
+
+impl apache_avro::AvroSchema for UnionColorLight {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionColorString {
+    #[serde(alias = "0")]
+    Color(crate::com::example::unions::Color),
+    #[serde(alias = "1")]
+    String(String),
+}
+
+impl Default for UnionColorString {
+    fn default() -> Self {
+        Self::Color(Default::default())
+    }
+}
+
+impl UnionColorString {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"[{"type":"enum","name":"Color","namespace":"com.example.unions","symbols":["RED","GREEN"]},"string"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionColorString {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionDateString {
+    #[serde(alias = "0")]
+    Date(#[serde(with = "crate::logical_types::Date")] chrono::NaiveDate),
+    #[serde(alias = "1")]
+    String(String),
+}
+
+impl Default for UnionDateString {
+    fn default() -> Self {
+        Self::Date(Default::default())
+    }
+}
+
+impl UnionDateString {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"[{"type":"int","logicalType":"date"},"string"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionDateString {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionDecimalString {
+    #[serde(alias = "0")]
+    Decimal(#[serde(with = "crate::decimal::PrecisionScale::<10, 2>")] apache_avro::Decimal),
+    #[serde(alias = "1")]
+    String(String),
+}
+
+impl Default for UnionDecimalString {
+    fn default() -> Self {
+        Self::Decimal(apache_avro::Decimal::from([0x00]))
+    }
+}
+
+impl UnionDecimalString {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"[{"type":"bytes","logicalType":"decimal","scale":2,"precision":10},"string"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionDecimalString {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionDurationString {
+    #[serde(alias = "0")]
+    Duration(crate::duration::Duration),
+    #[serde(alias = "1")]
+    String(String),
+}
+
+impl Default for UnionDurationString {
+    fn default() -> Self {
+        Self::Duration(Default::default())
+    }
+}
+
+impl UnionDurationString {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"[{"type":{"type":"fixed","name":"duration","size":12},"logicalType":"duration"},"string"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionDurationString {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionFloatDouble {
+    #[serde(alias = "0")]
+    Float(f32),
+    #[serde(alias = "1")]
+    Double(f64),
+}
+
+impl Default for UnionFloatDouble {
+    fn default() -> Self {
+        Self::Float(Default::default())
+    }
+}
+
+impl UnionFloatDouble {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"["float","double"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionFloatDouble {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionHashBytes {
+    #[serde(alias = "0")]
+    Hash(crate::com::example::unions::Hash),
+    #[serde(alias = "1")]
+    Bytes(#[serde(with = "crate::bytes::Bytes")] Vec<u8>),
+}
+
+impl Default for UnionHashBytes {
+    fn default() -> Self {
+        Self::Hash(Default::default())
+    }
+}
+
+impl UnionHashBytes {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"[{"type":"fixed","name":"Hash","namespace":"com.example.unions","size":4},"bytes"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionHashBytes {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionIntLong {
+    #[serde(alias = "0")]
+    Int(i32),
+    #[serde(alias = "1")]
+    Long(i64),
+}
+
+impl Default for UnionIntLong {
+    fn default() -> Self {
+        Self::Int(Default::default())
+    }
+}
+
+impl UnionIntLong {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"["int","long"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionIntLong {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionStringBytes {
+    #[serde(alias = "0")]
+    String(String),
+    #[serde(alias = "1")]
+    Bytes(#[serde(with = "crate::bytes::Bytes")] Vec<u8>),
+}
+
+impl Default for UnionStringBytes {
+    fn default() -> Self {
+        Self::String(Default::default())
+    }
+}
+
+impl UnionStringBytes {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"["string","bytes"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionStringBytes {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
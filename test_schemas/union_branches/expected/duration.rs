@@ -0,0 +1,137 @@
+/// Avro duration: an amount of months, days and milliseconds, independent of each other
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub struct Duration {
+    pub months: u32,
+    pub days: u32,
+    pub millis: u32,
+}
+
+impl From<[u8; 12]> for Duration {
+    fn from(bytes: [u8; 12]) -> Self {
+        let number = |index: usize| u32::from_le_bytes([bytes[index], bytes[index + 1], bytes[index + 2], bytes[index + 3]]);
+        Self {
+            months: number(0),
+            days: number(4),
+            millis: number(8),
+        }
+    }
+}
+
+impl From<Duration> for [u8; 12] {
+    fn from(duration: Duration) -> Self {
+        let mut bytes = [0; 12];
+        bytes[0..4].copy_from_slice(&duration.months.to_le_bytes());
+        bytes[4..8].copy_from_slice(&duration.days.to_le_bytes());
+        bytes[8..12].copy_from_slice(&duration.millis.to_le_bytes());
+        bytes
+    }
+}
+
+impl From<apache_avro::Duration> for Duration {
+    fn from(duration: apache_avro::Duration) -> Self {
+        Self::from(<[u8; 12]>::from(duration))
+    }
+}
+
+impl From<Duration> for apache_avro::Duration {
+    fn from(duration: Duration) -> Self {
+        Self::from(<[u8; 12]>::from(duration))
+    }
+}
+
+/// A day is 24 hours, a duration with months has no fixed length and returns an error
+impl TryFrom<Duration> for std::time::Duration {
+    type Error = DurationError;
+    fn try_from(duration: Duration) -> Result<Self, Self::Error> {
+        if duration.months != 0 {
+            return Err(DurationError::Months(duration.months));
+        }
+        Ok(std::time::Duration::from_secs(u64::from(duration.days) * 86_400) + std::time::Duration::from_millis(u64::from(duration.millis)))
+    }
+}
+
+/// The whole days are the days of the duration, the rest is truncated to milliseconds
+impl TryFrom<std::time::Duration> for Duration {
+    type Error = DurationError;
+    fn try_from(duration: std::time::Duration) -> Result<Self, Self::Error> {
+        let days = u32::try_from(duration.as_secs() / 86_400).map_err(|_| DurationError::OutOfRange(duration))?;
+        let millis = (duration.as_secs() % 86_400) * 1_000 + u64::from(duration.subsec_millis());
+        Ok(Self {
+            months: 0,
+            days,
+            millis: u32::try_from(millis).map_err(|_| DurationError::OutOfRange(duration))?,
+        })
+    }
+}
+
+impl serde::Serialize for Duration {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&<[u8; 12]>::from(*self))
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Duration {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct DurationVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for DurationVisitor {
+            type Value = Duration;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("the 12 bytes of a duration")
+            }
+
+            fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<Duration, E> {
+                <[u8; 12]>::try_from(bytes).map(Duration::from).map_err(|_| E::invalid_length(bytes.len(), &self))
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Duration, A::Error> {
+                let mut bytes = [0; 12];
+                for (index, byte) in bytes.iter_mut().enumerate() {
+                    *byte = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(index, &self))?;
+                }
+                if seq.next_element::<u8>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(13, &self));
+                }
+                Ok(Duration::from(bytes))
+            }
+        }
+
+        deserializer.deserialize_bytes(DurationVisitor)
+    }
+}
+
+/// Error of the conversions between the Avro durations and `std::time::Duration`
+#[derive(Debug, PartialEq)]
+pub enum DurationError {
+    /// The Avro duration has months, their number of days is unknown
+    Months(u32),
+    /// The days or the milliseconds don't fit in an Avro duration
+    OutOfRange(std::time::Duration),
+}
+
+impl std::fmt::Display for DurationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Months(months) => write!(f, "The duration has {months} months, which have no fixed length"),
+            Self::OutOfRange(duration) => write!(f, "The duration {duration:?} is out of the range of an Avro duration"),
+        }
+    }
+}
+
+impl std::error::Error for DurationError {}
+
+/// Replace the durations of a value read by apache_avro with their fixed, the serde deserializer of apache_avro doesn't read `Value::Duration`
+pub fn durations_as_fixed(value: apache_avro::types::Value) -> apache_avro::types::Value {
+    use apache_avro::types::Value;
+
+    match value {
+        Value::Duration(duration) => Value::Fixed(12, <[u8; 12]>::from(duration).to_vec()),
+        Value::Union(index, value) => Value::Union(index, Box::new(durations_as_fixed(*value))),
+        Value::Array(values) => Value::Array(values.into_iter().map(durations_as_fixed).collect()),
+        Value::Map(values) => Value::Map(values.into_iter().map(|(key, value)| (key, durations_as_fixed(value))).collect()),
+        Value::Record(fields) => Value::Record(fields.into_iter().map(|(name, value)| (name, durations_as_fixed(value))).collect()),
+        value => value,
+    }
+}
+
@@ -0,0 +1,4 @@
+pub mod books;
+pub mod catalog;
+pub mod games;
+
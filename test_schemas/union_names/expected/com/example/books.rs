@@ -0,0 +1,34 @@
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Item {
+    pub title: String,
+}
+
+impl Item {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Item","namespace":"com.example.books","fields":[{"name":"title","type":"string"}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xfa, 0xf1, 0x5c, 0x31, 0xde, 0xba, 0xe6, 0x80];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl Default for Item {
+    fn default() -> Self {
+        Self {
+            title: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Item {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
@@ -0,0 +1,165 @@
+/// Unions with the same types in their names
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Catalog {
+    pub first: crate::com::example::catalog::UnionStringItem,
+    pub second: crate::com::example::catalog::UnionStringItem2,
+    pub counts: crate::com::example::catalog::UnionStringArray,
+    pub totals: crate::com::example::catalog::UnionStringArray2,
+    #[serde(default = "Catalog::default_other_counts")]
+    pub other_counts: Option<crate::com::example::catalog::UnionStringArray>,
+}
+
+impl Catalog {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Catalog","namespace":"com.example.catalog","doc":"Unions with the same types in their names","fields":[{"name":"first","type":["string",{"type":"record","name":"Item","namespace":"com.example.books","fields":[{"name":"title","type":"string"}]}]},{"name":"second","type":["string",{"type":"record","name":"Item","namespace":"com.example.games","fields":[{"name":"players","type":"int"}]}]},{"name":"counts","type":["string",{"type":"array","items":"int"}]},{"name":"totals","type":["string",{"type":"array","items":"long"}]},{"name":"other_counts","type":["null","string",{"type":"array","items":"int"}],"default":null}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xeb, 0xf6, 0x1d, 0x61, 0x16, 0x55, 0xef, 0x3e];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    #[inline(always)]
+    pub fn default_other_counts() -> Option<crate::com::example::catalog::UnionStringArray> {
+        None
+    }
+}
+
+impl Default for Catalog {
+    fn default() -> Self {
+        Self {
+            first: Default::default(),
+            second: Default::default(),
+            counts: Default::default(),
+            totals: Default::default(),
+            other_counts: Self::default_other_counts(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Catalog {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionStringArray {
+    #[serde(alias = "0")]
+    String(String),
+    #[serde(alias = "1")]
+    Array(Vec<i32>),
+}
+
+impl Default for UnionStringArray {
+    fn default() -> Self {
+        Self::String(Default::default())
+    }
+}
+
+impl UnionStringArray {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"["string",{"type":"array","items":"int"}]"#;
+}
+
+impl apache_avro::AvroSchema for UnionStringArray {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionStringArray2 {
+    #[serde(alias = "0")]
+    String(String),
+    #[serde(alias = "1")]
+    Array(Vec<i64>),
+}
+
+impl Default for UnionStringArray2 {
+    fn default() -> Self {
+        Self::String(Default::default())
+    }
+}
+
+impl UnionStringArray2 {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"["string",{"type":"array","items":"long"}]"#;
+}
+
+impl apache_avro::AvroSchema for UnionStringArray2 {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionStringItem {
+    #[serde(alias = "0")]
+    String(String),
+    #[serde(alias = "1")]
+    Item(crate::com::example::books::Item),
+}
+
+impl Default for UnionStringItem {
+    fn default() -> Self {
+        Self::String(Default::default())
+    }
+}
+
+impl UnionStringItem {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"["string",{"type":"record","name":"Item","namespace":"com.example.books","fields":[{"name":"title","type":"string"}]}]"#;
+}
+
+impl apache_avro::AvroSchema for UnionStringItem {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionStringItem2 {
+    #[serde(alias = "0")]
+    String(String),
+    #[serde(alias = "1")]
+    Item(crate::com::example::games::Item),
+}
+
+impl Default for UnionStringItem2 {
+    fn default() -> Self {
+        Self::String(Default::default())
+    }
+}
+
+impl UnionStringItem2 {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"["string",{"type":"record","name":"Item","namespace":"com.example.games","fields":[{"name":"players","type":"int"}]}]"#;
+}
+
+impl apache_avro::AvroSchema for UnionStringItem2 {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
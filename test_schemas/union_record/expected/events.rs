@@ -0,0 +1,140 @@
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Event {
+    /// Identifier can be a text or a number
+    #[serde(rename = "Identifier")]
+    pub identifier: crate::events::UnionStringLong,
+    #[serde(rename = "Payload")]
+    pub payload: Option<crate::events::UnionStringLongSomeRecord>,
+    #[serde(rename = "History")]
+    pub history: Vec<crate::events::UnionStringLongSomeRecord>,
+    #[serde(rename = "Comment")]
+    pub comment: Option<String>,
+}
+
+impl Event {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Event","namespace":"events","fields":[{"name":"Identifier","type":["string","long"],"doc":"Identifier can be a text or a number"},{"name":"Payload","type":["null","string","long",{"type":"record","name":"SomeRecord","fields":[{"name":"Label","type":"string"}]}]},{"name":"History","type":{"type":"array","items":["string","long","SomeRecord"]}},{"name":"Comment","type":["null","string"]}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0xb9, 0x8e, 0xd0, 0x85, 0x6b, 0x8f, 0x70, 0x41];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl Default for Event {
+    fn default() -> Self {
+        Self {
+            identifier: Default::default(),
+            payload: Default::default(),
+            history: Default::default(),
+            comment: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Event {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct SomeRecord {
+    #[serde(rename = "Label")]
+    pub label: String,
+}
+
+impl SomeRecord {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"SomeRecord","namespace":"events","fields":[{"name":"Label","type":"string"}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x90, 0x7b, 0x4a, 0x0c, 0xdb, 0x28, 0x3b, 0xf5];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+}
+
+impl Default for SomeRecord {
+    fn default() -> Self {
+        Self {
+            label: Default::default(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for SomeRecord {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionStringLong {
+    #[serde(alias = "0")]
+    String(String),
+    #[serde(alias = "1")]
+    Long(i64),
+}
+
+impl Default for UnionStringLong {
+    fn default() -> Self {
+        Self::String(Default::default())
+    }
+}
+
+impl UnionStringLong {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"["string","long"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionStringLong {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionStringLongSomeRecord {
+    #[serde(alias = "0")]
+    String(String),
+    #[serde(alias = "1")]
+    Long(i64),
+    #[serde(alias = "2")]
+    SomeRecord(crate::events::SomeRecord),
+}
+
+impl Default for UnionStringLongSomeRecord {
+    fn default() -> Self {
+        Self::String(Default::default())
+    }
+}
+
+impl UnionStringLongSomeRecord {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"["string","long",{"type":"record","name":"SomeRecord","namespace":"events","fields":[{"name":"Label","type":"string"}]}]"#;
+}
+
+impl apache_avro::AvroSchema for UnionStringLongSomeRecord {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
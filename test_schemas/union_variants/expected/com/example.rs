@@ -0,0 +1,4 @@
+pub mod alerts;
+pub mod machines;
+pub mod shop;
+
@@ -0,0 +1,147 @@
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Alert {
+    #[serde(default = "Alert::default_status")]
+    pub status: crate::com::example::alerts::UnionMachinesStatusShopStatus,
+    #[serde(default = "Alert::default_previous")]
+    pub previous: Option<crate::com::example::alerts::UnionMachinesStatusShopStatusLong>,
+    #[serde(default = "Alert::default_level")]
+    pub level: crate::com::example::alerts::UnionIntLongString,
+}
+
+impl Alert {
+    /// JSON of the Avro schema
+    pub const SCHEMA_JSON: &str = r#"{"type":"record","name":"Alert","namespace":"com.example.alerts","fields":[{"name":"status","type":[{"type":"enum","name":"Status","namespace":"com.example.machines","symbols":["RUNNING","STOPPED"]},{"type":"enum","name":"Status","namespace":"com.example.shop","symbols":["OPEN","SHIPPED"]}],"default":"STOPPED"},{"name":"previous","type":["null","com.example.machines.Status","com.example.shop.Status","long"],"default":null},{"name":"level","type":["int","long","string"],"default":0}]}"#;
+
+    /// Fingerprints of the Parsing Canonical Form of the schema
+    pub const FINGERPRINT_RABIN: [u8; 8] = [0x37, 0xfb, 0x75, 0x60, 0x41, 0x6f, 0x08, 0xbb];
+
+    /// Avro schema, parsed the first time it's used
+    pub fn schema() -> &'static apache_avro::Schema {
+        static SCHEMA: std::sync::OnceLock<apache_avro::Schema> = std::sync::OnceLock::new();
+        SCHEMA.get_or_init(|| apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen"))
+    }
+
+    #[inline(always)]
+    pub fn default_status() -> crate::com::example::alerts::UnionMachinesStatusShopStatus {
+        crate::com::example::alerts::UnionMachinesStatusShopStatus::MachinesStatus(crate::com::example::machines::Status::Stopped)
+    }
+
+    #[inline(always)]
+    pub fn default_previous() -> Option<crate::com::example::alerts::UnionMachinesStatusShopStatusLong> {
+        None
+    }
+
+    #[inline(always)]
+    pub fn default_level() -> crate::com::example::alerts::UnionIntLongString {
+        crate::com::example::alerts::UnionIntLongString::Int(0)
+    }
+}
+
+impl Default for Alert {
+    fn default() -> Self {
+        Self {
+            status: Self::default_status(),
+            previous: Self::default_previous(),
+            level: Self::default_level(),
+        }
+    }
+}
+
+impl apache_avro::AvroSchema for Alert {
+    fn get_schema() -> apache_avro::Schema {
+        Self::schema().clone()
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionIntLongString {
+    #[serde(alias = "0")]
+    Int(i32),
+    #[serde(alias = "1")]
+    Long(i64),
+    #[serde(alias = "2")]
+    String(String),
+}
+
+impl Default for UnionIntLongString {
+    fn default() -> Self {
+        Self::Int(Default::default())
+    }
+}
+
+impl UnionIntLongString {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"["int","long","string"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionIntLongString {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionMachinesStatusShopStatus {
+    #[serde(alias = "0")]
+    MachinesStatus(crate::com::example::machines::Status),
+    #[serde(alias = "1")]
+    ShopStatus(crate::com::example::shop::Status),
+}
+
+impl Default for UnionMachinesStatusShopStatus {
+    fn default() -> Self {
+        Self::MachinesStatus(Default::default())
+    }
+}
+
+impl UnionMachinesStatusShopStatus {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"[{"type":"enum","name":"Status","namespace":"com.example.machines","symbols":["RUNNING","STOPPED"]},{"type":"enum","name":"Status","namespace":"com.example.shop","symbols":["OPEN","SHIPPED"]}]"#;
+}
+
+impl apache_avro::AvroSchema for UnionMachinesStatusShopStatus {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
+/// Enum generated for an avro union, each variant is a type of the union.
+///
+/// A variant is serialized with its index among the types of the union without null, `values::resolve` writes it
+/// with this type, and `values::union_variants` converts the union values read by `apache_avro` to this representation.
+#[derive(Debug, PartialEq, Clone, serde::Deserialize, serde::Serialize)]
+pub enum UnionMachinesStatusShopStatusLong {
+    #[serde(alias = "0")]
+    MachinesStatus(crate::com::example::machines::Status),
+    #[serde(alias = "1")]
+    ShopStatus(crate::com::example::shop::Status),
+    #[serde(alias = "2")]
+    Long(i64),
+}
+
+impl Default for UnionMachinesStatusShopStatusLong {
+    fn default() -> Self {
+        Self::MachinesStatus(Default::default())
+    }
+}
+
+impl UnionMachinesStatusShopStatusLong {
+    /// JSON of the Avro schema, the union without null
+    pub const SCHEMA_JSON: &str = r#"[{"type":"enum","name":"Status","namespace":"com.example.machines","symbols":["RUNNING","STOPPED"]},{"type":"enum","name":"Status","namespace":"com.example.shop","symbols":["OPEN","SHIPPED"]},"long"]"#;
+}
+
+impl apache_avro::AvroSchema for UnionMachinesStatusShopStatusLong {
+    fn get_schema() -> apache_avro::Schema {
+        apache_avro::Schema::parse_str(Self::SCHEMA_JSON).expect("The schema is checked by avrogen")
+    }
+}
+
@@ -20,10 +20,10 @@ fn join(
     for suffix in all_suffixes {
         let first_path = first_file_list
             .get(suffix)
-            .expect(format!("No file {suffix:?} in first folder").as_str());
+            .unwrap_or_else(|| panic!("No file {suffix:?} in first folder"));
         let second_path = second_file_list
             .get(suffix)
-            .expect(format!("No file {suffix:?} in second folder").as_str());
+            .unwrap_or_else(|| panic!("No file {suffix:?} in second folder"));
 
         pairs.push((first_path.to_owned(), second_path.to_owned()));
     }
@@ -47,12 +47,15 @@ fn browse_folder(path: &str) -> HashMap<PathBuf, PathBuf> {
 }
 
 fn compare_files_content(first_file_path: PathBuf, second_file_path: PathBuf) {
-    let first_file = fs::read_to_string(first_file_path.to_owned())
-        .expect(format!("impossible to read {:?}", first_file_path).as_str());
-    let second_file = fs::read_to_string(second_file_path.to_owned())
-        .expect(format!("impossible to read {:?}", second_file_path).as_str());
+    let first_file = fs::read_to_string(&first_file_path)
+        .unwrap_or_else(|_| panic!("impossible to read {:?}", first_file_path));
+    let second_file = fs::read_to_string(&second_file_path)
+        .unwrap_or_else(|_| panic!("impossible to read {:?}", second_file_path));
 
-    for (idx, content) in first_file.lines().zip(second_file.lines()).enumerate() {
+    let first_lines: Vec<&str> = first_file.lines().collect();
+    let second_lines: Vec<&str> = second_file.lines().collect();
+
+    for (idx, content) in first_lines.iter().zip(second_lines.iter()).enumerate() {
         if content.0.ne(content.1) {
             panic!(
                 "{:?} and {:?} are different at line {}",
@@ -62,11 +65,21 @@ fn compare_files_content(first_file_path: PathBuf, second_file_path: PathBuf) {
             );
         }
     }
+
+    if first_lines.len() != second_lines.len() {
+        panic!(
+            "{:?} has {} lines and {:?} has {} lines",
+            first_file_path,
+            first_lines.len(),
+            second_file_path,
+            second_lines.len()
+        );
+    }
 }
 
 pub fn compare_folders_content(first_path: &str, second_path: &str) {
-    let first_file_list = browse_folder(&first_path);
-    let second_file_list = browse_folder(&second_path);
+    let first_file_list = browse_folder(first_path);
+    let second_file_list = browse_folder(second_path);
 
     let pairs = join(first_file_list, second_file_list);
 
@@ -1,15 +1,24 @@
 mod compare;
 
-use avrogen::Avrogen;
+use avrogen::{
+    Avrogen, AvrogenError, DecimalBackend, Fingerprint, Location, ModuleLayout, TimeBackend,
+};
 use compare::compare_folders_content;
 
 fn standard_test(source_name: &str) {
     let source_file = format!("test_schemas/{}.avsc", source_name);
+    test_with_source(source_name, &source_file);
+}
+
+fn test_with_source(source_name: &str, source_file: &str) {
+    test_with_builder(source_name, Avrogen::new().add_source(source_file));
+}
+
+fn test_with_builder(source_name: &str, builder: Avrogen) {
     let dest_folder = format!("target/tmp/.result/{}/", source_name);
     let expected_folder = format!("test_schemas/{}/expected/", source_name);
 
-    Avrogen::new()
-        .add_source(source_file.as_str())
+    builder
         .output_folder_from_str(dest_folder.as_str())
         .set_verbosity_debug()
         .execute()
@@ -32,3 +41,622 @@ fn convert_simple_record() {
 fn convert_recursive_record() {
     standard_test("recursive_record");
 }
+
+#[test]
+fn convert_union_record() {
+    standard_test("union_record");
+}
+
+/// The different unions with the same name in a module are numbered
+#[test]
+fn convert_union_names() {
+    standard_test("union_names");
+}
+
+/// The unions of each kind of type, including the numbers and the enums that `apache_avro` can read as another type
+#[test]
+fn convert_union_branches() {
+    standard_test("union_branches");
+}
+
+#[test]
+fn convert_multiple_files() {
+    test_with_source("multiple_files", "test_schemas/multiple_files/*.avsc");
+}
+
+#[test]
+fn convert_single_file() {
+    test_with_builder(
+        "single_file",
+        Avrogen::new()
+            .add_source("test_schemas/multiple_files/*.avsc")
+            .single_file("schemas.rs"),
+    );
+}
+
+/// The code included and run by the round trip tests
+#[test]
+fn convert_round_trip() {
+    test_with_builder(
+        "round_trip",
+        Avrogen::new()
+            .add_sources(
+                [
+                    "union_record",
+                    "union_branches",
+                    "union_names",
+                    "decimal_record",
+                    "duration_record",
+                    "logical_types",
+                    "typed_default",
+                    "record_default",
+                    "fixed_record",
+                ]
+                .map(|name| format!("test_schemas/{name}.avsc"))
+                .to_vec(),
+            )
+            .single_file("schemas.rs")
+            .root_path("crate::generated")
+            .single_object_encoding()
+            .confluent_wire_format(),
+    );
+}
+
+/// The variants of the types with the same name are prefixed with their namespace
+#[test]
+fn convert_union_variants() {
+    standard_test("union_variants");
+}
+
+/// A rule without `.*` only moves the types of its exact namespace, with `.*` it moves the sub namespaces too
+#[test]
+fn namespace_rules_sub_namespaces() {
+    let generated_files = |rule: &str| {
+        let dest_folder = format!("target/tmp/.result/namespace_rules_sub_namespaces/{rule}/");
+        let _ = std::fs::remove_dir_all(&dest_folder);
+        Avrogen::new()
+            .add_source("test_schemas/flat_layout/*.avsc")
+            .map_namespace(rule, "store")
+            .output_folder_from_str(&dest_folder)
+            .set_verbosity_off()
+            .execute()
+            .expect("No error should appear");
+
+        let mut files: Vec<String> = walkdir::WalkDir::new(&dest_folder)
+            .into_iter()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_type().is_file())
+            .map(|entry| {
+                entry
+                    .path()
+                    .strip_prefix(&dest_folder)
+                    .unwrap()
+                    .display()
+                    .to_string()
+            })
+            .collect();
+        files.sort();
+        files
+    };
+
+    assert_eq!(
+        generated_files("com.mysite"),
+        [
+            "com.rs",
+            "com/mysite.rs",
+            "com/mysite/machines.rs",
+            "com/mysite/shop.rs",
+            "values.rs"
+        ]
+    );
+    assert_eq!(
+        generated_files("com.mysite.*"),
+        ["store.rs", "store/machines.rs", "store/shop.rs", "values.rs"]
+    );
+}
+
+#[test]
+fn write_to_stdout() {
+    let output = |args: &[&str]| {
+        let output = std::process::Command::new(env!("CARGO_BIN_EXE_avrogen"))
+            .args(["--source", "test_schemas/multiple_files/*.avsc", "--stdout"])
+            .args(args)
+            .output()
+            .expect("avrogen must run");
+        assert!(output.status.success());
+        // The expected files are written with LF line endings
+        String::from_utf8(output.stdout)
+            .expect("The generated code is UTF-8")
+            .replace("\r\n", "\n")
+    };
+
+    let expected_single_file =
+        std::fs::read_to_string("test_schemas/single_file/expected/schemas.rs").unwrap();
+    assert_eq!(
+        output(&["--single-file", "schemas.rs"]),
+        expected_single_file
+    );
+
+    let files = output(&[]);
+    let expected_status =
+        std::fs::read_to_string("test_schemas/multiple_files/expected/com/shop/status.rs").unwrap();
+    assert!(files.starts_with("// file: com.rs\n"));
+    assert!(files.ends_with(&format!("// file: com/shop/status.rs\n{expected_status}")));
+}
+
+#[test]
+fn convert_flat_layout() {
+    test_with_builder(
+        "flat_layout",
+        Avrogen::new()
+            .add_source("test_schemas/flat_layout/*.avsc")
+            .default_namespace("schemas")
+            .module_layout(ModuleLayout::FlatPrefixed),
+    );
+}
+
+/// The unions of the renamed types are named from the renamed types, in the root module
+#[test]
+fn convert_flat_unions() {
+    test_with_builder(
+        "flat_unions",
+        Avrogen::new()
+            .add_source("test_schemas/flat_unions/*.avsc")
+            .module_layout(ModuleLayout::FlatPrefixed)
+            .single_file("schemas.rs"),
+    );
+}
+
+#[test]
+fn convert_namespace_rules() {
+    test_with_builder(
+        "namespace_rules",
+        Avrogen::new()
+            .add_source("test_schemas/flat_layout/*.avsc")
+            .map_namespace("com.mysite.machines", "machines")
+            .map_namespace("com.mysite.*", "store"),
+    );
+}
+
+#[test]
+fn convert_root_path() {
+    test_with_builder(
+        "root_path",
+        Avrogen::new()
+            .add_source("test_schemas/multiple_files/*.avsc")
+            .root_path("crate::gen"),
+    );
+}
+
+#[test]
+fn convert_relative_paths() {
+    test_with_builder(
+        "relative_paths",
+        Avrogen::new()
+            .add_source("test_schemas/flat_layout/*.avsc")
+            .relative_paths(),
+    );
+}
+
+#[test]
+fn flat_layout_conflict() {
+    let error = Avrogen::new()
+        .add_source("test_schemas/flat_layout/*.avsc")
+        .default_namespace("schemas")
+        .module_layout(ModuleLayout::Flat)
+        .output_folder_from_str("target/tmp/.result/flat_layout_conflict/")
+        .set_verbosity_off()
+        .execute()
+        .expect_err("Two types with the same name must be reported");
+
+    assert_eq!(error.to_string(), "error: the type Status of the flat layout is already the type of com.mysite.machines.Status, rename one of them or use the nested layout\n   = type: com.mysite.shop.Status");
+}
+
+#[test]
+fn convert_idl_protocol() {
+    test_with_source("idl_protocol", "test_schemas/idl_protocol/*.avdl");
+}
+
+/// A reference to a type declared later is resolved with the namespace of its declaration
+#[test]
+fn convert_idl_forward_reference() {
+    test_with_source(
+        "idl_forward_reference",
+        "test_schemas/idl_forward_reference/*.avdl",
+    );
+}
+
+#[test]
+fn convert_avpr_protocol() {
+    test_with_source("avpr_protocol", "test_schemas/avpr_protocol/*.avpr");
+}
+
+#[test]
+fn convert_container_file() {
+    test_with_builder(
+        "container_file",
+        Avrogen::new()
+            .add_source("test_schemas/container_file/*.avro")
+            .add_fingerprint(Fingerprint::Md5)
+            .add_fingerprint(Fingerprint::Sha256)
+            .single_object_encoding(),
+    );
+}
+
+/// The corrupted headers are reported in their file, a huge length isn't allocated
+#[test]
+fn invalid_container_file() {
+    let folder = "target/tmp/.result/invalid_container_file";
+    std::fs::create_dir_all(folder).unwrap();
+    let header = std::fs::read("test_schemas/container_file/measures.avro").unwrap();
+
+    let huge_length = [
+        b"Obj\x01".as_slice(),
+        &[0x02, 0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f],
+    ]
+    .concat();
+    let cases: [(&str, &[u8], &str); 3] = [
+        (
+            "huge_length",
+            &huge_length,
+            "the container header is truncated: 4611686018427387903 bytes are expected",
+        ),
+        (
+            "truncated",
+            &header[..20],
+            "the container header is truncated",
+        ),
+        (
+            "not_container",
+            b"{\"type\": \"string\"}",
+            "the file is not an Avro object container file",
+        ),
+    ];
+
+    for (name, content, expected) in cases {
+        let file = format!("{folder}/{name}.avro");
+        std::fs::write(&file, content).unwrap();
+        let error = Avrogen::new()
+            .add_source(&file)
+            .output_folder_from_str(&format!("{folder}/{name}/"))
+            .set_verbosity_off()
+            .execute()
+            .expect_err("A corrupted container file must be reported");
+
+        assert_eq!(
+            error.location().and_then(|l| l.file.as_deref()),
+            Some(file.as_str())
+        );
+        assert!(error.to_string().contains(expected), "{error}");
+    }
+}
+
+#[test]
+fn convert_confluent_wire_format() {
+    test_with_builder(
+        "confluent_wire_format",
+        Avrogen::new()
+            .add_source("test_schemas/multiple_files/*.avsc")
+            .default_namespace("schemas")
+            .confluent_wire_format(),
+    );
+}
+
+#[test]
+fn convert_record_default() {
+    standard_test("record_default");
+}
+
+#[test]
+fn convert_typed_default() {
+    standard_test("typed_default");
+}
+
+#[test]
+fn convert_apache_avro_decimal() {
+    test_with_source("apache_avro_decimal", "test_schemas/decimal_record.avsc");
+}
+
+#[test]
+fn convert_rust_decimal() {
+    test_with_builder(
+        "rust_decimal",
+        Avrogen::new()
+            .add_source("test_schemas/decimal_record.avsc")
+            .decimal_backend(DecimalBackend::RustDecimal)
+            .single_object_encoding(),
+    );
+}
+
+#[test]
+fn convert_big_decimal() {
+    test_with_builder(
+        "big_decimal",
+        Avrogen::new()
+            .add_source("test_schemas/decimal_record.avsc")
+            .decimal_backend(DecimalBackend::BigDecimal),
+    );
+}
+
+/// The decimal backends compiled and encoded by the round trip tests
+#[test]
+fn convert_round_trip_decimal_backends() {
+    for (source_name, decimal_backend) in [
+        ("round_trip_rust_decimal", DecimalBackend::RustDecimal),
+        ("round_trip_big_decimal", DecimalBackend::BigDecimal),
+    ] {
+        test_with_builder(
+            source_name,
+            Avrogen::new()
+                .add_source("test_schemas/decimal_record.avsc")
+                .decimal_backend(decimal_backend)
+                .single_file("schemas.rs")
+                .root_path(&format!("crate::{source_name}"))
+                .single_object_encoding()
+                .confluent_wire_format(),
+        );
+    }
+}
+
+/// The time backends compiled and encoded by the round trip tests
+#[test]
+fn convert_round_trip_time_backends() {
+    for (source_name, time_backend) in [
+        ("round_trip_time", TimeBackend::Time),
+        ("round_trip_jiff", TimeBackend::Jiff),
+        ("round_trip_std", TimeBackend::Std),
+    ] {
+        test_with_builder(
+            source_name,
+            Avrogen::new()
+                .add_source("test_schemas/logical_types.avsc")
+                .time_backend(time_backend)
+                .single_file("schemas.rs")
+                .root_path(&format!("crate::{source_name}"))
+                .single_object_encoding()
+                .confluent_wire_format(),
+        );
+    }
+}
+
+#[test]
+fn convert_logical_types() {
+    standard_test("logical_types");
+}
+
+#[test]
+fn convert_time_crate() {
+    test_with_builder(
+        "time_crate",
+        Avrogen::new()
+            .add_source("test_schemas/logical_types.avsc")
+            .time_backend(TimeBackend::Time),
+    );
+}
+
+#[test]
+fn convert_jiff() {
+    test_with_builder(
+        "jiff",
+        Avrogen::new()
+            .add_source("test_schemas/logical_types.avsc")
+            .time_backend(TimeBackend::Jiff),
+    );
+}
+
+#[test]
+fn convert_std_time() {
+    test_with_builder(
+        "std_time",
+        Avrogen::new()
+            .add_source("test_schemas/logical_types.avsc")
+            .time_backend(TimeBackend::Std),
+    );
+}
+
+#[test]
+fn convert_top_level_schemas() {
+    test_with_source("top_level", "test_schemas/top_level/*.avsc");
+}
+
+#[test]
+fn unsupported_top_level_schema() {
+    let error = Avrogen::new()
+        .add_source("test_schemas/unsupported/*.avsc")
+        .output_folder_from_str("target/tmp/.result/unsupported/")
+        .set_verbosity_off()
+        .execute()
+        .expect_err("A top level decimal must be reported")
+        .to_string();
+
+    assert_eq!(error, "error: the schema decimal(10, 2) is not supported: a decimal is serialized with the precision and the scale of a field, use it in a record");
+}
+
+#[test]
+fn invalid_default_values() {
+    let error = Avrogen::new()
+        .add_source("test_schemas/invalid_default/*.avsc")
+        .output_folder_from_str("target/tmp/.result/invalid_default/")
+        .set_verbosity_off()
+        .execute()
+        .expect_err("Invalid default values must be reported");
+
+    let AvrogenError::Multiple(errors) = &error else {
+        panic!("All the invalid default values must be reported, found {error:?}");
+    };
+
+    let file = "test_schemas/invalid_default/sensor.avsc";
+    let problems: Vec<(Location, String)> = errors
+        .iter()
+        .map(|e| match e {
+            AvrogenError::InvalidDefault { location, message } => {
+                (location.to_owned(), message.to_owned())
+            }
+            e => panic!("Unexpected error {e:?}"),
+        })
+        .collect();
+
+    for (field_path, expected) in [
+        ("count", "the default value \"ten\" doesn't match the type int"),
+        ("label", "the default value \"none\" doesn't match the first type of the union, which is null"),
+        ("state", "the default value \"STANDBY\" is not a symbol of the enum com.example.invalid.State"),
+        ("hash", "the default value \"abc\" has 3 bytes instead of 4"),
+        ("position.x", "the value is missing and the field has no default value"),
+        ("states[1]", "the default value \"UNKNOWN\" is not a symbol of the enum com.example.invalid.State"),
+        ("serial", "the default value \"not-a-uuid\" is not a uuid with hexadecimal groups of 8-4-4-4-12 digits"),
+        ("wake_up", "the default value 86400000000 is out of the range 0-86399999999"),
+    ] {
+        let location = Location {
+            file: Some(file.to_string()),
+            type_name: Some("com.example.invalid.Sensor".to_string()),
+            field_path: Some(field_path.to_string()),
+            ..Default::default()
+        };
+        assert!(
+            problems.contains(&(location, expected.to_string())),
+            "{field_path}: {expected} not found in {error}"
+        );
+    }
+
+    assert!(error.to_string().contains(&format!(
+        "error: the default value \"ten\" doesn't match the type int\n  --> {file}\n   = field: com.example.invalid.Sensor.count"
+    )));
+}
+
+#[test]
+fn invalid_json_position() {
+    let error = Avrogen::new()
+        .add_source("test_schemas/invalid_json/*.avsc")
+        .output_folder_from_str("target/tmp/.result/invalid_json/")
+        .set_verbosity_off()
+        .execute()
+        .expect_err("An invalid JSON file must be reported");
+
+    let Some(location) = error.location() else {
+        panic!("The error must have a location, found {error:?}");
+    };
+    assert_eq!(
+        location.file.as_deref(),
+        Some("test_schemas/invalid_json/broken.avsc")
+    );
+    assert_eq!(location.position, Some((6, 5)));
+    assert!(error
+        .to_string()
+        .contains("\n  --> test_schemas/invalid_json/broken.avsc:6:5"));
+}
+
+/// A type defined twice is reported in the file of its second definition
+#[test]
+fn name_collision_file() {
+    let error = Avrogen::new()
+        .add_source("test_schemas/name_collision/*.avsc")
+        .output_folder_from_str("target/tmp/.result/name_collision/")
+        .set_verbosity_off()
+        .execute()
+        .expect_err("A type defined twice must be reported");
+
+    let Some(location) = error.location() else {
+        panic!("The error must have a location, found {error:?}");
+    };
+    assert_eq!(
+        location.file.as_deref(),
+        Some("test_schemas/name_collision/second.avsc")
+    );
+    assert_eq!(location.type_name.as_deref(), Some("com.example.Sensor"));
+}
+
+/// An unknown type isn't defined by a file, the error has no file
+#[test]
+fn unknown_type_without_file() {
+    let error = Avrogen::new()
+        .add_source("test_schemas/unknown_type/*.avsc")
+        .output_folder_from_str("target/tmp/.result/unknown_type/")
+        .set_verbosity_off()
+        .execute()
+        .expect_err("An unknown type must be reported");
+
+    assert!(matches!(error, AvrogenError::Parse { .. }), "{error:?}");
+    assert_eq!(error.location().and_then(|l| l.file.as_deref()), None);
+    assert!(error.to_string().contains("com.example.Location"), "{error}");
+}
+
+/// An error without location keeps the original error as its source
+#[test]
+fn located_error_source() {
+    let io_error = std::io::Error::new(std::io::ErrorKind::NotFound, "no such file");
+    let error = AvrogenError::from(io_error)
+        .in_type("com.example.Sensor")
+        .in_file("schemas/sensor.avsc");
+
+    assert_eq!(
+        error.to_string(),
+        "error: no such file\n  --> schemas/sensor.avsc\n   = type: com.example.Sensor"
+    );
+    let source = std::error::Error::source(&error).expect("The original error must be the source");
+    assert!(matches!(
+        source.downcast_ref::<AvrogenError>(),
+        Some(AvrogenError::Io(e)) if e.kind() == std::io::ErrorKind::NotFound
+    ));
+}
+
+#[test]
+fn convert_fixed_record() {
+    test_with_builder(
+        "fixed_record",
+        Avrogen::new()
+            .add_source("test_schemas/fixed_record.avsc")
+            .single_object_encoding(),
+    );
+}
+
+#[test]
+fn convert_duration_record() {
+    test_with_builder(
+        "duration_record",
+        Avrogen::new()
+            .add_source("test_schemas/duration_record.avsc")
+            .single_object_encoding(),
+    );
+}
+
+/// The fingerprints of the specification ignore the logical types, the reference values are the ones of
+/// `{"name":"com.example.billing.Subscription","type":"record","fields":[{"name":"plan","type":"string"},...]}`
+#[test]
+fn spec_fingerprints() {
+    let dest_folder = "target/tmp/.result/spec_fingerprints/";
+    Avrogen::new()
+        .add_source("test_schemas/duration_record.avsc")
+        .add_fingerprint(Fingerprint::Md5)
+        .add_fingerprint(Fingerprint::Sha256)
+        .output_folder_from_str(dest_folder)
+        .execute()
+        .expect("No error should appear");
+
+    let content = std::fs::read_to_string(format!("{dest_folder}com/example/billing.rs")).unwrap();
+    let constant = |name: &str, hex: &str| {
+        let bytes: Vec<String> = (0..hex.len())
+            .step_by(2)
+            .map(|i| format!("0x{}", &hex[i..i + 2]))
+            .collect();
+        format!(
+            "pub const {name}: [u8; {}] = [{}];",
+            bytes.len(),
+            bytes.join(", ")
+        )
+    };
+
+    assert!(content.contains(&constant("FINGERPRINT_RABIN", "4a07da76bc2947b0")));
+    assert!(content.contains(&constant(
+        "FINGERPRINT_MD5",
+        "3e4457107ac6a8180d769e0378722178"
+    )));
+    assert!(content.contains(&constant(
+        "FINGERPRINT_SHA256",
+        "40c43dfb62b7c5964fb608a8e22b93380ec3253a9def1e088e9f0f28b4e56759"
+    )));
+}
+
+#[test]
+fn convert_duration_reference() {
+    standard_test("duration_reference");
+}
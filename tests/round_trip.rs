@@ -0,0 +1,535 @@
+//! Compile the code generated by the `round_trip` test, and encode then decode its records
+
+use std::collections::HashMap;
+
+#[allow(dead_code, clippy::derivable_impls)]
+mod generated {
+    include!("../test_schemas/round_trip/expected/schemas.rs");
+}
+
+use generated::com::example::{
+    billing, books, catalog, config, devices, events, files, finance, games, unions,
+};
+use generated::duration::Duration;
+
+#[allow(dead_code, clippy::derivable_impls)]
+mod round_trip_time {
+    include!("../test_schemas/round_trip_time/expected/schemas.rs");
+}
+
+#[allow(dead_code, clippy::derivable_impls)]
+mod round_trip_jiff {
+    include!("../test_schemas/round_trip_jiff/expected/schemas.rs");
+}
+
+#[allow(dead_code, clippy::derivable_impls)]
+mod round_trip_std {
+    include!("../test_schemas/round_trip_std/expected/schemas.rs");
+}
+
+#[allow(dead_code, clippy::derivable_impls)]
+mod round_trip_rust_decimal {
+    include!("../test_schemas/round_trip_rust_decimal/expected/schemas.rs");
+}
+
+#[allow(dead_code, clippy::derivable_impls)]
+mod round_trip_big_decimal {
+    include!("../test_schemas/round_trip_big_decimal/expected/schemas.rs");
+}
+
+/// Encode the record with the single-object encoding and the Confluent wire format, then decode it
+macro_rules! assert_round_trip {
+    ($record_type:ty, $record:expr) => {{
+        let record: $record_type = $record;
+
+        let bytes = record
+            .to_single_object()
+            .expect("The record must be encoded");
+        assert_eq!(bytes[..10], <$record_type>::SINGLE_OBJECT_HEADER);
+        let decoded =
+            <$record_type>::from_single_object(&bytes).expect("The record must be decoded");
+        assert_eq!(decoded, record);
+
+        let bytes = record
+            .to_confluent_bytes(7)
+            .expect("The record must be encoded");
+        let resolver = HashMap::from([(7, <$record_type>::schema().clone())]);
+        let decoded = <$record_type>::from_confluent_bytes(&bytes, &resolver)
+            .expect("The record must be decoded");
+        assert_eq!(decoded, record);
+    }};
+}
+
+#[test]
+fn round_trip_unions() {
+    let some_record = generated::events::SomeRecord {
+        label: "label".to_string(),
+    };
+    assert_round_trip!(
+        generated::events::Event,
+        generated::events::Event {
+            identifier: generated::events::UnionStringLong::Long(42),
+            payload: Some(generated::events::UnionStringLongSomeRecord::SomeRecord(
+                some_record.clone()
+            )),
+            history: vec![
+                generated::events::UnionStringLongSomeRecord::String("first".to_string()),
+                generated::events::UnionStringLongSomeRecord::Long(2),
+                generated::events::UnionStringLongSomeRecord::SomeRecord(some_record),
+            ],
+            comment: None,
+        }
+    );
+}
+
+/// Each variant keeps its type of the union, even when `apache_avro` would choose another type for the value:
+/// a long which fits in an int, a double which fits in a float, a symbol of the first enum or bytes of the size of the fixed
+#[test]
+fn round_trip_union_branches() {
+    let decimal = |unscaled: i64| apache_avro::Decimal::from(unscaled.to_be_bytes().to_vec());
+    assert_round_trip!(
+        unions::Branches,
+        unions::Branches {
+            integer: unions::UnionIntLong::Long(42),
+            real: unions::UnionFloatDouble::Double(1.5),
+            color: unions::UnionColorString::Color(unions::Color::Green),
+            signal: unions::UnionColorLight::Light(unions::Light::Red),
+            content: unions::UnionStringBytes::Bytes(vec![0x00, 0xff]),
+            hash: unions::UnionHashBytes::Hash(unions::Hash([1, 2, 3, 4])),
+            amount: unions::UnionDecimalString::Decimal(decimal(1234)),
+            delay: unions::UnionDurationString::Duration(Duration {
+                months: 1,
+                days: 2,
+                millis: 3
+            }),
+            day: unions::UnionDateString::Date(
+                chrono::NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()
+            ),
+            optional: Some(unions::UnionIntLong::Long(7)),
+            integers: vec![unions::UnionIntLong::Int(1), unions::UnionIntLong::Long(2)],
+            reals: HashMap::from([("half".to_string(), unions::UnionFloatDouble::Double(0.5))]),
+        }
+    );
+    assert_round_trip!(
+        unions::Branches,
+        unions::Branches {
+            integer: unions::UnionIntLong::Int(42),
+            real: unions::UnionFloatDouble::Float(1.5),
+            color: unions::UnionColorString::String("RED".to_string()),
+            signal: unions::UnionColorLight::Color(unions::Color::Red),
+            content: unions::UnionStringBytes::String("text".to_string()),
+            hash: unions::UnionHashBytes::Bytes(vec![1, 2, 3, 4]),
+            amount: unions::UnionDecimalString::String("none".to_string()),
+            delay: unions::UnionDurationString::String("later".to_string()),
+            day: unions::UnionDateString::String("today".to_string()),
+            optional: None,
+            integers: Vec::new(),
+            reals: HashMap::from([("half".to_string(), unions::UnionFloatDouble::Float(0.5))]),
+        }
+    );
+}
+
+#[test]
+fn round_trip_numbered_unions() {
+    assert_round_trip!(
+        catalog::Catalog,
+        catalog::Catalog {
+            first: catalog::UnionStringItem::Item(books::Item {
+                title: "Dune".to_string()
+            }),
+            second: catalog::UnionStringItem2::Item(games::Item { players: 4 }),
+            counts: catalog::UnionStringArray::Array(vec![1, 2]),
+            totals: catalog::UnionStringArray2::Array(vec![3_000_000_000]),
+            other_counts: Some(catalog::UnionStringArray::String("none".to_string())),
+        }
+    );
+}
+
+#[test]
+fn round_trip_durations() {
+    assert_round_trip!(
+        billing::Subscription,
+        billing::Subscription {
+            plan: "monthly".to_string(),
+            period: Duration {
+                months: 1,
+                days: 0,
+                millis: 0
+            },
+            trial: Some(Duration {
+                months: 0,
+                days: 14,
+                millis: 0
+            }),
+            grace_periods: vec![Duration {
+                months: 0,
+                days: 3,
+                millis: 1500
+            }],
+            reminders: HashMap::from([(
+                "soon".to_string(),
+                Duration {
+                    months: 0,
+                    days: 1,
+                    millis: 0
+                }
+            )]),
+        }
+    );
+}
+
+#[test]
+fn round_trip_decimals() {
+    let decimal = |unscaled: i64| apache_avro::Decimal::from(unscaled.to_be_bytes().to_vec());
+    assert_round_trip!(
+        finance::Invoice,
+        finance::Invoice {
+            amount: decimal(1234),
+            rate: decimal(-125),
+            discount: Some(decimal(150)),
+            lines: vec![decimal(1000), decimal(-234)],
+            taxes: HashMap::from([("vat".to_string(), decimal(247))]),
+            adjustment: finance::UnionDecimalString::Decimal(decimal(-50)),
+        }
+    );
+}
+
+/// The decimals of the `rust_decimal` backend are written with the precision and scale of their schema
+#[test]
+fn round_trip_rust_decimals() {
+    use round_trip_rust_decimal::com::example::finance;
+    let decimal = |unscaled: i64, scale: u32| rust_decimal::Decimal::new(unscaled, scale);
+    assert_round_trip!(
+        finance::Invoice,
+        finance::Invoice {
+            amount: decimal(1234, 2),
+            rate: decimal(-125, 6),
+            discount: Some(decimal(15, 1)),
+            lines: vec![decimal(10, 0), decimal(-234, 2)],
+            taxes: HashMap::from([("vat".to_string(), decimal(247, 2))]),
+            adjustment: finance::UnionDecimalString::Decimal(decimal(-50, 2)),
+        }
+    );
+}
+
+/// The decimals of the `bigdecimal` backend are written with the precision and scale of their schema
+#[test]
+fn round_trip_big_decimals() {
+    use round_trip_big_decimal::com::example::finance;
+    let decimal = |value: &str| value.parse::<bigdecimal::BigDecimal>().unwrap();
+    assert_round_trip!(
+        finance::Invoice,
+        finance::Invoice {
+            amount: decimal("12.34"),
+            rate: decimal("-0.000125"),
+            discount: Some(decimal("1.5")),
+            lines: vec![decimal("10"), decimal("-2.34")],
+            taxes: HashMap::from([("vat".to_string(), decimal("2.47"))]),
+            adjustment: finance::UnionDecimalString::Decimal(decimal("-0.5")),
+        }
+    );
+}
+
+#[test]
+fn round_trip_logical_types() {
+    assert_round_trip!(
+        events::Event,
+        events::Event {
+            opening: chrono::NaiveTime::from_hms_milli_opt(8, 30, 0, 250).unwrap(),
+            updated: chrono::DateTime::from_timestamp_micros(1_705_312_800_123_456),
+            local_updated: chrono::DateTime::from_timestamp_micros(1_705_312_800_654_321)
+                .unwrap()
+                .naive_utc(),
+            holidays: vec![chrono::NaiveDate::from_ymd_opt(2024, 12, 25).unwrap()],
+            checkpoints: HashMap::from([(
+                "start".to_string(),
+                chrono::DateTime::from_timestamp_millis(1_705_312_800_000).unwrap()
+            )]),
+            reference: events::UnionUuidLong::Long(7),
+            ..Default::default()
+        }
+    );
+}
+
+#[test]
+fn round_trip_time_logical_types() {
+    use round_trip_time::com::example::events;
+    let timestamp = |nanos: i128| time::OffsetDateTime::from_unix_timestamp_nanos(nanos).unwrap();
+    assert_round_trip!(
+        events::Event,
+        events::Event {
+            opening: time::Time::from_hms_milli(8, 30, 0, 250).unwrap(),
+            updated: Some(timestamp(1_705_312_800_123_456_000)),
+            local_updated: time::PrimitiveDateTime::new(
+                time::Date::from_calendar_date(2024, time::Month::January, 15).unwrap(),
+                time::Time::from_hms_micro(10, 0, 0, 654_321).unwrap()
+            ),
+            holidays: vec![
+                time::Date::from_calendar_date(2024, time::Month::December, 25).unwrap()
+            ],
+            checkpoints: HashMap::from([(
+                "start".to_string(),
+                timestamp(1_705_312_800_000_000_000)
+            )]),
+            reference: events::UnionUuidLong::Long(7),
+            ..Default::default()
+        }
+    );
+}
+
+#[test]
+fn round_trip_jiff_logical_types() {
+    use round_trip_jiff::com::example::events;
+    assert_round_trip!(
+        events::Event,
+        events::Event {
+            opening: jiff::civil::time(8, 30, 0, 250_000_000),
+            updated: Some(jiff::Timestamp::from_microsecond(1_705_312_800_123_456).unwrap()),
+            local_updated: jiff::civil::date(2024, 1, 15).at(10, 0, 0, 654_321_000),
+            holidays: vec![jiff::civil::date(2024, 12, 25)],
+            checkpoints: HashMap::from([(
+                "start".to_string(),
+                jiff::Timestamp::from_millisecond(1_705_312_800_000).unwrap()
+            )]),
+            reference: events::UnionUuidLong::Long(7),
+            ..Default::default()
+        }
+    );
+}
+
+/// Without a time crate, the dates and the local timestamps are their Avro integers
+#[test]
+fn round_trip_std_logical_types() {
+    use round_trip_std::com::example::events;
+    use std::time::{Duration, UNIX_EPOCH};
+    assert_round_trip!(
+        events::Event,
+        events::Event {
+            opening: Duration::from_millis(30_600_250),
+            updated: Some(UNIX_EPOCH + Duration::from_micros(1_705_312_800_123_456)),
+            local_updated: 1_705_312_800_654_321,
+            holidays: vec![20082],
+            checkpoints: HashMap::from([(
+                "start".to_string(),
+                UNIX_EPOCH + Duration::from_millis(1_705_312_800_000)
+            )]),
+            reference: events::UnionUuidLong::Long(7),
+            ..Default::default()
+        }
+    );
+}
+
+#[test]
+fn round_trip_bytes() {
+    assert_round_trip!(
+        devices::Device,
+        devices::Device {
+            firmware: vec![0x00, 0xff, 0x10],
+            empty_bytes: Vec::new(),
+            reading: devices::UnionStateDouble::State(devices::State::On),
+            ..Default::default()
+        }
+    );
+}
+
+/// The bytes written with an older writer schema are read, the missing fields get their default values.
+/// The schema resolution of apache_avro reads the default of `mac` as UTF-8, the writer schema has the field
+#[test]
+fn decode_confluent_bytes_with_writer_schema() {
+    let writer_schema = apache_avro::Schema::parse_str(
+        r#"{"type": "record", "name": "Device", "namespace": "com.example.devices", "fields": [
+            {"name": "firmware", "type": "bytes"},
+            {"name": "mac", "type": {"type": "fixed", "name": "Mac", "size": 6}},
+            {"name": "reading", "type": "double"}
+        ]}"#,
+    )
+    .unwrap();
+    let value = apache_avro::types::Value::Record(vec![
+        (
+            "firmware".to_string(),
+            apache_avro::types::Value::Bytes(vec![0x02, 0x00]),
+        ),
+        (
+            "mac".to_string(),
+            apache_avro::types::Value::Fixed(6, vec![1, 2, 3, 4, 5, 6]),
+        ),
+        (
+            "reading".to_string(),
+            apache_avro::types::Value::Double(21.5),
+        ),
+    ]);
+    let mut bytes = vec![0, 0, 0, 0, 9];
+    bytes.append(&mut apache_avro::to_avro_datum(&writer_schema, value).unwrap());
+
+    let resolver = HashMap::from([(9, writer_schema)]);
+    let device = devices::Device::from_confluent_bytes(&bytes, &resolver)
+        .expect("The record must be decoded");
+    assert_eq!(
+        device,
+        devices::Device {
+            firmware: vec![0x02, 0x00],
+            mac: devices::Mac([1, 2, 3, 4, 5, 6]),
+            reading: devices::UnionStateDouble::Double(21.5),
+            ..Default::default()
+        }
+    );
+}
+
+#[test]
+fn round_trip_defaults() {
+    let device = devices::Device::default();
+    assert_eq!(device.state, devices::State::Standby);
+    assert_eq!(
+        device.reading,
+        devices::UnionStateDouble::State(devices::State::Off)
+    );
+
+    let service = config::Service::default();
+    assert_eq!(service.retry.max_attempts, 5);
+    assert_eq!(service.policies.len(), 1);
+    assert_round_trip!(config::Service, service);
+}
+
+/// The embedded schema is the original definition, without the default of the date that apache_avro rejects
+#[test]
+fn embedded_schema_json() {
+    let source = std::fs::read_to_string("test_schemas/logical_types.avsc").unwrap();
+    let mut original: serde_json::Value = serde_json::from_str(&source).unwrap();
+    let day = original["fields"]
+        .as_array_mut()
+        .unwrap()
+        .iter_mut()
+        .find(|field| field["name"] == "day")
+        .unwrap();
+    assert_eq!(
+        day.as_object_mut().unwrap().shift_remove("default"),
+        Some(19737.into())
+    );
+
+    let embedded: serde_json::Value = serde_json::from_str(events::Event::SCHEMA_JSON).unwrap();
+    assert_eq!(embedded, original);
+    assert_eq!(
+        events::Event::default().day,
+        chrono::NaiveDate::from_ymd_opt(2024, 1, 15).unwrap()
+    );
+}
+
+#[test]
+fn round_trip_fixed() {
+    let checksum = files::Md5([7; 16]);
+    assert_round_trip!(
+        files::Download,
+        files::Download {
+            path: "file.txt".to_string(),
+            checksum,
+            signature: generated::com::example::crypto::Signature([1; 64]),
+            previous_checksum: Some(checksum),
+            chunk_checksums: vec![checksum],
+            mirror_checksums: HashMap::from([("mirror".to_string(), checksum)]),
+        }
+    );
+}
+
+/// Payload of the Avro specification, encoded by hand: the single-object header with the CRC-64-AVRO fingerprint
+/// of the Parsing Canonical Form in little endian, then the binary encoding of the record.
+/// The fingerprint is the one of the Rabin implementation of apache_avro, for the canonical form written by hand
+/// with the rules of the specification: full names, no namespace, doc, default or logical type, and the keys in order
+#[test]
+fn decode_specification_single_object() {
+    use sha2::Digest;
+
+    let canonical_form = concat!(
+        r#"{"name":"com.example.billing.Subscription","type":"record","fields":["#,
+        r#"{"name":"plan","type":"string"},"#,
+        r#"{"name":"period","type":{"name":"com.example.billing.Period","type":"fixed","size":12}},"#,
+        r#"{"name":"trial","type":["null",{"name":"com.example.billing.Trial","type":"fixed","size":12}]},"#,
+        r#"{"name":"grace_periods","type":{"type":"array","items":{"name":"com.example.billing.Grace","type":"fixed","size":12}}},"#,
+        r#"{"name":"reminders","type":{"type":"map","values":{"name":"com.example.billing.Reminder","type":"fixed","size":12}}}"#,
+        "]}"
+    );
+    let fingerprint = apache_avro::rabin::Rabin::digest(canonical_form);
+
+    #[rustfmt::skip]
+    let payload: &[u8] = &[
+        // Marker and fingerprint
+        0xc3, 0x01, 0x4a, 0x07, 0xda, 0x76, 0xbc, 0x29, 0x47, 0xb0,
+        // plan: length 7 as a zigzag varint, then "monthly"
+        0x0e, b'm', b'o', b'n', b't', b'h', b'l', b'y',
+        // period: months, days and milliseconds as unsigned little endian integers
+        0x01, 0, 0, 0, 0x02, 0, 0, 0, 0x03, 0, 0, 0,
+        // trial: union branch 1 then the duration
+        0x02, 0, 0, 0, 0, 0x0e, 0, 0, 0, 0, 0, 0, 0,
+        // grace_periods: a block of 1 item then the end of the array
+        0x02, 0, 0, 0, 0, 0x03, 0, 0, 0, 0xdc, 0x05, 0, 0, 0x00,
+        // reminders: a block of 1 entry, the key "soon" then the value, then the end of the map
+        0x02, 0x08, b's', b'o', b'o', b'n', 0, 0, 0, 0, 0x01, 0, 0, 0, 0, 0, 0, 0, 0x00,
+    ];
+
+    assert_eq!(payload[..2], [0xc3, 0x01]);
+    assert_eq!(payload[2..10], fingerprint[..]);
+    assert_eq!(
+        billing::Subscription::FINGERPRINT_RABIN[..],
+        fingerprint[..]
+    );
+
+    let subscription =
+        billing::Subscription::from_single_object(payload).expect("The payload must be decoded");
+    assert_eq!(
+        subscription,
+        billing::Subscription {
+            plan: "monthly".to_string(),
+            period: Duration {
+                months: 1,
+                days: 2,
+                millis: 3
+            },
+            trial: Some(Duration {
+                months: 0,
+                days: 14,
+                millis: 0
+            }),
+            grace_periods: vec![Duration {
+                months: 0,
+                days: 3,
+                millis: 1500
+            }],
+            reminders: HashMap::from([(
+                "soon".to_string(),
+                Duration {
+                    months: 0,
+                    days: 1,
+                    millis: 0
+                }
+            )]),
+        }
+    );
+    assert_eq!(
+        subscription
+            .to_single_object()
+            .expect("The record must be encoded"),
+        payload
+    );
+}
+
+#[test]
+fn union_schemas() {
+    use apache_avro::AvroSchema;
+
+    assert_eq!(
+        devices::UnionLongString::get_schema(),
+        apache_avro::Schema::parse_str(r#"["long", "string"]"#).unwrap()
+    );
+    let apache_avro::Schema::Union(union_schema) =
+        generated::events::UnionStringLongSomeRecord::get_schema()
+    else {
+        panic!("The schema of an union enum must be an union");
+    };
+    assert!(matches!(
+        union_schema.variants(),
+        [
+            apache_avro::Schema::String,
+            apache_avro::Schema::Long,
+            apache_avro::Schema::Record(_)
+        ]
+    ));
+}